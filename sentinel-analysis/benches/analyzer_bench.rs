@@ -1,7 +1,13 @@
 use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use scoper::{
+    Rule, RulesRegistry, analyzer, rules_registry::create_default_registry, utilities::DebugLevel,
+};
 use std::fs;
 use std::sync::Arc;
-use scoper::{RulesRegistry, analyzer, utilities::DebugLevel};
 
 const SMALL_FILE: &str = r#"
 function test() {
@@ -106,10 +112,66 @@ fn bench_allocator_reuse(c: &mut Criterion) {
     group.finish();
 }
 
+const NOOP_RULES: [&str; 16] = [
+    "noop-1", "noop-2", "noop-3", "noop-4", "noop-5", "noop-6", "noop-7", "noop-8", "noop-9",
+    "noop-10", "noop-11", "noop-12", "noop-13", "noop-14", "noop-15", "noop-16",
+];
+
+/// Rule without any checks, to measure the cost of dispatching nodes to rules
+struct NoopRule(&'static str);
+
+impl Rule for NoopRule {
+    fn name(&self) -> &'static str {
+        self.0
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports nothing"
+    }
+}
+
+fn bench_rule_dispatch(c: &mut Criterion) {
+    let mut group = c.benchmark_group("rule_dispatch");
+
+    let source = include_str!("../test_files/large.ts");
+    let allocator = Allocator::default();
+    let parsed = Parser::new(&allocator, source, SourceType::ts()).parse();
+    let semantic = SemanticBuilder::new().build(&parsed.program);
+
+    // Per-node dispatch cost, without any work done by the rules themselves
+    for rule_count in [1, 4, 16] {
+        let mut registry = RulesRegistry::new();
+        for name in &NOOP_RULES[..rule_count] {
+            registry.register_rule(Box::new(NoopRule(name)));
+            registry.enable_rule(name);
+        }
+
+        group.bench_with_input(
+            BenchmarkId::new("noop_rules", rule_count),
+            &registry,
+            |b, registry| {
+                b.iter(|| registry.run_rules_with_metrics(black_box(&semantic), "large.ts", source))
+            },
+        );
+    }
+
+    // All built-in rules, as in a default run
+    let mut registry = create_default_registry();
+    for name in registry.get_registered_rules() {
+        registry.enable_rule(name);
+    }
+    group.bench_function("all_rules", |b| {
+        b.iter(|| registry.run_rules_with_metrics(black_box(&semantic), "large.ts", source))
+    });
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_file_analysis,
     bench_batch_sizes,
-    bench_allocator_reuse
+    bench_allocator_reuse,
+    bench_rule_dispatch
 );
 criterion_main!(benches);
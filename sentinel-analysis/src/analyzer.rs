@@ -1,18 +1,26 @@
-use crate::FileAnalysisResult;
 use crate::RuleDiagnostic;
-use crate::rules_registry::RulesRegistry;
+use crate::module_graph::import_sources;
+use crate::profiling;
+use crate::rules_registry::{
+    RULE_ERROR_ID, RegistryResolver, RulesRegistry, extract_position_info,
+};
+use crate::utilities::directives::FileDirectives;
+use crate::utilities::sfc::{PositionMapper, extract_script_block, is_sfc_path};
 use crate::utilities::{DebugLevel, log};
+use crate::{FileAnalysisResult, SkipReason};
 
 use oxc_allocator::Allocator;
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
-use oxc_span::SourceType;
+use oxc_span::{GetSpan, SourceType};
 
+use rayon::ThreadPool;
 use rayon::prelude::*;
 use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
 use std::time::{Duration, Instant};
 
 // Calculate optimal batch size based on available CPU cores
@@ -23,10 +31,63 @@ fn calculate_batch_size() -> usize {
     num_cpus * 2
 }
 
+/// Results of the batches analyzed so far, readable while the analysis is still running
+///
+/// Lets an interrupted run export what it has analyzed instead of losing all progress.
+#[derive(Default)]
+pub struct PartialResults {
+    /// Results of each finished batch, with the index of the batch
+    batches: Mutex<Vec<(usize, Vec<FileAnalysisResult>)>>,
+    /// Called with the results of each finished batch, before they are stored
+    listener: Option<BatchListener>,
+}
+
+/// Callback receiving the results of each batch as soon as it is analyzed
+pub type BatchListener = Box<dyn Fn(&[FileAnalysisResult]) + Send + Sync>;
+
+impl PartialResults {
+    /// Collect results, also passing each finished batch to `listener`
+    ///
+    /// Batches finish in parallel, so the listener sees them in completion order rather
+    /// than in the order of the input files, and may be called from several threads.
+    pub fn with_listener(listener: impl Fn(&[FileAnalysisResult]) + Send + Sync + 'static) -> Self {
+        Self {
+            batches: Mutex::default(),
+            listener: Some(Box::new(listener)),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<(usize, Vec<FileAnalysisResult>)>> {
+        // A panicking batch must not make the other results unreachable
+        self.batches.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn push(&self, batch_index: usize, results: Vec<FileAnalysisResult>) {
+        if let Some(listener) = &self.listener {
+            listener(&results);
+        }
+        self.lock().push((batch_index, results));
+    }
+
+    /// Remove and return the results collected so far, in the order of the input files
+    pub fn take(&self) -> Vec<FileAnalysisResult> {
+        let mut batches = std::mem::take(&mut *self.lock());
+        batches.sort_unstable_by_key(|(batch_index, _)| *batch_index);
+        batches
+            .into_iter()
+            .flat_map(|(_, results)| results)
+            .collect()
+    }
+}
+
 /// Holds shared resources for batch processing
 struct BatchProcessor {
     allocator: Allocator,
-    rules_registry: Arc<RulesRegistry>,
+    resolver: Arc<RegistryResolver>,
+    /// Bounded pool for reading files, `None` to read on the analysis pool
+    read_pool: Option<Arc<ThreadPool>>,
+    /// Record the import sources of each file, for the import graph export
+    collect_imports: bool,
     debug_level: DebugLevel,
 }
 
@@ -34,45 +95,87 @@ struct BatchProcessor {
 struct FileContent {
     content: String,
     source_type: Option<SourceType>,
+    /// Set for single-file components, whose script block is analyzed on its own
+    position_mapper: Option<PositionMapper>,
 }
 
+/// A file's content, or why it could not be read
+type ReadResult = Result<FileContent, (SkipReason, String)>;
+
+/// Rule ID of the finding reported for a file that could not be read
+pub const IO_ERROR_ID: &str = "io-error";
+
 impl BatchProcessor {
-    fn new(rules_registry: Arc<RulesRegistry>, debug_level: DebugLevel) -> Self {
+    fn new(
+        resolver: Arc<RegistryResolver>,
+        read_pool: Option<Arc<ThreadPool>>,
+        collect_imports: bool,
+        debug_level: DebugLevel,
+    ) -> Self {
         // Initialize with a larger capacity for reuse
         let allocator = Allocator::with_capacity(1024 * 1024); // 1MB initial capacity
         Self {
             allocator,
-            rules_registry,
+            resolver,
+            read_pool,
+            collect_imports,
             debug_level,
         }
     }
 
-    // Pre-load file contents in parallel
-    fn preload_files(files: &[String]) -> Vec<(String, Result<FileContent, String>)> {
+    // Pre-load file contents in parallel, on the read pool when reads are throttled
+    fn preload_files(&self, files: &[String]) -> Vec<(String, ReadResult)> {
+        match &self.read_pool {
+            Some(pool) => pool.install(|| Self::read_files(files)),
+            None => Self::read_files(files),
+        }
+    }
+
+    fn read_files(files: &[String]) -> Vec<(String, ReadResult)> {
         files
             .par_iter()
             .map(|file_path| {
                 let content = match fs::read(file_path) {
                     Ok(bytes) => match String::from_utf8(bytes) {
+                        Ok(content) if is_sfc_path(file_path) => Ok(Self::script_content(&content)),
                         Ok(content) => {
                             let source_type = SourceType::from_path(Path::new(file_path)).ok();
                             Ok(FileContent {
                                 content,
                                 source_type,
+                                position_mapper: None,
                             })
                         }
-                        Err(_) => Err("UTF-8 conversion failed".to_string()),
+                        Err(_) => {
+                            Err((SkipReason::Encoding, "UTF-8 conversion failed".to_string()))
+                        }
                     },
-                    Err(err) => Err(err.to_string()),
+                    Err(err) => Err((SkipReason::ReadError, err.to_string())),
                 };
                 (file_path.clone(), content)
             })
             .collect()
     }
 
+    // Extract the script block of a `.vue`/`.svelte` file; files without one analyze as empty
+    fn script_content(source: &str) -> FileContent {
+        match extract_script_block(source) {
+            Some(block) => FileContent {
+                source_type: Some(block.source_type()),
+                position_mapper: Some(PositionMapper::new(source, block.offset)),
+                content: block.content,
+            },
+            None => FileContent {
+                content: String::new(),
+                source_type: Some(SourceType::mjs()),
+                position_mapper: None,
+            },
+        }
+    }
+
     fn process_batch(&mut self, files: &[String]) -> Vec<FileAnalysisResult> {
         // Pre-load all files in parallel
-        let preloaded_files = Self::preload_files(files);
+        let preloaded_files = profiling::in_span("read", || self.preload_files(files));
 
         // Process preloaded files sequentially to reuse allocator
         preloaded_files
@@ -80,7 +183,7 @@ impl BatchProcessor {
             .map(|(file_path, content)| {
                 let result = match content {
                     Ok(file_content) => self.analyze_preloaded_file(file_path, file_content),
-                    Err(err) => self.create_error_result(file_path, err),
+                    Err((reason, err)) => self.create_error_result(file_path, *reason, err),
                 };
                 // Reset allocator for next file
                 self.allocator.reset();
@@ -94,97 +197,279 @@ impl BatchProcessor {
         file_path: &str,
         content: &FileContent,
     ) -> FileAnalysisResult {
-        let file_start = Instant::now();
-
-        // Parse file
-        let parse_start = Instant::now();
+        // Resolve the source type detected while preloading
         let source_type = match content.source_type {
             Some(st) => st,
-            None => return self.create_error_result(file_path, "Invalid source type"),
-        };
-
-        let parse_result = Parser::new(&self.allocator, &content.content, source_type).parse();
-        if !parse_result.errors.is_empty() {
-            log(
-                DebugLevel::Error,
-                self.debug_level,
-                &format!(
-                    "Parse errors in {}: {}",
+            None => {
+                return self.create_error_result(
                     file_path,
-                    parse_result.errors.len()
-                ),
-            );
-
-            let parser_diagnostics = parse_result
-                .errors
-                .into_iter()
-                .map(|err| RuleDiagnostic {
-                    rule_id: "parser".to_string(),
-                    diagnostic: err,
-                    source_code: content.content.clone(),
-                    line_number: 0,
-                    column_number: 0,
-                })
-                .collect();
-
-            return FileAnalysisResult {
-                file_path: file_path.to_string(),
-                parse_duration: parse_start.elapsed(),
-                semantic_duration: Duration::from_secs(0),
-                rule_durations: HashMap::new(),
-                total_duration: file_start.elapsed(),
-                diagnostics: parser_diagnostics,
-            };
-        }
-
-        let parse_duration = parse_start.elapsed();
-
-        // Semantic analysis
-        let semantic_start = Instant::now();
-        let semantic_result = SemanticBuilder::new().build(&parse_result.program);
-        let semantic_duration = semantic_start.elapsed();
+                    SkipReason::UnsupportedFileType,
+                    "Invalid source type",
+                );
+            }
+        };
 
-        // Run rules
-        let (diagnostics, rule_durations) = self.rules_registry.run_rules_with_metrics(
-            &semantic_result,
+        let rules_registry = self.resolver.registry_for(file_path);
+        let mut result = analyze_with_allocator(
+            &self.allocator,
             file_path,
             &content.content,
+            source_type,
+            &rules_registry,
+            self.collect_imports,
+            self.debug_level,
         );
 
-        FileAnalysisResult {
-            file_path: file_path.to_string(),
-            parse_duration,
-            semantic_duration,
-            rule_durations,
-            total_duration: file_start.elapsed(),
-            diagnostics,
+        // Report script block positions relative to the original file
+        if let Some(mapper) = &content.position_mapper {
+            for diagnostic in &mut result.diagnostics {
+                (diagnostic.line_number, diagnostic.column_number) =
+                    mapper.map(diagnostic.line_number, diagnostic.column_number);
+            }
         }
+        result
     }
 
-    fn create_error_result(&self, file_path: &str, error_msg: &str) -> FileAnalysisResult {
+    fn create_error_result(
+        &self,
+        file_path: &str,
+        reason: SkipReason,
+        error_msg: &str,
+    ) -> FileAnalysisResult {
         log(
             DebugLevel::Error,
             self.debug_level,
             &format!("Error processing {}: {}", file_path, error_msg),
         );
 
+        // An unreadable file must not look clean in the findings
+        let diagnostics = match reason {
+            SkipReason::ReadError => vec![RuleDiagnostic {
+                rule_id: IO_ERROR_ID.to_string(),
+                diagnostic: OxcDiagnostic::error(format!("Could not read file: {}", error_msg))
+                    .with_help(
+                        "Check that the file exists and is readable, then run the analysis again",
+                    ),
+                source_code: String::new(),
+                line_number: 0,
+                column_number: 0,
+            }],
+            _ => Vec::new(),
+        };
+
         FileAnalysisResult {
             file_path: file_path.to_string(),
             parse_duration: Duration::from_secs(0),
             semantic_duration: Duration::from_secs(0),
             rule_durations: HashMap::new(),
             total_duration: Duration::from_secs(0),
+            diagnostics,
+            imports: Vec::new(),
+            skip_reason: Some(reason),
+        }
+    }
+}
+
+/// Parse a source text, build its semantic model and run all enabled rules on it
+///
+/// Import sources are only recorded with `collect_imports`, since only the import graph
+/// export reads them.
+fn analyze_with_allocator(
+    allocator: &Allocator,
+    file_path: &str,
+    source: &str,
+    source_type: SourceType,
+    rules_registry: &RulesRegistry,
+    collect_imports: bool,
+    debug_level: DebugLevel,
+) -> FileAnalysisResult {
+    let _span = profiling::span("file");
+    let file_start = Instant::now();
+
+    if rules_registry.is_prefilter() && !rules_registry.any_rule_applies_to(source) {
+        log(
+            DebugLevel::Debug,
+            debug_level,
+            &format!("Skipping {}: no enabled rule applies to it", file_path),
+        );
+        return FileAnalysisResult {
+            file_path: file_path.to_string(),
+            parse_duration: Duration::from_secs(0),
+            semantic_duration: Duration::from_secs(0),
+            rule_durations: HashMap::new(),
+            total_duration: file_start.elapsed(),
             diagnostics: Vec::new(),
+            imports: Vec::new(),
+            skip_reason: Some(SkipReason::NoApplicableRules),
+        };
+    }
+
+    let parse_start = Instant::now();
+    let parse_result = profiling::in_span("parse", || {
+        Parser::new(allocator, source, source_type).parse()
+    });
+    let parse_duration = parse_start.elapsed();
+    let imports = if collect_imports {
+        import_sources(&parse_result.program)
+    } else {
+        Vec::new()
+    };
+
+    let mut diagnostics: Vec<RuleDiagnostic> = parse_result
+        .errors
+        .into_iter()
+        .map(|err| {
+            let (line, column) =
+                extract_position_info(&err.clone().with_source_code(source.to_string()));
+            RuleDiagnostic {
+                rule_id: "parser".to_string(),
+                diagnostic: err,
+                source_code: source.to_string(),
+                line_number: line,
+                column_number: column,
+            }
+        })
+        .collect();
+
+    if !diagnostics.is_empty() {
+        log(
+            DebugLevel::Error,
+            debug_level,
+            &format!("Parse errors in {}: {}", file_path, diagnostics.len()),
+        );
+
+        // A panicked parser leaves an empty program, so there is nothing to run rules on
+        if parse_result.panicked || !rules_registry.is_continue_on_parse_error() {
+            return FileAnalysisResult {
+                file_path: file_path.to_string(),
+                parse_duration,
+                semantic_duration: Duration::from_secs(0),
+                rule_durations: HashMap::new(),
+                total_duration: file_start.elapsed(),
+                diagnostics,
+                imports,
+                skip_reason: None,
+            };
         }
     }
+
+    // Semantic analysis
+    let semantic_start = Instant::now();
+    let semantic_result = profiling::in_span("semantic", || {
+        SemanticBuilder::new().build(&parse_result.program)
+    });
+    let semantic_duration = semantic_start.elapsed();
+
+    // Run rules
+    let (rule_diagnostics, rule_durations) = profiling::in_span("rules", || {
+        rules_registry.run_rules_with_metrics(&semantic_result, file_path, source)
+    });
+    for rule_error in rule_diagnostics
+        .iter()
+        .filter(|rule_diagnostic| rule_diagnostic.rule_id == RULE_ERROR_ID)
+    {
+        log(
+            DebugLevel::Error,
+            debug_level,
+            &format!("{} in {}", rule_error.diagnostic.message, file_path),
+        );
+    }
+
+    // Drop findings of rules turned off by `sentinel-disable` comments
+    let program = &parse_result.program;
+    let code_start = program
+        .body
+        .first()
+        .map_or(program.span.end, |statement| statement.span().start);
+    let directives = FileDirectives::parse(semantic_result.semantic.comments(), source, code_start);
+    diagnostics.extend(rule_diagnostics.into_iter().filter(|rule_diagnostic| {
+        directives.is_empty()
+            || !directives.suppresses(&rule_diagnostic.rule_id, &rule_diagnostic.diagnostic)
+    }));
+
+    FileAnalysisResult {
+        file_path: file_path.to_string(),
+        parse_duration,
+        semantic_duration,
+        rule_durations,
+        total_duration: file_start.elapsed(),
+        diagnostics,
+        imports,
+        skip_reason: None,
+    }
+}
+
+/// Analyze an in-memory source text as if it were the file at `file_path`
+///
+/// The source type is derived from the file extension, falling back to TypeScript.
+/// Intended for tests and tooling that work on snippets rather than files on disk.
+pub fn analyze_source(
+    file_path: &str,
+    source: &str,
+    rules_registry: &RulesRegistry,
+) -> FileAnalysisResult {
+    let allocator = Allocator::default();
+    let source_type = SourceType::from_path(Path::new(file_path))
+        .unwrap_or_else(|_| SourceType::default().with_typescript(true));
+
+    analyze_with_allocator(
+        &allocator,
+        file_path,
+        source,
+        source_type,
+        rules_registry,
+        true,
+        DebugLevel::None,
+    )
 }
 
 /// Process files in parallel using rayon with optimized batch processing
+///
+/// Import sources are not collected; see [`process_files_with_resolver`].
 pub fn process_files(
     files: &[String],
     rules_registry_arc: &Arc<RulesRegistry>,
     debug_level: DebugLevel,
 ) -> (Vec<FileAnalysisResult>, Duration) {
+    let resolver = Arc::new(RegistryResolver::single(Arc::clone(rules_registry_arc)));
+    process_files_with_resolver(files, &resolver, None, false, debug_level)
+}
+
+/// Process files in parallel, resolving the rules registry of each file through `resolver`
+///
+/// `read_concurrency` caps how many files are read at the same time, independently of
+/// the number of analysis threads. This keeps slow network filesystems from being
+/// flooded with concurrent reads. The `imports` of the results are only filled in with
+/// `collect_imports`, for building the import graph.
+pub fn process_files_with_resolver(
+    files: &[String],
+    resolver: &Arc<RegistryResolver>,
+    read_concurrency: Option<usize>,
+    collect_imports: bool,
+    debug_level: DebugLevel,
+) -> (Vec<FileAnalysisResult>, Duration) {
+    let partial_results = PartialResults::default();
+    let analysis_duration = process_files_into(
+        files,
+        resolver,
+        read_concurrency,
+        collect_imports,
+        &partial_results,
+        debug_level,
+    );
+    (partial_results.take(), analysis_duration)
+}
+
+/// Process files like [`process_files_with_resolver`], adding the results of each
+/// batch to `partial_results` as soon as it is done
+pub fn process_files_into(
+    files: &[String],
+    resolver: &Arc<RegistryResolver>,
+    read_concurrency: Option<usize>,
+    collect_imports: bool,
+    partial_results: &PartialResults,
+    debug_level: DebugLevel,
+) -> Duration {
     let analysis_start = Instant::now();
     let batch_size = calculate_batch_size();
 
@@ -192,19 +477,30 @@ pub fn process_files(
     let thread_pool = rayon::ThreadPoolBuilder::new()
         .build()
         .expect("Failed to create thread pool");
+    let read_pool = read_concurrency.map(|threads| {
+        Arc::new(
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .expect("Failed to create read thread pool"),
+        )
+    });
 
-    let analysis_results: Vec<FileAnalysisResult> = thread_pool.install(|| {
+    thread_pool.install(|| {
         files
             .par_chunks(batch_size)
-            .map(|batch| {
-                let mut processor =
-                    BatchProcessor::new(Arc::clone(rules_registry_arc), debug_level);
-                processor.process_batch(batch)
-            })
-            .flatten()
-            .collect()
+            .enumerate()
+            .for_each(|(batch_index, batch)| {
+                let _span = profiling::span("batch");
+                let mut processor = BatchProcessor::new(
+                    Arc::clone(resolver),
+                    read_pool.clone(),
+                    collect_imports,
+                    debug_level,
+                );
+                partial_results.push(batch_index, processor.process_batch(batch));
+            });
     });
 
-    let analysis_duration = analysis_start.elapsed();
-    (analysis_results, analysis_duration)
+    analysis_start.elapsed()
 }
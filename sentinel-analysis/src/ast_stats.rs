@@ -0,0 +1,210 @@
+use crate::error::SentinelError;
+use crate::utilities::sfc::{extract_script_block, is_sfc_path};
+use crate::utilities::{DebugLevel, log};
+
+use oxc_allocator::Allocator;
+use oxc_ast::AstType;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use tabled::{builder::Builder, settings::Style};
+
+/// How `--ast-stats` prints the node kind distribution
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum AstStatsFormat {
+    /// Table sorted by the number of nodes
+    #[default]
+    Table,
+    /// JSON document of [`AstStats`]
+    Json,
+}
+
+impl FromStr for AstStatsFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(AstStatsFormat::Table),
+            "json" => Ok(AstStatsFormat::Json),
+            _ => Err(format!(
+                "Invalid AST stats format '{}', expected table or json",
+                s
+            )),
+        }
+    }
+}
+
+/// Occurrences of one AST node kind
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct NodeKindStats {
+    /// Number of nodes of this kind in all files
+    pub count: usize,
+    /// Number of files with at least one node of this kind
+    pub files: usize,
+}
+
+/// Distribution of the AST node kinds of the analyzed files
+///
+/// Kinds are the `AstKind` variants that `Rule::run_on_node` receives, as printed by
+/// `print-ast`. Kinds that appear in no file are left out.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct AstStats {
+    /// Number of files parsed
+    pub files: usize,
+    /// Number of files that could not be read or have no parser source type
+    pub skipped_files: usize,
+    /// Number of nodes of all kinds
+    pub nodes: usize,
+    /// Occurrences by node kind
+    pub kinds: BTreeMap<String, NodeKindStats>,
+}
+
+/// Node kind counts of some files, merged across threads before naming the kinds
+#[derive(Default)]
+struct Tally {
+    files: usize,
+    skipped_files: usize,
+    kinds: HashMap<AstType, NodeKindStats>,
+}
+
+impl Tally {
+    fn add_file(mut self, counts: Option<HashMap<AstType, usize>>) -> Self {
+        let Some(counts) = counts else {
+            self.skipped_files += 1;
+            return self;
+        };
+        self.files += 1;
+        for (kind, count) in counts {
+            let stats = self.kinds.entry(kind).or_default();
+            stats.count += count;
+            stats.files += 1;
+        }
+        self
+    }
+
+    fn merge(mut self, other: Tally) -> Self {
+        self.files += other.files;
+        self.skipped_files += other.skipped_files;
+        for (kind, other_stats) in other.kinds {
+            let stats = self.kinds.entry(kind).or_default();
+            stats.count += other_stats.count;
+            stats.files += other_stats.files;
+        }
+        self
+    }
+
+    fn into_stats(self) -> AstStats {
+        let kinds: BTreeMap<String, NodeKindStats> = self
+            .kinds
+            .into_iter()
+            .map(|(kind, stats)| (format!("{:?}", kind), stats))
+            .collect();
+        AstStats {
+            files: self.files,
+            skipped_files: self.skipped_files,
+            nodes: kinds.values().map(|stats| stats.count).sum(),
+            kinds,
+        }
+    }
+}
+
+/// Count the nodes of each kind in a source text, `None` without a parser source type
+///
+/// Only the `<script>` block of `.vue` and `.svelte` files is parsed. Like in the
+/// analysis, the parser's recovered AST is counted for files with parse errors.
+pub fn count_node_kinds(file_path: &str, source: &str) -> Option<HashMap<AstType, usize>> {
+    let script;
+    let (source, source_type) = if is_sfc_path(file_path) {
+        match extract_script_block(source) {
+            Some(block) => {
+                let source_type = block.source_type();
+                script = block.content;
+                (script.as_str(), source_type)
+            }
+            None => return Some(HashMap::new()),
+        }
+    } else {
+        (source, SourceType::from_path(Path::new(file_path)).ok()?)
+    };
+
+    let allocator = Allocator::default();
+    let parse_result = Parser::new(&allocator, source, source_type).parse();
+    let semantic = SemanticBuilder::new().build(&parse_result.program).semantic;
+
+    let mut counts = HashMap::new();
+    for node in semantic.nodes().iter() {
+        *counts.entry(node.kind().ty()).or_insert(0) += 1;
+    }
+    Some(counts)
+}
+
+/// Tally the AST node kinds of all files in parallel, without running any rule
+pub fn collect_ast_stats(files: &[String], debug_level: DebugLevel) -> AstStats {
+    files
+        .par_iter()
+        .map(|file_path| {
+            let source = match fs::read_to_string(file_path) {
+                Ok(source) => source,
+                Err(e) => {
+                    log(
+                        DebugLevel::Warn,
+                        debug_level,
+                        &format!("Skipping {}: {}", file_path, e),
+                    );
+                    return None;
+                }
+            };
+            count_node_kinds(file_path, &source)
+        })
+        .fold(Tally::default, Tally::add_file)
+        .reduce(Tally::default, Tally::merge)
+        .into_stats()
+}
+
+/// Render the stats as a table of node kinds, most frequent first
+pub fn render_ast_stats_table(stats: &AstStats) -> String {
+    let mut kinds: Vec<(&String, &NodeKindStats)> = stats.kinds.iter().collect();
+    kinds.sort_by(|(a_name, a), (b_name, b)| b.count.cmp(&a.count).then(a_name.cmp(b_name)));
+
+    let mut builder = Builder::new();
+    builder.push_record(["Node kind", "Count", "Share", "Files"]);
+    for (name, kind_stats) in kinds {
+        let share = kind_stats.count as f64 * 100.0 / stats.nodes.max(1) as f64;
+        builder.push_record([
+            name.clone(),
+            kind_stats.count.to_string(),
+            format!("{:.1}%", share),
+            kind_stats.files.to_string(),
+        ]);
+    }
+
+    let mut table = builder.build();
+    table.with(Style::ascii_rounded());
+    format!(
+        "{}\n{} nodes of {} kinds in {} files",
+        table,
+        stats.nodes,
+        stats.kinds.len(),
+        stats.files
+    )
+}
+
+/// Print the stats to stdout in the requested format
+pub fn print_ast_stats(stats: &AstStats, format: AstStatsFormat) -> Result<(), SentinelError> {
+    match format {
+        AstStatsFormat::Table => println!("{}", render_ast_stats_table(stats)),
+        AstStatsFormat::Json => {
+            let json = serde_json::to_string_pretty(stats)
+                .map_err(|e| SentinelError::serialization("AST stats", e))?;
+            println!("{}", json);
+        }
+    }
+    Ok(())
+}
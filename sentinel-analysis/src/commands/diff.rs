@@ -0,0 +1,145 @@
+use crate::commands::load_findings;
+use crate::error::SentinelError;
+use crate::exporter::{FindingEntry, FindingsExport};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use tabled::{
+    builder::Builder,
+    settings::{Alignment, Style, object::Columns},
+};
+
+/// Per-rule change in the number of findings between two runs
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RuleDelta {
+    pub rule: String,
+    pub old_count: usize,
+    pub new_count: usize,
+    pub delta: i64,
+}
+
+/// Result of comparing two findings exports
+#[derive(Serialize, Deserialize, Clone)]
+pub struct FindingsDiff {
+    /// Findings present in the new export but not in the old one
+    pub added: Vec<FindingEntry>,
+    /// Findings present in the old export but not in the new one
+    pub removed: Vec<FindingEntry>,
+    /// Per-rule finding counts, sorted by rule name
+    pub rule_deltas: Vec<RuleDelta>,
+}
+
+/// Key used to match findings across runs, independent of line numbers
+fn finding_key(finding: &FindingEntry) -> (&str, &str, &str) {
+    (&finding.rule, &finding.file, &finding.message)
+}
+
+/// Return the findings of `findings` that are not matched by an entry in `baseline`
+///
+/// Findings are compared as a multiset, so two identical findings in the same
+/// file only match two findings in the baseline.
+fn unmatched(findings: &[FindingEntry], baseline: &[FindingEntry]) -> Vec<FindingEntry> {
+    let mut remaining: HashMap<(&str, &str, &str), usize> = HashMap::new();
+    for finding in baseline {
+        *remaining.entry(finding_key(finding)).or_insert(0) += 1;
+    }
+
+    findings
+        .iter()
+        .filter(|finding| match remaining.get_mut(&finding_key(finding)) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                false
+            }
+            _ => true,
+        })
+        .cloned()
+        .collect()
+}
+
+/// Compare two findings exports keyed by `(rule, file, message)`
+pub fn diff_findings(old: &FindingsExport, new: &FindingsExport) -> FindingsDiff {
+    let mut counts: BTreeMap<&str, (usize, usize)> = BTreeMap::new();
+    for finding in &old.findings {
+        counts.entry(&finding.rule).or_default().0 += 1;
+    }
+    for finding in &new.findings {
+        counts.entry(&finding.rule).or_default().1 += 1;
+    }
+
+    let rule_deltas = counts
+        .into_iter()
+        .map(|(rule, (old_count, new_count))| RuleDelta {
+            rule: rule.to_string(),
+            old_count,
+            new_count,
+            delta: new_count as i64 - old_count as i64,
+        })
+        .collect();
+
+    FindingsDiff {
+        added: unmatched(&new.findings, &old.findings),
+        removed: unmatched(&old.findings, &new.findings),
+        rule_deltas,
+    }
+}
+
+/// Print a findings diff in human-readable form
+fn print_diff(diff: &FindingsDiff) {
+    println!("\nAdded findings: {}", diff.added.len());
+    for finding in &diff.added {
+        println!(
+            "  + [{}] {}:{}:{} {}",
+            finding.rule, finding.file, finding.line, finding.column, finding.message
+        );
+    }
+
+    println!("\nRemoved findings: {}", diff.removed.len());
+    for finding in &diff.removed {
+        println!(
+            "  - [{}] {}:{}:{} {}",
+            finding.rule, finding.file, finding.line, finding.column, finding.message
+        );
+    }
+
+    println!("\nPer-rule delta:");
+    let mut builder = Builder::new();
+    builder.push_record(["Rule", "Old", "New", "Delta"]);
+    for delta in &diff.rule_deltas {
+        builder.push_record([
+            delta.rule.clone(),
+            delta.old_count.to_string(),
+            delta.new_count.to_string(),
+            format!("{:+}", delta.delta),
+        ]);
+    }
+
+    let mut table = builder.build();
+    table
+        .with(Style::ascii_rounded())
+        .modify(Columns::new(1..), Alignment::right());
+    println!("{}", table);
+}
+
+/// Run the `diff` subcommand, printing the result in the requested format
+pub fn run(old_path: &str, new_path: &str, format: &str) -> Result<(), SentinelError> {
+    let old = load_findings(old_path)?;
+    let new = load_findings(new_path)?;
+    let diff = diff_findings(&old, &new);
+
+    match format {
+        "json" => {
+            let json = serde_json::to_string_pretty(&diff)
+                .map_err(|e| SentinelError::serialization("diff", e))?;
+            println!("{}", json);
+        }
+        "text" => print_diff(&diff),
+        other => {
+            return Err(SentinelError::ConfigValidation(format!(
+                "unsupported diff format '{}'",
+                other
+            )));
+        }
+    }
+
+    Ok(())
+}
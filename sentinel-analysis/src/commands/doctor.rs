@@ -0,0 +1,259 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use reqwest::blocking::Client;
+
+use crate::exporter::STDOUT_OUTPUT;
+use crate::rules_registry::{create_default_registry, load_rule_config};
+use crate::utilities::config::{Config, DEFAULT_API_URL, DEFAULT_OUTPUT_DIR};
+
+/// How long to wait for the API endpoint before reporting it as unreachable
+const API_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Outcome of a single environment check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    Pass,
+    /// Something looks off, but an analysis run can still succeed
+    Warn,
+    /// An analysis run is expected to fail
+    Fail,
+}
+
+impl CheckStatus {
+    fn label(self) -> &'static str {
+        match self {
+            CheckStatus::Pass => "PASS",
+            CheckStatus::Warn => "WARN",
+            CheckStatus::Fail => "FAIL",
+        }
+    }
+}
+
+/// Result of one `scoper doctor` check
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub name: &'static str,
+    pub status: CheckStatus,
+    pub detail: String,
+}
+
+impl Check {
+    fn new(name: &'static str, status: CheckStatus, detail: impl Into<String>) -> Self {
+        Check {
+            name,
+            status,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// Load the configuration an analysis run would use
+///
+/// Returns the default configuration when no file is found, since a run falls back to
+/// it as well.
+fn check_config(config_path: Option<&str>) -> (Check, Config) {
+    const NAME: &str = "Config file";
+
+    let path = match config_path {
+        Some(path) => Some(PathBuf::from(path)),
+        None => Config::find_config_file(),
+    };
+    let Some(path) = path else {
+        return (
+            Check::new(
+                NAME,
+                CheckStatus::Pass,
+                "no sentinel.json found, using defaults",
+            ),
+            Config::default(),
+        );
+    };
+
+    match Config::load_from_file(&path.to_string_lossy()) {
+        Ok(config) => (
+            Check::new(NAME, CheckStatus::Pass, path.display().to_string()),
+            config,
+        ),
+        Err(e) => (
+            Check::new(NAME, CheckStatus::Fail, e.to_string()),
+            Config::default(),
+        ),
+    }
+}
+
+/// Check that the rules configuration parses and only names registered rules
+fn check_rules_config(config: &Config) -> Check {
+    const NAME: &str = "Rules config";
+
+    // Same fallback as an analysis run: rules.json next to the executable
+    let path = config.rules_config.clone().or_else(|| {
+        std::env::current_exe()
+            .ok()
+            .and_then(|exe| exe.parent().map(|dir| dir.join("rules.json")))
+            .filter(|path| path.exists())
+            .map(|path| path.to_string_lossy().to_string())
+    });
+    let Some(path) = path else {
+        return Check::new(
+            NAME,
+            CheckStatus::Pass,
+            "not configured, using default rules",
+        );
+    };
+
+    let rules = match load_rule_config(&path) {
+        Ok(rules) => rules,
+        Err(e) => return Check::new(NAME, CheckStatus::Fail, e.to_string()),
+    };
+
+    let registry = create_default_registry();
+    let unknown: Vec<&str> = rules
+        .iter()
+        .map(|(name, _, _)| name.as_str())
+        .filter(|name| registry.get_rule(name).is_none())
+        .collect();
+    if unknown.is_empty() {
+        Check::new(
+            NAME,
+            CheckStatus::Pass,
+            format!("{} ({} rules)", path, rules.len()),
+        )
+    } else {
+        Check::new(
+            NAME,
+            CheckStatus::Warn,
+            format!("{}: unknown rules {}", path, unknown.join(", ")),
+        )
+    }
+}
+
+/// Check that files can be created in the output directory
+///
+/// A directory that does not exist yet is checked through its closest existing
+/// ancestor, without creating it.
+fn check_output_dir(config: &Config) -> Check {
+    const NAME: &str = "Output directory";
+
+    let output_dir = config.output_dir.as_deref().unwrap_or(DEFAULT_OUTPUT_DIR);
+    if output_dir == STDOUT_OUTPUT {
+        return Check::new(NAME, CheckStatus::Pass, "findings are printed to stdout");
+    }
+
+    let output_path = Path::new(output_dir);
+    let Some(existing) = output_path
+        .ancestors()
+        .map(|dir| {
+            if dir.as_os_str().is_empty() {
+                Path::new(".")
+            } else {
+                dir
+            }
+        })
+        .find(|dir| dir.exists())
+    else {
+        return Check::new(
+            NAME,
+            CheckStatus::Fail,
+            format!("{} cannot be created", output_dir),
+        );
+    };
+
+    if !existing.is_dir() {
+        return Check::new(
+            NAME,
+            CheckStatus::Fail,
+            format!("{} is not a directory", existing.display()),
+        );
+    }
+    match tempfile::tempfile_in(existing) {
+        Ok(_) => Check::new(
+            NAME,
+            CheckStatus::Pass,
+            format!("{} is writable", output_dir),
+        ),
+        Err(e) => Check::new(
+            NAME,
+            CheckStatus::Fail,
+            format!("cannot write to {}: {}", existing.display(), e),
+        ),
+    }
+}
+
+/// Check that the API endpoint answers at all
+///
+/// Any HTTP response counts, as endpoints commonly reject `HEAD`. An unreachable API
+/// only affects the submission after the analysis, so it is reported as a warning.
+fn check_api(config: &Config) -> Check {
+    const NAME: &str = "API endpoint";
+
+    let api_url = config.api_url.as_deref().unwrap_or(DEFAULT_API_URL);
+    let response = Client::builder()
+        .timeout(API_TIMEOUT)
+        .build()
+        .and_then(|client| client.head(api_url).send());
+    match response {
+        Ok(response) => Check::new(
+            NAME,
+            CheckStatus::Pass,
+            format!("{} ({})", api_url, response.status()),
+        ),
+        Err(e) => Check::new(
+            NAME,
+            CheckStatus::Warn,
+            format!("{} is unreachable: {}", api_url, e),
+        ),
+    }
+}
+
+/// Check that the built-in rules register
+fn check_registry() -> Check {
+    const NAME: &str = "Rules registry";
+
+    let count = create_default_registry().get_registered_rules().len();
+    if count == 0 {
+        Check::new(NAME, CheckStatus::Fail, "no rules are registered")
+    } else {
+        Check::new(
+            NAME,
+            CheckStatus::Pass,
+            format!("{} rules registered", count),
+        )
+    }
+}
+
+/// Run every environment check, in the order they are printed
+pub fn run_checks(config_path: Option<&str>) -> Vec<Check> {
+    let (config_check, config) = check_config(config_path);
+    vec![
+        config_check,
+        check_rules_config(&config),
+        check_output_dir(&config),
+        check_api(&config),
+        check_registry(),
+    ]
+}
+
+/// Print the result of every check and return whether none of them failed
+pub fn run(config_path: Option<&str>) -> bool {
+    let checks = run_checks(config_path);
+    for check in &checks {
+        println!(
+            "[{}] {}: {}",
+            check.status.label(),
+            check.name,
+            check.detail
+        );
+    }
+
+    let failed = checks
+        .iter()
+        .filter(|check| check.status == CheckStatus::Fail)
+        .count();
+    if failed == 0 {
+        println!("\nAll critical checks passed");
+    } else {
+        println!("\n{} critical check(s) failed", failed);
+    }
+    failed == 0
+}
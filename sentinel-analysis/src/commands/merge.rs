@@ -0,0 +1,110 @@
+use crate::commands::load_findings;
+use crate::error::SentinelError;
+use crate::exporter::{CoverageSummary, FindingsExport, FindingsSummary, top_files_by_findings};
+use std::collections::HashMap;
+
+/// Combine the findings of several shard exports into a single export
+///
+/// Finding counts and the `top_files` most violated files are recomputed from the merged
+/// findings. Shards run side by side, so durations take the slowest shard while files,
+/// cores and coverage are summed across shards.
+pub fn merge_findings(exports: Vec<FindingsExport>, top_files: usize) -> FindingsExport {
+    let mut findings = Vec::new();
+    let mut findings_by_rule: HashMap<String, usize> = HashMap::new();
+    let mut findings_by_severity: HashMap<String, usize> = HashMap::new();
+
+    let mut total_duration_ms = 0;
+    let mut scan_duration_ms = 0;
+    let mut analysis_duration_ms = 0;
+    let mut files_processed = 0;
+    let mut parallel_cores_used = 0;
+    let mut efficiency_sum = 0.0;
+    let mut coverage = CoverageSummary::default();
+    let shard_count = exports.len();
+
+    for export in exports {
+        let summary = export.summary;
+        total_duration_ms = total_duration_ms.max(summary.total_duration_ms);
+        scan_duration_ms = scan_duration_ms.max(summary.scan_duration_ms);
+        analysis_duration_ms = analysis_duration_ms.max(summary.analysis_duration_ms);
+        files_processed += summary.files_processed;
+        parallel_cores_used += summary.parallel_cores_used;
+        efficiency_sum += summary.parallel_efficiency_percent;
+
+        coverage.files_scanned += summary.coverage.files_scanned;
+        coverage.files_with_findings += summary.coverage.files_with_findings;
+        coverage.files_parse_errored += summary.coverage.files_parse_errored;
+        for (reason, count) in summary.coverage.files_skipped {
+            *coverage.files_skipped.entry(reason).or_insert(0) += count;
+        }
+
+        for finding in export.findings {
+            *findings_by_rule.entry(finding.rule.clone()).or_insert(0) += 1;
+            *findings_by_severity
+                .entry(finding.severity.clone())
+                .or_insert(0) += 1;
+            findings.push(finding);
+        }
+    }
+
+    let files_per_second_wall_time = if analysis_duration_ms > 0 {
+        files_processed as f64 / (analysis_duration_ms as f64 / 1000.0)
+    } else {
+        0.0
+    };
+
+    let parallel_efficiency_percent = if shard_count > 0 {
+        efficiency_sum / shard_count as f64
+    } else {
+        0.0
+    };
+
+    let findings_by_file_top = top_files_by_findings(&findings, top_files);
+
+    FindingsExport {
+        summary: FindingsSummary {
+            total_findings: findings.len(),
+            findings_by_rule,
+            findings_by_severity,
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            total_duration_ms,
+            files_processed,
+            files_per_second_wall_time,
+            parallel_cores_used,
+            parallel_efficiency_percent,
+            scan_duration_ms,
+            analysis_duration_ms,
+            coverage,
+            findings_by_file_top,
+        },
+        findings,
+    }
+}
+
+/// Merge shard findings files and write the combined export to `output_path`
+pub fn run(inputs: &[String], output_path: &str, top_files: usize) -> Result<(), SentinelError> {
+    let exports = inputs
+        .iter()
+        .map(|path| load_findings(path))
+        .collect::<Result<Vec<_>, _>>()?;
+    let merged = merge_findings(exports, top_files);
+
+    if let Some(parent) = std::path::Path::new(output_path).parent()
+        && !parent.as_os_str().is_empty()
+    {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| SentinelError::io("create directory", parent.display().to_string(), e))?;
+    }
+
+    let json = serde_json::to_string_pretty(&merged)
+        .map_err(|e| SentinelError::serialization("findings", e))?;
+    std::fs::write(output_path, json).map_err(|e| SentinelError::io("write", output_path, e))?;
+
+    println!(
+        "Merged {} findings from {} files into {}",
+        merged.summary.total_findings,
+        inputs.len(),
+        output_path
+    );
+    Ok(())
+}
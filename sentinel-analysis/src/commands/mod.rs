@@ -0,0 +1,17 @@
+// Implementations of the `scoper` subcommands
+pub mod diff;
+pub mod doctor;
+pub mod merge;
+pub mod print_ast;
+pub mod rules_catalog;
+pub mod test_rule;
+
+use crate::error::SentinelError;
+use crate::exporter::FindingsExport;
+
+/// Load a findings.json file produced by a previous run
+pub fn load_findings(path: &str) -> Result<FindingsExport, SentinelError> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| SentinelError::io("read findings file", path, e))?;
+    serde_json::from_str(&content).map_err(|e| SentinelError::parse(path, e))
+}
@@ -0,0 +1,70 @@
+use crate::error::SentinelError;
+use oxc_allocator::Allocator;
+use oxc_diagnostics::reporter::Info;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::{GetSpan, SourceType};
+use std::fmt::Write;
+use std::path::Path;
+
+/// 1-based line and column of a byte offset
+fn position(source: &str, offset: u32) -> (usize, usize) {
+    let before = &source[..offset as usize];
+    let line = before.matches('\n').count() + 1;
+    let column = before.len() - before.rfind('\n').map_or(0, |newline| newline + 1) + 1;
+    (line, column)
+}
+
+/// Render the AST of a source file as an indented tree of node kinds and spans
+///
+/// Nodes come from the semantic node table, so the output shows exactly the kinds
+/// that `Rule::run_on_node` receives. Parse errors are listed before the tree; the
+/// tree then shows what the parser recovered.
+pub fn render_ast(file_path: &str, source: &str) -> String {
+    let allocator = Allocator::default();
+    let source_type = SourceType::from_path(Path::new(file_path))
+        .unwrap_or_else(|_| SourceType::default().with_typescript(true));
+    let parse_result = Parser::new(&allocator, source, source_type).parse();
+
+    let mut output = String::new();
+    if !parse_result.errors.is_empty() {
+        let _ = writeln!(output, "Parse errors:");
+        for error in &parse_result.errors {
+            let info = Info::new(&error.clone().with_source_code(source.to_string()));
+            let _ = writeln!(
+                output,
+                "  {}:{}: {}",
+                info.start.line, info.start.column, error.message
+            );
+        }
+        let _ = writeln!(output);
+    }
+
+    let semantic = SemanticBuilder::new().build(&parse_result.program).semantic;
+    let nodes = semantic.nodes();
+    for node in nodes.iter() {
+        let depth = nodes.ancestor_ids(node.id()).count() - 1;
+        let span = node.kind().span();
+        let (line, column) = position(source, span.start);
+        let _ = writeln!(
+            output,
+            "{}{} {}..{} ({}:{})",
+            "  ".repeat(depth),
+            node.kind().debug_name(),
+            span.start,
+            span.end,
+            line,
+            column
+        );
+    }
+
+    output
+}
+
+/// Print the AST of `file_path` to stdout
+pub fn run(file_path: &str) -> Result<(), SentinelError> {
+    let source =
+        std::fs::read_to_string(file_path).map_err(|e| SentinelError::io("read", file_path, e))?;
+    print!("{}", render_ast(file_path, &source));
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use crate::error::SentinelError;
+use crate::exporter::severity_name;
+use crate::rules_registry::{RulesRegistry, create_default_registry};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tabled::{builder::Builder, settings::Style};
+
+/// Description of a registered rule, for documentation and rule pickers
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RuleCatalogEntry {
+    pub id: String,
+    pub description: String,
+    pub tags: Vec<String>,
+    /// Severity of the rule's diagnostics, using the names of findings.json
+    pub default_severity: String,
+    /// JSON schema of the rule options, absent for rules without options
+    pub config_schema: Option<Value>,
+}
+
+/// Describe every rule registered in `registry`, sorted by id
+pub fn build_catalog(registry: &RulesRegistry) -> Vec<RuleCatalogEntry> {
+    let mut names = registry.get_registered_rules();
+    names.sort_unstable();
+
+    names
+        .into_iter()
+        .filter_map(|name| registry.get_rule(name))
+        .map(|rule| RuleCatalogEntry {
+            id: rule.name().to_string(),
+            description: rule.description().to_string(),
+            tags: rule.tags().iter().map(|tag| tag.to_string()).collect(),
+            default_severity: severity_name(rule.default_severity()).to_string(),
+            config_schema: rule.config_schema(),
+        })
+        .collect()
+}
+
+/// Print the catalog as a table of ids, severities and descriptions
+fn print_catalog(catalog: &[RuleCatalogEntry]) {
+    let mut builder = Builder::new();
+    builder.push_record(["Rule", "Severity", "Tags", "Description"]);
+    for entry in catalog {
+        builder.push_record([
+            entry.id.clone(),
+            entry.default_severity.clone(),
+            entry.tags.join(", "),
+            entry.description.clone(),
+        ]);
+    }
+
+    let mut table = builder.build();
+    table.with(Style::ascii_rounded());
+    println!("{}", table);
+}
+
+/// Run the `rules-catalog` subcommand
+pub fn run(json: bool) -> Result<(), SentinelError> {
+    let catalog = build_catalog(&create_default_registry());
+
+    if json {
+        let json = serde_json::to_string_pretty(&catalog)
+            .map_err(|e| SentinelError::serialization("rules catalog", e))?;
+        println!("{}", json);
+    } else {
+        print_catalog(&catalog);
+    }
+
+    Ok(())
+}
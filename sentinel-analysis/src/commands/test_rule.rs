@@ -0,0 +1,101 @@
+use crate::analyzer::analyze_source;
+use crate::error::SentinelError;
+use crate::rules_registry::{configure_registry, create_default_registry};
+use oxc_diagnostics::Severity;
+use serde_json::Value;
+use std::fmt::Write;
+
+/// File name a `--code` snippet is analyzed as, unless `--file-name` is given
+pub const DEFAULT_SNIPPET_FILE: &str = "snippet.ts";
+
+/// Run a single rule on a source snippet and render its findings, one per line
+///
+/// The snippet goes through `analyze_source` with a registry in which only `rule` is
+/// enabled, so parsing, inline `sentinel-disable` comments and positions behave exactly
+/// as in a full run. `file_path` selects the source type and is matched against
+/// file globs in the rule options; the file itself is not read.
+pub fn render_rule_findings(
+    rule: &str,
+    options: Option<Value>,
+    file_path: &str,
+    source: &str,
+) -> Result<String, SentinelError> {
+    let mut registry = create_default_registry();
+    if registry.get_rule(rule).is_none() {
+        return Err(SentinelError::ConfigValidation(format!(
+            "Unknown rule '{}'",
+            rule
+        )));
+    }
+    configure_registry(
+        &mut registry,
+        &[(rule.to_string(), options, "error".to_string())],
+    );
+
+    let diagnostics = analyze_source(file_path, source, &registry).diagnostics;
+
+    let mut output = String::new();
+    for rule_diagnostic in &diagnostics {
+        let severity = match rule_diagnostic.diagnostic.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            _ => "info",
+        };
+        let _ = writeln!(
+            output,
+            "{}:{}:{} {} {}: {}",
+            file_path,
+            rule_diagnostic.line_number,
+            rule_diagnostic.column_number,
+            severity,
+            rule_diagnostic.rule_id,
+            rule_diagnostic.diagnostic.message
+        );
+        if let Some(help) = &rule_diagnostic.diagnostic.help {
+            let _ = writeln!(output, "  help: {}", help);
+        }
+    }
+    let _ = writeln!(output, "{} findings", diagnostics.len());
+
+    Ok(output)
+}
+
+/// Print the findings of `rule` for a snippet given inline or read from a file
+pub fn run(
+    rule: &str,
+    code: Option<&str>,
+    file: Option<&str>,
+    file_name: Option<&str>,
+    options: Option<&str>,
+) -> Result<(), SentinelError> {
+    let options = options
+        .map(|json| {
+            serde_json::from_str(json).map_err(|e| {
+                SentinelError::ConfigValidation(format!("Invalid rule options JSON: {}", e))
+            })
+        })
+        .transpose()?;
+
+    let (file_path, source) = match (code, file) {
+        (Some(code), _) => (
+            file_name.unwrap_or(DEFAULT_SNIPPET_FILE).to_string(),
+            code.to_string(),
+        ),
+        (None, Some(path)) => {
+            let source =
+                std::fs::read_to_string(path).map_err(|e| SentinelError::io("read", path, e))?;
+            (file_name.unwrap_or(path).to_string(), source)
+        }
+        (None, None) => {
+            return Err(SentinelError::ConfigValidation(
+                "Pass the snippet with --code or --file".to_string(),
+            ));
+        }
+    };
+
+    print!(
+        "{}",
+        render_rule_findings(rule, options, &file_path, &source)?
+    );
+    Ok(())
+}
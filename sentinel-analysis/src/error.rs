@@ -0,0 +1,61 @@
+use thiserror::Error;
+
+/// Errors returned by the library API
+///
+/// The `Display` output is written for end users, so the CLI prints these errors as-is.
+#[derive(Debug, Error)]
+pub enum SentinelError {
+    /// Reading or writing a file failed
+    #[error("Failed to {action} {path}: {source}")]
+    Io {
+        action: &'static str,
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// A JSON input file could not be parsed
+    #[error("Failed to parse {path}: {source}")]
+    Parse {
+        path: String,
+        #[source]
+        source: serde_json::Error,
+    },
+
+    /// A configuration value is missing or has the wrong shape
+    #[error("Invalid configuration: {0}")]
+    ConfigValidation(String),
+
+    /// An output document could not be serialized
+    #[error("Failed to serialize {what}: {source}")]
+    Serialization {
+        what: &'static str,
+        #[source]
+        source: serde_json::Error,
+    },
+
+    /// Metrics were exported before `Metrics::stop` recorded the total duration
+    #[error("Total duration not measured yet. Call stop() first.")]
+    MetricsNotStopped,
+}
+
+impl SentinelError {
+    pub fn io(action: &'static str, path: impl Into<String>, source: std::io::Error) -> Self {
+        SentinelError::Io {
+            action,
+            path: path.into(),
+            source,
+        }
+    }
+
+    pub fn parse(path: impl Into<String>, source: serde_json::Error) -> Self {
+        SentinelError::Parse {
+            path: path.into(),
+            source,
+        }
+    }
+
+    pub fn serialization(what: &'static str, source: serde_json::Error) -> Self {
+        SentinelError::Serialization { what, source }
+    }
+}
@@ -1,15 +1,24 @@
-use crate::FileAnalysisResult;
+use crate::porcelain::print_summary_record;
+use crate::report_print;
+use crate::utilities::config::{Config, DEFAULT_OUTPUT_DIR, get_output_dir};
+use crate::utilities::file_utils::{Shard, fnv1a_hash, to_relative_path};
+use crate::utilities::logging::errors_to_stderr;
 use crate::utilities::{DebugLevel, log};
+use crate::{FileAnalysisResult, RuleDiagnostic};
+use globset::{GlobBuilder, GlobMatcher};
 use oxc_diagnostics::Severity;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use tabled::{
+    Table,
     builder::Builder,
     settings::{Alignment, Style, object::Columns},
 };
 
 /// Structure for JSON export of findings
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct FindingEntry {
     pub rule: String,
     pub message: String,
@@ -18,6 +27,21 @@ pub struct FindingEntry {
     pub column: usize,
     pub severity: String,
     pub help: Option<String>,
+    /// Stable identity of the finding across runs, independent of its line number
+    #[serde(default)]
+    pub fingerprint: String,
+    /// Source lines around the finding, only exported with `--include-snippets`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<SourceSnippet>,
+}
+
+/// Source lines of a finding plus a few lines of context
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct SourceSnippet {
+    /// 1-based line number of the first entry in `lines`
+    pub start_line: usize,
+    /// Source lines, each cut to a maximum length
+    pub lines: Vec<String>,
 }
 
 /// Structure for findings export with summary
@@ -44,6 +68,472 @@ pub struct FindingsSummary {
     pub parallel_efficiency_percent: f64,
     pub scan_duration_ms: u64,
     pub analysis_duration_ms: u64,
+
+    // Which files were actually analyzed
+    #[serde(default)]
+    pub coverage: CoverageSummary,
+
+    // Files with the most findings, most findings first
+    #[serde(default)]
+    pub findings_by_file_top: Vec<FileHits>,
+}
+
+/// Number of files listed in the most violated files table, by default
+pub const DEFAULT_TOP_FILES: usize = 10;
+
+/// Number of findings in one file, an entry of `findings_by_file_top`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct FileHits {
+    pub file: String,
+    pub findings: usize,
+}
+
+/// The `limit` files with the most findings, most findings first
+///
+/// Files with the same number of findings are sorted by path, so the list is stable.
+pub fn top_files_by_findings(findings: &[FindingEntry], limit: usize) -> Vec<FileHits> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for finding in findings {
+        *counts.entry(finding.file.as_str()).or_insert(0) += 1;
+    }
+
+    let mut files: Vec<(&str, usize)> = counts.into_iter().collect();
+    files.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+    files
+        .into_iter()
+        .take(limit)
+        .map(|(file, findings)| FileHits {
+            file: file.to_string(),
+            findings,
+        })
+        .collect()
+}
+
+/// How many of the discovered files were analyzed, skipped or failed to parse
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct CoverageSummary {
+    /// Files that were parsed and checked, including those with parse errors
+    pub files_scanned: usize,
+    /// Scanned files with at least one diagnostic
+    pub files_with_findings: usize,
+    /// Files that were not analyzed, by reason
+    pub files_skipped: HashMap<String, usize>,
+    /// Scanned files with a parser diagnostic
+    pub files_parse_errored: usize,
+}
+
+/// Count scanned, skipped and parse-errored files of a run
+pub fn coverage_summary(results: &[FileAnalysisResult]) -> CoverageSummary {
+    let mut coverage = CoverageSummary::default();
+
+    for result in results {
+        if let Some(reason) = result.skip_reason {
+            *coverage
+                .files_skipped
+                .entry(reason.as_str().to_string())
+                .or_insert(0) += 1;
+            continue;
+        }
+
+        coverage.files_scanned += 1;
+        if !result.diagnostics.is_empty() {
+            coverage.files_with_findings += 1;
+        }
+        // Parse errors are reported under the pseudo rule `parser`
+        if result
+            .diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic.rule_id == "parser")
+        {
+            coverage.files_parse_errored += 1;
+        }
+    }
+
+    coverage
+}
+
+/// Output directory value that writes the findings JSON to stdout
+pub const STDOUT_OUTPUT: &str = "-";
+
+/// Border style of the rule hit summary table
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TableStyle {
+    /// ASCII borders with rounded corners
+    #[default]
+    Ascii,
+    /// GitHub-flavored markdown table, e.g. for PR comments
+    Markdown,
+    /// Columns aligned with spaces, without borders
+    Minimal,
+}
+
+impl FromStr for TableStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ascii" => Ok(TableStyle::Ascii),
+            "markdown" => Ok(TableStyle::Markdown),
+            "minimal" => Ok(TableStyle::Minimal),
+            _ => Err(format!(
+                "Invalid table style '{}', expected ascii, markdown or minimal",
+                s
+            )),
+        }
+    }
+}
+
+/// Format of the findings printed to stdout in addition to the findings file
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    /// Only the rule hit summary table
+    #[default]
+    Text,
+    /// GitHub Actions workflow commands, rendered as inline annotations in PRs
+    Github,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "github" => Ok(OutputFormat::Github),
+            _ => Err(format!(
+                "Invalid output format '{}', expected text or github",
+                s
+            )),
+        }
+    }
+}
+
+/// Minimum severity of the findings that are reported
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum SeverityLevel {
+    /// Report every finding
+    #[default]
+    Info,
+    Warning,
+    Error,
+}
+
+impl FromStr for SeverityLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "info" => Ok(SeverityLevel::Info),
+            "warning" | "warn" => Ok(SeverityLevel::Warning),
+            "error" => Ok(SeverityLevel::Error),
+            _ => Err(format!(
+                "Invalid severity '{}', expected error, warning or info",
+                s
+            )),
+        }
+    }
+}
+
+impl SeverityLevel {
+    /// Level of a finding's exported `severity`
+    fn of_finding(finding: &FindingEntry) -> Self {
+        match finding.severity.as_str() {
+            "error" => SeverityLevel::Error,
+            "warning" => SeverityLevel::Warning,
+            _ => SeverityLevel::Info,
+        }
+    }
+
+    /// Name of the level, as exported in a finding's `severity`
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SeverityLevel::Info => "info",
+            SeverityLevel::Warning => "warning",
+            SeverityLevel::Error => "error",
+        }
+    }
+}
+
+/// Severities that replace the rule severity of findings in files matching a glob
+///
+/// Globs are matched against the exported file path, so with relative paths (the
+/// default) they are relative to the scan root. `*` does not cross directory boundaries;
+/// `**` does. When several globs match a file, the longest glob wins, so `src/legacy/**`
+/// takes precedence over `src/**`. Invalid globs are skipped.
+#[derive(Debug, Clone, Default)]
+pub struct PathSeverities {
+    /// Compiled globs with their severity, longest glob first
+    overrides: Vec<(GlobMatcher, SeverityLevel)>,
+}
+
+impl PathSeverities {
+    pub fn new(globs: &HashMap<String, SeverityLevel>) -> Self {
+        let mut overrides: Vec<(GlobMatcher, SeverityLevel)> = globs
+            .iter()
+            .filter_map(|(pattern, severity)| {
+                let glob = GlobBuilder::new(pattern)
+                    .literal_separator(true)
+                    .build()
+                    .ok()?;
+                Some((glob.compile_matcher(), *severity))
+            })
+            .collect();
+        // Longest glob first, then by pattern so equally long globs resolve the same way every run
+        overrides.sort_by(|(a, _), (b, _)| {
+            b.glob()
+                .glob()
+                .len()
+                .cmp(&a.glob().glob().len())
+                .then_with(|| a.glob().glob().cmp(b.glob().glob()))
+        });
+        Self { overrides }
+    }
+
+    /// Severity configured for an exported file path, if any glob matches it
+    pub fn severity_for(&self, file_path: &str) -> Option<SeverityLevel> {
+        let file_path = file_path.replace('\\', "/");
+        self.overrides
+            .iter()
+            .find(|(glob, _)| glob.is_match(&file_path))
+            .map(|(_, severity)| *severity)
+    }
+
+    /// Replace the severity of a finding in a file matching one of the globs
+    fn apply(&self, finding: &mut FindingEntry) {
+        if let Some(severity) = self.severity_for(&finding.file) {
+            finding.severity = severity.as_str().to_string();
+        }
+    }
+}
+
+/// Options controlling where and how findings are exported
+#[derive(Debug, Clone)]
+pub struct ExportOptions {
+    /// Directory the findings file is written to
+    pub output_dir: String,
+    /// Name of the findings file inside `output_dir`
+    pub file_name: String,
+    /// When set, file paths are rewritten relative to this root
+    pub path_root: Option<String>,
+    /// Write the findings file even when there are no findings
+    pub write_empty: bool,
+    /// Print the findings JSON to stdout instead of writing a file
+    pub to_stdout: bool,
+    /// Style of the rule hit summary table
+    pub table_style: TableStyle,
+    /// Attach the source lines around each finding
+    pub include_snippets: bool,
+    /// Format of the findings printed to stdout
+    pub format: OutputFormat,
+    /// Findings below this severity are not printed to stdout
+    pub min_severity: SeverityLevel,
+    /// Number of files listed in the most violated files table, 0 to leave it out
+    pub top_files: usize,
+    /// Print the summary as a porcelain record instead of human-readable tables
+    pub porcelain: bool,
+    /// Severity overrides for findings in matching files
+    pub path_severities: PathSeverities,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            output_dir: DEFAULT_OUTPUT_DIR.to_string(),
+            file_name: findings_file_name(None),
+            path_root: None,
+            write_empty: false,
+            to_stdout: false,
+            table_style: TableStyle::default(),
+            include_snippets: false,
+            format: OutputFormat::default(),
+            min_severity: SeverityLevel::default(),
+            top_files: DEFAULT_TOP_FILES,
+            porcelain: false,
+            path_severities: PathSeverities::default(),
+        }
+    }
+}
+
+impl ExportOptions {
+    /// Build export options from the effective configuration
+    pub fn from_config(config: &Config) -> Self {
+        // Rewrite finding paths relative to the scan root unless disabled
+        let path_root = if config.relative_paths.unwrap_or(true) {
+            config.path.clone()
+        } else {
+            None
+        };
+
+        let output_dir = get_output_dir(config, &std::env::args().collect::<Vec<_>>());
+        let to_stdout = output_dir == STDOUT_OUTPUT;
+
+        Self {
+            output_dir,
+            file_name: findings_file_name(config.shard.as_ref()),
+            path_root,
+            // Every shard needs an output so `scoper merge` sees its file counts
+            write_empty: config.shard.is_some(),
+            to_stdout,
+            table_style: config.table_style.unwrap_or_default(),
+            include_snippets: config.include_snippets.unwrap_or(false),
+            format: config.format.unwrap_or_default(),
+            min_severity: config.severity.unwrap_or_default(),
+            top_files: config.top_files.unwrap_or(DEFAULT_TOP_FILES),
+            porcelain: config.porcelain.unwrap_or(false),
+            path_severities: config
+                .path_severities
+                .as_ref()
+                .map(PathSeverities::new)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Path of an analyzed file as it appears in the findings
+    pub fn export_path(&self, file_path: &str) -> String {
+        match &self.path_root {
+            Some(root) => to_relative_path(file_path, root),
+            None => file_path.to_string(),
+        }
+    }
+
+    /// Full path of the findings file
+    pub fn findings_path(&self) -> String {
+        format!("{}/{}", self.output_dir, self.file_name)
+    }
+}
+
+/// Name of the findings file, suffixed with the shard index for sharded runs
+pub fn findings_file_name(shard: Option<&Shard>) -> String {
+    match shard {
+        Some(shard) => format!("findings-{}.json", shard.index),
+        None => "findings.json".to_string(),
+    }
+}
+
+/// Name of the findings file written by an interrupted run, e.g. `findings.partial.json`
+pub fn partial_findings_file_name(file_name: &str) -> String {
+    match file_name.strip_suffix(".json") {
+        Some(stem) => format!("{}.partial.json", stem),
+        None => format!("{}.partial", file_name),
+    }
+}
+
+/// Name of the file findings are appended to as batches finish, e.g. `findings.ndjson`
+pub fn incremental_findings_file_name(file_name: &str) -> String {
+    match file_name.strip_suffix(".json") {
+        Some(stem) => format!("{}.ndjson", stem),
+        None => format!("{}.ndjson", file_name),
+    }
+}
+
+/// Number of bytes taken on each side of a finding's span for its fingerprint context
+const FINGERPRINT_CONTEXT_BYTES: usize = 40;
+
+/// Code surrounding the primary label of a diagnostic, with whitespace collapsed
+///
+/// The context extends a few bytes beyond the span but never past the lines the span
+/// is on, so edits to neighbouring lines leave it unchanged. Falls back to the message
+/// for diagnostics without a label.
+pub fn code_context(diagnostic: &RuleDiagnostic) -> String {
+    let source = diagnostic.source_code.as_str();
+    let Some(label) = diagnostic
+        .diagnostic
+        .labels
+        .as_ref()
+        .and_then(|labels| labels.first())
+    else {
+        return diagnostic.diagnostic.message.to_string();
+    };
+
+    let span_start = label.offset().min(source.len());
+    let span_end = (label.offset() + label.len()).min(source.len());
+    let line_start = source[..span_start].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = source[span_end..]
+        .find('\n')
+        .map_or(source.len(), |i| span_end + i);
+
+    let mut start = span_start
+        .saturating_sub(FINGERPRINT_CONTEXT_BYTES)
+        .max(line_start);
+    let mut end = (span_end + FINGERPRINT_CONTEXT_BYTES).min(line_end);
+    while !source.is_char_boundary(start) {
+        start += 1;
+    }
+    while !source.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    // Re-indenting the code must not change the fingerprint
+    source[start..end]
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Fingerprint of a finding: a hash of the rule, the normalized file path and the code context
+pub fn finding_fingerprint(rule: &str, file_path: &str, context: &str) -> String {
+    let normalized_path = file_path.replace('\\', "/");
+    let key = format!("{}\0{}\0{}", rule, normalized_path, context);
+    format!("{:016x}", fnv1a_hash(key.as_bytes()))
+}
+
+/// Number of lines shown before and after the lines of a finding in its snippet
+const SNIPPET_CONTEXT_LINES: usize = 2;
+
+/// Maximum number of lines in a snippet, so findings on whole classes stay small
+const SNIPPET_MAX_LINES: usize = 10;
+
+/// Maximum number of characters kept of each snippet line
+const SNIPPET_MAX_LINE_CHARS: usize = 200;
+
+/// Source lines covered by the primary label of a diagnostic, plus context lines
+///
+/// Returns `None` for diagnostics without a label or source code. Lines are numbered
+/// like the finding itself, which accounts for the position of script blocks in
+/// `.vue`/`.svelte` files.
+pub fn source_snippet(diagnostic: &RuleDiagnostic) -> Option<SourceSnippet> {
+    let source = diagnostic.source_code.as_str();
+    let label = diagnostic.diagnostic.labels.as_ref()?.first()?;
+    if source.is_empty() {
+        return None;
+    }
+
+    let lines: Vec<&str> = source.lines().collect();
+    let line_of = |offset: usize| -> usize {
+        let offset = offset.min(source.len());
+        source.as_bytes()[..offset]
+            .iter()
+            .filter(|&&byte| byte == b'\n')
+            .count()
+            .min(lines.len().saturating_sub(1))
+    };
+    let first = line_of(label.offset());
+    let last = line_of(label.offset() + label.len().saturating_sub(1)).max(first);
+
+    let start = first.saturating_sub(SNIPPET_CONTEXT_LINES);
+    let end = (last + SNIPPET_CONTEXT_LINES)
+        .min(lines.len().saturating_sub(1))
+        .min(start + SNIPPET_MAX_LINES - 1);
+
+    // Keep the line numbers of the file when the source is a block inside it
+    let start_line = match diagnostic.line_number {
+        0 => start + 1,
+        line => line.saturating_sub(first - start),
+    };
+    let lines = lines[start..=end]
+        .iter()
+        .map(
+            |line| match line.char_indices().nth(SNIPPET_MAX_LINE_CHARS) {
+                Some((cut, _)) => format!("{}...", &line[..cut]),
+                None => line.to_string(),
+            },
+        )
+        .collect();
+
+    Some(SourceSnippet { start_line, lines })
 }
 
 /// Extract position information from diagnostic when available
@@ -65,22 +555,75 @@ fn get_total_duration_ms(metrics: &crate::Metrics) -> u64 {
     scan_ms + analysis_ms
 }
 
-/// Export diagnostics to findings.json
-pub fn export_findings_json(
+/// Exported `severity` of a diagnostic
+pub(crate) fn severity_name(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        _ => "info",
+    }
+}
+
+/// Convert one diagnostic into its exported finding
+///
+/// `file_path` is the path as exported, see [`ExportOptions::export_path`].
+pub fn finding_entry(
+    rule_diagnostic: &RuleDiagnostic,
+    file_path: &str,
+    include_snippets: bool,
+) -> FindingEntry {
+    let rule = rule_diagnostic.rule_id.clone();
+    FindingEntry {
+        fingerprint: finding_fingerprint(&rule, file_path, &code_context(rule_diagnostic)),
+        rule,
+        message: rule_diagnostic.diagnostic.message.to_string(),
+        file: file_path.to_string(),
+        line: rule_diagnostic.line_number,
+        column: rule_diagnostic.column_number,
+        severity: severity_name(rule_diagnostic.diagnostic.severity).to_string(),
+        help: rule_diagnostic
+            .diagnostic
+            .help
+            .as_ref()
+            .map(|h| h.to_string()),
+        snippet: if include_snippets {
+            source_snippet(rule_diagnostic)
+        } else {
+            None
+        },
+    }
+}
+
+/// Convert the diagnostics of analyzed files into exported findings, in file order
+pub fn finding_entries(
+    results: &[FileAnalysisResult],
+    options: &ExportOptions,
+) -> Vec<FindingEntry> {
+    results
+        .iter()
+        .flat_map(|result| {
+            let file_path = options.export_path(&result.file_path);
+            result.diagnostics.iter().map(move |rule_diagnostic| {
+                let mut finding =
+                    finding_entry(rule_diagnostic, &file_path, options.include_snippets);
+                options.path_severities.apply(&mut finding);
+                finding
+            })
+        })
+        .collect()
+}
+
+/// Collect the diagnostics of a run into the findings export structure
+pub fn build_findings_export(
     results: &[FileAnalysisResult],
     metrics: &crate::Metrics,
     debug_level: DebugLevel,
-    output_dir: &String,
-) {
+    options: &ExportOptions,
+) -> FindingsExport {
     let mut findings: Vec<FindingEntry> = Vec::new();
     let mut rule_counts: HashMap<String, usize> = HashMap::new();
     let mut severity_counts: HashMap<String, usize> = HashMap::new();
 
-    // Use static string references to avoid repeated allocations
-    let error_str = "error".to_string();
-    let warning_str = "warning".to_string();
-    let info_str = "info".to_string();
-
     // Pre-allocate approximate capacity based on results size to avoid reallocations
     let estimated_findings = results.iter().map(|r| r.diagnostics.len()).sum::<usize>();
     if estimated_findings > 0 {
@@ -91,82 +634,31 @@ pub fn export_findings_json(
 
     // Process each file result
     for result in results {
-        // Extract position information once per file rather than per diagnostic
-        for rule_diagnostic in &result.diagnostics {
-            // Get the message text
-            let message = rule_diagnostic.diagnostic.message.to_string();
-
-            // Get rule ID directly from RuleDiagnostic
-            let rule_name = rule_diagnostic.rule_id.clone();
+        let file_path = options.export_path(&result.file_path);
 
+        for rule_diagnostic in &result.diagnostics {
             // Log the rule ID at debug level
             log(
                 DebugLevel::Debug,
                 debug_level,
-                &format!("Using rule ID '{}' for diagnostic: {}", rule_name, message),
+                &format!(
+                    "Using rule ID '{}' for diagnostic: {}",
+                    rule_diagnostic.rule_id, rule_diagnostic.diagnostic.message
+                ),
             );
 
-            // Count occurrences by rule
-            *rule_counts.entry(rule_name.clone()).or_insert(0) += 1;
-
-            // Get severity - reuse existing strings instead of creating new ones each time
-            let severity = match rule_diagnostic.diagnostic.severity {
-                Severity::Error => error_str.clone(),
-                Severity::Warning => warning_str.clone(),
-                _ => info_str.clone(),
-            };
+            let mut finding = finding_entry(rule_diagnostic, &file_path, options.include_snippets);
+            options.path_severities.apply(&mut finding);
 
-            // Count occurrences by severity
-            *severity_counts.entry(severity.clone()).or_insert(0) += 1;
-
-            // Create a basic finding entry
-            let finding = FindingEntry {
-                rule: rule_name.clone(),
-                message,
-                file: result.file_path.clone(),
-                line: rule_diagnostic.line_number,
-                column: rule_diagnostic.column_number,
-                severity,
-                help: rule_diagnostic
-                    .diagnostic
-                    .help
-                    .as_ref()
-                    .map(|h| h.to_string()),
-            };
+            // Count occurrences by rule and severity
+            *rule_counts.entry(finding.rule.clone()).or_insert(0) += 1;
+            *severity_counts.entry(finding.severity.clone()).or_insert(0) += 1;
 
             // Add finding to the flat list
             findings.push(finding);
         }
     }
 
-    // Print rule summary
-    println!("\nRule hit summary:");
-    println!("----------------");
-    let mut rules: Vec<(&String, &usize)> = rule_counts.iter().collect();
-    rules.sort_by(|a, b| a.0.cmp(b.0)); // Sort by rule name, alphabetically
-
-    // Build table
-    let mut builder = Builder::new();
-    builder.push_record(["Rule", "Hits"]);
-
-    for (rule, count) in rules {
-        builder.push_record([rule.as_str(), &count.to_string()]);
-    }
-
-    let mut table = builder.build();
-    table
-        .with(Style::ascii_rounded())
-        .modify(Columns::single(1), Alignment::right()); // Right align the second column (Hits) using 0-based index
-
-    // Print the table
-    println!("{}", table);
-
-    println!("----------------");
-    println!(
-        "Total: {} issues found\n",
-        rule_counts.values().sum::<usize>()
-    );
-
     // Get total duration in ms
     let total_duration_ms = get_total_duration_ms(metrics);
 
@@ -216,8 +708,9 @@ pub fn export_findings_json(
         .map(|d| d.as_millis() as u64)
         .unwrap_or(0);
 
-    // Create findings export structure
-    let findings_export = FindingsExport {
+    let findings_by_file_top = top_files_by_findings(&findings, options.top_files);
+
+    FindingsExport {
         findings,
         summary: FindingsSummary {
             total_findings: rule_counts.values().sum::<usize>(),
@@ -231,11 +724,330 @@ pub fn export_findings_json(
             parallel_efficiency_percent,
             scan_duration_ms,
             analysis_duration_ms,
+            coverage: coverage_summary(results),
+            findings_by_file_top,
         },
+    }
+}
+
+/// Render the rule hit summary: a table of hits per rule and the total
+pub fn render_rule_summary(summary: &FindingsSummary, style: TableStyle) -> String {
+    let total = format!("Total: {} issues found", summary.total_findings);
+    render_rule_hits(&summary.findings_by_rule, &total, style)
+}
+
+/// Render a table of hits per rule, followed by a total line
+fn render_rule_hits(
+    findings_by_rule: &HashMap<String, usize>,
+    total: &str,
+    style: TableStyle,
+) -> String {
+    let mut rules: Vec<(&String, &usize)> = findings_by_rule.iter().collect();
+    rules.sort_by(|a, b| a.0.cmp(b.0)); // Sort by rule name, alphabetically
+
+    // Build table
+    let mut builder = Builder::new();
+    builder.push_record(["Rule", "Hits"]);
+
+    for (rule, count) in rules {
+        builder.push_record([rule.as_str(), &count.to_string()]);
+    }
+
+    let table = styled_table(builder, style);
+
+    match style {
+        // Dashed lines would turn into headings and rules in markdown
+        TableStyle::Markdown => format!("\n{}\n\n{}\n", table, total),
+        _ => format!(
+            "\nRule hit summary:\n----------------\n{}\n----------------\n{}\n",
+            table, total
+        ),
+    }
+}
+
+/// Build a two-column table in the given style, with the counts in the second column
+fn styled_table(builder: Builder, style: TableStyle) -> Table {
+    let mut table = builder.build();
+    match style {
+        TableStyle::Ascii => table.with(Style::ascii_rounded()),
+        TableStyle::Markdown => table.with(Style::markdown()),
+        TableStyle::Minimal => table.with(Style::blank()),
+    };
+    table.modify(Columns::single(1), Alignment::right()); // Right align the second column (Hits) using 0-based index
+    table
+}
+
+/// Render the table of the files with the most findings, or nothing without findings
+pub fn render_top_files(top_files: &[FileHits], style: TableStyle) -> String {
+    if top_files.is_empty() {
+        return String::new();
+    }
+
+    let mut builder = Builder::new();
+    builder.push_record(["File", "Findings"]);
+    for entry in top_files {
+        builder.push_record([entry.file.as_str(), &entry.findings.to_string()]);
+    }
+    let table = styled_table(builder, style);
+
+    match style {
+        TableStyle::Markdown => format!("\n{}\n", table),
+        _ => format!(
+            "\nMost violated files:\n-------------------\n{}\n-------------------\n",
+            table
+        ),
+    }
+}
+
+/// Render the coverage section of the summary
+pub fn render_coverage_summary(coverage: &CoverageSummary) -> String {
+    let mut reasons: Vec<(&String, &usize)> = coverage.files_skipped.iter().collect();
+    reasons.sort_by(|a, b| a.0.cmp(b.0));
+    let skipped: usize = coverage.files_skipped.values().sum();
+
+    let mut output = format!(
+        "Coverage: {} files scanned, {} with findings, {} skipped, {} with parse errors\n",
+        coverage.files_scanned, coverage.files_with_findings, skipped, coverage.files_parse_errored
+    );
+    for (reason, count) in reasons {
+        output.push_str(&format!("  skipped ({}): {}\n", reason, count));
+    }
+    output
+}
+
+/// Number of findings per rule
+fn count_by_rule(findings: &[FindingEntry]) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for finding in findings {
+        *counts.entry(finding.rule.clone()).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// A rule that reported more findings than its budget allows
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BudgetOverrun {
+    pub rule: String,
+    pub findings: usize,
+    pub budget: usize,
+}
+
+/// Rules whose number of findings exceeds their budget, sorted by rule name
+pub fn exceeded_budgets(
+    findings_by_rule: &HashMap<String, usize>,
+    budgets: &HashMap<String, usize>,
+) -> Vec<BudgetOverrun> {
+    let mut overruns: Vec<BudgetOverrun> = budgets
+        .iter()
+        .filter_map(|(rule, &budget)| {
+            let findings = findings_by_rule.get(rule).copied().unwrap_or(0);
+            (findings > budget).then(|| BudgetOverrun {
+                rule: rule.clone(),
+                findings,
+                budget,
+            })
+        })
+        .collect();
+    overruns.sort_by(|a, b| a.rule.cmp(&b.rule));
+    overruns
+}
+
+/// Escape the message of a GitHub Actions workflow command
+fn escape_workflow_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escape a property value of a GitHub Actions workflow command
+fn escape_workflow_property(value: &str) -> String {
+    escape_workflow_data(value)
+        .replace(':', "%3A")
+        .replace(',', "%2C")
+}
+
+/// Render a finding as a GitHub Actions workflow command, e.g.
+/// `::error file=src/app.ts,line=3,col=1,title=no-debugger::Unexpected debugger statement`
+pub fn github_annotation(finding: &FindingEntry) -> String {
+    let command = match SeverityLevel::of_finding(finding) {
+        SeverityLevel::Error => "error",
+        SeverityLevel::Warning => "warning",
+        SeverityLevel::Info => "notice",
+    };
+    format!(
+        "::{} file={},line={},col={},title={}::{}",
+        command,
+        escape_workflow_property(&finding.file),
+        finding.line,
+        finding.column,
+        escape_workflow_property(&finding.rule),
+        escape_workflow_data(&finding.message)
+    )
+}
+
+/// Directory GitHub resolves annotation paths against: `GITHUB_WORKSPACE`, or the
+/// working directory outside of GitHub Actions
+pub fn github_workspace() -> PathBuf {
+    match std::env::var_os("GITHUB_WORKSPACE") {
+        Some(workspace) if !workspace.is_empty() => PathBuf::from(workspace),
+        _ => std::env::current_dir().unwrap_or_default(),
+    }
+}
+
+/// Path of a finding's file relative to `workspace`, for the `file=` of an annotation
+///
+/// `file` is the exported path, relative to `path_root` when one is given. Files outside
+/// the workspace keep their exported path.
+pub fn workspace_relative_path(file: &str, path_root: Option<&str>, workspace: &Path) -> String {
+    let path = match path_root {
+        Some(root) if Path::new(file).is_relative() => {
+            let root = Path::new(root);
+            let root = if root.is_file() {
+                root.parent().unwrap_or(root)
+            } else {
+                root
+            };
+            root.join(file)
+        }
+        _ => PathBuf::from(file),
     };
+    let absolute = std::fs::canonicalize(&path)
+        .or_else(|_| std::path::absolute(&path))
+        .unwrap_or(path);
+    let workspace = std::fs::canonicalize(workspace).unwrap_or_else(|_| workspace.to_path_buf());
+
+    match absolute.strip_prefix(&workspace) {
+        Ok(relative) if !relative.as_os_str().is_empty() => {
+            relative.to_string_lossy().replace('\\', "/")
+        }
+        _ => file.to_string(),
+    }
+}
+
+/// Render the findings at or above `min_severity` as GitHub Actions annotations, one per line
+///
+/// File paths are rewritten relative to `workspace`, see [`workspace_relative_path`].
+pub fn render_github_annotations(
+    findings: &[FindingEntry],
+    min_severity: SeverityLevel,
+    path_root: Option<&str>,
+    workspace: &Path,
+) -> String {
+    findings
+        .iter()
+        .filter(|finding| SeverityLevel::of_finding(finding) >= min_severity)
+        .map(|finding| {
+            let annotated = FindingEntry {
+                file: workspace_relative_path(&finding.file, path_root, workspace),
+                ..finding.clone()
+            };
+            format!("{}\n", github_annotation(&annotated))
+        })
+        .collect()
+}
+
+/// Export diagnostics to findings.json
+pub fn export_findings_json(
+    results: &[FileAnalysisResult],
+    metrics: &crate::Metrics,
+    debug_level: DebugLevel,
+    options: &ExportOptions,
+) {
+    let findings_export = build_findings_export(results, metrics, debug_level, options);
+    write_findings_export(&findings_export, debug_level, options);
+}
+
+/// Print the rule hit summary and write an assembled findings export
+pub fn write_findings_export(
+    findings_export: &FindingsExport,
+    debug_level: DebugLevel,
+    options: &ExportOptions,
+) {
+    let output_dir = &options.output_dir;
+
+    // Error-severity findings may be routed to stderr, separately from the rest
+    let (errors, others): (Vec<FindingEntry>, Vec<FindingEntry>) = findings_export
+        .findings
+        .iter()
+        .cloned()
+        .partition(|finding| SeverityLevel::of_finding(finding) == SeverityLevel::Error);
+
+    // Stdout carries porcelain records; the findings were printed as they were analyzed
+    if options.porcelain
+        && let Err(e) = print_summary_record(&findings_export.summary)
+    {
+        log(
+            DebugLevel::Error,
+            debug_level,
+            &format!("Failed to print the summary record: {}", e),
+        );
+    }
+
+    // Print rule summary, unless stdout carries the findings JSON or porcelain records
+    if !options.to_stdout && !options.porcelain {
+        if errors_to_stderr() {
+            if !errors.is_empty() {
+                let total = format!("Total: {} errors found", errors.len());
+                let table = render_rule_hits(&count_by_rule(&errors), &total, options.table_style);
+                report_print!(true, "{}\n", table);
+            }
+            let total = format!("Total: {} issues found", others.len());
+            let table = render_rule_hits(&count_by_rule(&others), &total, options.table_style);
+            report_print!(false, "{}\n", table);
+        } else {
+            println!(
+                "{}",
+                render_rule_summary(&findings_export.summary, options.table_style)
+            );
+        }
+        print!(
+            "{}",
+            render_top_files(
+                &findings_export.summary.findings_by_file_top,
+                options.table_style
+            )
+        );
+        print!(
+            "{}",
+            render_coverage_summary(&findings_export.summary.coverage)
+        );
+    }
+
+    // Annotations are picked up from the log of the step running the analysis
+    if options.format == OutputFormat::Github && !options.to_stdout && !options.porcelain {
+        let workspace = github_workspace();
+        let render = |findings: &[FindingEntry]| {
+            render_github_annotations(
+                findings,
+                options.min_severity,
+                options.path_root.as_deref(),
+                &workspace,
+            )
+        };
+        if errors_to_stderr() {
+            report_print!(true, "{}", render(&errors));
+            report_print!(false, "{}", render(&others));
+        } else {
+            print!("{}", render(&findings_export.findings));
+        }
+    }
+
+    // Print findings to stdout, keeping it pure JSON
+    if options.to_stdout {
+        match serde_json::to_string_pretty(findings_export) {
+            Ok(json) => println!("{}", json),
+            Err(e) => log(
+                DebugLevel::Error,
+                debug_level,
+                &format!("Failed to serialize findings: {}", e),
+            ),
+        }
+        return;
+    }
 
     // Save to findings.json
-    if !findings_export.findings.is_empty() {
+    if !findings_export.findings.is_empty() || options.write_empty {
         // Create the output directory if needed
         if let Err(e) = std::fs::create_dir_all(output_dir) {
             log(
@@ -247,10 +1059,10 @@ pub fn export_findings_json(
         }
 
         // Construct the full file path
-        let file_path = format!("{}/findings.json", output_dir);
+        let file_path = options.findings_path();
 
         // Write findings to JSON
-        let json = match serde_json::to_string_pretty(&findings_export) {
+        let json = match serde_json::to_string_pretty(findings_export) {
             Ok(json) => json,
             Err(e) => {
                 log(
@@ -0,0 +1,66 @@
+use crate::FileAnalysisResult;
+use crate::error::SentinelError;
+use crate::exporter::{ExportOptions, finding_entries, incremental_findings_file_name};
+
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::sync::{Mutex, PoisonError};
+
+/// Appends the findings of each finished batch to an NDJSON file in the output directory
+///
+/// Every line is one finding, shaped like an entry of `findings` in findings.json. The
+/// file is flushed after each batch, so a crashed run loses at most the batches that were
+/// still being analyzed. Batches are appended in completion order; the findings.json of
+/// a complete run holds the same findings in file order.
+pub struct IncrementalFindingsWriter {
+    /// Path of the NDJSON file
+    path: String,
+    writer: Mutex<BufWriter<File>>,
+    options: ExportOptions,
+}
+
+impl IncrementalFindingsWriter {
+    /// Create the output directory and start an empty NDJSON file next to the findings file
+    pub fn create(options: ExportOptions) -> Result<Self, SentinelError> {
+        fs::create_dir_all(&options.output_dir)
+            .map_err(|e| SentinelError::io("create", &options.output_dir, e))?;
+        let path = format!(
+            "{}/{}",
+            options.output_dir,
+            incremental_findings_file_name(&options.file_name)
+        );
+        let file = File::create(&path).map_err(|e| SentinelError::io("create", &path, e))?;
+
+        Ok(Self {
+            path,
+            writer: Mutex::new(BufWriter::new(file)),
+            options,
+        })
+    }
+
+    /// Path of the NDJSON file
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Append the findings of a finished batch and flush them to disk
+    pub fn append(&self, results: &[FileAnalysisResult]) -> Result<(), SentinelError> {
+        let mut lines = String::new();
+        for finding in finding_entries(results, &self.options) {
+            let line = serde_json::to_string(&finding)
+                .map_err(|e| SentinelError::serialization("incremental finding", e))?;
+            lines.push_str(&line);
+            lines.push('\n');
+        }
+        if lines.is_empty() {
+            return Ok(());
+        }
+
+        // A failed append on another thread must not stop the following batches
+        let mut writer = self.writer.lock().unwrap_or_else(PoisonError::into_inner);
+        writer
+            .write_all(lines.as_bytes())
+            .and_then(|()| writer.flush())
+            .map_err(|e| SentinelError::io("write", &self.path, e))
+    }
+}
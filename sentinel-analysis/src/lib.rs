@@ -1,7 +1,14 @@
 // Expose the modules
 pub mod analyzer;
+pub mod ast_stats;
+pub mod commands;
+pub mod error;
 pub mod exporter;
+pub mod incremental;
 pub mod metrics;
+pub mod module_graph;
+pub mod porcelain;
+pub mod profiling;
 pub mod rules;
 pub mod rules_registry;
 pub mod utilities;
@@ -24,6 +31,31 @@ pub struct RuleDiagnostic {
     pub column_number: usize,
 }
 
+/// Why a file was not analyzed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkipReason {
+    /// The file could not be read
+    ReadError,
+    /// The file is not valid UTF-8
+    Encoding,
+    /// No parser source type matches the file
+    UnsupportedFileType,
+    /// None of the enabled rules applies to the file (with `--prefilter`)
+    NoApplicableRules,
+}
+
+impl SkipReason {
+    /// Key of the reason in the coverage summary
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SkipReason::ReadError => "read_error",
+            SkipReason::Encoding => "encoding",
+            SkipReason::UnsupportedFileType => "unsupported_file_type",
+            SkipReason::NoApplicableRules => "no_applicable_rules",
+        }
+    }
+}
+
 /// Structure to hold analysis results for a single file
 #[derive(Debug)]
 pub struct FileAnalysisResult {
@@ -33,9 +65,16 @@ pub struct FileAnalysisResult {
     pub rule_durations: HashMap<String, Duration>,
     pub total_duration: Duration,
     pub diagnostics: Vec<RuleDiagnostic>,
+    /// Module specifiers of the file's imports and re-exports, for the module graph
+    ///
+    /// Only collected when the import graph is exported, empty otherwise.
+    pub imports: Vec<String>,
+    /// Set when the file was not analyzed at all
+    pub skip_reason: Option<SkipReason>,
 }
 
 // Add any other public exports needed from the library modules here
+pub use error::SentinelError;
 pub use metrics::Metrics;
 pub use rules::Rule;
 pub use rules_registry::RulesRegistry;
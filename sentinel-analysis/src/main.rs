@@ -1,13 +1,40 @@
-use std::{env, sync::Arc};
+use std::{
+    collections::HashMap,
+    env,
+    path::PathBuf,
+    process::ExitCode,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use scoper::{
-    analyzer::process_files,
-    metrics::{aggregate_metrics, export_results},
-    rules_registry::setup_rules_registry,
+    SentinelError,
+    analyzer::{BatchListener, PartialResults, process_files_into},
+    ast_stats::{AstStatsFormat, collect_ast_stats, print_ast_stats},
+    commands,
+    exporter::{
+        DEFAULT_TOP_FILES, ExportOptions, OutputFormat, STDOUT_OUTPUT, SeverityLevel, TableStyle,
+        exceeded_budgets,
+    },
+    incremental::IncrementalFindingsWriter,
+    metrics::{aggregate_metrics, export_partial_results, export_results},
+    porcelain::{print_finding_records, print_version_record},
+    rules_registry::{
+        InlineRuleConfig, load_rule_budgets, setup_registry_resolver, setup_rules_registry,
+    },
+    status_println,
     utilities::{
+        DebugLevel,
+        archive::{extract_archive, is_archive_path},
         cli::{get_debug_level_from_args, parse_args},
-        config::{Config, get_target_path},
-        file_utils::find_files,
+        config::{Config, DEFAULT_API_URL, get_output_dir, get_target_path},
+        file_utils::{
+            DEFAULT_EXTENSIONS, Shard, find_files_from_list, find_files_with_extensions,
+            select_shard,
+        },
+        log,
+        logging::{reserve_stdout, route_errors_to_stderr, stdout_reserved},
+        sfc::SFC_EXTENSIONS,
         threading::configure_thread_pool,
     },
 };
@@ -16,19 +43,125 @@ use scoper::{
 use reqwest::blocking::Client; // Changed to blocking client
 use serde_json::Value; // To represent the analysis_results as JSON
 
-fn main() {
+// Parsing and semantic analysis allocate heavily per file; mimalloc copes better with
+// that across many threads than the system allocator
+#[cfg(feature = "mimalloc")]
+#[global_allocator]
+static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
+
+fn main() -> ExitCode {
     // Parse command-line arguments
     let command = parse_args();
     let matches = command.get_matches();
 
+    // Subcommands run standalone and skip the analysis pipeline
+    if let Some(("diff", diff_matches)) = matches.subcommand() {
+        let old_path = diff_matches.get_one::<String>("OLD").unwrap();
+        let new_path = diff_matches.get_one::<String>("NEW").unwrap();
+        let format = diff_matches.get_one::<String>("format").unwrap();
+        if let Err(e) = commands::diff::run(old_path, new_path, format) {
+            eprintln!("ERROR: {}", e);
+            return ExitCode::FAILURE;
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    if let Some(("doctor", doctor_matches)) = matches.subcommand() {
+        let config_path = doctor_matches.get_one::<String>("config");
+        if !commands::doctor::run(config_path.map(String::as_str)) {
+            return ExitCode::FAILURE;
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    if let Some(("merge", merge_matches)) = matches.subcommand() {
+        let inputs: Vec<String> = merge_matches
+            .get_many::<String>("FILES")
+            .unwrap()
+            .cloned()
+            .collect();
+        let output = merge_matches.get_one::<String>("output").unwrap();
+        let top_files = merge_matches
+            .get_one::<u64>("top-files")
+            .map_or(DEFAULT_TOP_FILES, |top_files| *top_files as usize);
+        if let Err(e) = commands::merge::run(&inputs, output, top_files) {
+            eprintln!("ERROR: {}", e);
+            return ExitCode::FAILURE;
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    if let Some(("print-ast", print_matches)) = matches.subcommand() {
+        let file = print_matches.get_one::<String>("FILE").unwrap();
+        if let Err(e) = commands::print_ast::run(file) {
+            eprintln!("ERROR: {}", e);
+            return ExitCode::FAILURE;
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    if let Some(("rules-catalog", catalog_matches)) = matches.subcommand() {
+        if let Err(e) = commands::rules_catalog::run(catalog_matches.get_flag("json")) {
+            eprintln!("ERROR: {}", e);
+            return ExitCode::FAILURE;
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    if let Some(("test-rule", test_matches)) = matches.subcommand() {
+        let arg = |name: &str| test_matches.get_one::<String>(name).map(String::as_str);
+        if let Err(e) = commands::test_rule::run(
+            arg("rule").unwrap(),
+            arg("code"),
+            arg("file"),
+            arg("file-name"),
+            arg("options"),
+        ) {
+            eprintln!("ERROR: {}", e);
+            return ExitCode::FAILURE;
+        }
+        return ExitCode::SUCCESS;
+    }
+
     // Initialize configuration and setup
-    let mut config = Config::load();
+    let mut config = match matches.get_one::<String>("config") {
+        Some(config_path) => match Config::load_from_file(config_path) {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!("ERROR: {}", e);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => Config::load(),
+    };
     let debug_level = get_debug_level_from_args(&matches);
 
     // Get output directory from command-line arguments
     if let Some(output_dir) = matches.get_one::<String>("output-dir") {
         config.output_dir = Some(output_dir.clone());
-        println!("DEBUG: Output directory set to: {}", output_dir);
+    }
+    // An output directory of `-` prints the findings JSON, so log lines have to go to stderr
+    if config.output_dir.as_deref() == Some(STDOUT_OUTPUT) {
+        reserve_stdout();
+    }
+    // Porcelain records are the only output on stdout
+    if matches.get_flag("porcelain") {
+        if stdout_reserved() {
+            eprintln!("ERROR: --porcelain cannot be combined with an output directory of `-`");
+            return ExitCode::FAILURE;
+        }
+        config.porcelain = Some(true);
+        reserve_stdout();
+    }
+    if let Some(format) = matches.get_one::<AstStatsFormat>("ast-stats") {
+        config.ast_stats = Some(*format);
+        // Log lines must not end up in the JSON document
+        if *format == AstStatsFormat::Json {
+            reserve_stdout();
+        }
+    }
+    if let Some(output_dir) = matches.get_one::<String>("output-dir") {
+        status_println!("DEBUG: Output directory set to: {}", output_dir);
     }
 
     // Get rules config path from command-line arguments
@@ -36,10 +169,120 @@ fn main() {
         config.rules_config = Some(rules_config_path.clone());
         // Optional: Add a debug print to confirm the path is being set
         if debug_level >= scoper::utilities::DebugLevel::Debug {
-            println!("DEBUG: Rules config path set from command line: {}", rules_config_path);
+            status_println!("DEBUG: Rules config path set from command line: {}", rules_config_path);
         }
     }
 
+    if let Some(rule_configs) = matches.get_many::<InlineRuleConfig>("rule-config") {
+        config.rule_configs = rule_configs.cloned().collect();
+    }
+
+    if let Some(combined_report) = matches.get_one::<String>("combined-report") {
+        config.combined_report = Some(combined_report.clone());
+    }
+
+    if let Some(export_graph) = matches.get_one::<String>("export-graph") {
+        config.export_graph = Some(export_graph.clone());
+    }
+
+    if matches.get_flag("metrics-environment") {
+        config.metrics_environment = Some(true);
+    }
+
+    if let Some(limit) = matches.get_one::<u64>("rule-file-timings") {
+        config.rule_file_timings = Some(*limit as usize);
+    }
+
+    if let Some(table_style) = matches.get_one::<TableStyle>("table-style") {
+        config.table_style = Some(*table_style);
+    }
+
+    if let Some(top_files) = matches.get_one::<u64>("top-files") {
+        config.top_files = Some(*top_files as usize);
+    }
+
+    if let Some(format) = matches.get_one::<OutputFormat>("format") {
+        config.format = Some(*format);
+    }
+
+    if let Some(severity) = matches.get_one::<SeverityLevel>("severity") {
+        config.severity = Some(*severity);
+    }
+    if matches.get_flag("errors-to-stderr") {
+        config.errors_to_stderr = Some(true);
+    }
+    if config.errors_to_stderr == Some(true) {
+        route_errors_to_stderr();
+    }
+
+    if config.format == Some(OutputFormat::Github) && stdout_reserved() {
+        let stdout_content = if config.porcelain == Some(true) {
+            "porcelain records"
+        } else {
+            "JSON"
+        };
+        log(
+            DebugLevel::Warn,
+            debug_level,
+            &format!(
+                "Findings are printed to stdout as {}, so no GitHub annotations are printed",
+                stdout_content
+            ),
+        );
+    }
+
+    if matches.get_flag("include-snippets") {
+        config.include_snippets = Some(true);
+    }
+
+    if let Some(relative_paths) = matches.get_one::<bool>("relative-paths") {
+        config.relative_paths = Some(*relative_paths);
+    }
+
+    if matches.get_flag("fail-fast-per-file") {
+        config.fail_fast_per_file = Some(true);
+    }
+
+    if matches.get_flag("continue-on-parse-error") {
+        config.continue_on_parse_error = Some(true);
+    }
+
+    if matches.get_flag("prefilter") {
+        config.prefilter = Some(true);
+    }
+
+    if matches.get_flag("experimental-sfc") {
+        config.experimental_sfc = Some(true);
+    }
+
+    if let Some(read_concurrency) = matches.get_one::<u64>("read-concurrency") {
+        config.read_concurrency = Some(*read_concurrency as usize);
+    }
+
+    if matches.get_flag("follow-symlinks") {
+        config.follow_symlinks = Some(true);
+    }
+
+    if matches.get_flag("nested-configs") {
+        config.nested_configs = Some(true);
+    }
+
+    if matches.get_flag("incremental-findings") {
+        config.incremental_findings = Some(true);
+    }
+
+    if matches.get_flag("no-sort-files") {
+        config.sort_files = Some(false);
+    }
+
+    if let Some(shard) = matches.get_one::<Shard>("shard") {
+        config.shard = Some(*shard);
+    }
+
+    if let Some(files_from) = matches.get_one::<String>("files-from") {
+        config.files_from = Some(files_from.clone());
+    }
+
     // Fallback: If rules_config is not set by CLI or sentinel.json (via Config::load),
     // try to find rules.json next to the executable.
     if config.rules_config.is_none() {
@@ -50,7 +293,7 @@ fn main() {
                     if let Some(path_str) = rules_path_beside_exe.to_str() {
                         config.rules_config = Some(path_str.to_string());
                         if debug_level >= scoper::utilities::DebugLevel::Debug {
-                            println!("DEBUG: Rules config path set from rules.json next to executable: {}", path_str);
+                            status_println!("DEBUG: Rules config path set from rules.json next to executable: {}", path_str);
                         }
                     } else {
                         if debug_level >= scoper::utilities::DebugLevel::Warn {
@@ -65,10 +308,38 @@ fn main() {
     // Check if --help was provided
     if matches.contains_id("help") {
         // clap has already displayed the help message
-        return;
+        return ExitCode::SUCCESS;
     }
 
-    // Configure thread pool and rules registry
+    // Find and process files
+    let mut dir_path = match matches.get_one::<String>("PATH") {
+        Some(path) => path.clone(),
+        None => get_target_path(&config, &env::args().collect::<Vec<_>>()),
+    };
+    // Archives are scanned from a temporary extraction, removed when this guard is dropped
+    let extracted_archive = if is_archive_path(&dir_path) {
+        match extract_archive(&dir_path) {
+            Ok(extracted) => {
+                log(
+                    DebugLevel::Info,
+                    debug_level,
+                    &format!("Extracted {} for analysis", dir_path),
+                );
+                dir_path = extracted.path().to_string_lossy().to_string();
+                Some(extracted)
+            }
+            Err(e) => {
+                eprintln!("ERROR: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        None
+    };
+    // Remember the effective scan root so exported paths can be made relative to it
+    config.path = Some(dir_path.clone());
+
+    // Configure thread pool and rules registry, with rules seeing root-relative paths
     configure_thread_pool(&config, debug_level);
     let rules_registry_arc = Arc::new(setup_rules_registry(
         &config,
@@ -76,33 +347,242 @@ fn main() {
         debug_level,
     ));
 
-    // Find and process files
-    let dir_path = match matches.get_one::<String>("PATH") {
-        Some(path) => path.clone(),
-        None => get_target_path(&config, &env::args().collect::<Vec<_>>()),
+    let mut extensions = DEFAULT_EXTENSIONS.to_vec();
+    if config.experimental_sfc.unwrap_or(false) {
+        extensions.extend(SFC_EXTENSIONS);
+    }
+    // Never analyze what earlier runs wrote into an output directory below the scan root
+    let output_dir = get_output_dir(&config, &env::args().collect::<Vec<_>>());
+    let output_dirs: Vec<&str> = if output_dir == STDOUT_OUTPUT {
+        Vec::new()
+    } else {
+        vec![&output_dir]
     };
-
-    let (files, scan_duration) = find_files(&dir_path, debug_level);
-    let (analysis_results, analysis_duration) =
-        process_files(&files, &rules_registry_arc, debug_level);
+    // Profiled builds record the run as folded stacks when SENTINEL_PROFILE is set
+    #[cfg(feature = "profiling")]
+    let profiler = match scoper::profiling::Profiler::from_env() {
+        Ok(profiler) => profiler,
+        Err(e) => {
+            eprintln!("ERROR: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let (mut files, scan_duration) = match &config.files_from {
+        // An externally computed file set replaces the directory walk
+        Some(manifest) => match find_files_from_list(manifest, &extensions, debug_level) {
+            Ok(found) => found,
+            Err(e) => {
+                eprintln!("ERROR: {}", e);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => find_files_with_extensions(
+            &dir_path,
+            &extensions,
+            config.follow_symlinks.unwrap_or(false),
+            &output_dirs,
+            debug_level,
+        ),
+    };
+    // Discovery order depends on the filesystem, so sort to process files the same way every run
+    if config.sort_files.unwrap_or(true) {
+        files.sort_unstable();
+    }
+    if let Some(shard) = &config.shard {
+        files = select_shard(files, shard, &dir_path);
+        log(
+            DebugLevel::Info,
+            debug_level,
+            &format!("Shard {}: analyzing {} files", shard, files.len()),
+        );
+    }
+    // A separate pass over the discovered files that does not run any rule
+    if let Some(format) = config.ast_stats {
+        let stats = collect_ast_stats(&files, debug_level);
+        if let Err(e) = print_ast_stats(&stats, format) {
+            eprintln!("ERROR: {}", e);
+            return ExitCode::FAILURE;
+        }
+        return ExitCode::SUCCESS;
+    }
+    let resolver = Arc::new(setup_registry_resolver(
+        &config,
+        rules_registry_arc,
+        &dir_path,
+        &env::args().collect::<Vec<_>>(),
+        debug_level,
+    ));
+    let partial_results = match create_partial_results(&config, debug_level) {
+        Ok(partial_results) => Arc::new(partial_results),
+        Err(e) => {
+            eprintln!("ERROR: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    install_interrupt_handler(
+        &config,
+        &partial_results,
+        scan_duration,
+        extracted_archive
+            .as_ref()
+            .map(|extracted| extracted.path().to_path_buf()),
+        debug_level,
+    );
+    let analysis_duration = process_files_into(
+        &files,
+        &resolver,
+        config.read_concurrency,
+        config.export_graph.is_some(),
+        &partial_results,
+        debug_level,
+    );
+    let analysis_results = partial_results.take();
+    #[cfg(feature = "profiling")]
+    if let Some(profiler) = profiler {
+        let path = profiler.path().to_string();
+        match profiler.finish() {
+            Ok(()) => log(
+                DebugLevel::Info,
+                debug_level,
+                &format!("Wrote profile to {}", path),
+            ),
+            Err(e) => eprintln!("ERROR: {}", e),
+        }
+    }
 
     // Export results
-    let metrics = aggregate_metrics(&analysis_results, scan_duration, analysis_duration);
-    export_results(&config, &metrics, &analysis_results, debug_level);
+    let mut metrics = aggregate_metrics(&analysis_results, scan_duration, analysis_duration);
+    metrics.print_summary(debug_level);
+    if config.metrics_environment == Some(true) {
+        metrics.record_environment();
+    }
+    let findings_by_rule = export_results(&config, &metrics, &analysis_results, debug_level);
+    let budgets_exceeded = check_rule_budgets(&config, &findings_by_rule);
+
+    if config.shard.is_some() {
+        // Shard outputs are partial; they are submitted once combined with `scoper merge`
+        log(
+            DebugLevel::Info,
+            debug_level,
+            "Sharded run: skipping API submission, merge the shard outputs first",
+        );
+    } else if config.output_dir.as_deref() == Some(STDOUT_OUTPUT) {
+        // Findings printed to stdout were not written anywhere the submission could read them
+        log(
+            DebugLevel::Info,
+            debug_level,
+            "Findings written to stdout: skipping API submission",
+        );
+    } else {
+        submit_findings(&config, debug_level);
+    }
+
+    if budgets_exceeded {
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+/// Collect the analysis results, streaming each finished batch where the config asks for it
+fn create_partial_results(
+    config: &Config,
+    debug_level: DebugLevel,
+) -> Result<PartialResults, SentinelError> {
+    let mut listeners: Vec<BatchListener> = Vec::new();
+
+    if config.porcelain == Some(true) {
+        print_version_record()?;
+        // Stream the findings of each batch as soon as it is analyzed
+        let options = ExportOptions::from_config(config);
+        listeners.push(Box::new(move |results| {
+            if let Err(e) = print_finding_records(results, &options) {
+                eprintln!("ERROR: Failed to print finding records: {}", e);
+            }
+        }));
+    }
+
+    if config.incremental_findings == Some(true) {
+        let options = ExportOptions::from_config(config);
+        if options.to_stdout {
+            log(
+                DebugLevel::Warn,
+                debug_level,
+                "Findings are written to stdout, so no incremental findings file is written",
+            );
+        } else {
+            let writer = IncrementalFindingsWriter::create(options)?;
+            log(
+                DebugLevel::Info,
+                debug_level,
+                &format!("Appending findings to {} as batches finish", writer.path()),
+            );
+            listeners.push(Box::new(move |results| {
+                if let Err(e) = writer.append(results) {
+                    eprintln!("ERROR: Failed to append incremental findings: {}", e);
+                }
+            }));
+        }
+    }
+
+    if listeners.is_empty() {
+        return Ok(PartialResults::default());
+    }
+    Ok(PartialResults::with_listener(move |results| {
+        for listener in &listeners {
+            listener(results);
+        }
+    }))
+}
+
+/// Compare the findings per rule with the `budgets` of the rules config
+///
+/// Prints every rule over its budget and returns whether the run should fail. An invalid
+/// budgets section fails the run as well, rather than passing unchecked.
+fn check_rule_budgets(config: &Config, findings_by_rule: &HashMap<String, usize>) -> bool {
+    let Some(rules_config_path) = &config.rules_config else {
+        return false;
+    };
+    let budgets = match load_rule_budgets(rules_config_path) {
+        Ok(budgets) => budgets,
+        Err(e @ SentinelError::ConfigValidation(_)) => {
+            eprintln!("ERROR: Could not check rule budgets: {}", e);
+            return true;
+        }
+        // An unreadable rules config was already reported while setting up the rules
+        Err(_) => return false,
+    };
 
-    // Determine the path to findings.json
-    let output_dir_str = config.output_dir.as_deref().unwrap_or("findings");
-    let findings_path = std::path::Path::new(output_dir_str).join("findings.json");
+    let overruns = exceeded_budgets(findings_by_rule, &budgets);
+    if overruns.is_empty() {
+        return false;
+    }
+    eprintln!(
+        "ERROR: {} rules exceeded their finding budget:",
+        overruns.len()
+    );
+    for overrun in &overruns {
+        eprintln!(
+            "  {}: {} findings (budget: {})",
+            overrun.rule, overrun.findings, overrun.budget
+        );
+    }
+    true
+}
+
+/// Submit the findings.json of this run to the API
+fn submit_findings(config: &Config, debug_level: DebugLevel) {
+    // Read the findings from where this run exported them
+    let findings_path = ExportOptions::from_config(config).findings_path();
 
     if debug_level >= scoper::utilities::DebugLevel::Info {
-        println!("INFO: Attempting to read findings from: {}", findings_path.display());
+        status_println!("INFO: Attempting to read findings from: {}", findings_path);
     }
 
     match std::fs::read_to_string(&findings_path) {
         Ok(findings_content) => {
             match serde_json::from_str::<Value>(&findings_content) {
                 Ok(json_payload) => {
-                    if let Err(e) = send_results_to_api(&config, &json_payload, debug_level) {
+                    if let Err(e) = send_results_to_api(config, &json_payload, debug_level) {
                         if debug_level >= scoper::utilities::DebugLevel::Error {
                             eprintln!("ERROR: Failed to send results to API: {}", e);
                         }
@@ -117,21 +597,70 @@ fn main() {
         }
         Err(e) => {
             if debug_level >= scoper::utilities::DebugLevel::Error {
-                eprintln!("ERROR: Failed to read findings.json from {}: {}", findings_path.display(), e);
+                eprintln!("ERROR: Failed to read findings.json from {}: {}", findings_path, e);
             }
         }
     }
 }
 
+/// Exit status of a run interrupted with Ctrl-C, as for other programs killed by SIGINT
+const INTERRUPTED_EXIT_CODE: i32 = 130;
+
+/// On Ctrl-C, write the findings analyzed so far to findings.partial.json and exit
+///
+/// The handler exits without unwinding `main`, so it removes the directory an archive
+/// was extracted to itself.
+fn install_interrupt_handler(
+    config: &Config,
+    partial_results: &Arc<PartialResults>,
+    scan_duration: Duration,
+    extracted_archive: Option<PathBuf>,
+    debug_level: DebugLevel,
+) {
+    let config = config.clone();
+    let partial_results = Arc::clone(partial_results);
+    let analysis_start = Instant::now();
+
+    let result = ctrlc::set_handler(move || {
+        let results = partial_results.take();
+        // Nothing was analyzed yet, or the complete results are already being exported
+        if !results.is_empty() {
+            let path = export_partial_results(
+                &config,
+                &results,
+                scan_duration,
+                analysis_start.elapsed(),
+                debug_level,
+            );
+            eprintln!(
+                "Interrupted: wrote the findings of {} analyzed files to {}",
+                results.len(),
+                path
+            );
+        }
+        if let Some(extracted_archive) = &extracted_archive {
+            let _ = std::fs::remove_dir_all(extracted_archive);
+        }
+        std::process::exit(INTERRUPTED_EXIT_CODE);
+    });
+    if let Err(e) = result {
+        log(
+            DebugLevel::Warn,
+            debug_level,
+            &format!("Could not install the Ctrl-C handler: {}", e),
+        );
+    }
+}
+
 fn send_results_to_api(
     config: &Config,
     analysis_results: &Value, // Ensure this is serde_json::Value
     debug_level: scoper::utilities::DebugLevel,
 ) -> Result<(), Box<dyn std::error::Error>> { // Return a boxed error for more flexibility
-    let api_url = config.api_url.as_deref().unwrap_or("https://api.scoper.cloud/api/v1/projects/3/analysis_submissions");
+    let api_url = config.api_url.as_deref().unwrap_or(DEFAULT_API_URL);
 
     if debug_level >= scoper::utilities::DebugLevel::Info {
-        println!("INFO: Sending analysis results to {}", api_url);
+        status_println!("INFO: Sending analysis results to {}", api_url);
     }
 
     let client = Client::new();
@@ -139,12 +668,12 @@ fn send_results_to_api(
 
     let status = response.status();
     if debug_level >= scoper::utilities::DebugLevel::Debug {
-        println!("DEBUG: API Response Status: {}", status);
+        status_println!("DEBUG: API Response Status: {}", status);
     }
 
     if status.is_success() {
         if debug_level >= scoper::utilities::DebugLevel::Info {
-            println!("INFO: Successfully sent analysis results to API.");
+            status_println!("INFO: Successfully sent analysis results to API.");
         }
         // Optionally print response body for success if needed and content type is JSON
         // if debug_level >= scoper::utilities::DebugLevel::Debug {
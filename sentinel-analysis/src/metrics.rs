@@ -1,6 +1,13 @@
 use crate::FileAnalysisResult;
-use crate::exporter::export_findings_json;
+use crate::error::SentinelError;
+use crate::exporter::{
+    ExportOptions, FindingsExport, STDOUT_OUTPUT, build_findings_export,
+    partial_findings_file_name, write_findings_export,
+};
+use crate::module_graph::export_module_graph;
+use crate::status_println;
 use crate::utilities::config::Config;
+use crate::utilities::file_utils::to_relative_path;
 use crate::utilities::{DebugLevel, log};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -9,6 +16,7 @@ use std::io::{Read, Write};
 use std::ops::AddAssign;
 use std::path::Path;
 use std::time::{Duration, Instant};
+use sysinfo::{CpuRefreshKind, MemoryRefreshKind, RefreshKind, System};
 
 /// Performance metrics for tracking execution time of different operations
 /// Now aggregates results after parallel processing.
@@ -31,6 +39,47 @@ pub struct Metrics {
     pub rule_times: HashMap<String, Duration>,
     /// Rule execution counts (rule name -> count)
     pub rule_counts: HashMap<String, usize>,
+    /// Time each rule took on each file, for percentiles (rule name -> durations)
+    pub rule_samples: HashMap<String, Vec<Duration>>,
+    /// Machine the scan ran on, recorded only when requested
+    pub environment: Option<ScanEnvironment>,
+}
+
+/// Hardware and OS of the machine a scan ran on
+///
+/// Lets throughput numbers such as `files_per_second_wall_time` be compared across
+/// machines, e.g. different CI runners. Values the OS does not report are left empty.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ScanEnvironment {
+    pub cpu_model: Option<String>,
+    pub physical_cores: Option<usize>,
+    pub logical_cores: usize,
+    pub total_memory_bytes: u64,
+    pub os: Option<String>,
+}
+
+impl ScanEnvironment {
+    /// Query the CPU, memory and OS of the current machine
+    pub fn detect() -> Self {
+        let system = System::new_with_specifics(
+            RefreshKind::nothing()
+                .with_cpu(CpuRefreshKind::nothing())
+                .with_memory(MemoryRefreshKind::nothing().with_ram()),
+        );
+        let cpu_model = system
+            .cpus()
+            .first()
+            .map(|cpu| cpu.brand().trim().to_string())
+            .filter(|brand| !brand.is_empty());
+
+        Self {
+            cpu_model,
+            physical_cores: system.physical_core_count(),
+            logical_cores: system.cpus().len(),
+            total_memory_bytes: system.total_memory(),
+            os: System::long_os_version(),
+        }
+    }
 }
 
 /// Serializable metrics for export to JSON
@@ -62,6 +111,9 @@ struct ExportableMetrics {
     avg_semantic_time_ms: f64,
     // Rule execution metrics
     rule_execution_metrics: Vec<RuleMetric>,
+    // Scanning machine, only with `--metrics-environment`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    environment: Option<ScanEnvironment>,
 }
 
 /// Individual rule metrics for export
@@ -72,6 +124,35 @@ struct RuleMetric {
     execution_count: usize,
     avg_time_per_execution_us: f64,
     percent_of_total_rule_time: f64,
+    // Distribution of the time per file; absent in entries of older versions
+    #[serde(default)]
+    p50_time_us: f64,
+    #[serde(default)]
+    p95_time_us: f64,
+    #[serde(default)]
+    max_time_us: f64,
+}
+
+/// Time one rule spent on one file, an entry of `rule_file_timings.json`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RuleFileTiming {
+    pub rule: String,
+    pub file: String,
+    pub duration_ms: f64,
+}
+
+/// Name of the file listing the slowest rule and file combinations
+pub const RULE_FILE_TIMINGS_FILE: &str = "rule_file_timings.json";
+
+/// Single document holding everything a run produced
+///
+/// The findings and summary sit at the top level as in `findings.json`, so consumers of
+/// that format can read the combined report as well.
+#[derive(Serialize)]
+struct CombinedReport {
+    #[serde(flatten)]
+    findings: FindingsExport,
+    metrics: ExportableMetrics,
 }
 
 impl Metrics {
@@ -87,9 +168,16 @@ impl Metrics {
             semantic_times: HashMap::new(),
             rule_times: HashMap::new(),
             rule_counts: HashMap::new(),
+            rule_samples: HashMap::new(),
+            environment: None,
         }
     }
 
+    /// Record the machine the scan runs on, to be exported with the metrics
+    pub fn record_environment(&mut self) {
+        self.environment = Some(ScanEnvironment::detect());
+    }
+
     /// Record the duration of scanning for files (called once)
     pub fn record_scan_time(&mut self, duration: Duration) {
         self.scan_duration = Some(duration);
@@ -116,7 +204,12 @@ impl Metrics {
                 .or_insert(Duration::default())
                 .add_assign(duration);
             // Increment rule counts
-            *self.rule_counts.entry(rule_name).or_insert(0) += 1;
+            *self.rule_counts.entry(rule_name.clone()).or_insert(0) += 1;
+            // Keep the sample for percentiles
+            self.rule_samples
+                .entry(rule_name)
+                .or_default()
+                .push(duration);
         }
     }
 
@@ -130,11 +223,11 @@ impl Metrics {
         &self,
         json_path: Option<&String>,
         csv_path: Option<&String>,
-    ) -> Result<(), String> {
+    ) -> Result<(), SentinelError> {
         // Export metrics to JSON if configured
         if let Some(path) = json_path {
-            println!();
-            println!(
+            status_println!();
+            status_println!(
                 "\x1b[94mINFO:\x1b[0m Exporting metrics to JSON: \x1b[96m{}\x1b[0m",
                 path
             );
@@ -143,19 +236,19 @@ impl Metrics {
                     "\x1b[91mERROR:\x1b[0m Error exporting metrics to JSON: {}",
                     err
                 );
-                return Err(format!("Error exporting metrics to JSON: {}", err));
+                return Err(err);
             }
         }
 
         // Export metrics to CSV if configured
         if let Some(path) = csv_path {
-            println!(
+            status_println!(
                 "\x1b[94mINFO:\x1b[0m Exporting metrics to CSV: \x1b[96m{}\x1b[0m",
                 path
             );
             if let Err(err) = self.export_to_csv(path) {
                 eprintln!("ERROR: Error exporting metrics to CSV: {}", err);
-                return Err(format!("Error exporting metrics to CSV: {}", err));
+                return Err(err);
             }
         }
 
@@ -163,15 +256,15 @@ impl Metrics {
     }
 
     /// Export metrics to a JSON file, appending to existing data
-    pub fn export_to_json(&self, file_path: &str) -> Result<(), String> {
+    pub fn export_to_json(&self, file_path: &str) -> Result<(), SentinelError> {
         if self.total_duration.is_none() {
-            return Err("Total duration not measured yet. Call stop() first.".to_string());
+            return Err(SentinelError::MetricsNotStopped);
         }
 
         // Create directory if it doesn't exist
         if let Some(parent) = Path::new(file_path).parent() {
             fs::create_dir_all(parent)
-                .map_err(|e| format!("Failed to create directory for {}: {}", file_path, e))?;
+                .map_err(|e| SentinelError::io("create directory for", file_path, e))?;
         }
 
         let metrics = self.calculate_metrics()?;
@@ -179,11 +272,11 @@ impl Metrics {
         // Check if file exists and read existing metrics
         let mut metrics_array: Vec<ExportableMetrics> = if Path::new(file_path).exists() {
             let mut file = File::open(file_path)
-                .map_err(|e| format!("Failed to open existing file {}: {}", file_path, e))?;
+                .map_err(|e| SentinelError::io("open existing file", file_path, e))?;
 
             let mut contents = String::new();
             file.read_to_string(&mut contents)
-                .map_err(|e| format!("Failed to read existing file {}: {}", file_path, e))?;
+                .map_err(|e| SentinelError::io("read existing file", file_path, e))?;
 
             // Try to parse as array first
             serde_json::from_str::<Vec<ExportableMetrics>>(&contents).unwrap_or_else(|_| {
@@ -204,27 +297,50 @@ impl Metrics {
 
         // Serialize and write to file
         let json = serde_json::to_string_pretty(&metrics_array)
-            .map_err(|e| format!("Failed to serialize metrics: {}", e))?;
+            .map_err(|e| SentinelError::serialization("metrics", e))?;
 
-        let mut file = File::create(file_path)
-            .map_err(|e| format!("Failed to create file {}: {}", file_path, e))?;
+        let mut file =
+            File::create(file_path).map_err(|e| SentinelError::io("create file", file_path, e))?;
 
         file.write_all(json.as_bytes())
-            .map_err(|e| format!("Failed to write to file {}: {}", file_path, e))?;
+            .map_err(|e| SentinelError::io("write to file", file_path, e))?;
 
         Ok(())
     }
 
+    /// Write the findings export together with these metrics to one JSON file
+    ///
+    /// Unlike the metrics JSON export, an existing file is overwritten.
+    pub fn export_combined_report(
+        &self,
+        findings: FindingsExport,
+        file_path: &str,
+    ) -> Result<(), SentinelError> {
+        let report = CombinedReport {
+            findings,
+            metrics: self.calculate_metrics()?,
+        };
+
+        if let Some(parent) = Path::new(file_path).parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| SentinelError::io("create directory for", file_path, e))?;
+        }
+
+        let json = serde_json::to_string_pretty(&report)
+            .map_err(|e| SentinelError::serialization("combined report", e))?;
+        fs::write(file_path, json).map_err(|e| SentinelError::io("write to file", file_path, e))
+    }
+
     /// Export metrics to a CSV file, appending to existing data
-    pub fn export_to_csv(&self, file_path: &str) -> Result<(), String> {
+    pub fn export_to_csv(&self, file_path: &str) -> Result<(), SentinelError> {
         if self.total_duration.is_none() {
-            return Err("Total duration not measured yet. Call stop() first.".to_string());
+            return Err(SentinelError::MetricsNotStopped);
         }
 
         // Create directory if it doesn't exist
         if let Some(parent) = Path::new(file_path).parent() {
             fs::create_dir_all(parent)
-                .map_err(|e| format!("Failed to create directory for {}: {}", file_path, e))?;
+                .map_err(|e| SentinelError::io("create directory for", file_path, e))?;
         }
 
         let metrics = self.calculate_metrics()?;
@@ -265,26 +381,26 @@ impl Metrics {
             .create(true)
             .append(true)
             .open(file_path)
-            .map_err(|e| format!("Failed to open file {}: {}", file_path, e))?;
+            .map_err(|e| SentinelError::io("open file", file_path, e))?;
 
         // Write header only if file is new
         if !file_exists {
             file.write_all(header.as_bytes())
-                .map_err(|e| format!("Failed to write header to file {}: {}", file_path, e))?;
+                .map_err(|e| SentinelError::io("write header to file", file_path, e))?;
         }
 
         // Always append the new record
         file.write_all(record.as_bytes())
-            .map_err(|e| format!("Failed to write record to file {}: {}", file_path, e))?;
+            .map_err(|e| SentinelError::io("write record to file", file_path, e))?;
 
         Ok(())
     }
 
     /// Calculate normalized metrics, accounting for parallel processing
-    fn calculate_metrics(&self) -> Result<ExportableMetrics, String> {
+    fn calculate_metrics(&self) -> Result<ExportableMetrics, SentinelError> {
         let total_duration = self
             .total_duration
-            .ok_or_else(|| "Total duration not measured yet".to_string())?;
+            .ok_or(SentinelError::MetricsNotStopped)?;
         let scan_duration = self.scan_duration.unwrap_or(Duration::default());
         let analysis_duration = self.analysis_duration.unwrap_or(Duration::default());
 
@@ -313,12 +429,21 @@ impl Metrics {
             };
 
             if count > 0 {
+                let mut samples = self
+                    .rule_samples
+                    .get(rule_name)
+                    .cloned()
+                    .unwrap_or_default();
+                samples.sort_unstable();
                 rule_execution_metrics.push(RuleMetric {
                     rule_name: rule_name.clone(),
                     total_time_ms: duration.as_millis() as u64,
                     execution_count: count,
                     avg_time_per_execution_us: avg_time_us,
                     percent_of_total_rule_time: percent_of_total,
+                    p50_time_us: percentile_us(&samples, 50.0),
+                    p95_time_us: percentile_us(&samples, 95.0),
+                    max_time_us: percentile_us(&samples, 100.0),
                 });
             }
         }
@@ -401,17 +526,20 @@ impl Metrics {
             avg_parse_time_ms: avg_parse_time,
             avg_semantic_time_ms: avg_semantic_time,
             rule_execution_metrics,
+            environment: self.environment.clone(),
         })
     }
 
-    /// Print a summary of the collected metrics
-    pub fn print_summary(&self, debug_level: Option<&str>) {
+    /// Print a summary of the collected metrics, including per-rule time percentiles
+    ///
+    /// The summary is only printed at the `trace` debug level.
+    pub fn print_summary(&self, debug_level: DebugLevel) {
         if self.total_duration.is_none() {
             return;
         }
 
         // Only show detailed metrics for trace level
-        if debug_level != Some("trace") {
+        if debug_level < DebugLevel::Trace {
             return;
         }
 
@@ -421,44 +549,45 @@ impl Metrics {
 
                 // CPU Usage metrics
                 let cpu_time = Duration::from_millis(metrics.cumulative_processing_time_ms);
-                println!("--- CPU Usage Metrics ---");
-                println!("Cumulative CPU time: {:.2?}", cpu_time);
-                println!(
+                status_println!("--- CPU Usage Metrics ---");
+                status_println!("Cumulative CPU time: {:.2?}", cpu_time);
+                status_println!(
                     "Average time per file: {:.2?} μs",
                     metrics.avg_time_per_file_ms * 1000.0
                 );
-                println!(
+                status_println!(
                     "Processing rate per core: {:.2} files/sec",
                     metrics.files_per_second_cpu_time
                 );
 
                 // Parallelism metrics
-                println!("\n--- Parallelism Metrics ---");
-                println!(
+                status_println!("\n--- Parallelism Metrics ---");
+                status_println!(
                     "Parallel processing: {} threads",
                     metrics.parallel_cores_used
                 );
-                println!("Speedup factor: {:.2}x", metrics.parallel_speedup_factor);
-                println!(
+                status_println!("Speedup factor: {:.2}x", metrics.parallel_speedup_factor);
+                status_println!(
                     "Parallel efficiency: {:.1}%",
                     metrics.parallel_efficiency_percent
                 );
 
                 // Slowest file
                 let slowest_duration = Duration::from_millis(metrics.slowest_file_duration_ms);
-                println!(
+                status_println!(
                     "Slowest file: {} ({:.2?})",
-                    metrics.slowest_file, slowest_duration
+                    metrics.slowest_file,
+                    slowest_duration
                 );
 
                 // Parse and semantic analysis breakdown
-                println!("\n--- Detailed Analysis ---");
+                status_println!("\n--- Detailed Analysis ---");
                 let parse_time = Duration::from_millis(metrics.total_parse_time_ms);
                 let semantic_time = Duration::from_millis(metrics.total_semantic_time_ms);
 
                 // Clarify these are cumulative times across all cores
-                println!("Cumulative parse time (all cores): {:.2?}", parse_time);
-                println!(
+                status_println!("Cumulative parse time (all cores): {:.2?}", parse_time);
+                status_println!(
                     "Cumulative semantic analysis time (all cores): {:.2?}",
                     semantic_time
                 );
@@ -470,19 +599,19 @@ impl Metrics {
                     let normalized_semantic_time =
                         semantic_time.div_f64(metrics.parallel_cores_used as f64);
 
-                    println!("Est. parse time per thread: {:.2?}", normalized_parse_time);
-                    println!(
+                    status_println!("Est. parse time per thread: {:.2?}", normalized_parse_time);
+                    status_println!(
                         "Est. semantic analysis time per thread: {:.2?}",
                         normalized_semantic_time
                     );
                 }
 
                 // Per-file averages
-                println!(
+                status_println!(
                     "Average parse time per file: {:.2?} μs",
                     metrics.avg_parse_time_ms * 1000.0
                 );
-                println!(
+                status_println!(
                     "Average semantic analysis time per file: {:.2?} μs",
                     metrics.avg_semantic_time_ms * 1000.0
                 );
@@ -494,30 +623,34 @@ impl Metrics {
                         let parse_percent = parse_time.as_secs_f64() / total.as_secs_f64() * 100.0;
                         let semantic_percent =
                             semantic_time.as_secs_f64() / total.as_secs_f64() * 100.0;
-                        println!(
+                        status_println!(
                             "Phase breakdown: Parsing {:.1}% / Semantic Analysis {:.1}%",
-                            parse_percent, semantic_percent
+                            parse_percent,
+                            semantic_percent
                         );
                     }
                 }
 
                 // Rule execution metrics
                 if !metrics.rule_execution_metrics.is_empty() {
-                    println!("\n--- Rule Execution Metrics ---");
-                    println!(
-                        "Rule Name                          | Total Time  | Executions | Avg Time (μs) | % of Rule Time"
+                    status_println!("\n--- Rule Execution Metrics ---");
+                    status_println!(
+                        "Rule Name                          | Total Time  | Executions | Avg Time (μs) | P50 (μs)   | P95 (μs)   | Max (μs)   | % of Rule Time"
                     );
-                    println!(
-                        "-----------------------------------|-------------|------------|---------------|---------------"
+                    status_println!(
+                        "-----------------------------------|-------------|------------|---------------|------------|------------|------------|---------------"
                     );
 
                     for rule in &metrics.rule_execution_metrics {
-                        println!(
-                            "{:<35} | {:>11.2?} | {:>10} | {:>13.2} | {:>13.1}%",
+                        status_println!(
+                            "{:<35} | {:>11.2?} | {:>10} | {:>13.2} | {:>10.2} | {:>10.2} | {:>10.2} | {:>13.1}%",
                             rule.rule_name,
                             Duration::from_millis(rule.total_time_ms),
                             rule.execution_count,
                             rule.avg_time_per_execution_us,
+                            rule.p50_time_us,
+                            rule.p95_time_us,
+                            rule.max_time_us,
                             rule.percent_of_total_rule_time
                         );
                     }
@@ -530,6 +663,15 @@ impl Metrics {
     }
 }
 
+/// Nearest-rank percentile of sorted durations, in microseconds (0 without samples)
+fn percentile_us(sorted: &[Duration], percent: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = (percent / 100.0 * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1].as_secs_f64() * 1_000_000.0
+}
+
 /// Aggregate metrics from analysis results
 pub fn aggregate_metrics(
     analysis_results: &[FileAnalysisResult],
@@ -550,37 +692,126 @@ pub fn aggregate_metrics(
             rule_durations: result.rule_durations.clone(),
             total_duration: result.total_duration,
             diagnostics: Vec::new(), // Empty vec as diagnostics aren't needed for metrics
+            imports: Vec::new(),
+            skip_reason: result.skip_reason,
         };
         metrics.aggregate_file_result(result_to_aggregate);
     }
 
     metrics.stop();
-    metrics.print_summary(None);
 
     metrics
 }
 
+/// The `limit` slowest (rule, file) combinations of a run, slowest first
+///
+/// Paths are made relative to `path_root` when given, as in `findings.json`.
+pub fn slowest_rule_file_timings(
+    analysis_results: &[FileAnalysisResult],
+    limit: usize,
+    path_root: Option<&str>,
+) -> Vec<RuleFileTiming> {
+    let mut timings: Vec<(&str, &str, Duration)> = analysis_results
+        .iter()
+        .flat_map(|result| {
+            result
+                .rule_durations
+                .iter()
+                .map(|(rule, duration)| (rule.as_str(), result.file_path.as_str(), *duration))
+        })
+        .collect();
+    // Ties are broken by name so the list is stable across runs
+    timings.sort_by(|a, b| b.2.cmp(&a.2).then_with(|| (a.0, a.1).cmp(&(b.0, b.1))));
+
+    timings
+        .into_iter()
+        .take(limit)
+        .map(|(rule, file, duration)| RuleFileTiming {
+            rule: rule.to_string(),
+            file: match path_root {
+                Some(root) => to_relative_path(file, root),
+                None => file.to_string(),
+            },
+            duration_ms: duration.as_secs_f64() * 1000.0,
+        })
+        .collect()
+}
+
+/// Write the slowest rule and file combinations to `rule_file_timings.json`, if configured
+pub fn export_rule_file_timings(
+    config: &Config,
+    analysis_results: &[FileAnalysisResult],
+    debug_level: DebugLevel,
+) {
+    let Some(limit) = config.rule_file_timings else {
+        return;
+    };
+
+    let options = ExportOptions::from_config(config);
+    if options.to_stdout {
+        log(
+            DebugLevel::Warn,
+            debug_level,
+            &format!(
+                "Findings are printed to stdout, so {} is not written",
+                RULE_FILE_TIMINGS_FILE
+            ),
+        );
+        return;
+    }
+
+    let timings = slowest_rule_file_timings(analysis_results, limit, options.path_root.as_deref());
+    let file_path = format!("{}/{}", options.output_dir, RULE_FILE_TIMINGS_FILE);
+    let result = fs::create_dir_all(&options.output_dir)
+        .map_err(|e| SentinelError::io("create directory for", &file_path, e))
+        .and_then(|_| {
+            serde_json::to_string_pretty(&timings)
+                .map_err(|e| SentinelError::serialization("rule file timings", e))
+        })
+        .and_then(|json| {
+            fs::write(&file_path, json)
+                .map_err(|e| SentinelError::io("write to file", &file_path, e))
+        });
+
+    match result {
+        Ok(()) => log(
+            DebugLevel::Info,
+            debug_level,
+            &format!(
+                "Exported the {} slowest rule and file combinations to {}",
+                timings.len(),
+                file_path
+            ),
+        ),
+        Err(err) => log(
+            DebugLevel::Error,
+            debug_level,
+            &format!("Failed to export rule file timings: {}", err),
+        ),
+    }
+}
+
 /// Export metrics to files if configured
 pub fn export_metrics(config: &Config, metrics: &Metrics, debug_level: DebugLevel) {
     // Get the output directory
     let output_dir =
         crate::utilities::config::get_output_dir(config, &std::env::args().collect::<Vec<_>>());
 
-    // Set metrics paths based on output directory
-    let json_path = if let Some(path) = &config.export_metrics_json {
-        path.clone()
-    } else {
-        format!("{}/metrics.json", output_dir)
-    };
-
-    let csv_path = if let Some(path) = &config.export_metrics_csv {
-        path.clone()
-    } else {
-        format!("{}/metrics.csv", output_dir)
+    // Without an output directory only explicitly configured metrics files are written
+    let default_path = |file_name: &str| {
+        (output_dir != STDOUT_OUTPUT).then(|| format!("{}/{}", output_dir, file_name))
     };
+    let json_path = config
+        .export_metrics_json
+        .clone()
+        .or_else(|| default_path("metrics.json"));
+    let csv_path = config
+        .export_metrics_csv
+        .clone()
+        .or_else(|| default_path("metrics.csv"));
 
     // Call the export_to_configured_formats method on Metrics
-    if let Err(err) = metrics.export_to_configured_formats(Some(&json_path), Some(&csv_path)) {
+    if let Err(err) = metrics.export_to_configured_formats(json_path.as_ref(), csv_path.as_ref()) {
         log(
             DebugLevel::Error,
             debug_level,
@@ -590,18 +821,69 @@ pub fn export_metrics(config: &Config, metrics: &Metrics, debug_level: DebugLeve
 }
 
 /// Export analysis results and metrics
+///
+/// Returns the number of findings per rule, as in the findings summary.
 pub fn export_results(
     config: &Config,
     metrics: &Metrics,
     analysis_results: &[FileAnalysisResult],
     debug_level: DebugLevel,
-) {
+) -> HashMap<String, usize> {
     export_metrics(config, metrics, debug_level);
+    export_rule_file_timings(config, analysis_results, debug_level);
+
+    let options = ExportOptions::from_config(config);
+    export_module_graph(
+        config,
+        analysis_results,
+        options.path_root.as_deref(),
+        debug_level,
+    );
+    let findings_export = build_findings_export(analysis_results, metrics, debug_level, &options);
+    write_findings_export(&findings_export, debug_level, &options);
+    let findings_by_rule = findings_export.summary.findings_by_rule.clone();
+
+    if let Some(report_path) = &config.combined_report {
+        match metrics.export_combined_report(findings_export, report_path) {
+            Ok(()) => log(
+                DebugLevel::Info,
+                debug_level,
+                &format!("Exported combined report to {}", report_path),
+            ),
+            Err(err) => log(
+                DebugLevel::Error,
+                debug_level,
+                &format!("Failed to export combined report: {}", err),
+            ),
+        }
+    }
 
-    // Get output directory
-    let output_dir =
-        crate::utilities::config::get_output_dir(config, &std::env::args().collect::<Vec<_>>());
+    findings_by_rule
+}
 
-    // Pass output_dir to export_findings_json
-    export_findings_json(analysis_results, metrics, debug_level, &output_dir);
+/// Export the findings of an interrupted run to `findings.partial.json`
+///
+/// Metrics files and the combined report are left untouched, so the outputs of the
+/// last complete run stay intact. Returns where the findings were written.
+pub fn export_partial_results(
+    config: &Config,
+    analysis_results: &[FileAnalysisResult],
+    scan_duration: Duration,
+    analysis_duration: Duration,
+    debug_level: DebugLevel,
+) -> String {
+    let metrics = aggregate_metrics(analysis_results, scan_duration, analysis_duration);
+
+    let mut options = ExportOptions::from_config(config);
+    options.file_name = partial_findings_file_name(&options.file_name);
+    options.write_empty = true;
+
+    let findings_export = build_findings_export(analysis_results, &metrics, debug_level, &options);
+    write_findings_export(&findings_export, debug_level, &options);
+
+    if options.to_stdout {
+        STDOUT_OUTPUT.to_string()
+    } else {
+        options.findings_path()
+    }
 }
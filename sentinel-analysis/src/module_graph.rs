@@ -0,0 +1,210 @@
+use crate::FileAnalysisResult;
+use crate::error::SentinelError;
+use crate::utilities::config::Config;
+use crate::utilities::file_utils::to_relative_path;
+use crate::utilities::{DebugLevel, log};
+
+use oxc_ast::ast::{Program, Statement};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+/// Extensions tried, in order, for import specifiers without one
+const RESOLVED_EXTENSIONS: [&str; 8] = ["ts", "tsx", "mts", "cts", "js", "jsx", "mjs", "cjs"];
+
+/// Import graph of the analyzed files, as written by `--export-graph`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModuleGraph {
+    /// Every analyzed file, sorted
+    pub nodes: Vec<String>,
+    /// Imports between analyzed files, sorted by importing and imported file
+    pub edges: Vec<ModuleEdge>,
+}
+
+/// An import of one analyzed file by another
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct ModuleEdge {
+    /// The importing file
+    pub from: String,
+    /// The imported file
+    pub to: String,
+}
+
+/// Module specifiers of the imports and re-exports of a program, in source order
+pub fn import_sources(program: &Program) -> Vec<String> {
+    program
+        .body
+        .iter()
+        .filter_map(|statement| match statement {
+            Statement::ImportDeclaration(import) => Some(&import.source),
+            Statement::ExportNamedDeclaration(export) => export.source.as_ref(),
+            Statement::ExportAllDeclaration(export) => Some(&export.source),
+            _ => None,
+        })
+        .map(|source| source.value.to_string())
+        .collect()
+}
+
+/// Remove `.` and `..` components without touching the file system
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    normalized.push("..");
+                }
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+/// Paths a relative import specifier may refer to, most specific first
+fn candidates(base: &Path) -> Vec<PathBuf> {
+    let mut candidates = vec![base.to_path_buf()];
+    let name = base.to_string_lossy();
+
+    // TypeScript sources import each other with the extension of the compiled file
+    if let Some(stem) = name.strip_suffix(".js") {
+        candidates.extend(["ts", "tsx"].map(|ext| PathBuf::from(format!("{}.{}", stem, ext))));
+    }
+    candidates.extend(RESOLVED_EXTENSIONS.map(|ext| PathBuf::from(format!("{}.{}", name, ext))));
+    candidates.extend(RESOLVED_EXTENSIONS.map(|ext| base.join(format!("index.{}", ext))));
+    candidates
+}
+
+/// Build the import graph of the analyzed files
+///
+/// Only relative specifiers (`./a`, `../b/index.js`) are resolved, against the analyzed
+/// files rather than the file system. Imports of packages and of files outside the
+/// analysis are left out. Paths are made relative to `path_root` when given, as in
+/// `findings.json`.
+pub fn build_module_graph(
+    analysis_results: &[FileAnalysisResult],
+    path_root: Option<&str>,
+) -> ModuleGraph {
+    let display_path = |file_path: &str| match path_root {
+        Some(root) => to_relative_path(file_path, root),
+        None => file_path.replace('\\', "/"),
+    };
+    let files: HashMap<PathBuf, String> = analysis_results
+        .iter()
+        .map(|result| {
+            (
+                normalize(Path::new(&result.file_path)),
+                display_path(&result.file_path),
+            )
+        })
+        .collect();
+
+    let mut edges = BTreeSet::new();
+    for result in analysis_results {
+        let directory = Path::new(&result.file_path)
+            .parent()
+            .unwrap_or(Path::new(""));
+        for specifier in &result.imports {
+            if !specifier.starts_with("./") && !specifier.starts_with("../") {
+                continue;
+            }
+            let base = normalize(&directory.join(specifier));
+            if let Some(target) = candidates(&base)
+                .iter()
+                .find_map(|candidate| files.get(candidate))
+            {
+                edges.insert(ModuleEdge {
+                    from: display_path(&result.file_path),
+                    to: target.clone(),
+                });
+            }
+        }
+    }
+
+    let mut nodes: Vec<String> = files.into_values().collect();
+    nodes.sort();
+    nodes.dedup();
+    ModuleGraph {
+        nodes,
+        edges: edges.into_iter().collect(),
+    }
+}
+
+impl ModuleGraph {
+    /// Render the graph in the DOT language of Graphviz
+    pub fn to_dot(&self) -> String {
+        let quote =
+            |value: &str| format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""));
+
+        let mut dot = String::from("digraph modules {\n");
+        for node in &self.nodes {
+            dot.push_str(&format!("  {};\n", quote(node)));
+        }
+        for edge in &self.edges {
+            dot.push_str(&format!(
+                "  {} -> {};\n",
+                quote(&edge.from),
+                quote(&edge.to)
+            ));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Whether a graph file is written as DOT rather than JSON, judged by its extension
+fn is_dot_path(file_path: &str) -> bool {
+    Path::new(file_path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| matches!(ext, "dot" | "gv"))
+}
+
+/// Write the import graph of the analyzed files to the configured `--export-graph` file
+///
+/// Files ending in `.dot` or `.gv` are written as DOT, all others as JSON.
+pub fn export_module_graph(
+    config: &Config,
+    analysis_results: &[FileAnalysisResult],
+    path_root: Option<&str>,
+    debug_level: DebugLevel,
+) {
+    let Some(file_path) = &config.export_graph else {
+        return;
+    };
+
+    let graph = build_module_graph(analysis_results, path_root);
+    let content = if is_dot_path(file_path) {
+        Ok(graph.to_dot())
+    } else {
+        serde_json::to_string_pretty(&graph)
+            .map_err(|e| SentinelError::serialization("module graph", e))
+    };
+    let result = content.and_then(|content| {
+        if let Some(parent) = Path::new(file_path).parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| SentinelError::io("create directory for", file_path, e))?;
+        }
+        fs::write(file_path, content).map_err(|e| SentinelError::io("write to file", file_path, e))
+    });
+
+    match result {
+        Ok(()) => log(
+            DebugLevel::Info,
+            debug_level,
+            &format!(
+                "Exported the import graph of {} files ({} imports) to {}",
+                graph.nodes.len(),
+                graph.edges.len(),
+                file_path
+            ),
+        ),
+        Err(err) => log(
+            DebugLevel::Error,
+            debug_level,
+            &format!("Failed to export module graph: {}", err),
+        ),
+    }
+}
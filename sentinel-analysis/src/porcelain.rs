@@ -0,0 +1,88 @@
+use crate::FileAnalysisResult;
+use crate::error::SentinelError;
+use crate::exporter::{ExportOptions, FindingsSummary, finding_entries};
+
+use serde::Serialize;
+use serde_json::json;
+use std::io::{self, Write};
+
+/// First field of every porcelain record
+pub const PORCELAIN_PREFIX: &str = "SENTINEL";
+
+/// Version of the porcelain record grammar
+///
+/// Bumped whenever a record kind is removed or an existing payload field is removed,
+/// renamed or changes its type. Adding record kinds or payload fields is not a breaking
+/// change, so consumers must ignore what they do not know.
+pub const PORCELAIN_VERSION: u32 = 1;
+
+/// Kind of a porcelain record, its second field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordKind {
+    /// First record of a run: `{"version": <PORCELAIN_VERSION>}`
+    Version,
+    /// One finding, shaped like an entry of `findings` in findings.json
+    Finding,
+    /// Last record of a run, shaped like `summary` in findings.json
+    Summary,
+}
+
+impl RecordKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RecordKind::Version => "version",
+            RecordKind::Finding => "finding",
+            RecordKind::Summary => "summary",
+        }
+    }
+}
+
+/// Render a porcelain record: `SENTINEL<TAB><kind><TAB><json>`, without a line break
+///
+/// The payload is compact JSON, which escapes tabs and line breaks inside strings, so a
+/// record never spans more than one line.
+pub fn porcelain_record(
+    kind: RecordKind,
+    payload: &impl Serialize,
+) -> Result<String, SentinelError> {
+    let json = serde_json::to_string(payload)
+        .map_err(|e| SentinelError::serialization("porcelain record", e))?;
+    Ok(format!("{}\t{}\t{}", PORCELAIN_PREFIX, kind.as_str(), json))
+}
+
+/// Print records to stdout, one per line, without interleaving other threads' records
+fn print_records(records: &[String]) -> Result<(), SentinelError> {
+    let mut stdout = io::stdout().lock();
+    for record in records {
+        writeln!(stdout, "{}", record).map_err(|e| SentinelError::io("write", "stdout", e))?;
+    }
+    stdout
+        .flush()
+        .map_err(|e| SentinelError::io("write", "stdout", e))
+}
+
+/// Print the `version` record that starts every porcelain run
+pub fn print_version_record() -> Result<(), SentinelError> {
+    let record = porcelain_record(
+        RecordKind::Version,
+        &json!({ "version": PORCELAIN_VERSION }),
+    )?;
+    print_records(&[record])
+}
+
+/// Print a `finding` record for every diagnostic of the analyzed files
+pub fn print_finding_records(
+    results: &[FileAnalysisResult],
+    options: &ExportOptions,
+) -> Result<(), SentinelError> {
+    let records = finding_entries(results, options)
+        .iter()
+        .map(|finding| porcelain_record(RecordKind::Finding, finding))
+        .collect::<Result<Vec<_>, _>>()?;
+    print_records(&records)
+}
+
+/// Print the `summary` record that ends every porcelain run
+pub fn print_summary_record(summary: &FindingsSummary) -> Result<(), SentinelError> {
+    print_records(&[porcelain_record(RecordKind::Summary, summary)?])
+}
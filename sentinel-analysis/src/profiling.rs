@@ -0,0 +1,165 @@
+//! Spans for profiling a run with flamegraphs
+//!
+//! With the `profiling` feature, [`span`] and [`in_span`] record `tracing` spans around
+//! file discovery, each batch, the parse, semantic and rules phases of each file and
+//! each rule run on the semantic model. [`Profiler::from_env`] writes them as folded
+//! stacks to the file named by `SENTINEL_PROFILE`, for `inferno-flamegraph` or
+//! `flamegraph.pl`. Without the feature, or when no profiler was started, spans cost
+//! nothing beyond a function call.
+
+#[cfg(feature = "profiling")]
+use crate::error::SentinelError;
+
+/// Environment variable naming the folded stacks file of a profiled run
+pub const PROFILE_ENV: &str = "SENTINEL_PROFILE";
+
+/// Guard of an entered span, exited when the guard is dropped
+#[must_use = "the span is exited as soon as the guard is dropped"]
+pub struct ProfileSpan {
+    #[cfg(feature = "profiling")]
+    _entered: tracing::span::EnteredSpan,
+}
+
+/// Enter a span named `name` until the returned guard is dropped
+///
+/// Rule names are only known at runtime, so spans are not created with the `tracing`
+/// macros, which need a literal name: each distinct name gets its own callsite.
+#[inline]
+pub fn span(name: &'static str) -> ProfileSpan {
+    let _ = name;
+    ProfileSpan {
+        #[cfg(feature = "profiling")]
+        _entered: enabled::new_span(name).entered(),
+    }
+}
+
+/// Run `f` inside a span named `name`
+#[inline]
+pub fn in_span<T>(name: &'static str, f: impl FnOnce() -> T) -> T {
+    let _span = span(name);
+    f()
+}
+
+/// Records the spans of a run into a folded stacks file
+#[cfg(feature = "profiling")]
+pub struct Profiler {
+    /// Path of the folded stacks file
+    path: String,
+    guard: tracing_flame::FlushGuard<std::io::BufWriter<std::fs::File>>,
+}
+
+#[cfg(feature = "profiling")]
+impl Profiler {
+    /// Start recording into the file named by `SENTINEL_PROFILE`, if the variable is set
+    pub fn from_env() -> Result<Option<Self>, SentinelError> {
+        match std::env::var(PROFILE_ENV) {
+            Ok(path) if !path.is_empty() => Self::start(&path).map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// Install a global subscriber writing the spans of all threads to `path`
+    ///
+    /// Stacks of all threads are merged, so the flamegraph shows the total time spent in
+    /// each phase across the thread pool.
+    pub fn start(path: &str) -> Result<Self, SentinelError> {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let file = std::fs::File::create(path).map_err(|e| SentinelError::io("create", path, e))?;
+        let layer = tracing_flame::FlameLayer::new(std::io::BufWriter::new(file))
+            .with_threads_collapsed(true)
+            .with_module_path(false)
+            .with_file_and_line(false);
+        let guard = layer.flush_on_drop();
+        // Only the first subscriber is installed; the binary starts a single profiler
+        let _ = tracing::subscriber::set_global_default(tracing_subscriber::registry().with(layer));
+
+        Ok(Self {
+            path: path.to_string(),
+            guard,
+        })
+    }
+
+    /// Path of the folded stacks file
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Write the stacks of all spans closed so far to the file
+    pub fn finish(self) -> Result<(), SentinelError> {
+        self.guard
+            .flush()
+            .map_err(|e| SentinelError::io("write", &self.path, std::io::Error::other(e)))
+    }
+}
+
+#[cfg(feature = "profiling")]
+mod enabled {
+    use std::collections::HashMap;
+    use std::sync::{LazyLock, OnceLock, PoisonError, RwLock};
+
+    use tracing_core::callsite::{self, Callsite, Identifier};
+    use tracing_core::field::FieldSet;
+    use tracing_core::metadata::Kind;
+    use tracing_core::subscriber::Interest;
+    use tracing_core::{Level, Metadata};
+
+    /// Callsite of the spans with one name, registered the first time the name is used
+    struct NamedCallsite {
+        metadata: OnceLock<Metadata<'static>>,
+    }
+
+    impl Callsite for NamedCallsite {
+        fn set_interest(&self, _interest: Interest) {}
+
+        fn metadata(&self) -> &Metadata<'_> {
+            self.metadata
+                .get()
+                .expect("callsite metadata is set before registration")
+        }
+    }
+
+    /// Metadata of the callsite of each span name
+    static CALLSITES: LazyLock<RwLock<HashMap<&'static str, &'static Metadata<'static>>>> =
+        LazyLock::new(Default::default);
+
+    fn metadata(name: &'static str) -> &'static Metadata<'static> {
+        let known = CALLSITES
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(name)
+            .copied();
+        if let Some(metadata) = known {
+            return metadata;
+        }
+
+        let mut callsites = CALLSITES.write().unwrap_or_else(PoisonError::into_inner);
+        callsites.entry(name).or_insert_with(|| {
+            // Callsites live for the whole run; there is one per phase and enabled rule
+            let callsite: &'static NamedCallsite = Box::leak(Box::new(NamedCallsite {
+                metadata: OnceLock::new(),
+            }));
+            let _ = callsite.metadata.set(Metadata::new(
+                name,
+                "scoper",
+                Level::INFO,
+                None,
+                None,
+                None,
+                FieldSet::new(&[], Identifier(callsite)),
+                Kind::SPAN,
+            ));
+            callsite::register(callsite);
+            callsite.metadata()
+        })
+    }
+
+    pub(super) fn new_span(name: &'static str) -> tracing::Span {
+        // Skip the callsite lookup when no profiler was started
+        if !tracing::dispatcher::has_been_set() {
+            return tracing::Span::none();
+        }
+        let metadata = metadata(name);
+        tracing::Span::new(metadata, &metadata.fields().value_set(&[]))
+    }
+}
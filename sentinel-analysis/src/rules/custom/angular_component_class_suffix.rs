@@ -3,9 +3,9 @@ use oxc_ast::ast::{Class, Decorator, Expression};
 use oxc_ast_visit::Visit;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::Span;
-use serde_json::Value;
+use serde_json::{Value, json};
 
-use crate::rules::Rule;
+use crate::rules::{ANGULAR_CORE_MODULE, Rule};
 
 /// Rule that enforces Angular component class naming convention
 ///
@@ -120,6 +120,23 @@ impl Rule for AngularComponentClassSuffixRule {
         "Enforces that classes decorated with @Component have the suffix 'Component' (or custom suffix)"
     }
 
+    fn applies_to_source(&self, source: &str) -> bool {
+        source.contains(ANGULAR_CORE_MODULE)
+    }
+
+    fn config_schema(&self) -> Option<Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "suffixes": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "default": ["Component"]
+                }
+            }
+        }))
+    }
+
     fn set_config(&mut self, config: Value) {
         if let Some(obj) = config.as_object() {
             if let Some(suffixes) = obj.get("suffixes") {
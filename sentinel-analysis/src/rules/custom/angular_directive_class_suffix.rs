@@ -3,9 +3,9 @@ use oxc_ast::ast::{Class, Decorator, Expression};
 use oxc_ast_visit::Visit;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::Span;
-use serde_json::Value;
+use serde_json::{Value, json};
 
-use crate::rules::Rule;
+use crate::rules::{ANGULAR_CORE_MODULE, Rule};
 
 /// Rule that enforces Angular directive class naming convention
 ///
@@ -119,6 +119,23 @@ impl Rule for AngularDirectiveClassSuffixRule {
         "Enforces that classes decorated with @Directive have the suffix 'Directive' (or custom suffix)"
     }
 
+    fn applies_to_source(&self, source: &str) -> bool {
+        source.contains(ANGULAR_CORE_MODULE)
+    }
+
+    fn config_schema(&self) -> Option<Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "suffixes": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "default": ["Directive"]
+                }
+            }
+        }))
+    }
+
     fn set_config(&mut self, config: Value) {
         if let Some(obj) = config.as_object() {
             if let Some(suffixes) = obj.get("suffixes") {
@@ -3,9 +3,9 @@ use oxc_ast::ast::{CallExpression, Expression};
 use oxc_ast_visit::Visit;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::Span;
-use serde_json::Value;
+use serde_json::{Value, json};
 
-use crate::rules::Rule;
+use crate::rules::{ANGULAR_CORE_MODULE, Rule};
 
 /// Rule that checks for excessive Angular signal inputs
 ///
@@ -124,6 +124,19 @@ impl Rule for AngularInputCountRule {
         "Checks for excessive Angular signal inputs"
     }
 
+    fn applies_to_source(&self, source: &str) -> bool {
+        source.contains(ANGULAR_CORE_MODULE)
+    }
+
+    fn config_schema(&self) -> Option<Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "maxInputs": { "type": "integer", "minimum": 0, "default": 5 }
+            }
+        }))
+    }
+
     fn set_config(&mut self, config: Value) {
         if let Some(obj) = config.as_object() {
             if let Some(max_inputs) = obj.get("maxInputs") {
@@ -1,10 +1,10 @@
 use oxc_ast::AstKind;
 use oxc_ast::ast::Expression;
-use oxc_diagnostics::OxcDiagnostic;
+use oxc_diagnostics::{OxcDiagnostic, Severity};
 use oxc_span::Span;
 use std::collections::HashSet;
 
-use crate::rules::Rule;
+use crate::rules::{ANGULAR_CORE_MODULE, Rule};
 
 /// Rule that checks for legacy Angular decorators that should be replaced with signal-based alternatives
 ///
@@ -68,6 +68,14 @@ impl Rule for AngularLegacyDecoratorsRule {
         "Detects usage of legacy Angular decorators that should be replaced with signal-based alternatives"
     }
 
+    fn applies_to_source(&self, source: &str) -> bool {
+        source.contains(ANGULAR_CORE_MODULE)
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
     fn run_on_node(&self, node: &AstKind, _span: Span, _file_path: &str) -> Vec<OxcDiagnostic> {
         let mut diagnostics = Vec::new();
 
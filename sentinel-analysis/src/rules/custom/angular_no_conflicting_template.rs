@@ -0,0 +1,122 @@
+use oxc_ast::AstKind;
+use oxc_ast::ast::{Argument, Decorator, Expression, ObjectPropertyKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::Span;
+
+use crate::rules::{ANGULAR_CORE_MODULE, Rule, prop_key_name};
+
+/// Inline and external variants of the same `@Component` metadata
+const CONFLICTING_KEYS: [(&str, &[&str]); 2] = [
+    ("template", &["templateUrl"]),
+    ("styles", &["styleUrls", "styleUrl"]),
+];
+
+/// Rule that flags `@Component` decorators with both inline and external templates or styles
+///
+/// A component has a single template: Angular rejects metadata defining both `template`
+/// and `templateUrl`. Mixing `styles` with `styleUrls` or `styleUrl` is reported as well,
+/// as it splits the styles of one component across two places.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code:
+///
+/// ```typescript
+/// @Component({
+///   selector: 'app-card',
+///   template: '<div class="card"></div>',
+///   templateUrl: './card.component.html',
+/// })
+/// export class CardComponent {}
+/// ```
+///
+/// Examples of **correct** code:
+///
+/// ```typescript
+/// @Component({
+///   selector: 'app-card',
+///   templateUrl: './card.component.html',
+///   styleUrls: ['./card.component.css'],
+/// })
+/// export class CardComponent {}
+/// ```
+pub struct AngularNoConflictingTemplateRule;
+
+impl AngularNoConflictingTemplateRule {
+    /// Pairs of conflicting keys defined by a `@Component({...})` decorator
+    fn conflicting_keys(decorator: &Decorator) -> Vec<(&'static str, &'static str)> {
+        let Expression::CallExpression(call) = &decorator.expression else {
+            return Vec::new();
+        };
+        if !matches!(&call.callee, Expression::Identifier(callee) if callee.name == "Component") {
+            return Vec::new();
+        }
+        let Some(Argument::ObjectExpression(metadata)) = call.arguments.first() else {
+            return Vec::new();
+        };
+
+        let keys: Vec<&str> = metadata
+            .properties
+            .iter()
+            .filter_map(|property| match property {
+                ObjectPropertyKind::ObjectProperty(property) => prop_key_name(&property.key),
+                ObjectPropertyKind::SpreadProperty(_) => None,
+            })
+            .collect();
+
+        let mut conflicts = Vec::new();
+        for (inline, externals) in CONFLICTING_KEYS {
+            if !keys.contains(&inline) {
+                continue;
+            }
+            for external in externals {
+                if keys.contains(external) {
+                    conflicts.push((inline, *external));
+                }
+            }
+        }
+        conflicts
+    }
+
+    fn create_diagnostic(conflicts: &[(&str, &str)], span: Span) -> OxcDiagnostic {
+        let pairs: Vec<String> = conflicts
+            .iter()
+            .map(|(inline, external)| format!("`{}` and `{}`", inline, external))
+            .collect();
+
+        OxcDiagnostic::error(format!("@Component defines both {}", pairs.join(", ")))
+            .with_help("Keep either the inline metadata or the external file reference")
+            .with_label(span.label("Conflicting @Component metadata"))
+    }
+}
+
+impl Rule for AngularNoConflictingTemplateRule {
+    fn name(&self) -> &'static str {
+        "angular-no-conflicting-template"
+    }
+
+    fn description(&self) -> &'static str {
+        "Disallows @Component metadata with both inline and external templates or styles"
+    }
+
+    fn applies_to_source(&self, source: &str) -> bool {
+        source.contains(ANGULAR_CORE_MODULE)
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["angular"]
+    }
+
+    fn run_on_node(&self, node: &AstKind, _span: Span, _file_path: &str) -> Vec<OxcDiagnostic> {
+        let AstKind::Decorator(decorator) = node else {
+            return Vec::new();
+        };
+
+        let conflicts = Self::conflicting_keys(decorator);
+        if conflicts.is_empty() {
+            Vec::new()
+        } else {
+            vec![Self::create_diagnostic(&conflicts, decorator.span)]
+        }
+    }
+}
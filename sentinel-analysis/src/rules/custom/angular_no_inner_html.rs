@@ -0,0 +1,216 @@
+use oxc_ast::AstKind;
+use oxc_ast::ast::{
+    Argument, AssignmentExpression, AssignmentTarget, Class, Decorator, Expression,
+    ObjectPropertyKind,
+};
+use oxc_ast_visit::{Visit, walk};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::SemanticBuilderReturn;
+use oxc_span::{GetSpan, Span};
+use serde_json::{Value, json};
+
+use crate::rules::{Rule, prop_key_name};
+
+/// Template binding that renders raw HTML
+const INNER_HTML_BINDING: &str = "[innerHTML]";
+
+/// Rule that flags rendering of raw HTML through `innerHTML`
+///
+/// Assigning untrusted values to `innerHTML` is a common XSS vector. The rule reports
+/// `[innerHTML]` bindings in inline `@Component` templates (found by scanning the
+/// template string) and `element.innerHTML = ...` assignments in TypeScript code.
+///
+/// A finding is skipped when the bound expression or the assigned value contains one of
+/// the allowed patterns, which marks values that were sanitized with `DomSanitizer`.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code:
+///
+/// ```typescript
+/// @Component({ template: '<div [innerHTML]="comment.body"></div>' })
+/// export class CommentComponent {}
+///
+/// element.innerHTML = userInput;
+/// ```
+///
+/// Examples of **correct** code:
+///
+/// ```typescript
+/// @Component({ template: '<div [innerHTML]="sanitizer.sanitize(1, comment.body)"></div>' })
+/// export class CommentComponent {}
+///
+/// element.textContent = userInput;
+/// ```
+///
+/// ## Rule Options
+///
+/// - `allowedPatterns`: Substrings marking sanitized values (default: `["sanitize("]`)
+pub struct AngularNoInnerHtmlRule {
+    /// Substrings that mark a bound or assigned value as sanitized
+    allowed_patterns: Vec<String>,
+}
+
+impl Default for AngularNoInnerHtmlRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AngularNoInnerHtmlRule {
+    pub fn new() -> Self {
+        Self {
+            allowed_patterns: vec!["sanitize(".to_string()],
+        }
+    }
+}
+
+/// Visitor that collects `innerHTML` usages from templates and assignments
+struct InnerHtmlVisitor<'r, 's> {
+    diagnostics: Vec<OxcDiagnostic>,
+    source: &'s str,
+    allowed_patterns: &'r [String],
+}
+
+impl InnerHtmlVisitor<'_, '_> {
+    fn is_allowed(&self, value: &str) -> bool {
+        self.allowed_patterns
+            .iter()
+            .any(|pattern| value.contains(pattern.as_str()))
+    }
+
+    fn create_diagnostic(span: Span, label: &'static str) -> OxcDiagnostic {
+        OxcDiagnostic::error("Untrusted value rendered through innerHTML")
+            .with_help("Sanitize the value with `DomSanitizer` or render it as text instead")
+            .with_label(span.label(label))
+    }
+
+    /// Extract the quoted expression following `[innerHTML]=`
+    fn binding_expression(rest: &str) -> Option<&str> {
+        let rest = rest.trim_start().strip_prefix('=')?.trim_start();
+        let quote = rest.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+        let rest = &rest[1..];
+        rest.find(quote).map(|end| &rest[..end])
+    }
+
+    /// Scan the raw text of an inline template for `[innerHTML]` bindings
+    fn check_template(&mut self, template: &Expression) {
+        if !matches!(
+            template,
+            Expression::StringLiteral(_) | Expression::TemplateLiteral(_)
+        ) {
+            return;
+        }
+
+        let span = template.span();
+        let text = &self.source[span.start as usize..span.end as usize];
+        for (offset, _) in text.match_indices(INNER_HTML_BINDING) {
+            let rest = &text[offset + INNER_HTML_BINDING.len()..];
+            if Self::binding_expression(rest).is_some_and(|expr| self.is_allowed(expr)) {
+                continue;
+            }
+
+            let start = span.start + offset as u32;
+            self.diagnostics.push(Self::create_diagnostic(
+                Span::new(start, start + INNER_HTML_BINDING.len() as u32),
+                "[innerHTML] binding in template",
+            ));
+        }
+    }
+
+    fn check_component(&mut self, decorator: &Decorator) {
+        let Expression::CallExpression(call) = &decorator.expression else {
+            return;
+        };
+        if !matches!(&call.callee, Expression::Identifier(ident) if ident.name == "Component") {
+            return;
+        }
+        let Some(Argument::ObjectExpression(metadata)) = call.arguments.first() else {
+            return;
+        };
+
+        for property in &metadata.properties {
+            if let ObjectPropertyKind::ObjectProperty(property) = property
+                && prop_key_name(&property.key) == Some("template")
+            {
+                self.check_template(&property.value);
+            }
+        }
+    }
+}
+
+impl<'a> Visit<'a> for InnerHtmlVisitor<'_, '_> {
+    fn visit_class(&mut self, class: &Class<'a>) {
+        for decorator in &class.decorators {
+            self.check_component(decorator);
+        }
+        walk::walk_class(self, class);
+    }
+
+    fn visit_assignment_expression(&mut self, expr: &AssignmentExpression<'a>) {
+        if let AssignmentTarget::StaticMemberExpression(member) = &expr.left
+            && member.property.name == "innerHTML"
+            && !self.is_allowed(expr.right.span().source_text(self.source))
+        {
+            self.diagnostics
+                .push(Self::create_diagnostic(expr.span, "innerHTML assignment"));
+        }
+        walk::walk_assignment_expression(self, expr);
+    }
+}
+
+impl Rule for AngularNoInnerHtmlRule {
+    fn name(&self) -> &'static str {
+        "angular-no-inner-html"
+    }
+
+    fn description(&self) -> &'static str {
+        "Flags [innerHTML] template bindings and innerHTML assignments"
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["angular", "security"]
+    }
+
+    fn config_schema(&self) -> Option<Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "allowedPatterns": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "default": ["sanitize("]
+                }
+            }
+        }))
+    }
+
+    fn set_config(&mut self, config: Value) {
+        if let Some(patterns) = config.get("allowedPatterns").and_then(Value::as_array) {
+            self.allowed_patterns = patterns
+                .iter()
+                .filter_map(|pattern| pattern.as_str().map(str::to_string))
+                .collect();
+        }
+    }
+
+    fn run_on_semantic(
+        &self,
+        semantic_result: &SemanticBuilderReturn,
+        _file_path: &str,
+    ) -> Vec<OxcDiagnostic> {
+        let semantic = &semantic_result.semantic;
+        let mut visitor = InnerHtmlVisitor {
+            diagnostics: Vec::new(),
+            source: semantic.source_text(),
+            allowed_patterns: &self.allowed_patterns,
+        };
+
+        if let Some(AstKind::Program(program)) =
+            semantic.nodes().root_node().map(|node| node.kind())
+        {
+            visitor.visit_program(program);
+        }
+        visitor.diagnostics
+    }
+}
@@ -1,11 +1,11 @@
-use crate::rules::custom::prop_key_name;
+use crate::rules::prop_key_name;
 use oxc_ast::AstKind;
 use oxc_ast::ast::{Argument, Class, Decorator, Expression, ObjectPropertyKind};
 use oxc_ast_visit::Visit;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::Span;
 
-use crate::rules::Rule;
+use crate::rules::{ANGULAR_CORE_MODULE, Rule};
 
 /// Rule that enforces maximum lines in Angular component inline declarations
 pub struct AngularObsoleteStandaloneTrueRule {}
@@ -71,7 +71,7 @@ impl DecoratorPropertyVisitor {
 
         // Use find() instead of for loop for better performance and cleaner code
         if let Some(ObjectPropertyKind::ObjectProperty(loc_prop)) = expr.properties.iter().find(|prop| {
-            matches!(prop, ObjectPropertyKind::ObjectProperty(p) if prop_key_name(&p.key) == Self::STANDALONE)
+            matches!(prop, ObjectPropertyKind::ObjectProperty(p) if prop_key_name(&p.key) == Some(Self::STANDALONE))
         }) {
             // Create diagnostic first to avoid multiple mutable borrows
             let diagnostic = self.create_diagnostic(loc_prop.span);
@@ -111,6 +111,10 @@ impl Rule for AngularObsoleteStandaloneTrueRule {
         "Alerts when standalone is set to true, because since v19 this is the default"
     }
 
+    fn applies_to_source(&self, source: &str) -> bool {
+        source.contains(ANGULAR_CORE_MODULE)
+    }
+
     fn run_on_node(&self, _node: &AstKind, _span: Span, _file_path: &str) -> Vec<OxcDiagnostic> {
         let mut visitor = DecoratorPropertyVisitor::new();
 
@@ -0,0 +1,232 @@
+use oxc_ast::AstKind;
+use oxc_ast::ast::{
+    Argument, AssignmentExpression, CallExpression, Class, Decorator, Expression,
+    ObjectPropertyKind, SimpleAssignmentTarget, UpdateExpression,
+};
+use oxc_ast_visit::{Visit, walk};
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_semantic::SemanticBuilderReturn;
+
+use crate::rules::{ANGULAR_CORE_MODULE, Rule, prop_key_name};
+
+/// Rule that flags manual state updates in `OnPush` components without `markForCheck()`
+///
+/// Components using `ChangeDetectionStrategy.OnPush` are only re-rendered when an input
+/// changes or change detection is requested. State assigned from a subscription or a
+/// timer callback is therefore not rendered until something else triggers a check.
+///
+/// Without type information the rule uses a heuristic: inside an `OnPush` component,
+/// every arrow function passed to `.subscribe(...)` (directly or as `next`), `setTimeout`
+/// or `setInterval` is inspected. When it assigns to a `this.` field but never calls
+/// `markForCheck()` or `detectChanges()` itself, the call is reported. Calls made through
+/// helper methods are not followed, and signals or the `async` pipe avoid the problem
+/// entirely.
+///
+/// Add a `// sentinel-ignore: angular-on-push-mark-for-check` comment on the line before
+/// (or at the end of the line of) a call to suppress the finding, like for any other rule.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code:
+///
+/// ```typescript
+/// @Component({ changeDetection: ChangeDetectionStrategy.OnPush })
+/// export class UserComponent {
+///   ngOnInit() {
+///     this.users$.subscribe(users => {
+///       this.users = users;
+///     });
+///   }
+/// }
+/// ```
+///
+/// Examples of **correct** code:
+///
+/// ```typescript
+/// @Component({ changeDetection: ChangeDetectionStrategy.OnPush })
+/// export class UserComponent {
+///   ngOnInit() {
+///     this.users$.subscribe(users => {
+///       this.users = users;
+///       this.cdr.markForCheck();
+///     });
+///   }
+/// }
+/// ```
+pub struct AngularOnPushMarkForCheckRule;
+
+/// Visitor that inspects a callback body for `this.` assignments and change detection calls
+#[derive(Default)]
+struct CallbackVisitor {
+    assigns_this: bool,
+    requests_check: bool,
+}
+
+impl CallbackVisitor {
+    fn is_this_member(target: &SimpleAssignmentTarget) -> bool {
+        target
+            .as_member_expression()
+            .is_some_and(|member| matches!(member.object(), Expression::ThisExpression(_)))
+    }
+}
+
+impl<'a> Visit<'a> for CallbackVisitor {
+    fn visit_assignment_expression(&mut self, expr: &AssignmentExpression<'a>) {
+        if let Some(target) = expr.left.as_simple_assignment_target() {
+            self.assigns_this |= Self::is_this_member(target);
+        }
+        walk::walk_assignment_expression(self, expr);
+    }
+
+    fn visit_update_expression(&mut self, expr: &UpdateExpression<'a>) {
+        self.assigns_this |= Self::is_this_member(&expr.argument);
+        walk::walk_update_expression(self, expr);
+    }
+
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        if let Expression::StaticMemberExpression(callee) = &call.callee {
+            self.requests_check |= matches!(
+                callee.property.name.as_str(),
+                "markForCheck" | "detectChanges"
+            );
+        }
+        walk::walk_call_expression(self, call);
+    }
+}
+
+/// Visitor that finds unchecked state updates inside `OnPush` components
+struct OnPushVisitor {
+    diagnostics: Vec<OxcDiagnostic>,
+    /// Whether the visitor is inside an `OnPush` component class
+    in_on_push: bool,
+}
+
+impl OnPushVisitor {
+    /// Check whether a `@Component` decorator sets `changeDetection: ChangeDetectionStrategy.OnPush`
+    fn is_on_push_component(decorator: &Decorator) -> bool {
+        let Expression::CallExpression(call) = &decorator.expression else {
+            return false;
+        };
+        if !matches!(&call.callee, Expression::Identifier(ident) if ident.name == "Component") {
+            return false;
+        }
+        let Some(Argument::ObjectExpression(metadata)) = call.arguments.first() else {
+            return false;
+        };
+
+        metadata.properties.iter().any(|property| match property {
+            ObjectPropertyKind::ObjectProperty(property) => {
+                prop_key_name(&property.key) == Some("changeDetection")
+                    && matches!(&property.value, Expression::StaticMemberExpression(member) if member.property.name == "OnPush")
+            }
+            _ => false,
+        })
+    }
+
+    /// Arrow function callbacks whose `this` is the component
+    fn callbacks<'b, 'a>(call: &'b CallExpression<'a>) -> Vec<&'b Expression<'a>> {
+        let mut callbacks = Vec::new();
+        for argument in &call.arguments {
+            match argument {
+                Argument::ArrowFunctionExpression(_) => {
+                    callbacks.push(argument.to_expression());
+                }
+                // Observer objects: `subscribe({ next: value => ... })`
+                Argument::ObjectExpression(observer) => {
+                    for property in &observer.properties {
+                        if let ObjectPropertyKind::ObjectProperty(property) = property
+                            && matches!(property.value, Expression::ArrowFunctionExpression(_))
+                        {
+                            callbacks.push(&property.value);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        callbacks
+    }
+
+    fn is_manual_update_call(call: &CallExpression) -> bool {
+        match &call.callee {
+            Expression::StaticMemberExpression(callee) => callee.property.name == "subscribe",
+            Expression::Identifier(callee) => {
+                matches!(callee.name.as_str(), "setTimeout" | "setInterval")
+            }
+            _ => false,
+        }
+    }
+
+    fn check_call(&mut self, call: &CallExpression) {
+        let mut callback_visitor = CallbackVisitor::default();
+        for callback in Self::callbacks(call) {
+            callback_visitor.visit_expression(callback);
+        }
+
+        if callback_visitor.assigns_this && !callback_visitor.requests_check {
+            self.diagnostics.push(
+                OxcDiagnostic::warn("OnPush component state is updated without markForCheck()")
+                    .with_help(
+                        "Call `ChangeDetectorRef.markForCheck()` after updating the state, or use signals or the async pipe",
+                    )
+                    .with_label(call.span.label("Callback assigns to `this` without requesting change detection")),
+            );
+        }
+    }
+}
+
+impl<'a> Visit<'a> for OnPushVisitor {
+    fn visit_class(&mut self, class: &Class<'a>) {
+        let outer = self.in_on_push;
+        self.in_on_push = class.decorators.iter().any(Self::is_on_push_component);
+        walk::walk_class(self, class);
+        self.in_on_push = outer;
+    }
+
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        if self.in_on_push && Self::is_manual_update_call(call) {
+            self.check_call(call);
+        }
+        walk::walk_call_expression(self, call);
+    }
+}
+
+impl Rule for AngularOnPushMarkForCheckRule {
+    fn name(&self) -> &'static str {
+        "angular-on-push-mark-for-check"
+    }
+
+    fn description(&self) -> &'static str {
+        "Flags OnPush components updating state from subscriptions or timers without markForCheck()"
+    }
+
+    fn applies_to_source(&self, source: &str) -> bool {
+        source.contains(ANGULAR_CORE_MODULE)
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["angular"]
+    }
+
+    fn run_on_semantic(
+        &self,
+        semantic_result: &SemanticBuilderReturn,
+        _file_path: &str,
+    ) -> Vec<OxcDiagnostic> {
+        let semantic = &semantic_result.semantic;
+        let mut visitor = OnPushVisitor {
+            diagnostics: Vec::new(),
+            in_on_push: false,
+        };
+        if let Some(AstKind::Program(program)) =
+            semantic.nodes().root_node().map(|node| node.kind())
+        {
+            visitor.visit_program(program);
+        }
+        visitor.diagnostics
+    }
+}
@@ -0,0 +1,169 @@
+use oxc_ast::AstKind;
+use oxc_ast::ast::{CallExpression, Decorator, Expression};
+use oxc_ast_visit::{Visit, walk};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::Span;
+use serde_json::{Value, json};
+
+use crate::rules::{ANGULAR_CORE_MODULE, Rule};
+
+/// Default maximum number of outputs per component
+const DEFAULT_MAX_OUTPUTS: usize = 5;
+
+/// Rule that checks for excessive Angular outputs
+///
+/// This rule counts the signal-based `output()` calls and `@Output()` decorators of a
+/// component and reports every output beyond the configured maximum. Like too many
+/// inputs, a long list of outputs usually means the component does too much.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code:
+///
+/// ```typescript
+/// import { Component, EventEmitter, Output, output } from '@angular/core';
+///
+/// @Component({...})
+/// export class MyComponent {
+///   saved = output<void>();
+///   deleted = output<void>();
+///   @Output() opened = new EventEmitter<void>();
+///   @Output() closed = new EventEmitter<void>();
+///   // ... many more outputs
+/// }
+/// ```
+///
+/// Examples of **correct** code:
+///
+/// ```typescript
+/// import { Component, output } from '@angular/core';
+///
+/// @Component({...})
+/// export class MyComponent {
+///   // Reasonable number of outputs
+///   changed = output<Change>();
+///   closed = output<void>();
+/// }
+/// ```
+///
+/// ## Rule Options
+///
+/// - `maxOutputs`: Maximum number of outputs per component (default: `5`)
+pub struct AngularOutputCountRule {
+    /// Maximum number of outputs allowed before triggering a diagnostic
+    max_outputs: usize,
+}
+
+impl Default for AngularOutputCountRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AngularOutputCountRule {
+    pub fn new() -> Self {
+        Self {
+            max_outputs: DEFAULT_MAX_OUTPUTS,
+        }
+    }
+}
+
+/// Visitor counting the outputs declared in a class
+struct OutputCountVisitor {
+    /// Collection of diagnostics found during AST traversal
+    diagnostics: Vec<OxcDiagnostic>,
+    /// Outputs counted so far
+    output_count: usize,
+    /// Maximum number of outputs allowed
+    max_outputs: usize,
+}
+
+impl OutputCountVisitor {
+    fn new(max_outputs: usize) -> Self {
+        Self {
+            diagnostics: Vec::new(),
+            output_count: 0,
+            max_outputs,
+        }
+    }
+
+    /// Count an output, reporting it when it exceeds the maximum
+    fn count_output(&mut self, span: Span) {
+        self.output_count += 1;
+        if self.output_count > self.max_outputs {
+            self.diagnostics.push(
+                OxcDiagnostic::error("Too many Angular output properties detected")
+                    .with_help(
+                        "Consider breaking this component into smaller components with fewer outputs",
+                    )
+                    .with_label(span.label(format!(
+                        "Component has {} outputs, which exceeds the recommended maximum of {}",
+                        self.output_count, self.max_outputs
+                    ))),
+            );
+        }
+    }
+}
+
+impl<'a> Visit<'a> for OutputCountVisitor {
+    fn visit_decorator(&mut self, decorator: &Decorator<'a>) {
+        // @Output() or @Output('alias')
+        if let Expression::CallExpression(call) = &decorator.expression
+            && matches!(&call.callee, Expression::Identifier(callee) if callee.name == "Output")
+        {
+            self.count_output(decorator.span);
+            return;
+        }
+        walk::walk_decorator(self, decorator);
+    }
+
+    fn visit_call_expression(&mut self, call_expr: &CallExpression<'a>) {
+        if matches!(&call_expr.callee, Expression::Identifier(callee) if callee.name == "output") {
+            self.count_output(call_expr.span);
+        }
+        walk::walk_call_expression(self, call_expr);
+    }
+}
+
+impl Rule for AngularOutputCountRule {
+    fn name(&self) -> &'static str {
+        "angular-output-count"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for excessive Angular outputs"
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["angular"]
+    }
+
+    fn applies_to_source(&self, source: &str) -> bool {
+        source.contains(ANGULAR_CORE_MODULE)
+    }
+
+    fn config_schema(&self) -> Option<Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "maxOutputs": { "type": "integer", "minimum": 0, "default": DEFAULT_MAX_OUTPUTS }
+            }
+        }))
+    }
+
+    fn set_config(&mut self, config: Value) {
+        if let Some(max) = config.get("maxOutputs").and_then(Value::as_u64) {
+            self.max_outputs = max as usize;
+        }
+    }
+
+    fn run_on_node(&self, node: &AstKind, _span: Span, _file_path: &str) -> Vec<OxcDiagnostic> {
+        let AstKind::Class(class) = node else {
+            return Vec::new();
+        };
+
+        let mut visitor = OutputCountVisitor::new(self.max_outputs);
+        visitor.visit_class(class);
+        visitor.diagnostics
+    }
+}
@@ -4,7 +4,7 @@ use oxc_ast_visit::Visit;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::Span;
 
-use crate::rules::Rule;
+use crate::rules::{ANGULAR_CORE_MODULE, Rule};
 
 /// Rule that prevents naming collisions between Angular outputs and native DOM events
 pub struct AngularOutputEventCollisionRule {}
@@ -76,6 +76,10 @@ impl Rule for AngularOutputEventCollisionRule {
         "Prevents naming collisions between Angular outputs and native DOM events"
     }
 
+    fn applies_to_source(&self, source: &str) -> bool {
+        source.contains(ANGULAR_CORE_MODULE)
+    }
+
     fn run_on_node(&self, node: &AstKind, _span: Span, _file_path: &str) -> Vec<OxcDiagnostic> {
         let mut visitor = OutputEventVisitor::new();
         
@@ -0,0 +1,93 @@
+use oxc_ast::AstKind;
+use oxc_ast::ast::Expression;
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_span::Span;
+
+use crate::rules::{ANGULAR_CORE_MODULE, Rule};
+
+/// Decorators that should be replaced by the `host` metadata object
+const HOST_DECORATORS: [&str; 2] = ["HostBinding", "HostListener"];
+
+/// Rule that flags `@HostBinding`/`@HostListener` decorators in favor of the `host` metadata
+///
+/// The Angular style guide recommends declaring host bindings and listeners in the `host`
+/// property of the `@Component`/`@Directive` decorator instead of member decorators.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code:
+///
+/// ```typescript
+/// @Component({ selector: 'app-button' })
+/// export class ButtonComponent {
+///   @HostBinding('class.active') active = false;
+///   @HostListener('click') onClick() {}
+/// }
+/// ```
+///
+/// Examples of **correct** code:
+///
+/// ```typescript
+/// @Component({
+///   selector: 'app-button',
+///   host: { '[class.active]': 'active', '(click)': 'onClick()' },
+/// })
+/// export class ButtonComponent {
+///   active = false;
+///   onClick() {}
+/// }
+/// ```
+pub struct AngularPreferHostMetadataRule;
+
+impl AngularPreferHostMetadataRule {
+    fn create_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+        OxcDiagnostic::warn(format!("Angular @{} decorator detected", name))
+            .with_help("Move host bindings and listeners to the `host` object of the @Component or @Directive decorator")
+            .with_label(span.label(format!("@{} decorator usage", name)))
+    }
+}
+
+impl Rule for AngularPreferHostMetadataRule {
+    fn name(&self) -> &'static str {
+        "angular-prefer-host-metadata"
+    }
+
+    fn description(&self) -> &'static str {
+        "Prefers the `host` metadata object over @HostBinding and @HostListener decorators"
+    }
+
+    fn applies_to_source(&self, source: &str) -> bool {
+        source.contains(ANGULAR_CORE_MODULE)
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["angular"]
+    }
+
+    fn run_on_node(&self, node: &AstKind, _span: Span, _file_path: &str) -> Vec<OxcDiagnostic> {
+        let AstKind::Decorator(decorator) = node else {
+            return Vec::new();
+        };
+
+        let name = match &decorator.expression {
+            // Simple identifier decorator: @HostBinding
+            Expression::Identifier(ident) => ident.name.as_str(),
+            // Decorator with arguments: @HostListener('click', ['$event'])
+            Expression::CallExpression(call_expr) => match &call_expr.callee {
+                Expression::Identifier(callee) => callee.name.as_str(),
+                _ => return Vec::new(),
+            },
+            _ => return Vec::new(),
+        };
+
+        if HOST_DECORATORS.contains(&name) {
+            vec![Self::create_diagnostic(name, decorator.span)]
+        } else {
+            Vec::new()
+        }
+    }
+}
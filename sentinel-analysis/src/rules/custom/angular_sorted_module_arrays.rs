@@ -0,0 +1,283 @@
+use oxc_allocator::Allocator;
+use oxc_ast::AstKind;
+use oxc_ast::ast::{Argument, ArrayExpression, Decorator, Expression, ObjectPropertyKind};
+use oxc_ast_visit::{Visit, walk};
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType, Span};
+use serde_json::{Value, json};
+
+use crate::rules::{ANGULAR_CORE_MODULE, Rule, RuleFix, prop_key_name};
+
+/// `@NgModule` arrays checked by default
+const DEFAULT_ARRAYS: [&str; 3] = ["declarations", "imports", "providers"];
+
+/// Rule that enforces alphabetical order in `@NgModule` arrays
+///
+/// Entries appended wherever there is room make module files conflict-prone and their
+/// diffs noisy. Entries are compared case-insensitively by name: `RouterModule.forRoot(routes)`
+/// sorts as `RouterModule.forRoot` and `{ provide: API_URL, useValue: '/api' }` as `API_URL`.
+/// Arrays containing spreads or entries without a name are skipped. Only the first
+/// out-of-order entry of an array is reported; the autofix sorts the whole array.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code:
+///
+/// ```typescript
+/// @NgModule({
+///   imports: [RouterModule, CommonModule],
+/// })
+/// export class AppModule {}
+/// ```
+///
+/// Examples of **correct** code:
+///
+/// ```typescript
+/// @NgModule({
+///   imports: [CommonModule, RouterModule],
+/// })
+/// export class AppModule {}
+/// ```
+///
+/// ## Rule Options
+///
+/// - `arrays`: Names of the `@NgModule` arrays to check
+///   (default: `["declarations", "imports", "providers"]`)
+pub struct AngularSortedModuleArraysRule {
+    /// Names of the `@NgModule` properties whose arrays must be sorted
+    arrays: Vec<String>,
+}
+
+impl Default for AngularSortedModuleArraysRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AngularSortedModuleArraysRule {
+    pub fn new() -> Self {
+        Self {
+            arrays: DEFAULT_ARRAYS.iter().map(|name| name.to_string()).collect(),
+        }
+    }
+
+    /// Configured arrays of an `@NgModule(...)` decorator, with their property names
+    fn module_arrays<'d, 'a>(
+        &self,
+        decorator: &'d Decorator<'a>,
+    ) -> Vec<(&'a str, &'d ArrayExpression<'a>)> {
+        let Expression::CallExpression(call_expr) = &decorator.expression else {
+            return Vec::new();
+        };
+        if !matches!(&call_expr.callee, Expression::Identifier(callee) if callee.name == "NgModule")
+        {
+            return Vec::new();
+        }
+        let Some(Argument::ObjectExpression(metadata)) = call_expr.arguments.first() else {
+            return Vec::new();
+        };
+
+        metadata
+            .properties
+            .iter()
+            .filter_map(|property| match property {
+                ObjectPropertyKind::ObjectProperty(property) => Some(property),
+                ObjectPropertyKind::SpreadProperty(_) => None,
+            })
+            .filter_map(|property| {
+                let name = prop_key_name(&property.key)?;
+                match &property.value {
+                    Expression::ArrayExpression(array)
+                        if self.arrays.iter().any(|array_name| array_name == name) =>
+                    {
+                        Some((name, array.as_ref()))
+                    }
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    /// Name an array entry is sorted by, `None` if it has no usable name
+    fn expression_key(expression: &Expression) -> Option<String> {
+        match expression {
+            Expression::Identifier(ident) => Some(ident.name.to_string()),
+            Expression::StringLiteral(literal) => Some(literal.value.to_string()),
+            Expression::StaticMemberExpression(member) => Some(format!(
+                "{}.{}",
+                Self::expression_key(&member.object)?,
+                member.property.name
+            )),
+            // `RouterModule.forRoot(routes)` sorts by its callee
+            Expression::CallExpression(call_expr) => Self::expression_key(&call_expr.callee),
+            // Provider objects sort by their token
+            Expression::ObjectExpression(object) => {
+                object
+                    .properties
+                    .iter()
+                    .find_map(|property| match property {
+                        ObjectPropertyKind::ObjectProperty(property)
+                            if prop_key_name(&property.key) == Some("provide") =>
+                        {
+                            Self::expression_key(&property.value)
+                        }
+                        _ => None,
+                    })
+            }
+            _ => None,
+        }
+    }
+
+    /// Sort keys of all entries, `None` if any entry cannot be sorted
+    fn element_keys(array: &ArrayExpression) -> Option<Vec<String>> {
+        array
+            .elements
+            .iter()
+            .map(|element| Self::expression_key(element.as_expression()?))
+            .collect()
+    }
+
+    /// Index of the first entry sorting before its predecessor
+    fn first_unsorted(keys: &[String]) -> Option<usize> {
+        keys.windows(2)
+            .position(|pair| pair[1].to_lowercase() < pair[0].to_lowercase())
+            .map(|index| index + 1)
+    }
+
+    fn check_decorator(&self, decorator: &Decorator) -> Vec<OxcDiagnostic> {
+        self.module_arrays(decorator)
+            .into_iter()
+            .filter_map(|(name, array)| {
+                let keys = Self::element_keys(array)?;
+                let index = Self::first_unsorted(&keys)?;
+                Some(Self::create_diagnostic(
+                    name,
+                    &keys[index],
+                    &keys[index - 1],
+                    array.elements[index].span(),
+                ))
+            })
+            .collect()
+    }
+
+    fn create_diagnostic(array: &str, entry: &str, previous: &str, span: Span) -> OxcDiagnostic {
+        OxcDiagnostic::warn(format!(
+            "@NgModule `{}` is not sorted: `{}` should come before `{}`",
+            array, entry, previous
+        ))
+        .with_help("Sort the entries alphabetically to keep diffs of the module small")
+        .with_label(span.label("Out-of-order entry"))
+    }
+
+    /// Edit sorting `array`, keeping the separators and comments between its entries
+    fn sort_fix(array: &ArrayExpression, source: &str) -> Option<RuleFix> {
+        let keys = Self::element_keys(array)?;
+        let spans: Vec<Span> = array.elements.iter().map(GetSpan::span).collect();
+        let (first, last) = (spans.first()?, spans.last()?);
+
+        let mut order: Vec<usize> = (0..spans.len()).collect();
+        order.sort_by_key(|&index| keys[index].to_lowercase());
+
+        let mut replacement = String::new();
+        for (position, &index) in order.iter().enumerate() {
+            if position > 0 {
+                let separator = Span::new(spans[position - 1].end, spans[position].start);
+                replacement.push_str(separator.source_text(source));
+            }
+            replacement.push_str(spans[index].source_text(source));
+        }
+
+        Some(RuleFix {
+            span: Span::new(first.start, last.end),
+            replacement,
+        })
+    }
+}
+
+/// Visitor locating the `@NgModule` array a diagnostic was reported in
+struct ArrayFixVisitor<'r, 's> {
+    rule: &'r AngularSortedModuleArraysRule,
+    source: &'s str,
+    offset: u32,
+    fix: Option<RuleFix>,
+}
+
+impl<'a> Visit<'a> for ArrayFixVisitor<'_, '_> {
+    fn visit_decorator(&mut self, decorator: &Decorator<'a>) {
+        for (_, array) in self.rule.module_arrays(decorator) {
+            if array.span.start <= self.offset && self.offset < array.span.end {
+                self.fix = AngularSortedModuleArraysRule::sort_fix(array, self.source);
+            }
+        }
+        walk::walk_decorator(self, decorator);
+    }
+}
+
+impl Rule for AngularSortedModuleArraysRule {
+    fn name(&self) -> &'static str {
+        "angular-sorted-module-arrays"
+    }
+
+    fn description(&self) -> &'static str {
+        "Requires alphabetically sorted declarations, imports and providers in @NgModule"
+    }
+
+    fn applies_to_source(&self, source: &str) -> bool {
+        source.contains(ANGULAR_CORE_MODULE)
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["angular", "style"]
+    }
+
+    fn config_schema(&self) -> Option<Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "arrays": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "default": DEFAULT_ARRAYS
+                }
+            }
+        }))
+    }
+
+    fn set_config(&mut self, config: Value) {
+        if let Some(arrays) = config.get("arrays").and_then(Value::as_array) {
+            self.arrays = arrays
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect();
+        }
+    }
+
+    fn run_on_node(&self, node: &AstKind, _span: Span, _file_path: &str) -> Vec<OxcDiagnostic> {
+        match node {
+            AstKind::Decorator(decorator) => self.check_decorator(decorator),
+            _ => Vec::new(),
+        }
+    }
+
+    fn fix(&self, diagnostic: &OxcDiagnostic, source: &str) -> Option<RuleFix> {
+        let offset = diagnostic.labels.as_ref()?.first()?.offset();
+
+        // Diagnostics only carry spans, so the array is looked up in a fresh parse
+        let allocator = Allocator::default();
+        let parsed = Parser::new(&allocator, source, SourceType::ts()).parse();
+        let mut visitor = ArrayFixVisitor {
+            rule: self,
+            source,
+            offset: u32::try_from(offset).ok()?,
+            fix: None,
+        };
+        visitor.visit_program(&parsed.program);
+        visitor.fix
+    }
+}
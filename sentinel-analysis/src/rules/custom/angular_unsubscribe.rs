@@ -0,0 +1,308 @@
+use oxc_ast::AstKind;
+use oxc_ast::ast::{
+    Argument, AssignmentExpression, CallExpression, Class, Decorator, Expression,
+    PropertyDefinition, StaticMemberExpression,
+};
+use oxc_ast_visit::{Visit, walk};
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_semantic::SemanticBuilderReturn;
+use oxc_span::Span;
+use serde_json::{Value, json};
+
+use crate::rules::{ANGULAR_CORE_MODULE, Rule};
+
+/// Operators completing a subscription when the component is destroyed, by default
+const DEFAULT_CLEANUP_OPERATORS: [&str; 2] = ["takeUntil", "takeUntilDestroyed"];
+
+/// Methods collecting subscriptions for a later `unsubscribe()`, by default
+const DEFAULT_CLEANUP_METHODS: [&str; 1] = ["add"];
+
+/// Rule that flags subscriptions in Angular components that are never cleaned up
+///
+/// A subscription outliving its component keeps the component in memory and keeps
+/// running its callback. Inside `@Component` classes, every `.subscribe(...)` call is
+/// reported unless its result is kept for cleanup:
+///
+/// - assigned to a field: `this.subscription = source$.subscribe(...)`, or used as a
+///   field initializer: `private subscription = source$.subscribe(...)`
+/// - passed to a cleanup method: `this.subscriptions.add(source$.subscribe(...))`
+/// - completed by a cleanup operator in the `pipe(...)` directly before it:
+///   `source$.pipe(takeUntilDestroyed()).subscribe(...)`
+///
+/// Without type information this is a heuristic. It does not check that a stored
+/// subscription is actually unsubscribed in `ngOnDestroy`, nor where in the pipe the
+/// operator sits. Subscriptions that complete on their own, such as most `HttpClient`
+/// requests or `take(1)`, are reported as well; add their operators to
+/// `cleanupOperators` or wrap single calls in `// sentinel-disable angular-unsubscribe`
+/// and `// sentinel-enable angular-unsubscribe` comments.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code:
+///
+/// ```typescript
+/// @Component({ selector: 'app-user' })
+/// export class UserComponent {
+///   ngOnInit() {
+///     this.user$.subscribe(user => (this.user = user));
+///   }
+/// }
+/// ```
+///
+/// Examples of **correct** code:
+///
+/// ```typescript
+/// @Component({ selector: 'app-user' })
+/// export class UserComponent {
+///   ngOnInit() {
+///     this.user$
+///       .pipe(takeUntilDestroyed(this.destroyRef))
+///       .subscribe(user => (this.user = user));
+///   }
+/// }
+/// ```
+///
+/// ## Rule Options
+///
+/// - `cleanupOperators`: Operators that end a subscription with the component
+///   (default: `["takeUntil", "takeUntilDestroyed"]`)
+/// - `cleanupMethods`: Methods collecting subscriptions, e.g. `Subscription.add`
+///   (default: `["add"]`)
+pub struct AngularUnsubscribeRule {
+    /// Names of operators that complete the subscription
+    cleanup_operators: Vec<String>,
+    /// Names of methods that store subscriptions for cleanup
+    cleanup_methods: Vec<String>,
+}
+
+impl Default for AngularUnsubscribeRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AngularUnsubscribeRule {
+    pub fn new() -> Self {
+        Self {
+            cleanup_operators: DEFAULT_CLEANUP_OPERATORS
+                .iter()
+                .map(|name| name.to_string())
+                .collect(),
+            cleanup_methods: DEFAULT_CLEANUP_METHODS
+                .iter()
+                .map(|name| name.to_string())
+                .collect(),
+        }
+    }
+}
+
+/// Visitor reporting unmanaged `.subscribe(...)` calls inside component classes
+struct SubscribeVisitor<'r> {
+    rule: &'r AngularUnsubscribeRule,
+    /// Whether the innermost enclosing class is a component
+    in_component: bool,
+    /// Start offsets of subscribe calls whose result is kept for cleanup
+    stored_calls: Vec<u32>,
+    diagnostics: Vec<OxcDiagnostic>,
+}
+
+impl SubscribeVisitor<'_> {
+    fn is_component(decorator: &Decorator) -> bool {
+        matches!(
+            &decorator.expression,
+            Expression::CallExpression(call)
+                if matches!(&call.callee, Expression::Identifier(callee) if callee.name == "Component")
+        )
+    }
+
+    /// The `.subscribe` callee of a call, if it is a subscribe call
+    fn subscribe_callee<'b, 'a>(
+        call: &'b CallExpression<'a>,
+    ) -> Option<&'b StaticMemberExpression<'a>> {
+        match &call.callee {
+            Expression::StaticMemberExpression(callee) if callee.property.name == "subscribe" => {
+                Some(callee)
+            }
+            _ => None,
+        }
+    }
+
+    /// Start offset of a subscribe call, looking through parentheses
+    fn subscribe_call_start(expression: &Expression) -> Option<u32> {
+        match expression.without_parentheses() {
+            Expression::CallExpression(call) if Self::subscribe_callee(call).is_some() => {
+                Some(call.span.start)
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether the subscribed observable is `source.pipe(...)` with a cleanup operator
+    fn is_piped_with_cleanup(&self, callee: &StaticMemberExpression) -> bool {
+        let Expression::CallExpression(pipe_call) = callee.object.without_parentheses() else {
+            return false;
+        };
+        let is_pipe = matches!(
+            &pipe_call.callee,
+            Expression::StaticMemberExpression(pipe) if pipe.property.name == "pipe"
+        );
+
+        is_pipe
+            && pipe_call.arguments.iter().any(|argument| match argument {
+                Argument::CallExpression(operator) => matches!(
+                    &operator.callee,
+                    Expression::Identifier(name)
+                        if self.rule.cleanup_operators.iter().any(|op| op == name.name.as_str())
+                ),
+                _ => false,
+            })
+    }
+
+    fn check_subscribe(&mut self, call: &CallExpression, callee: &StaticMemberExpression) {
+        if self.stored_calls.contains(&call.span.start) || self.is_piped_with_cleanup(callee) {
+            return;
+        }
+
+        self.diagnostics
+            .push(create_diagnostic(callee.property.span));
+    }
+}
+
+fn create_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Subscription in a component is never cleaned up")
+        .with_help("Pipe through takeUntilDestroyed(), store the subscription in a field, or add it to a Subscription that is unsubscribed in ngOnDestroy")
+        .with_label(span.label("Subscription may outlive the component"))
+}
+
+impl<'a> Visit<'a> for SubscribeVisitor<'_> {
+    fn visit_class(&mut self, class: &Class<'a>) {
+        let outer = self.in_component;
+        self.in_component = class.decorators.iter().any(Self::is_component);
+        walk::walk_class(self, class);
+        self.in_component = outer;
+    }
+
+    fn visit_assignment_expression(&mut self, expr: &AssignmentExpression<'a>) {
+        let assigns_field = expr
+            .left
+            .as_simple_assignment_target()
+            .and_then(|target| target.as_member_expression())
+            .is_some_and(|member| matches!(member.object(), Expression::ThisExpression(_)));
+        if assigns_field && let Some(start) = Self::subscribe_call_start(&expr.right) {
+            self.stored_calls.push(start);
+        }
+        walk::walk_assignment_expression(self, expr);
+    }
+
+    fn visit_property_definition(&mut self, property: &PropertyDefinition<'a>) {
+        if let Some(start) = property.value.as_ref().and_then(Self::subscribe_call_start) {
+            self.stored_calls.push(start);
+        }
+        walk::walk_property_definition(self, property);
+    }
+
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        if self.in_component {
+            if let Expression::StaticMemberExpression(callee) = &call.callee
+                && self
+                    .rule
+                    .cleanup_methods
+                    .iter()
+                    .any(|method| method == callee.property.name.as_str())
+            {
+                let stored = call
+                    .arguments
+                    .iter()
+                    .filter_map(|argument| Self::subscribe_call_start(argument.as_expression()?));
+                self.stored_calls.extend(stored);
+            }
+
+            if let Some(callee) = Self::subscribe_callee(call) {
+                self.check_subscribe(call, callee);
+            }
+        }
+        walk::walk_call_expression(self, call);
+    }
+}
+
+impl Rule for AngularUnsubscribeRule {
+    fn name(&self) -> &'static str {
+        "angular-unsubscribe"
+    }
+
+    fn description(&self) -> &'static str {
+        "Flags subscriptions in components that are not cleaned up"
+    }
+
+    fn applies_to_source(&self, source: &str) -> bool {
+        source.contains(ANGULAR_CORE_MODULE)
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["angular", "rxjs"]
+    }
+
+    fn config_schema(&self) -> Option<Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "cleanupOperators": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "default": DEFAULT_CLEANUP_OPERATORS
+                },
+                "cleanupMethods": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "default": DEFAULT_CLEANUP_METHODS
+                }
+            }
+        }))
+    }
+
+    fn set_config(&mut self, config: Value) {
+        let names = |key: &str| {
+            config.get(key).and_then(Value::as_array).map(|names| {
+                names
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(str::to_string)
+                    .collect()
+            })
+        };
+        if let Some(operators) = names("cleanupOperators") {
+            self.cleanup_operators = operators;
+        }
+        if let Some(methods) = names("cleanupMethods") {
+            self.cleanup_methods = methods;
+        }
+    }
+
+    fn run_on_semantic(
+        &self,
+        semantic_result: &SemanticBuilderReturn,
+        _file_path: &str,
+    ) -> Vec<OxcDiagnostic> {
+        let Some(AstKind::Program(program)) = semantic_result
+            .semantic
+            .nodes()
+            .root_node()
+            .map(|node| node.kind())
+        else {
+            return Vec::new();
+        };
+
+        let mut visitor = SubscribeVisitor {
+            rule: self,
+            in_component: false,
+            stored_calls: Vec::new(),
+            diagnostics: Vec::new(),
+        };
+        visitor.visit_program(program);
+        visitor.diagnostics
+    }
+}
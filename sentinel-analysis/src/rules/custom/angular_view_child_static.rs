@@ -0,0 +1,176 @@
+use std::collections::HashSet;
+
+use oxc_ast::AstKind;
+use oxc_ast::ast::{
+    Argument, Class, ClassElement, Decorator, Expression, Function, ObjectPropertyKind,
+    StaticMemberExpression,
+};
+use oxc_ast_visit::{Visit, walk};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::ScopeFlags;
+use oxc_span::Span;
+
+use crate::rules::{ANGULAR_CORE_MODULE, Rule, prop_key_name};
+
+/// Rule that flags `@ViewChild` queries read in `ngOnInit` without `static: true`
+///
+/// Dynamic queries, the default, are resolved after the first change detection run,
+/// so in `ngOnInit` the decorated member is still `undefined`. A query that does not
+/// depend on `*ngIf`, `@if` or similar blocks can be marked `{ static: true }` to be
+/// resolved before `ngOnInit`.
+///
+/// The check is a heuristic within one class: it collects every `this.<member>` read in
+/// the body of `ngOnInit`, including arrow function callbacks, and reports `@ViewChild`
+/// decorators on those members whose options do not mention `static`. Members used only
+/// through helper methods called from `ngOnInit` are not found. An explicit
+/// `static: false` is taken as deliberate, and options passed as a variable are skipped.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code:
+///
+/// ```typescript
+/// export class SearchComponent {
+///   @ViewChild('query') query: ElementRef;
+///
+///   ngOnInit() {
+///     this.query.nativeElement.focus();
+///   }
+/// }
+/// ```
+///
+/// Examples of **correct** code:
+///
+/// ```typescript
+/// export class SearchComponent {
+///   @ViewChild('query', { static: true }) query: ElementRef;
+///
+///   ngOnInit() {
+///     this.query.nativeElement.focus();
+///   }
+/// }
+/// ```
+pub struct AngularViewChildStaticRule;
+
+/// Visitor collecting the names of `this.` members used in a method body
+#[derive(Default)]
+struct MemberUseVisitor {
+    members: HashSet<String>,
+}
+
+impl<'a> Visit<'a> for MemberUseVisitor {
+    fn visit_static_member_expression(&mut self, expr: &StaticMemberExpression<'a>) {
+        if matches!(expr.object, Expression::ThisExpression(_)) {
+            self.members.insert(expr.property.name.to_string());
+        }
+        walk::walk_static_member_expression(self, expr);
+    }
+
+    // Regular functions and nested classes have their own `this`
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, _class: &Class<'a>) {}
+}
+
+impl AngularViewChildStaticRule {
+    /// Members of the class used in the body of its `ngOnInit` method
+    fn members_used_in_ng_on_init(class: &Class) -> HashSet<String> {
+        let mut visitor = MemberUseVisitor::default();
+        for element in &class.body.body {
+            if let ClassElement::MethodDefinition(method) = element
+                && prop_key_name(&method.key) == Some("ngOnInit")
+                && let Some(body) = &method.value.body
+            {
+                visitor.visit_function_body(body);
+            }
+        }
+        visitor.members
+    }
+
+    /// Whether a decorator is a `@ViewChild(...)` query without a `static` option
+    fn is_dynamic_view_child(decorator: &Decorator) -> bool {
+        let Expression::CallExpression(call) = &decorator.expression else {
+            return false;
+        };
+        if !matches!(&call.callee, Expression::Identifier(callee) if callee.name == "ViewChild") {
+            return false;
+        }
+
+        match call.arguments.get(1) {
+            None => true,
+            Some(Argument::ObjectExpression(options)) => {
+                !options.properties.iter().any(|property| {
+                    matches!(
+                        property,
+                        ObjectPropertyKind::ObjectProperty(property)
+                            if prop_key_name(&property.key) == Some("static")
+                    )
+                })
+            }
+            // Options built elsewhere cannot be checked
+            Some(_) => false,
+        }
+    }
+
+    fn check_class(class: &Class) -> Vec<OxcDiagnostic> {
+        let used = Self::members_used_in_ng_on_init(class);
+        if used.is_empty() {
+            return Vec::new();
+        }
+
+        let mut diagnostics = Vec::new();
+        for element in &class.body.body {
+            let ClassElement::PropertyDefinition(property) = element else {
+                continue;
+            };
+            let Some(name) = prop_key_name(&property.key) else {
+                continue;
+            };
+            if !used.contains(name) {
+                continue;
+            }
+            if let Some(decorator) = property
+                .decorators
+                .iter()
+                .find(|decorator| Self::is_dynamic_view_child(decorator))
+            {
+                diagnostics.push(Self::create_diagnostic(name, decorator.span));
+            }
+        }
+        diagnostics
+    }
+
+    fn create_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+        OxcDiagnostic::error(format!(
+            "`{}` is used in ngOnInit but its @ViewChild query is not static",
+            name
+        ))
+        .with_help("Add `{ static: true }` to the query, or move the access to ngAfterViewInit")
+        .with_label(span.label("Query is still unresolved in ngOnInit"))
+    }
+}
+
+impl Rule for AngularViewChildStaticRule {
+    fn name(&self) -> &'static str {
+        "angular-view-child-static"
+    }
+
+    fn description(&self) -> &'static str {
+        "Requires `static: true` on @ViewChild queries used in ngOnInit"
+    }
+
+    fn applies_to_source(&self, source: &str) -> bool {
+        source.contains(ANGULAR_CORE_MODULE)
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["angular"]
+    }
+
+    fn run_on_node(&self, node: &AstKind, _span: Span, _file_path: &str) -> Vec<OxcDiagnostic> {
+        match node {
+            AstKind::Class(class) => Self::check_class(class),
+            _ => Vec::new(),
+        }
+    }
+}
@@ -1,13 +1,21 @@
-use oxc_ast::ast::PropertyKey;
-
 // Module declarations for custom rules
 pub mod angular_component_class_suffix;
 pub mod angular_directive_class_suffix;
 pub mod angular_input_count;
 pub mod angular_legacy_decorators;
+pub mod angular_no_conflicting_template;
+pub mod angular_no_inner_html;
 pub mod angular_obsolete_standalone_true;
+pub mod angular_on_push_mark_for_check;
+pub mod angular_output_count;
 pub mod angular_output_event_collision;
+pub mod angular_prefer_host_metadata;
+pub mod angular_sorted_module_arrays;
+pub mod angular_unsubscribe;
+pub mod angular_view_child_static;
+pub mod typescript_max_union_members;
 pub mod typescript_non_null_assertion_operator;
+pub mod typescript_prefer_abstract_class;
 pub mod typescript_type_assertion;
 
 // Re-export custom rules
@@ -15,17 +23,19 @@ pub use angular_component_class_suffix::AngularComponentClassSuffixRule;
 pub use angular_directive_class_suffix::AngularDirectiveClassSuffixRule;
 pub use angular_input_count::AngularInputCountRule;
 pub use angular_legacy_decorators::AngularLegacyDecoratorsRule;
+pub use angular_no_conflicting_template::AngularNoConflictingTemplateRule;
+pub use angular_no_inner_html::AngularNoInnerHtmlRule;
 pub use angular_obsolete_standalone_true::AngularObsoleteStandaloneTrueRule;
+pub use angular_on_push_mark_for_check::AngularOnPushMarkForCheckRule;
+pub use angular_output_count::AngularOutputCountRule;
 pub use angular_output_event_collision::AngularOutputEventCollisionRule;
+pub use angular_prefer_host_metadata::AngularPreferHostMetadataRule;
+pub use angular_sorted_module_arrays::AngularSortedModuleArraysRule;
+pub use angular_unsubscribe::AngularUnsubscribeRule;
+pub use angular_view_child_static::AngularViewChildStaticRule;
+pub use typescript_max_union_members::MaxUnionMembersRule;
 pub use typescript_non_null_assertion_operator::TypeScriptNonNullAssertionRule;
+pub use typescript_prefer_abstract_class::TypeScriptPreferAbstractClassRule;
 pub use typescript_type_assertion::TypeScriptAssertionRule;
 
-// Module declarations for custom rules
-pub fn prop_key_name<'a>(key: &PropertyKey<'a>) -> &'a str {
-    match key {
-        PropertyKey::StaticIdentifier(ident) => ident.name.as_str(),
-        PropertyKey::PrivateIdentifier(ident) => ident.name.as_str(),
-        PropertyKey::Identifier(ident) => ident.name.as_str(),
-        _ => "false",
-    }
-}
\ No newline at end of file
+// Module declarations for custom rules
\ No newline at end of file
@@ -0,0 +1,144 @@
+use oxc_ast::AstKind;
+use oxc_ast::ast::{TSType, TSUnionType};
+use oxc_ast_visit::Visit;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::Span;
+use serde_json::{Value, json};
+
+use crate::rules::Rule;
+
+/// Rule that limits the number of members in a union type
+///
+/// Very large union types slow down the TypeScript compiler and are hard to read.
+/// Nested unions such as `(A | B) | (C | D)` are flattened before counting, and
+/// only the outermost union is reported.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code (with `max: 3`):
+///
+/// ```typescript
+/// type Size = 'xs' | 'sm' | 'md' | 'lg';
+/// type Mixed = ('a' | 'b') | ('c' | 'd');
+/// ```
+///
+/// Examples of **correct** code (with `max: 3`):
+///
+/// ```typescript
+/// type Size = 'sm' | 'md' | 'lg';
+/// ```
+///
+/// ## Rule Options
+///
+/// - `max`: Maximum number of union members allowed (default: 10)
+pub struct MaxUnionMembersRule {
+    /// Maximum number of union members allowed
+    max: usize,
+}
+
+impl Default for MaxUnionMembersRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MaxUnionMembersRule {
+    pub fn new() -> Self {
+        Self { max: 10 }
+    }
+}
+
+/// Visitor that reports oversized unions without re-reporting their nested unions
+struct UnionVisitor {
+    diagnostics: Vec<OxcDiagnostic>,
+    max: usize,
+}
+
+impl UnionVisitor {
+    /// Collect the non-union members of a union, flattening nested and parenthesized unions
+    fn flatten<'b, 'a>(union: &'b TSUnionType<'a>, members: &mut Vec<&'b TSType<'a>>) {
+        for member in &union.types {
+            match Self::as_union(member) {
+                Some(nested) => Self::flatten(nested, members),
+                None => members.push(member),
+            }
+        }
+    }
+
+    fn as_union<'b, 'a>(ts_type: &'b TSType<'a>) -> Option<&'b TSUnionType<'a>> {
+        match ts_type {
+            TSType::TSUnionType(union) => Some(union),
+            TSType::TSParenthesizedType(paren) => Self::as_union(&paren.type_annotation),
+            _ => None,
+        }
+    }
+
+    fn create_diagnostic(&self, count: usize, span: Span) -> OxcDiagnostic {
+        OxcDiagnostic::error("Union type has too many members")
+            .with_help("Consider grouping members into named types or using an enum-like object")
+            .with_label(span.label(format!(
+                "Union has {} members, which exceeds the maximum of {}",
+                count, self.max
+            )))
+    }
+}
+
+impl<'a> Visit<'a> for UnionVisitor {
+    fn visit_ts_union_type(&mut self, union: &TSUnionType<'a>) {
+        let mut members = Vec::new();
+        Self::flatten(union, &mut members);
+
+        if members.len() > self.max {
+            self.diagnostics
+                .push(self.create_diagnostic(members.len(), union.span));
+        }
+
+        // Keep looking for unions nested inside non-union members, e.g. object types
+        for member in members {
+            self.visit_ts_type(member);
+        }
+    }
+}
+
+impl Rule for MaxUnionMembersRule {
+    fn name(&self) -> &'static str {
+        "typescript-max-union-members"
+    }
+
+    fn description(&self) -> &'static str {
+        "Limits the number of members in a union type"
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["typescript"]
+    }
+
+    fn config_schema(&self) -> Option<Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "max": { "type": "integer", "minimum": 0, "default": 10 }
+            }
+        }))
+    }
+
+    fn set_config(&mut self, config: Value) {
+        if let Some(max) = config.get("max").and_then(Value::as_u64) {
+            self.max = max as usize;
+        }
+    }
+
+    fn run_on_node(&self, node: &AstKind, _span: Span, _file_path: &str) -> Vec<OxcDiagnostic> {
+        match node {
+            AstKind::Program(program) => {
+                let mut visitor = UnionVisitor {
+                    diagnostics: Vec::new(),
+                    max: self.max,
+                };
+                visitor.visit_program(program);
+                visitor.diagnostics
+            }
+            _ => Vec::new(),
+        }
+    }
+}
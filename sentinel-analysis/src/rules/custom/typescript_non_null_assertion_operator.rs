@@ -3,7 +3,7 @@ use oxc_ast::ast::TSNonNullExpression;
 use oxc_ast_visit::Visit;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::Span;
-use serde_json::Value;
+use serde_json::{Value, json};
 
 use crate::rules::Rule;
 
@@ -104,6 +104,15 @@ impl Rule for TypeScriptNonNullAssertionRule {
         "Disallows TypeScript's non-null assertion operator"
     }
 
+    fn config_schema(&self) -> Option<Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "skipInTests": { "type": "boolean", "default": false }
+            }
+        }))
+    }
+
     fn set_config(&mut self, config: Value) {
         if let Some(obj) = config.as_object() {
             if let Some(skip_in_tests) = obj.get("skipInTests") {
@@ -0,0 +1,140 @@
+use oxc_ast::AstKind;
+use oxc_ast::ast::{
+    BinaryExpression, Class, ClassElement, Expression, MethodDefinitionKind, Statement,
+};
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_span::Span;
+
+use crate::rules::Rule;
+
+/// Rule that flags classes whose constructor always throws
+///
+/// A constructor that throws before doing anything else means the class is never meant
+/// to be instantiated directly, which is what `abstract` expresses at compile time.
+///
+/// The rule uses a heuristic and only inspects the first statement of the constructor.
+/// A class is reported when that statement is a `throw`, or an `if` guarding a `throw`
+/// that compares `new.target` (the usual runtime emulation of an abstract class).
+/// Classes that are already `abstract` are skipped. Like every rule it is opt-in and
+/// only runs when enabled in the rules configuration.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code:
+///
+/// ```typescript
+/// class Shape {
+///   constructor() {
+///     throw new Error('Shape is abstract');
+///   }
+/// }
+///
+/// class Repository {
+///   constructor() {
+///     if (new.target === Repository) throw new Error('Not implemented');
+///   }
+/// }
+/// ```
+///
+/// Examples of **correct** code:
+///
+/// ```typescript
+/// abstract class Shape {}
+///
+/// class Shape {
+///   private constructor() {}
+///   static create(): Shape { return new Shape(); }
+/// }
+/// ```
+pub struct TypeScriptPreferAbstractClassRule;
+
+impl TypeScriptPreferAbstractClassRule {
+    /// Check whether an expression is a comparison involving `new.target`
+    fn is_new_target_check(test: &Expression) -> bool {
+        let Expression::BinaryExpression(binary) = test.get_inner_expression() else {
+            return false;
+        };
+        let BinaryExpression { left, right, .. } = binary.as_ref();
+        [left, right].iter().any(|side| match side {
+            Expression::MetaProperty(meta) => {
+                meta.meta.name == "new" && meta.property.name == "target"
+            }
+            _ => false,
+        })
+    }
+
+    /// Check whether a statement unconditionally throws, or throws behind a `new.target` guard
+    fn throws_immediately(statement: &Statement) -> bool {
+        match statement {
+            Statement::ThrowStatement(_) => true,
+            Statement::IfStatement(if_statement) => {
+                Self::is_new_target_check(&if_statement.test)
+                    && match &if_statement.consequent {
+                        Statement::ThrowStatement(_) => true,
+                        Statement::BlockStatement(block) => {
+                            matches!(block.body.first(), Some(Statement::ThrowStatement(_)))
+                        }
+                        _ => false,
+                    }
+            }
+            _ => false,
+        }
+    }
+
+    /// Check whether the class constructor throws as its first statement
+    fn has_throwing_constructor(class: &Class) -> bool {
+        class.body.body.iter().any(|element| match element {
+            ClassElement::MethodDefinition(method)
+                if method.kind == MethodDefinitionKind::Constructor =>
+            {
+                method
+                    .value
+                    .body
+                    .as_ref()
+                    .and_then(|body| body.statements.first())
+                    .is_some_and(Self::throws_immediately)
+            }
+            _ => false,
+        })
+    }
+
+    fn create_diagnostic(class: &Class) -> OxcDiagnostic {
+        let name = class
+            .id
+            .as_ref()
+            .map(|id| id.name.as_str())
+            .unwrap_or("<anonymous>");
+
+        OxcDiagnostic::warn(format!(
+            "Class `{}` throws in its constructor and should be abstract",
+            name
+        ))
+        .with_help(
+            "Declare the class `abstract`, or use a private constructor with a factory method",
+        )
+        .with_label(class.span.label("This class cannot be instantiated"))
+    }
+}
+
+impl Rule for TypeScriptPreferAbstractClassRule {
+    fn name(&self) -> &'static str {
+        "typescript-prefer-abstract-class"
+    }
+
+    fn description(&self) -> &'static str {
+        "Flags classes whose constructor immediately throws, suggesting `abstract`"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn run_on_node(&self, node: &AstKind, _span: Span, _file_path: &str) -> Vec<OxcDiagnostic> {
+        match node {
+            AstKind::Class(class) if !class.r#abstract && Self::has_throwing_constructor(class) => {
+                vec![Self::create_diagnostic(class)]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
@@ -1,9 +1,9 @@
 use oxc_ast::AstKind;
-use oxc_ast::ast::{TSAsExpression, TSNonNullExpression, TSType, TSTypeAssertion};
+use oxc_ast::ast::{Expression, TSAsExpression, TSNonNullExpression, TSType, TSTypeAssertion};
 use oxc_ast_visit::Visit;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::Span;
-use serde_json::Value;
+use serde_json::{Value, json};
 
 use crate::rules::Rule;
 
@@ -84,6 +84,7 @@ use crate::rules::Rule;
 ///
 /// - `skipInTests`: Set to `true` to disable the rule in test files (default: false)
 /// - `allowDomAssertions`: Set to `true` to allow type assertions on DOM elements (default: true)
+/// - `reportAnyCasts`: Set to `true` to also report `x as any`, which `no-as-any` covers on its own (default: false)
 ///
 /// ## Best Practices
 ///
@@ -99,6 +100,8 @@ pub struct TypeScriptAssertionRule {
     skip_in_tests: bool,
     /// Whether to allow type assertions in specific patterns (like DOM queries)
     allow_dom_assertions: bool,
+    /// Whether to report `as any` casts, which are left to `no-as-any` by default
+    report_any_casts: bool,
 }
 
 impl TypeScriptAssertionRule {
@@ -106,6 +109,7 @@ impl TypeScriptAssertionRule {
         Self {
             skip_in_tests: false,
             allow_dom_assertions: true,
+            report_any_casts: false,
         }
     }
 }
@@ -133,6 +137,8 @@ struct AssertionVisitor {
     skip_in_tests: bool,
     /// Whether to allow DOM-related assertions
     allow_dom_assertions: bool,
+    /// Whether to report `as any` casts
+    report_any_casts: bool,
     /// Current file path being analyzed
     file_path: String,
     /// Static patterns for test file detection
@@ -140,11 +146,17 @@ struct AssertionVisitor {
 }
 
 impl AssertionVisitor {
-    fn new(skip_in_tests: bool, allow_dom_assertions: bool, file_path: String) -> Self {
+    fn new(
+        skip_in_tests: bool,
+        allow_dom_assertions: bool,
+        report_any_casts: bool,
+        file_path: String,
+    ) -> Self {
         Self {
             diagnostics: Vec::with_capacity(1), // Most files will have 0-1 violations
             skip_in_tests,
             allow_dom_assertions,
+            report_any_casts,
             file_path,
             test_patterns: TestPathPatterns::new(),
         }
@@ -160,30 +172,18 @@ impl AssertionVisitor {
 
     #[inline]
     fn is_any_type(&self, type_annotation: &TSType) -> bool {
-        // Avoid string allocation by checking specific AST patterns
-        // that indicate 'any' type
-        match type_annotation {
-            TSType::TSAnyKeyword(_) => true,
-            TSType::TSTypeReference(type_ref) => {
-                let type_name = format!("{:?}", type_ref);
-                type_name.contains("any")
-            }
-            _ => false,
-        }
+        matches!(type_annotation, TSType::TSAnyKeyword(_))
     }
 
+    /// Check for `x as any as T` / `x as unknown as T`, where the inner cast launders the type
     #[inline]
     fn check_double_assertion(&self, node: &TSAsExpression) -> bool {
-        // First check if it's an 'any' type to avoid unnecessary string operations
-        if !self.is_any_type(&node.type_annotation) {
-            return false;
-        }
-
-        // Only do the more expensive check if we found an 'any' type
-        if let TSType::TSAnyKeyword(_) = node.type_annotation {
-            true
-        } else {
-            false
+        match node.expression.without_parentheses() {
+            Expression::TSAsExpression(inner) => matches!(
+                inner.type_annotation,
+                TSType::TSAnyKeyword(_) | TSType::TSUnknownKeyword(_)
+            ),
+            _ => false,
         }
     }
 
@@ -273,17 +273,19 @@ impl<'a> Visit<'a> for AssertionVisitor {
             return;
         }
 
-        // Check for 'any' type assertions (simplified double assertion check)
+        // Check for assertions chained through 'any' or 'unknown'
         if self.check_double_assertion(node) {
             self.diagnostics
                 .push(self.create_diagnostic(node.span, "double-assertion"));
             return;
         }
 
-        // Check if assertion involves 'any' type
+        // Check if assertion involves 'any' type; these are reported by no-as-any unless enabled here
         if self.is_any_type(&node.type_annotation) {
-            self.diagnostics
-                .push(self.create_diagnostic(node.span, "any"));
+            if self.report_any_casts {
+                self.diagnostics
+                    .push(self.create_diagnostic(node.span, "any"));
+            }
         } else {
             self.diagnostics
                 .push(self.create_diagnostic(node.span, "type"));
@@ -300,6 +302,17 @@ impl Rule for TypeScriptAssertionRule {
         "Disallows unsafe TypeScript type assertions and non-null assertions"
     }
 
+    fn config_schema(&self) -> Option<Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "skipInTests": { "type": "boolean", "default": false },
+                "allowDomAssertions": { "type": "boolean", "default": true },
+                "reportAnyCasts": { "type": "boolean", "default": false }
+            }
+        }))
+    }
+
     fn set_config(&mut self, config: Value) {
         if let Some(obj) = config.as_object() {
             if let Some(skip_tests) = obj.get("skipInTests").and_then(Value::as_bool) {
@@ -308,6 +321,9 @@ impl Rule for TypeScriptAssertionRule {
             if let Some(allow_dom) = obj.get("allowDomAssertions").and_then(Value::as_bool) {
                 self.allow_dom_assertions = allow_dom;
             }
+            if let Some(report_any) = obj.get("reportAnyCasts").and_then(Value::as_bool) {
+                self.report_any_casts = report_any;
+            }
         }
     }
 
@@ -315,6 +331,7 @@ impl Rule for TypeScriptAssertionRule {
         let mut visitor = AssertionVisitor::new(
             self.skip_in_tests,
             self.allow_dom_assertions,
+            self.report_any_casts,
             file_path.to_string(),
         );
         match node {
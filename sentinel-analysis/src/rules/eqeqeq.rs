@@ -0,0 +1,162 @@
+use oxc_ast::AstKind;
+use oxc_ast::ast::{BinaryExpression, BinaryOperator, Expression};
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_semantic::SemanticBuilderReturn;
+use oxc_span::{GetSpan, Span};
+use serde_json::{Value, json};
+
+use crate::rules::{Rule, RuleFix};
+
+/// Rule that flags the loose equality operators `==` and `!=`
+///
+/// Loose equality converts its operands before comparing them, so `0 == ''` and
+/// `'1' == 1` are both true. `===` and `!==` compare without conversion and say what
+/// is meant. With `allowNull`, `x == null` and `x != null` are allowed as the short
+/// form of checking for both `null` and `undefined`.
+///
+/// The fix replaces the operator with its strict counterpart. This may change the
+/// result of comparisons that relied on the conversion, so review fixed code.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code:
+///
+/// ```typescript
+/// if (count == '0') {}
+/// if (user != undefined) {}
+/// ```
+///
+/// Examples of **correct** code:
+///
+/// ```typescript
+/// if (count === 0) {}
+/// if (user !== undefined) {}
+/// ```
+///
+/// ## Rule Options
+///
+/// - `allowNull`: Allow `==` and `!=` comparisons against `null` (default: false)
+pub struct EqeqeqRule {
+    /// Allow loose comparisons against the `null` literal
+    allow_null: bool,
+}
+
+impl Default for EqeqeqRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EqeqeqRule {
+    pub fn new() -> Self {
+        Self { allow_null: false }
+    }
+
+    /// Strict counterpart of a loose equality operator
+    fn strict_operator(operator: BinaryOperator) -> Option<&'static str> {
+        match operator {
+            BinaryOperator::Equality => Some("==="),
+            BinaryOperator::Inequality => Some("!=="),
+            _ => None,
+        }
+    }
+
+    fn check_expression(&self, expr: &BinaryExpression, source: &str) -> Option<OxcDiagnostic> {
+        let strict = Self::strict_operator(expr.operator)?;
+        if self.allow_null
+            && (matches!(expr.left, Expression::NullLiteral(_))
+                || matches!(expr.right, Expression::NullLiteral(_)))
+        {
+            return None;
+        }
+
+        // Only whitespace, parentheses and comments can surround the operator here
+        let loose = expr.operator.as_str();
+        let between_start = expr.left.span().end;
+        let between = source.get(between_start as usize..expr.right.span().start as usize)?;
+        let offset = between_start + u32::try_from(between.find(loose)?).ok()?;
+        let operator_span = Span::new(offset, offset + loose.len() as u32);
+
+        Some(
+            OxcDiagnostic::warn(format!("Expected `{}` instead of `{}`", strict, loose))
+                .with_help(format!(
+                    "Use `{}` to compare without type conversion",
+                    strict
+                ))
+                .with_label(operator_span.label("Loose equality")),
+        )
+    }
+}
+
+impl Rule for EqeqeqRule {
+    fn name(&self) -> &'static str {
+        "eqeqeq"
+    }
+
+    fn description(&self) -> &'static str {
+        "Requires `===` and `!==` instead of `==` and `!=`"
+    }
+
+    fn applies_to_source(&self, source: &str) -> bool {
+        source.contains("==") || source.contains("!=")
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["correctness"]
+    }
+
+    fn config_schema(&self) -> Option<Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "allowNull": { "type": "boolean", "default": false }
+            }
+        }))
+    }
+
+    fn set_config(&mut self, config: Value) {
+        if let Some(allow_null) = config.get("allowNull").and_then(Value::as_bool) {
+            self.allow_null = allow_null;
+        }
+    }
+
+    fn run_on_semantic(
+        &self,
+        semantic_result: &SemanticBuilderReturn,
+        _file_path: &str,
+    ) -> Vec<OxcDiagnostic> {
+        let semantic = &semantic_result.semantic;
+        let source = semantic.source_text();
+
+        semantic
+            .nodes()
+            .iter()
+            .filter_map(|node| match node.kind() {
+                AstKind::BinaryExpression(expr) => self.check_expression(expr, source),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn fix(&self, diagnostic: &OxcDiagnostic, source: &str) -> Option<RuleFix> {
+        let label = diagnostic.labels.as_ref()?.first()?;
+        let start = label.offset();
+        let replacement = match source.get(start..start + label.len())? {
+            "==" => "===",
+            "!=" => "!==",
+            _ => return None,
+        };
+
+        Some(RuleFix {
+            span: Span::new(
+                u32::try_from(start).ok()?,
+                u32::try_from(start + label.len()).ok()?,
+            ),
+            replacement: replacement.to_string(),
+        })
+    }
+}
@@ -0,0 +1,167 @@
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_semantic::{SemanticBuilderReturn, SymbolFlags};
+use oxc_span::Span;
+use serde_json::{Value, json};
+
+use crate::rules::Rule;
+
+/// Names allowed below the minimum length by default: loop counters, coordinates and
+/// the conventional name of unused parameters
+const DEFAULT_EXCEPTIONS: [&str; 6] = ["_", "i", "j", "k", "x", "y"];
+
+/// Rule that flags identifiers that are too short or too long to read well
+///
+/// Checks the names of declared variables, functions, classes, parameters and types.
+/// Imported bindings are skipped since their names are chosen by the exporting module,
+/// and so are type parameters, which are conventionally a single letter.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code with the default options:
+///
+/// ```typescript
+/// const a = load();
+/// function totalNumberOfUnprocessedNotificationsForTheCurrentlySignedInUser() {}
+/// ```
+///
+/// Examples of **correct** code:
+///
+/// ```typescript
+/// const user = load();
+/// for (let i = 0; i < users.length; i++) {}
+/// ```
+///
+/// ## Rule Options
+///
+/// - `min`: Minimum number of characters (default: 2)
+/// - `max`: Maximum number of characters (default: 40)
+/// - `exceptions`: Names allowed regardless of their length
+///   (default: `["_", "i", "j", "k", "x", "y"]`)
+pub struct IdentifierLengthRule {
+    /// Minimum number of characters of a name
+    min: usize,
+    /// Maximum number of characters of a name
+    max: usize,
+    /// Names that are never reported
+    exceptions: Vec<String>,
+}
+
+impl Default for IdentifierLengthRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IdentifierLengthRule {
+    pub fn new() -> Self {
+        Self {
+            min: 2,
+            max: 40,
+            exceptions: DEFAULT_EXCEPTIONS
+                .iter()
+                .map(|name| name.to_string())
+                .collect(),
+        }
+    }
+
+    fn check_name(&self, name: &str, span: Span) -> Option<OxcDiagnostic> {
+        if self.exceptions.iter().any(|exception| exception == name) {
+            return None;
+        }
+
+        let length = name.chars().count();
+        if length < self.min {
+            Some(
+                OxcDiagnostic::warn(format!(
+                    "Identifier `{}` is shorter than {} characters",
+                    name, self.min
+                ))
+                .with_help("Use a name that describes the value")
+                .with_label(span.label("Identifier declared here")),
+            )
+        } else if length > self.max {
+            Some(
+                OxcDiagnostic::warn(format!(
+                    "Identifier `{}` is longer than {} characters",
+                    name, self.max
+                ))
+                .with_help("Use a shorter name; the surrounding scope usually provides context")
+                .with_label(span.label("Identifier declared here")),
+            )
+        } else {
+            None
+        }
+    }
+}
+
+impl Rule for IdentifierLengthRule {
+    fn name(&self) -> &'static str {
+        "identifier-length"
+    }
+
+    fn description(&self) -> &'static str {
+        "Enforces a minimum and maximum length of declared identifiers"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["style"]
+    }
+
+    fn config_schema(&self) -> Option<Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "min": { "type": "integer", "minimum": 0, "default": 2 },
+                "max": { "type": "integer", "minimum": 1, "default": 40 },
+                "exceptions": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "default": DEFAULT_EXCEPTIONS
+                }
+            }
+        }))
+    }
+
+    fn set_config(&mut self, config: Value) {
+        if let Some(min) = config.get("min").and_then(Value::as_u64) {
+            self.min = min as usize;
+        }
+        if let Some(max) = config.get("max").and_then(Value::as_u64) {
+            self.max = max as usize;
+        }
+        if let Some(exceptions) = config.get("exceptions").and_then(Value::as_array) {
+            self.exceptions = exceptions
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect();
+        }
+    }
+
+    fn run_on_semantic(
+        &self,
+        semantic_result: &SemanticBuilderReturn,
+        _file_path: &str,
+    ) -> Vec<OxcDiagnostic> {
+        let scoping = semantic_result.semantic.scoping();
+
+        scoping
+            .symbol_ids()
+            .filter(|&symbol_id| {
+                !scoping
+                    .symbol_flags(symbol_id)
+                    .intersects(SymbolFlags::Import | SymbolFlags::TypeParameter)
+            })
+            .filter_map(|symbol_id| {
+                self.check_name(
+                    scoping.symbol_name(symbol_id),
+                    scoping.symbol_span(symbol_id),
+                )
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,96 @@
+use oxc_ast::AstKind;
+use oxc_ast::ast::{Program, Statement};
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_span::{GetSpan, Span};
+
+use crate::rules::Rule;
+
+/// Rule that requires `import` declarations to come before any other statement
+///
+/// Imports are hoisted, so an import in the middle of a file still runs before the code
+/// above it. Keeping them at the top makes the dependencies of a file visible at a glance
+/// and matches the order in which they are evaluated. Directives such as `'use strict'`
+/// are not statements and may precede the imports.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code:
+///
+/// ```typescript
+/// import { a } from './a';
+/// const value = a();
+/// import { b } from './b';
+/// ```
+///
+/// Examples of **correct** code:
+///
+/// ```typescript
+/// 'use strict';
+/// import { a } from './a';
+/// import { b } from './b';
+/// const value = a(b);
+/// ```
+pub struct ImportsFirstRule;
+
+impl Default for ImportsFirstRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ImportsFirstRule {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn check_program(program: &Program) -> Vec<OxcDiagnostic> {
+        let Some(first_statement) = program
+            .body
+            .iter()
+            .position(|statement| !matches!(statement, Statement::ImportDeclaration(_)))
+        else {
+            return Vec::new();
+        };
+
+        program.body[first_statement..]
+            .iter()
+            .filter(|statement| matches!(statement, Statement::ImportDeclaration(_)))
+            .map(|statement| Self::create_diagnostic(statement.span()))
+            .collect()
+    }
+
+    fn create_diagnostic(span: Span) -> OxcDiagnostic {
+        OxcDiagnostic::warn("Import declaration after other statements")
+            .with_help("Move this import to the top of the file, above all other statements")
+            .with_label(span.label("Out-of-place import"))
+    }
+}
+
+impl Rule for ImportsFirstRule {
+    fn name(&self) -> &'static str {
+        "imports-first"
+    }
+
+    fn description(&self) -> &'static str {
+        "Requires import declarations to come before all other statements"
+    }
+
+    fn applies_to_source(&self, source: &str) -> bool {
+        source.contains("import")
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["style"]
+    }
+
+    fn run_on_node(&self, node: &AstKind, _span: Span, _file_path: &str) -> Vec<OxcDiagnostic> {
+        match node {
+            AstKind::Program(program) => Self::check_program(program),
+            _ => Vec::new(),
+        }
+    }
+}
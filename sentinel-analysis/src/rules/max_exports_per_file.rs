@@ -0,0 +1,101 @@
+use oxc_ast::AstKind;
+use oxc_ast::ast::{Declaration, Program, Statement};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::Span;
+use serde_json::{Value, json};
+
+use crate::rules::Rule;
+
+/// Rule that limits the number of top-level exports per file
+///
+/// Encourages single-responsibility modules by counting exported declarations
+/// (`export class`, `export const a, b`, `export default`, `export { a }`) at the top
+/// level of a file. Re-exports from other modules (`export { a } from './a'`,
+/// `export * from './b'`) are not counted, so barrel files are unaffected.
+///
+/// ## Rule Options
+///
+/// - `max`: Maximum number of exports allowed per file (default: 1)
+pub struct MaxExportsPerFileRule {
+    /// Maximum number of exports allowed per file
+    max: usize,
+}
+
+impl Default for MaxExportsPerFileRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MaxExportsPerFileRule {
+    pub fn new() -> Self {
+        Self { max: 1 }
+    }
+
+    /// Count the exported bindings declared by a single top-level statement
+    fn count_exports(statement: &Statement) -> usize {
+        match statement {
+            Statement::ExportNamedDeclaration(export) if export.source.is_none() => {
+                match &export.declaration {
+                    Some(Declaration::VariableDeclaration(decl)) => decl.declarations.len(),
+                    Some(_) => 1,
+                    None => export.specifiers.len(),
+                }
+            }
+            Statement::ExportDefaultDeclaration(_) | Statement::TSExportAssignment(_) => 1,
+            _ => 0,
+        }
+    }
+
+    fn check_program(&self, program: &Program) -> Vec<OxcDiagnostic> {
+        let count: usize = program.body.iter().map(Self::count_exports).sum();
+        if count <= self.max {
+            return Vec::new();
+        }
+
+        vec![
+            OxcDiagnostic::error("Too many exports in a single file")
+                .with_help("Split this file into smaller modules with a single responsibility")
+                .with_label(Span::new(0, 0).label(format!(
+                    "File has {} exports, which exceeds the maximum of {}",
+                    count, self.max
+                ))),
+        ]
+    }
+}
+
+impl Rule for MaxExportsPerFileRule {
+    fn name(&self) -> &'static str {
+        "max-exports-per-file"
+    }
+
+    fn description(&self) -> &'static str {
+        "Limits the number of top-level exports per file"
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["maintainability"]
+    }
+
+    fn config_schema(&self) -> Option<Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "max": { "type": "integer", "minimum": 0, "default": 1 }
+            }
+        }))
+    }
+
+    fn set_config(&mut self, config: Value) {
+        if let Some(max) = config.get("max").and_then(Value::as_u64) {
+            self.max = max as usize;
+        }
+    }
+
+    fn run_on_node(&self, node: &AstKind, _span: Span, _file_path: &str) -> Vec<OxcDiagnostic> {
+        match node {
+            AstKind::Program(program) => self.check_program(program),
+            _ => Vec::new(),
+        }
+    }
+}
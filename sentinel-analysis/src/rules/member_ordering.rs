@@ -0,0 +1,213 @@
+use oxc_ast::AstKind;
+use oxc_ast::ast::{Class, ClassElement, MethodDefinitionKind, PropertyKey, TSAccessibility};
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_span::{GetSpan, Span};
+use serde_json::{Value, json};
+
+use crate::rules::Rule;
+
+/// Member groups, in the default order
+const DEFAULT_ORDER: &[&str] = &[
+    "static-field",
+    "instance-field",
+    "constructor",
+    "static-method",
+    "public-method",
+    "protected-method",
+    "private-method",
+];
+
+/// Rule that enforces a consistent order of class members
+///
+/// Every member is classified into a group and the groups must appear in the configured
+/// order. Fields (including `accessor` fields) are grouped by `static`, methods and
+/// getters/setters by `static` and accessibility; `#private` names count as private.
+/// Static blocks, index signatures and groups missing from the configured order are
+/// ignored. Only the first out-of-order member of each class is reported.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code:
+///
+/// ```typescript
+/// class UserService {
+///   constructor(private http: HttpClient) {}
+///   users: User[] = [];
+/// }
+/// ```
+///
+/// Examples of **correct** code:
+///
+/// ```typescript
+/// class UserService {
+///   users: User[] = [];
+///   constructor(private http: HttpClient) {}
+/// }
+/// ```
+///
+/// ## Rule Options
+///
+/// - `order`: Member groups in the expected order (default: `["static-field",
+///   "instance-field", "constructor", "static-method", "public-method",
+///   "protected-method", "private-method"]`)
+pub struct MemberOrderingRule {
+    /// Expected order of member groups
+    order: Vec<String>,
+}
+
+impl Default for MemberOrderingRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MemberOrderingRule {
+    pub fn new() -> Self {
+        Self {
+            order: DEFAULT_ORDER
+                .iter()
+                .map(|group| group.to_string())
+                .collect(),
+        }
+    }
+
+    /// Classify a class member, `None` for members that are not ordered
+    fn member_group(element: &ClassElement) -> Option<&'static str> {
+        match element {
+            ClassElement::PropertyDefinition(property) => {
+                Some(Self::field_group(property.r#static))
+            }
+            ClassElement::AccessorProperty(property) => Some(Self::field_group(property.r#static)),
+            ClassElement::MethodDefinition(method) => {
+                if method.kind == MethodDefinitionKind::Constructor {
+                    Some("constructor")
+                } else if method.r#static {
+                    Some("static-method")
+                } else if method.key.is_private_identifier() {
+                    Some("private-method")
+                } else {
+                    match method.accessibility {
+                        Some(TSAccessibility::Private) => Some("private-method"),
+                        Some(TSAccessibility::Protected) => Some("protected-method"),
+                        _ => Some("public-method"),
+                    }
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn field_group(is_static: bool) -> &'static str {
+        if is_static {
+            "static-field"
+        } else {
+            "instance-field"
+        }
+    }
+
+    /// Readable name of a member for diagnostics
+    fn member_name(element: &ClassElement) -> String {
+        let key = match element {
+            ClassElement::PropertyDefinition(property) => &property.key,
+            ClassElement::AccessorProperty(property) => &property.key,
+            ClassElement::MethodDefinition(method) => &method.key,
+            _ => return String::from("member"),
+        };
+        match key {
+            PropertyKey::PrivateIdentifier(ident) => format!("#{}", ident.name),
+            key => key
+                .static_name()
+                .map(|name| name.to_string())
+                .unwrap_or_else(|| String::from("[computed]")),
+        }
+    }
+
+    fn rank(&self, group: &str) -> Option<usize> {
+        self.order.iter().position(|ordered| ordered == group)
+    }
+
+    fn check_class(&self, class: &Class) -> Option<OxcDiagnostic> {
+        // Highest ranked member seen so far
+        let mut previous: Option<(usize, &str, Span)> = None;
+
+        for element in &class.body.body {
+            let Some(group) = Self::member_group(element) else {
+                continue;
+            };
+            let Some(rank) = self.rank(group) else {
+                continue;
+            };
+
+            match previous {
+                Some((previous_rank, previous_group, previous_span)) if rank < previous_rank => {
+                    return Some(
+                        OxcDiagnostic::warn(format!(
+                            "`{}` ({}) should be declared before {} members",
+                            Self::member_name(element),
+                            group,
+                            previous_group
+                        ))
+                        .with_help(format!("Expected member order: {}", self.order.join(", ")))
+                        .with_labels([
+                            element.span().label(format!("{} declared here", group)),
+                            previous_span.label(format!("after this {}", previous_group)),
+                        ]),
+                    );
+                }
+                Some((previous_rank, ..)) if rank == previous_rank => {}
+                _ => previous = Some((rank, group, element.span())),
+            }
+        }
+
+        None
+    }
+}
+
+impl Rule for MemberOrderingRule {
+    fn name(&self) -> &'static str {
+        "member-ordering"
+    }
+
+    fn description(&self) -> &'static str {
+        "Enforce a consistent order of class members"
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["style"]
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn config_schema(&self) -> Option<Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "order": {
+                    "type": "array",
+                    "items": { "enum": DEFAULT_ORDER },
+                    "default": DEFAULT_ORDER
+                }
+            }
+        }))
+    }
+
+    fn set_config(&mut self, config: Value) {
+        if let Some(order) = config.get("order").and_then(Value::as_array) {
+            self.order = order
+                .iter()
+                .filter_map(Value::as_str)
+                .filter(|group| DEFAULT_ORDER.contains(group))
+                .map(str::to_string)
+                .collect();
+        }
+    }
+
+    fn run_on_node(&self, node: &AstKind, _span: Span, _file_path: &str) -> Vec<OxcDiagnostic> {
+        match node {
+            AstKind::Class(class) => self.check_class(class).into_iter().collect(),
+            _ => Vec::new(),
+        }
+    }
+}
@@ -1,18 +1,54 @@
 // Module declarations
+pub mod eqeqeq;
+pub mod identifier_length;
+pub mod imports_first;
+pub mod max_exports_per_file;
+pub mod member_ordering;
+pub mod no_as_any;
+pub mod no_circular_type_ref;
+pub mod no_commented_code;
 pub mod no_debugger;
+pub mod no_default_export;
+pub mod no_deprecated_rxjs;
+pub mod no_direct_date;
+pub mod no_duplicate_keys;
+pub mod no_empty_module;
 pub mod no_empty_pattern;
+pub mod no_enum;
+pub mod no_hardcoded_secrets;
+pub mod no_misused_promise_all;
+pub mod no_permissive_cors;
+pub mod no_redundant_async;
+pub mod no_redundant_public;
+pub mod no_scattered_env_access;
+pub mod no_shadow;
+pub mod no_ts_ignore;
+pub mod no_unreachable;
+pub mod no_unused_private_members;
+pub mod no_var;
+pub mod no_zero_delay_timer;
+pub mod numeric_separators;
+pub mod prefer_const_assertion;
+pub mod require_async_error_handling;
+pub mod require_await_on_async;
+pub mod require_file_header;
+pub mod safe_json_parse;
 
 // Try to import custom rules if they exist
 #[cfg(feature = "custom_rules")]
 pub mod custom;
 
 // Re-export types and functions needed by other modules
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
 use oxc_ast::AstKind;
-use oxc_diagnostics::OxcDiagnostic;
+use oxc_ast::ast::PropertyKey;
+use oxc_diagnostics::{OxcDiagnostic, Severity};
 use oxc_semantic::SemanticBuilderReturn;
 use oxc_span::Span;
 use serde_json::Value;
 
+use crate::utilities::{DebugLevel, log};
+
 /// Trait that all rules must implement
 pub trait Rule: Send + Sync {
     /// Get the name of the rule
@@ -22,6 +58,24 @@ pub trait Rule: Send + Sync {
     #[allow(dead_code)]
     fn description(&self) -> &'static str;
 
+    /// Get the tags used to group this rule (e.g. `angular`, `style`)
+    /// Default implementation returns no tags
+    fn tags(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Get the severity of the diagnostics this rule reports
+    /// Default implementation returns `Severity::Error`
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    /// Get a JSON schema describing the options accepted by `set_config`
+    /// Default implementation returns `None` for rules without options
+    fn config_schema(&self) -> Option<Value> {
+        None
+    }
+
     /// Set configuration for this rule
     /// Default implementation does nothing - rules must override to use configuration
     fn set_config(&mut self, _config: Value) {}
@@ -45,11 +99,121 @@ pub trait Rule: Send + Sync {
     ) -> Vec<OxcDiagnostic> {
         Vec::new()
     }
+
+    /// Cheap check whether the rule can report anything on a source text (optional)
+    /// Only consulted with `--prefilter`: files no enabled rule applies to are not parsed.
+    /// Must never return `false` for a source the rule would report on, so checks are
+    /// limited to text that every finding depends on, such as an import.
+    /// Default implementation returns `true`
+    fn applies_to_source(&self, _source: &str) -> bool {
+        true
+    }
+
+    /// Compute an edit of `source` that resolves one of this rule's diagnostics (optional)
+    /// Default implementation returns `None` for rules without autofix
+    fn fix(&self, _diagnostic: &OxcDiagnostic, _source: &str) -> Option<RuleFix> {
+        None
+    }
+}
+
+/// Source edit resolving a diagnostic: the text covered by `span` becomes `replacement`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleFix {
+    pub span: Span,
+    pub replacement: String,
+}
+
+impl RuleFix {
+    /// Apply the edit to the source it was computed for
+    pub fn apply(&self, source: &str) -> String {
+        let start = self.span.start as usize;
+        let end = self.span.end as usize;
+        format!("{}{}{}", &source[..start], self.replacement, &source[end..])
+    }
+}
+
+/// Compile file globs from a rule configuration, skipping invalid patterns with a warning
+///
+/// `*` does not cross directory boundaries; `**` does.
+pub(crate) fn build_glob_set(patterns: &[&str]) -> GlobSet {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        match GlobBuilder::new(pattern).literal_separator(true).build() {
+            Ok(glob) => {
+                builder.add(glob);
+            }
+            // Rules do not know the run's debug level, so warn at the default one
+            Err(e) => log(
+                DebugLevel::Warn,
+                DebugLevel::default(),
+                &format!("ignoring invalid file glob '{}': {}", pattern, e),
+            ),
+        }
+    }
+    builder.build().unwrap_or_else(|_| GlobSet::empty())
+}
+
+/// Module Angular decorators are imported from, for [`Rule::applies_to_source`]
+#[cfg(feature = "custom_rules")]
+pub(crate) const ANGULAR_CORE_MODULE: &str = "@angular/core";
+
+/// Name of an object or class property key written as an identifier or a string literal
+///
+/// Returns `None` for other keys, such as numbers or computed expressions.
+pub fn prop_key_name<'a>(key: &PropertyKey<'a>) -> Option<&'a str> {
+    match key {
+        PropertyKey::StaticIdentifier(ident) => Some(ident.name.as_str()),
+        PropertyKey::PrivateIdentifier(ident) => Some(ident.name.as_str()),
+        PropertyKey::Identifier(ident) => Some(ident.name.as_str()),
+        PropertyKey::StringLiteral(literal) => Some(literal.value.as_str()),
+        _ => None,
+    }
+}
+
+/// Check a file path against globs built with [`build_glob_set`]
+///
+/// In a run, rules receive paths relative to the scan root (see
+/// [`RulesRegistry::set_path_root`](crate::rules_registry::RulesRegistry::set_path_root)),
+/// so `src/**` matches the `src` directory of the scanned project.
+pub(crate) fn matches_file_glob(globs: &GlobSet, file_path: &str) -> bool {
+    globs.is_match(file_path.replace('\\', "/"))
 }
 
 // Re-export rules for easier access
+pub use eqeqeq::EqeqeqRule;
+pub use identifier_length::IdentifierLengthRule;
+pub use imports_first::ImportsFirstRule;
+pub use max_exports_per_file::MaxExportsPerFileRule;
+pub use member_ordering::MemberOrderingRule;
+pub use no_as_any::NoAsAnyRule;
+pub use no_circular_type_ref::NoCircularTypeRefRule;
+pub use no_commented_code::NoCommentedCodeRule;
 pub use no_debugger::NoDebuggerRule;
+pub use no_default_export::NoDefaultExportRule;
+pub use no_deprecated_rxjs::NoDeprecatedRxjsRule;
+pub use no_direct_date::NoDirectDateRule;
+pub use no_duplicate_keys::NoDuplicateKeysRule;
+pub use no_empty_module::NoEmptyModuleRule;
 pub use no_empty_pattern::NoEmptyPatternRule;
+pub use no_enum::NoEnumRule;
+pub use no_hardcoded_secrets::NoHardcodedSecretsRule;
+pub use no_misused_promise_all::NoMisusedPromiseAllRule;
+pub use no_permissive_cors::NoPermissiveCorsRule;
+pub use no_redundant_async::NoRedundantAsyncRule;
+pub use no_redundant_public::NoRedundantPublicRule;
+pub use no_scattered_env_access::NoScatteredEnvAccessRule;
+pub use no_shadow::NoShadowRule;
+pub use no_ts_ignore::NoTsIgnoreRule;
+pub use no_unreachable::NoUnreachableRule;
+pub use no_unused_private_members::NoUnusedPrivateMembersRule;
+pub use no_var::NoVarRule;
+pub use no_zero_delay_timer::NoZeroDelayTimerRule;
+pub use numeric_separators::NumericSeparatorsRule;
+pub use prefer_const_assertion::PreferConstAssertionRule;
+pub use require_async_error_handling::RequireAsyncErrorHandlingRule;
+pub use require_await_on_async::RequireAwaitOnAsyncRule;
+pub use require_file_header::RequireFileHeaderRule;
+pub use safe_json_parse::SafeJsonParseRule;
 
 // Re-export custom rules if they exist
 #[cfg(feature = "custom_rules")]
@@ -0,0 +1,38 @@
+use oxc_ast::AstKind;
+use oxc_ast::ast::TSType;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::Span;
+
+use crate::rules::Rule;
+
+/// Rule that disallows casting values to `any` with `as any`
+///
+/// `x as any` switches off type checking for everything the value flows into. Other
+/// assertions are left to `typescript-type-assertion`; this rule only reports the `any`
+/// cast itself, including the inner cast of a double assertion (`x as any as Foo`).
+pub struct NoAsAnyRule;
+
+impl Rule for NoAsAnyRule {
+    fn name(&self) -> &'static str {
+        "no-as-any"
+    }
+
+    fn description(&self) -> &'static str {
+        "Disallow casting values to `any` with `as any`"
+    }
+
+    fn run_on_node(&self, node: &AstKind, span: Span, _file_path: &str) -> Vec<OxcDiagnostic> {
+        match node {
+            AstKind::TSAsExpression(expression)
+                if matches!(expression.type_annotation, TSType::TSAnyKeyword(_)) =>
+            {
+                vec![
+                    OxcDiagnostic::error("`as any` cast disables type checking")
+                        .with_help("Use a specific type, `unknown` with a type guard, or fix the underlying type definition")
+                        .with_label(span.label("Value is cast to `any`")),
+                ]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
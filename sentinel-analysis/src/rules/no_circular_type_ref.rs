@@ -0,0 +1,281 @@
+use std::collections::HashMap;
+
+use oxc_ast::AstKind;
+use oxc_ast::ast::{
+    Declaration, Expression, Program, Statement, TSInterfaceDeclaration, TSType,
+    TSTypeAliasDeclaration, TSTypeName,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::SemanticBuilderReturn;
+use oxc_span::Span;
+
+use crate::rules::Rule;
+
+/// Rule that flags type aliases and interfaces that circularly reference themselves
+///
+/// TypeScript resolves some type references eagerly: the members of a union or an
+/// intersection, a parenthesized type, the operand of `keyof` or `readonly`, the object
+/// of an indexed access and the base types of an interface. When such references lead
+/// back to the declaration, the compiler reports "circularly references itself" and the
+/// type degrades to `any`. References through an indirection are resolved lazily and are
+/// allowed: object type literals, arrays, tuples, function types, type arguments of a
+/// generic, conditional and mapped types. So `type Json = string | Json[]` is fine, while
+/// `type A = B | string; type B = A` is not.
+///
+/// The scope is deliberately conservative: only the type aliases and interfaces declared
+/// at the top level of a file are connected, so cycles through imported types are not
+/// detected, and any reference the rule cannot classify is treated as lazy. Following
+/// references across files needs the imports of the whole project, which rules only see
+/// one file at a time; it can be added once rules can run on the project as a whole.
+///
+/// Every declaration on a cycle is reported at its span, with the cycle in the message.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code:
+///
+/// ```typescript
+/// type Node = Leaf | Branch;
+/// type Branch = Node | Node[];
+/// type Leaf = string;
+/// ```
+///
+/// Examples of **correct** code:
+///
+/// ```typescript
+/// type Node = Leaf | Branch;
+/// type Branch = { children: Node[] };
+/// type Leaf = string;
+/// ```
+pub struct NoCircularTypeRefRule;
+
+impl Default for NoCircularTypeRefRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NoCircularTypeRefRule {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Collect the type declarations of a file with the names they reference eagerly
+    fn type_declarations<'a>(program: &'a Program<'a>) -> Vec<TypeDeclaration<'a>> {
+        let mut declarations = Vec::new();
+        for statement in &program.body {
+            let declaration = match statement {
+                Statement::TSTypeAliasDeclaration(alias) => Some(TypeDeclaration::alias(alias)),
+                Statement::TSInterfaceDeclaration(interface) => {
+                    Some(TypeDeclaration::interface(interface))
+                }
+                Statement::ExportNamedDeclaration(export) => match &export.declaration {
+                    Some(Declaration::TSTypeAliasDeclaration(alias)) => {
+                        Some(TypeDeclaration::alias(alias))
+                    }
+                    Some(Declaration::TSInterfaceDeclaration(interface)) => {
+                        Some(TypeDeclaration::interface(interface))
+                    }
+                    _ => None,
+                },
+                _ => None,
+            };
+            declarations.extend(declaration);
+        }
+        declarations
+    }
+
+    /// Names of the declarations reached from `start` and back, if `start` is on a cycle
+    fn find_cycle<'a>(
+        start: &'a str,
+        references: &HashMap<&'a str, Vec<&'a str>>,
+    ) -> Option<Vec<&'a str>> {
+        let mut path = vec![start];
+        let mut visited = vec![start];
+        Self::search(start, start, references, &mut path, &mut visited).then_some(path)
+    }
+
+    /// Depth-first search for a path from `current` back to `start`
+    fn search<'a>(
+        start: &'a str,
+        current: &'a str,
+        references: &HashMap<&'a str, Vec<&'a str>>,
+        path: &mut Vec<&'a str>,
+        visited: &mut Vec<&'a str>,
+    ) -> bool {
+        for &next in references.get(current).into_iter().flatten() {
+            if next == start {
+                path.push(next);
+                return true;
+            }
+            if visited.contains(&next) || !references.contains_key(next) {
+                continue;
+            }
+            visited.push(next);
+            path.push(next);
+            if Self::search(start, next, references, path, visited) {
+                return true;
+            }
+            path.pop();
+        }
+        false
+    }
+
+    fn create_diagnostic(declaration: &TypeDeclaration, cycle: &[&str]) -> OxcDiagnostic {
+        let message = if cycle.len() == 2 {
+            format!(
+                "{} `{}` circularly references itself",
+                declaration.kind, declaration.name
+            )
+        } else {
+            let cycle: Vec<String> = cycle.iter().map(|name| format!("`{}`", name)).collect();
+            format!(
+                "{} `{}` circularly references itself through {}",
+                declaration.kind,
+                declaration.name,
+                cycle.join(" -> ")
+            )
+        };
+        OxcDiagnostic::error(message)
+            .with_help("Break the cycle with an object type, an array or an interface member, which TypeScript resolves lazily")
+            .with_label(declaration.span.label("Circular type declaration"))
+    }
+}
+
+/// A top-level type alias or interface and the type names it references eagerly
+struct TypeDeclaration<'a> {
+    /// `Type alias` or `Interface`, for the message
+    kind: &'static str,
+    name: &'a str,
+    span: Span,
+    references: Vec<&'a str>,
+}
+
+impl<'a> TypeDeclaration<'a> {
+    fn alias(alias: &'a TSTypeAliasDeclaration<'a>) -> Self {
+        let mut references = Vec::new();
+        eager_references(&alias.type_annotation, &mut references);
+        // A type parameter shadows a declaration of the same name
+        if let Some(type_parameters) = &alias.type_parameters {
+            references.retain(|name| {
+                !type_parameters
+                    .params
+                    .iter()
+                    .any(|param| param.name.name == *name)
+            });
+        }
+        Self {
+            kind: "Type alias",
+            name: alias.id.name.as_str(),
+            span: alias.span,
+            references,
+        }
+    }
+
+    fn interface(interface: &'a TSInterfaceDeclaration<'a>) -> Self {
+        // Only base types are resolved eagerly; members are resolved when they are used
+        let references = interface
+            .extends
+            .iter()
+            .flatten()
+            .filter_map(|heritage| match &heritage.expression {
+                Expression::Identifier(ident) => Some(ident.name.as_str()),
+                _ => None,
+            })
+            .collect();
+        Self {
+            kind: "Interface",
+            name: interface.id.name.as_str(),
+            span: interface.span,
+            references,
+        }
+    }
+}
+
+/// Collect the type names a type resolves eagerly, skipping lazily resolved positions
+fn eager_references<'a>(ty: &'a TSType<'a>, references: &mut Vec<&'a str>) {
+    match ty {
+        TSType::TSTypeReference(reference) => {
+            // Type arguments of a generic are deferred, so only the name itself counts
+            if let TSTypeName::IdentifierReference(ident) = &reference.type_name {
+                references.push(ident.name.as_str());
+            }
+        }
+        TSType::TSUnionType(union) => {
+            for ty in &union.types {
+                eager_references(ty, references);
+            }
+        }
+        TSType::TSIntersectionType(intersection) => {
+            for ty in &intersection.types {
+                eager_references(ty, references);
+            }
+        }
+        TSType::TSParenthesizedType(parenthesized) => {
+            eager_references(&parenthesized.type_annotation, references);
+        }
+        TSType::TSTypeOperatorType(operator) => {
+            eager_references(&operator.type_annotation, references);
+        }
+        TSType::TSIndexedAccessType(indexed) => {
+            eager_references(&indexed.object_type, references);
+        }
+        _ => {}
+    }
+}
+
+impl Rule for NoCircularTypeRefRule {
+    fn name(&self) -> &'static str {
+        "no-circular-type-ref"
+    }
+
+    fn description(&self) -> &'static str {
+        "Disallows type aliases and interfaces that circularly reference themselves"
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["typescript"]
+    }
+
+    fn applies_to_source(&self, source: &str) -> bool {
+        source.contains("type") || source.contains("interface")
+    }
+
+    fn run_on_semantic(
+        &self,
+        semantic_result: &SemanticBuilderReturn,
+        _file_path: &str,
+    ) -> Vec<OxcDiagnostic> {
+        let Some(AstKind::Program(program)) = semantic_result
+            .semantic
+            .nodes()
+            .root_node()
+            .map(|node| node.kind())
+        else {
+            return Vec::new();
+        };
+
+        let declarations = Self::type_declarations(program);
+        // Merged interface declarations contribute their base types to one name
+        let mut references: HashMap<&str, Vec<&str>> = HashMap::new();
+        for declaration in &declarations {
+            references
+                .entry(declaration.name)
+                .or_default()
+                .extend(&declaration.references);
+        }
+
+        let mut diagnostics = Vec::new();
+        let mut reported: Vec<&str> = Vec::new();
+        for declaration in &declarations {
+            if reported.contains(&declaration.name) {
+                continue;
+            }
+            if let Some(cycle) = Self::find_cycle(declaration.name, &references) {
+                reported.push(declaration.name);
+                diagnostics.push(Self::create_diagnostic(declaration, &cycle));
+            }
+        }
+        diagnostics
+    }
+}
@@ -0,0 +1,176 @@
+use oxc_ast::Comment;
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_semantic::SemanticBuilderReturn;
+use oxc_span::Span;
+use serde_json::{Value, json};
+
+use crate::rules::Rule;
+
+/// Keywords that start a line of code but rarely a sentence
+const CODE_KEYWORDS: &[&str] = &[
+    "const ",
+    "let ",
+    "var ",
+    "function ",
+    "return ",
+    "import ",
+    "export ",
+    "class ",
+    "if (",
+    "for (",
+    "while (",
+    "switch (",
+    "await ",
+    "this.",
+];
+
+/// Rule that flags comments that look like commented-out code
+///
+/// Dead code kept in comments goes stale quickly and is better recovered from version
+/// control. Consecutive `//` comments on adjacent lines are treated as one block; block
+/// comments are checked on their own. JSDoc comments (`/** ... */`) and comments that
+/// follow code on the same line are never checked.
+///
+/// A block is reported when it spans at least `minLines` lines and more than half of
+/// its non-empty lines look like code: they end in `;`, `{`, `}` or `)`, contain `=>`,
+/// or start with a keyword such as `const`, `function`, `return` or `if (`.
+///
+/// The heuristic is tuned to keep false positives on prose low, but it is a heuristic:
+/// comments containing code examples or pseudo-code are reported too, while commented
+/// out expressions without any of these markers are missed.
+///
+/// ## Rule Options
+///
+/// - `minLines`: Minimum number of lines a comment block must span to be reported (default: 2)
+pub struct NoCommentedCodeRule {
+    /// Minimum number of lines of a reported comment block
+    min_lines: usize,
+}
+
+impl Default for NoCommentedCodeRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NoCommentedCodeRule {
+    pub fn new() -> Self {
+        Self { min_lines: 2 }
+    }
+
+    /// Check whether a single comment line looks like a line of code
+    fn looks_like_code(line: &str) -> bool {
+        line.ends_with([';', '{', '}', ')'])
+            || line.contains("=>")
+            || CODE_KEYWORDS
+                .iter()
+                .any(|keyword| line.starts_with(keyword))
+    }
+
+    /// Check whether a comment is the only thing on its line
+    fn starts_own_line(source: &str, comment: &Comment) -> bool {
+        let before = &source[..comment.span.start as usize];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        before[line_start..].trim().is_empty()
+    }
+
+    /// Check whether a line comment directly continues the previous one on the next line
+    fn continues_block(source: &str, previous: &Comment, comment: &Comment) -> bool {
+        let between = &source[previous.span.end as usize..comment.span.start as usize];
+        between.trim().is_empty() && between.matches('\n').count() == 1
+    }
+
+    /// Group comments into blocks: runs of adjacent line comments, or single block comments
+    fn comment_blocks<'c>(source: &str, comments: &'c [Comment]) -> Vec<Vec<&'c Comment>> {
+        let mut blocks: Vec<Vec<&Comment>> = Vec::new();
+        for comment in comments {
+            if comment.is_jsdoc() || !Self::starts_own_line(source, comment) {
+                continue;
+            }
+            match blocks.last_mut() {
+                Some(block)
+                    if comment.is_line()
+                        && block.last().is_some_and(|previous| {
+                            previous.is_line() && Self::continues_block(source, previous, comment)
+                        }) =>
+                {
+                    block.push(comment)
+                }
+                _ => blocks.push(vec![comment]),
+            }
+        }
+        blocks
+    }
+
+    /// Check whether a block of comments spans enough lines and mostly contains code
+    fn is_commented_code(&self, source: &str, block: &[&Comment]) -> bool {
+        let lines: Vec<&str> = block
+            .iter()
+            .flat_map(|comment| comment.content_span().source_text(source).lines())
+            .map(|line| line.trim().trim_start_matches('*').trim())
+            .collect();
+        if lines.len() < self.min_lines {
+            return false;
+        }
+
+        let non_empty: Vec<&str> = lines.into_iter().filter(|line| !line.is_empty()).collect();
+        let code_lines = non_empty
+            .iter()
+            .filter(|line| Self::looks_like_code(line))
+            .count();
+        code_lines * 2 > non_empty.len()
+    }
+}
+
+impl Rule for NoCommentedCodeRule {
+    fn name(&self) -> &'static str {
+        "no-commented-code"
+    }
+
+    fn description(&self) -> &'static str {
+        "Flags comment blocks that look like commented-out code"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["maintainability"]
+    }
+
+    fn config_schema(&self) -> Option<Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "minLines": { "type": "integer", "minimum": 1, "default": 2 }
+            }
+        }))
+    }
+
+    fn set_config(&mut self, config: Value) {
+        if let Some(min_lines) = config.get("minLines").and_then(Value::as_u64) {
+            self.min_lines = min_lines as usize;
+        }
+    }
+
+    fn run_on_semantic(
+        &self,
+        semantic_result: &SemanticBuilderReturn,
+        _file_path: &str,
+    ) -> Vec<OxcDiagnostic> {
+        let semantic = &semantic_result.semantic;
+        let source = semantic.source_text();
+
+        Self::comment_blocks(source, semantic.comments())
+            .into_iter()
+            .filter(|block| self.is_commented_code(source, block))
+            .map(|block| {
+                let span = Span::new(block[0].span.start, block[block.len() - 1].span.end);
+                OxcDiagnostic::warn("Commented-out code")
+                    .with_help("Remove the code; version control keeps its history")
+                    .with_label(span.label("This comment looks like code"))
+            })
+            .collect()
+    }
+}
@@ -16,6 +16,10 @@ impl Rule for NoDebuggerRule {
         "Disallow the use of debugger statements"
     }
 
+    fn applies_to_source(&self, source: &str) -> bool {
+        source.contains("debugger")
+    }
+
     fn run_on_node(&self, node: &AstKind, span: Span, _file_path: &str) -> Vec<OxcDiagnostic> {
         match node {
             AstKind::DebuggerStatement(_) => {
@@ -0,0 +1,122 @@
+use globset::GlobSet;
+use oxc_ast::AstKind;
+use oxc_ast::ast::ModuleExportName;
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_span::{GetSpan, Span};
+use serde_json::{Value, json};
+
+use crate::rules::{Rule, build_glob_set, matches_file_glob};
+
+/// Rule that flags default exports
+///
+/// Named exports keep the name of a binding identical at every import site and work
+/// better with refactoring tools and auto-imports. Both `export default ...` and
+/// `export { foo as default }` are reported, unless the file matches one of the
+/// `allowedFiles` globs (for frameworks that require a default export).
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code:
+///
+/// ```typescript
+/// export default class UserService {}
+/// export { UserService as default };
+/// ```
+///
+/// Examples of **correct** code:
+///
+/// ```typescript
+/// export class UserService {}
+/// ```
+///
+/// ## Rule Options
+///
+/// - `allowedFiles`: Globs of files allowed to have a default export (default: `[]`),
+///   e.g. `["**/pages/**/*.tsx"]`. `*` does not cross directory boundaries; use `**` for that.
+pub struct NoDefaultExportRule {
+    /// Files allowed to have a default export
+    allowed_files: GlobSet,
+}
+
+impl Default for NoDefaultExportRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NoDefaultExportRule {
+    pub fn new() -> Self {
+        Self {
+            allowed_files: GlobSet::empty(),
+        }
+    }
+
+    fn create_diagnostic(span: Span) -> OxcDiagnostic {
+        OxcDiagnostic::warn("Unexpected default export")
+            .with_help(
+                "Use a named export so the binding has the same name everywhere it is imported",
+            )
+            .with_label(span.label("Default export"))
+    }
+}
+
+impl Rule for NoDefaultExportRule {
+    fn name(&self) -> &'static str {
+        "no-default-export"
+    }
+
+    fn description(&self) -> &'static str {
+        "Disallow default exports in favor of named exports"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["style"]
+    }
+
+    fn config_schema(&self) -> Option<Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "allowedFiles": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "default": []
+                }
+            }
+        }))
+    }
+
+    fn set_config(&mut self, config: Value) {
+        if let Some(patterns) = config.get("allowedFiles").and_then(Value::as_array) {
+            let patterns: Vec<&str> = patterns.iter().filter_map(Value::as_str).collect();
+            self.allowed_files = build_glob_set(&patterns);
+        }
+    }
+
+    fn run_on_node(&self, node: &AstKind, _span: Span, file_path: &str) -> Vec<OxcDiagnostic> {
+        let spans: Vec<Span> = match node {
+            // Label the `export default` keywords rather than the whole declaration
+            AstKind::ExportDefaultDeclaration(export) => {
+                vec![Span::new(export.span.start, export.declaration.span().start)]
+            }
+            AstKind::ExportNamedDeclaration(export) => export
+                .specifiers
+                .iter()
+                .filter(|specifier| {
+                    matches!(&specifier.exported, ModuleExportName::IdentifierName(name) if name.name == "default")
+                })
+                .map(|specifier| specifier.span)
+                .collect(),
+            _ => return Vec::new(),
+        };
+        if spans.is_empty() || matches_file_glob(&self.allowed_files, file_path) {
+            return Vec::new();
+        }
+
+        spans.into_iter().map(Self::create_diagnostic).collect()
+    }
+}
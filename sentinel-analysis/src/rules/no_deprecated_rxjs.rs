@@ -0,0 +1,178 @@
+use std::collections::HashMap;
+
+use oxc_ast::AstKind;
+use oxc_ast::ast::ImportDeclarationSpecifier;
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_span::Span;
+use serde_json::{Map, Value, json};
+
+use crate::rules::Rule;
+
+/// Module whose exports are checked against the deprecation list
+const RXJS_OPERATORS_MODULE: &str = "rxjs/operators";
+
+/// Deprecated operators of `rxjs/operators` and what to use instead
+const DEFAULT_DEPRECATED_OPERATORS: &[(&str, &str)] = &[
+    ("combineAll", "combineLatestAll"),
+    (
+        "combineLatest",
+        "combineLatestWith, or the combineLatest creation function from 'rxjs'",
+    ),
+    (
+        "concat",
+        "concatWith, or the concat creation function from 'rxjs'",
+    ),
+    ("exhaust", "exhaustAll"),
+    ("flatMap", "mergeMap"),
+    ("mapTo", "map(() => value)"),
+    (
+        "merge",
+        "mergeWith, or the merge creation function from 'rxjs'",
+    ),
+    ("multicast", "connect or share"),
+    ("partition", "the partition creation function from 'rxjs'"),
+    ("pluck", "map(value => value.property)"),
+    ("publish", "connect or share"),
+    ("publishBehavior", "share with a BehaviorSubject connector"),
+    ("publishLast", "share with an AsyncSubject connector"),
+    (
+        "publishReplay",
+        "share with a ReplaySubject connector, or shareReplay",
+    ),
+    (
+        "race",
+        "raceWith, or the race creation function from 'rxjs'",
+    ),
+    ("refCount", "share"),
+    ("zip", "zipWith, or the zip creation function from 'rxjs'"),
+];
+
+/// Rule that flags imports of deprecated RxJS operators
+///
+/// RxJS 7 deprecated a number of operators in favor of renamed or more general
+/// alternatives, and they are removed in RxJS 8. Every import of a deprecated operator
+/// from `rxjs/operators` is reported together with its replacement. Usages are found
+/// through their import, so each deprecated operator is reported once per file.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code:
+///
+/// ```typescript
+/// import { mapTo, pluck } from 'rxjs/operators';
+/// ```
+///
+/// Examples of **correct** code:
+///
+/// ```typescript
+/// import { map } from 'rxjs/operators';
+/// ```
+///
+/// ## Rule Options
+///
+/// - `deprecated`: Additional operators to flag, mapping each name to its replacement
+///   (e.g. `{ "shareReplay": "share" }`). Entries override the built-in list.
+pub struct NoDeprecatedRxjsRule {
+    /// Deprecated operator names mapped to their replacement
+    deprecated: HashMap<String, String>,
+}
+
+impl Default for NoDeprecatedRxjsRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NoDeprecatedRxjsRule {
+    pub fn new() -> Self {
+        Self {
+            deprecated: DEFAULT_DEPRECATED_OPERATORS
+                .iter()
+                .map(|(name, replacement)| (name.to_string(), replacement.to_string()))
+                .collect(),
+        }
+    }
+
+    fn create_diagnostic(name: &str, replacement: &str, span: Span) -> OxcDiagnostic {
+        OxcDiagnostic::warn(format!(
+            "`{}` from {} is deprecated, use {} instead",
+            name, RXJS_OPERATORS_MODULE, replacement
+        ))
+        .with_help(format!("Replace `{}` with {}", name, replacement))
+        .with_label(span.label(format!("Deprecated operator `{}`", name)))
+    }
+}
+
+impl Rule for NoDeprecatedRxjsRule {
+    fn name(&self) -> &'static str {
+        "no-deprecated-rxjs"
+    }
+
+    fn description(&self) -> &'static str {
+        "Flags imports of deprecated RxJS operators and suggests their replacements"
+    }
+
+    fn applies_to_source(&self, source: &str) -> bool {
+        source.contains(RXJS_OPERATORS_MODULE)
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["rxjs"]
+    }
+
+    fn config_schema(&self) -> Option<Value> {
+        let defaults: Map<String, Value> = DEFAULT_DEPRECATED_OPERATORS
+            .iter()
+            .map(|(name, replacement)| (name.to_string(), json!(replacement)))
+            .collect();
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "deprecated": {
+                    "type": "object",
+                    "additionalProperties": { "type": "string" },
+                    "default": defaults
+                }
+            }
+        }))
+    }
+
+    fn set_config(&mut self, config: Value) {
+        if let Some(deprecated) = config.get("deprecated").and_then(Value::as_object) {
+            for (name, replacement) in deprecated {
+                if let Some(replacement) = replacement.as_str() {
+                    self.deprecated
+                        .insert(name.clone(), replacement.to_string());
+                }
+            }
+        }
+    }
+
+    fn run_on_node(&self, node: &AstKind, _span: Span, _file_path: &str) -> Vec<OxcDiagnostic> {
+        let AstKind::ImportDeclaration(import) = node else {
+            return Vec::new();
+        };
+        if import.source.value != RXJS_OPERATORS_MODULE {
+            return Vec::new();
+        }
+
+        import
+            .specifiers
+            .iter()
+            .flatten()
+            .filter_map(|specifier| match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                    let name = specifier.imported.name();
+                    self.deprecated.get(name.as_str()).map(|replacement| {
+                        Self::create_diagnostic(&name, replacement, specifier.span)
+                    })
+                }
+                _ => None,
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,126 @@
+use globset::GlobSet;
+use oxc_ast::AstKind;
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_span::Span;
+use serde_json::{Value, json};
+
+use crate::rules::{Rule, build_glob_set, matches_file_glob};
+
+/// Default location of the clock abstraction allowed to read the system time
+const DEFAULT_ALLOWED_FILES: &str = "**/clock.ts";
+
+/// Rule that flags reads of the current time outside of a clock abstraction
+///
+/// Code calling `Date.now()` or `new Date()` directly depends on the system time, which
+/// makes it hard to test. Reading the time through an injected clock lets tests control
+/// it. `new Date(value)` with arguments does not read the current time and is allowed.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code (in `src/app/session.ts`):
+///
+/// ```typescript
+/// const expiresAt = Date.now() + TTL;
+/// const createdAt = new Date();
+/// ```
+///
+/// Examples of **correct** code (in `src/app/session.ts`):
+///
+/// ```typescript
+/// const expiresAt = this.clock.now() + TTL;
+/// const createdAt = new Date(timestamp);
+/// ```
+///
+/// ## Rule Options
+///
+/// - `allowedFiles`: Globs of files allowed to read the system time
+///   (default: `["**/clock.ts"]`). `*` does not cross directory boundaries; use `**` for that.
+pub struct NoDirectDateRule {
+    /// Files allowed to read the system time
+    allowed_files: GlobSet,
+}
+
+impl Default for NoDirectDateRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NoDirectDateRule {
+    pub fn new() -> Self {
+        Self {
+            allowed_files: build_glob_set(&[DEFAULT_ALLOWED_FILES]),
+        }
+    }
+
+    fn create_diagnostic(expression: &str, span: Span) -> OxcDiagnostic {
+        OxcDiagnostic::warn(format!("`{}` reads the system time directly", expression))
+            .with_help("Inject a clock or time source so tests can control the current time")
+            .with_label(span.label("Reads the current time"))
+    }
+}
+
+impl Rule for NoDirectDateRule {
+    fn name(&self) -> &'static str {
+        "no-direct-date"
+    }
+
+    fn description(&self) -> &'static str {
+        "Disallow Date.now() and new Date() outside of an injectable clock"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["maintainability"]
+    }
+
+    fn config_schema(&self) -> Option<Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "allowedFiles": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "default": [DEFAULT_ALLOWED_FILES]
+                }
+            }
+        }))
+    }
+
+    fn set_config(&mut self, config: Value) {
+        if let Some(patterns) = config.get("allowedFiles").and_then(Value::as_array) {
+            let patterns: Vec<&str> = patterns.iter().filter_map(Value::as_str).collect();
+            self.allowed_files = build_glob_set(&patterns);
+        }
+    }
+
+    fn run_on_node(&self, node: &AstKind, span: Span, file_path: &str) -> Vec<OxcDiagnostic> {
+        let expression = match node {
+            AstKind::CallExpression(call) => {
+                let is_date_now = call.callee.as_member_expression().is_some_and(|callee| {
+                    callee.object().is_specific_id("Date")
+                        && callee.static_property_name() == Some("now")
+                });
+                if !is_date_now {
+                    return Vec::new();
+                }
+                "Date.now()"
+            }
+            AstKind::NewExpression(new_expr)
+                if new_expr.callee.is_specific_id("Date") && new_expr.arguments.is_empty() =>
+            {
+                "new Date()"
+            }
+            _ => return Vec::new(),
+        };
+
+        if matches_file_glob(&self.allowed_files, file_path) {
+            return Vec::new();
+        }
+
+        vec![Self::create_diagnostic(expression, span)]
+    }
+}
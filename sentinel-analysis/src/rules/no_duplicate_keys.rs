@@ -0,0 +1,125 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use oxc_ast::AstKind;
+use oxc_ast::ast::{ObjectExpression, ObjectProperty, ObjectPropertyKind, PropertyKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{GetSpan, Span};
+
+use crate::rules::{Rule, prop_key_name};
+
+/// Rule that flags object literals defining the same key more than once
+///
+/// Only the last value of a duplicated key survives, so the earlier ones are silently
+/// lost. Keys are compared by their static name, so `a`, `'a'` and `['a']` are the
+/// same key. Computed keys such as `[name]` depend on runtime values and are skipped.
+/// A getter and a setter for the same key are a valid pair.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code:
+///
+/// ```typescript
+/// const user = { name: 'Ada', role: 'admin', name: 'Grace' };
+/// const options = { timeout, 'timeout': 500 };
+/// ```
+///
+/// Examples of **correct** code:
+///
+/// ```typescript
+/// const user = { name: 'Ada', role: 'admin' };
+/// const counter = { get value() { return 1; }, set value(v) {} };
+/// ```
+pub struct NoDuplicateKeysRule;
+
+/// Kinds of definitions seen so far for one key
+#[derive(Default)]
+struct KeyDefinitions {
+    /// Span of the first definition, reported as the original
+    first: Option<Span>,
+    init: bool,
+    get: bool,
+    set: bool,
+}
+
+impl KeyDefinitions {
+    /// Record a definition, returning whether it conflicts with an earlier one
+    fn add(&mut self, kind: PropertyKind, span: Span) -> bool {
+        let conflicts = match kind {
+            PropertyKind::Init => self.init || self.get || self.set,
+            PropertyKind::Get => self.init || self.get,
+            PropertyKind::Set => self.init || self.set,
+        };
+        match kind {
+            PropertyKind::Init => self.init = true,
+            PropertyKind::Get => self.get = true,
+            PropertyKind::Set => self.set = true,
+        }
+        self.first.get_or_insert(span);
+        conflicts
+    }
+}
+
+impl NoDuplicateKeysRule {
+    /// Name of a property key, `None` if it is only known at runtime
+    fn static_key<'a>(property: &ObjectProperty<'a>) -> Option<Cow<'a, str>> {
+        if property.key.is_identifier() {
+            return prop_key_name(&property.key).map(Cow::Borrowed);
+        }
+        // Literal keys such as `'a'`, `1` or `['a']` are known statically
+        property.key.static_name()
+    }
+
+    fn check_object(object: &ObjectExpression) -> Vec<OxcDiagnostic> {
+        let mut keys: HashMap<Cow<str>, KeyDefinitions> = HashMap::new();
+        let mut diagnostics = Vec::new();
+
+        for property in &object.properties {
+            let ObjectPropertyKind::ObjectProperty(property) = property else {
+                continue;
+            };
+            let Some(name) = Self::static_key(property) else {
+                continue;
+            };
+
+            let span = property.key.span();
+            let definitions = keys.entry(name.clone()).or_default();
+            if definitions.add(property.kind, span) {
+                let first = definitions.first.unwrap_or(span);
+                diagnostics.push(Self::create_diagnostic(&name, span, first));
+            }
+        }
+
+        diagnostics
+    }
+
+    fn create_diagnostic(name: &str, span: Span, first: Span) -> OxcDiagnostic {
+        OxcDiagnostic::error(format!("Duplicate key `{}` in object literal", name))
+            .with_help("Only the last value of a key is kept; remove or rename one of the keys")
+            .with_labels([
+                span.primary_label(format!("`{}` is defined again here", name)),
+                first.label(format!("`{}` is first defined here", name)),
+            ])
+    }
+}
+
+impl Rule for NoDuplicateKeysRule {
+    fn name(&self) -> &'static str {
+        "no-duplicate-keys"
+    }
+
+    fn description(&self) -> &'static str {
+        "Disallows duplicate keys in object literals"
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["correctness"]
+    }
+
+    fn run_on_node(&self, node: &AstKind, _span: Span, _file_path: &str) -> Vec<OxcDiagnostic> {
+        match node {
+            AstKind::ObjectExpression(object) => Self::check_object(object),
+            _ => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use std::path::Path;
+
+use oxc_ast::AstKind;
+use oxc_ast::ast::{Program, Statement};
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_span::Span;
+use serde_json::{Value, json};
+
+use crate::rules::Rule;
+
+/// Rule that flags modules without any meaningful code
+///
+/// A file that is empty, or only imports bindings it never gets to use, is usually a
+/// leftover from a refactoring. Side-effect imports (`import './polyfills'`) count as
+/// code, since loading them is the point of the file.
+///
+/// Re-exports (`export * from './a'`) count as code, since a module made of them is
+/// usually a public entry point. With `checkReExports`, files whose statements are all
+/// re-exports are flagged too, except for barrel files: an `index.ts` re-exporting its
+/// siblings is allowed unless `ignoreBarrelFiles` is disabled.
+///
+/// ## Rule Options
+///
+/// - `checkReExports`: Flag files that consist of re-exports (default: false)
+/// - `ignoreBarrelFiles`: Allow `index` files that consist of re-exports when
+///   `checkReExports` is enabled (default: true)
+pub struct NoEmptyModuleRule {
+    /// Treat re-exports as carrying no code of their own
+    check_re_exports: bool,
+    /// Allow `index` files that only re-export other modules
+    ignore_barrel_files: bool,
+}
+
+impl Default for NoEmptyModuleRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NoEmptyModuleRule {
+    pub fn new() -> Self {
+        Self {
+            check_re_exports: false,
+            ignore_barrel_files: true,
+        }
+    }
+
+    /// Check whether a statement re-exports bindings of another module
+    fn is_re_export(statement: &Statement) -> bool {
+        match statement {
+            Statement::ExportAllDeclaration(_) => true,
+            Statement::ExportNamedDeclaration(export) => export.source.is_some(),
+            _ => false,
+        }
+    }
+
+    /// Check whether a top-level statement carries no code of its own
+    fn is_empty_statement(&self, statement: &Statement) -> bool {
+        match statement {
+            Statement::ImportDeclaration(import) => import
+                .specifiers
+                .as_ref()
+                .is_some_and(|specifiers| !specifiers.is_empty()),
+            // `export {}` only marks the file as a module
+            Statement::ExportNamedDeclaration(export) => {
+                if export.source.is_some() {
+                    self.check_re_exports
+                } else {
+                    export.declaration.is_none() && export.specifiers.is_empty()
+                }
+            }
+            Statement::ExportAllDeclaration(_) => self.check_re_exports,
+            Statement::EmptyStatement(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Check whether the file is an `index` module re-exporting other modules
+    fn is_barrel_file(program: &Program, file_path: &str) -> bool {
+        Path::new(file_path)
+            .file_stem()
+            .is_some_and(|stem| stem == "index")
+            && program.body.iter().any(Self::is_re_export)
+    }
+
+    fn check_program(&self, program: &Program, file_path: &str) -> Vec<OxcDiagnostic> {
+        if !program
+            .body
+            .iter()
+            .all(|statement| self.is_empty_statement(statement))
+        {
+            return Vec::new();
+        }
+        if self.ignore_barrel_files && Self::is_barrel_file(program, file_path) {
+            return Vec::new();
+        }
+
+        vec![
+            OxcDiagnostic::warn("Module contains no code")
+                .with_help("Remove the file if it is no longer used")
+                .with_label(Span::new(0, 0).label("This file only contains imports or re-exports")),
+        ]
+    }
+}
+
+impl Rule for NoEmptyModuleRule {
+    fn name(&self) -> &'static str {
+        "no-empty-module"
+    }
+
+    fn description(&self) -> &'static str {
+        "Flags files that contain no code besides imports"
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["maintainability"]
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn config_schema(&self) -> Option<Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "checkReExports": { "type": "boolean", "default": false },
+                "ignoreBarrelFiles": { "type": "boolean", "default": true }
+            }
+        }))
+    }
+
+    fn set_config(&mut self, config: Value) {
+        if let Some(check) = config.get("checkReExports").and_then(Value::as_bool) {
+            self.check_re_exports = check;
+        }
+        if let Some(ignore) = config.get("ignoreBarrelFiles").and_then(Value::as_bool) {
+            self.ignore_barrel_files = ignore;
+        }
+    }
+
+    fn run_on_node(&self, node: &AstKind, _span: Span, file_path: &str) -> Vec<OxcDiagnostic> {
+        match node {
+            AstKind::Program(program) => self.check_program(program, file_path),
+            _ => Vec::new(),
+        }
+    }
+}
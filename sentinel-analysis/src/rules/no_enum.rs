@@ -0,0 +1,115 @@
+use oxc_ast::AstKind;
+use oxc_ast::ast::TSEnumDeclaration;
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_span::Span;
+use serde_json::{Value, json};
+
+use crate::rules::Rule;
+
+/// Rule that flags TypeScript enums
+///
+/// Enums are one of the few TypeScript features that emit runtime code, and numeric
+/// enums accept any number. Union types of literals, or an object literal declared
+/// `as const`, cover the same use cases with plain JavaScript semantics.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code:
+///
+/// ```typescript
+/// enum Direction { Up, Down }
+/// ```
+///
+/// Examples of **correct** code:
+///
+/// ```typescript
+/// type Direction = 'up' | 'down';
+/// const Direction = { Up: 'up', Down: 'down' } as const;
+/// ```
+///
+/// ## Rule Options
+///
+/// - `allowConst`: Allow `const enum`, which is inlined at compile time (default: false)
+pub struct NoEnumRule {
+    /// Allow `const enum` declarations
+    allow_const: bool,
+}
+
+impl Default for NoEnumRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NoEnumRule {
+    pub fn new() -> Self {
+        Self { allow_const: false }
+    }
+
+    fn check_enum(&self, declaration: &TSEnumDeclaration) -> Vec<OxcDiagnostic> {
+        if declaration.r#const && self.allow_const {
+            return Vec::new();
+        }
+
+        let kind = if declaration.r#const {
+            "const enum"
+        } else {
+            "enum"
+        };
+        vec![
+            OxcDiagnostic::warn(format!(
+                "Unexpected {} `{}`",
+                kind, declaration.id.name
+            ))
+            .with_help(format!(
+                "Use a union type (`type {0} = 'a' | 'b'`) or an object literal declared `as const` instead",
+                declaration.id.name
+            ))
+            .with_label(declaration.id.span.label("Enum declared here")),
+        ]
+    }
+}
+
+impl Rule for NoEnumRule {
+    fn name(&self) -> &'static str {
+        "no-enum"
+    }
+
+    fn description(&self) -> &'static str {
+        "Disallow TypeScript enums in favor of union types"
+    }
+
+    fn applies_to_source(&self, source: &str) -> bool {
+        source.contains("enum")
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["typescript"]
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn config_schema(&self) -> Option<Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "allowConst": { "type": "boolean", "default": false }
+            }
+        }))
+    }
+
+    fn set_config(&mut self, config: Value) {
+        if let Some(allow) = config.get("allowConst").and_then(Value::as_bool) {
+            self.allow_const = allow;
+        }
+    }
+
+    fn run_on_node(&self, node: &AstKind, _span: Span, _file_path: &str) -> Vec<OxcDiagnostic> {
+        match node {
+            AstKind::TSEnumDeclaration(declaration) => self.check_enum(declaration),
+            _ => Vec::new(),
+        }
+    }
+}
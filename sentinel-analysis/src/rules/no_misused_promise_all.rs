@@ -0,0 +1,124 @@
+use oxc_ast::AstKind;
+use oxc_ast::ast::{Argument, CallExpression, Expression};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{GetSpan, Span};
+
+use crate::rules::Rule;
+
+/// Rule that flags array iterations whose async callbacks are never awaited
+///
+/// `forEach` ignores the return value of its callback, so with an `async` callback the
+/// promises are dropped: the iteration returns before any of them settles and their
+/// rejections go unhandled. `map` with an `async` callback returns an array of promises,
+/// which is just as lost when the call is a statement on its own. Both are reported;
+/// the fix is to await `Promise.all` over the mapped promises, or to use a `for...of`
+/// loop with `await` to run them one after the other.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code:
+///
+/// ```typescript
+/// async saveAll(users: User[]) {
+///   users.forEach(async (user) => {
+///     await this.api.save(user);
+///   });
+/// }
+/// ```
+///
+/// Examples of **correct** code:
+///
+/// ```typescript
+/// async saveAll(users: User[]) {
+///   await Promise.all(users.map(async (user) => {
+///     await this.api.save(user);
+///   }));
+/// }
+/// ```
+pub struct NoMisusedPromiseAllRule;
+
+impl Default for NoMisusedPromiseAllRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NoMisusedPromiseAllRule {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Whether the first argument of a call is an `async` arrow function or function
+    fn has_async_callback(call: &CallExpression) -> bool {
+        match call.arguments.first() {
+            Some(Argument::ArrowFunctionExpression(arrow)) => arrow.r#async,
+            Some(Argument::FunctionExpression(function)) => function.r#async,
+            _ => false,
+        }
+    }
+
+    /// Name of the called method, for calls such as `items.forEach(...)`
+    fn method_name<'a>(call: &'a CallExpression) -> Option<&'a str> {
+        match call.callee.get_inner_expression() {
+            Expression::StaticMemberExpression(member) => Some(member.property.name.as_str()),
+            _ => None,
+        }
+    }
+
+    fn diagnostic(call: &CallExpression, method: &str) -> OxcDiagnostic {
+        let message = if method == "forEach" {
+            "`forEach` does not wait for the promises of its async callback".to_string()
+        } else {
+            format!(
+                "Promises returned by the async `{}` callback are never awaited",
+                method
+            )
+        };
+        OxcDiagnostic::error(message)
+            .with_help("Use `await Promise.all(items.map(async (item) => ...))`, or a `for...of` loop with `await` to run them in sequence")
+            .with_label(call.span().label("Promises of this iteration are dropped"))
+    }
+}
+
+impl Rule for NoMisusedPromiseAllRule {
+    fn name(&self) -> &'static str {
+        "no-misused-promise-all"
+    }
+
+    fn description(&self) -> &'static str {
+        "Disallows async callbacks in forEach and discarded map calls, whose promises are never awaited"
+    }
+
+    fn applies_to_source(&self, source: &str) -> bool {
+        source.contains("async")
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["correctness"]
+    }
+
+    fn run_on_node(&self, node: &AstKind, _span: Span, _file_path: &str) -> Vec<OxcDiagnostic> {
+        match node {
+            // items.forEach(async (item) => ...), wherever it appears
+            AstKind::CallExpression(call)
+                if Self::method_name(call) == Some("forEach") && Self::has_async_callback(call) =>
+            {
+                vec![Self::diagnostic(call, "forEach")]
+            }
+            // items.map(async (item) => ...); with the array of promises thrown away
+            AstKind::ExpressionStatement(statement) => {
+                let Expression::CallExpression(call) = statement.expression.without_parentheses()
+                else {
+                    return Vec::new();
+                };
+                match Self::method_name(call) {
+                    Some("map") if Self::has_async_callback(call) => {
+                        vec![Self::diagnostic(call, "map")]
+                    }
+                    _ => Vec::new(),
+                }
+            }
+            _ => Vec::new(),
+        }
+    }
+}
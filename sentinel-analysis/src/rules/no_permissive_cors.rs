@@ -0,0 +1,161 @@
+use globset::GlobSet;
+use oxc_ast::AstKind;
+use oxc_ast::ast::{Expression, ObjectExpression, ObjectProperty, ObjectPropertyKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{GetSpan, Span};
+use serde_json::{Value, json};
+
+use crate::rules::{Rule, build_glob_set, matches_file_glob, prop_key_name};
+
+/// Rule that flags CORS options allowing requests from any origin
+///
+/// Checks object literals such as the options of `app.enableCors()` in NestJS or of the
+/// `cors` middleware. An `origin` of `'*'` lets any site read the responses, and
+/// `origin: true` reflects the origin of every request, which has the same effect.
+/// Combined with `credentials: true`, any site can make authenticated requests on
+/// behalf of a logged-in user.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code:
+///
+/// ```typescript
+/// app.enableCors({ origin: '*' });
+/// app.use(cors({ origin: true, credentials: true }));
+/// ```
+///
+/// Examples of **correct** code:
+///
+/// ```typescript
+/// app.enableCors({ origin: ['https://app.example.com'], credentials: true });
+/// ```
+///
+/// ## Rule Options
+///
+/// - `allowedFiles`: Globs of development-only files allowed to use wildcard origins
+///   (default: none). `*` does not cross directory boundaries; use `**` for that.
+pub struct NoPermissiveCorsRule {
+    /// Files allowed to use wildcard origins
+    allowed_files: GlobSet,
+}
+
+impl Default for NoPermissiveCorsRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NoPermissiveCorsRule {
+    pub fn new() -> Self {
+        Self {
+            allowed_files: GlobSet::empty(),
+        }
+    }
+
+    /// Property of an object literal with the given static key
+    fn find_property<'o, 'a>(
+        object: &'o ObjectExpression<'a>,
+        name: &str,
+    ) -> Option<&'o ObjectProperty<'a>> {
+        object
+            .properties
+            .iter()
+            .find_map(|property| match property {
+                ObjectPropertyKind::ObjectProperty(property)
+                    if !property.computed && prop_key_name(&property.key) == Some(name) =>
+                {
+                    Some(&**property)
+                }
+                _ => None,
+            })
+    }
+
+    /// Check whether an `origin` value allows every origin
+    fn is_wildcard_origin(value: &Expression) -> bool {
+        match value {
+            Expression::StringLiteral(literal) => literal.value == "*",
+            Expression::TemplateLiteral(template) => {
+                template.expressions.is_empty()
+                    && template
+                        .quasis
+                        .iter()
+                        .all(|quasi| quasi.value.raw.as_str() == "*")
+            }
+            Expression::BooleanLiteral(literal) => literal.value,
+            _ => false,
+        }
+    }
+
+    fn check_object(object: &ObjectExpression) -> Option<OxcDiagnostic> {
+        let origin = Self::find_property(object, "origin")?;
+        if !Self::is_wildcard_origin(&origin.value) {
+            return None;
+        }
+        let origin_label = origin.value.span().primary_label("Allows every origin");
+
+        let credentials = Self::find_property(object, "credentials").filter(|property| {
+            matches!(&property.value, Expression::BooleanLiteral(literal) if literal.value)
+        });
+        let Some(credentials) = credentials else {
+            return Some(
+                OxcDiagnostic::error("CORS allows requests from any origin")
+                    .with_help("List the trusted origins explicitly, or allow wildcards in development-only files with `allowedFiles`")
+                    .with_label(origin_label),
+            );
+        };
+
+        Some(
+            OxcDiagnostic::error("CORS allows credentialed requests from any origin")
+                .with_help("Any site could make authenticated requests for a logged-in user; list the trusted origins explicitly")
+                .with_labels([
+                    origin_label,
+                    credentials.span.label("Credentials are sent along"),
+                ]),
+        )
+    }
+}
+
+impl Rule for NoPermissiveCorsRule {
+    fn name(&self) -> &'static str {
+        "no-permissive-cors"
+    }
+
+    fn description(&self) -> &'static str {
+        "Disallows CORS options that allow requests from any origin"
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["security"]
+    }
+
+    fn config_schema(&self) -> Option<Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "allowedFiles": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "default": []
+                }
+            }
+        }))
+    }
+
+    fn set_config(&mut self, config: Value) {
+        if let Some(patterns) = config.get("allowedFiles").and_then(Value::as_array) {
+            let patterns: Vec<&str> = patterns.iter().filter_map(Value::as_str).collect();
+            self.allowed_files = build_glob_set(&patterns);
+        }
+    }
+
+    fn run_on_node(&self, node: &AstKind, _span: Span, file_path: &str) -> Vec<OxcDiagnostic> {
+        match node {
+            AstKind::ObjectExpression(object)
+                if !matches_file_glob(&self.allowed_files, file_path) =>
+            {
+                Self::check_object(object).into_iter().collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,320 @@
+use std::collections::HashSet;
+
+use oxc_ast::AstKind;
+use oxc_ast::ast::{
+    ArrowFunctionExpression, AwaitExpression, BindingPatternKind, Class, Expression,
+    ForOfStatement, Function, MethodDefinition, ObjectProperty, PropertyDefinition,
+    VariableDeclarator,
+};
+use oxc_ast_visit::{Visit, walk};
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_semantic::{ScopeFlags, SemanticBuilderReturn};
+use oxc_span::{GetSpan, Span};
+use serde_json::{Value, json};
+
+use crate::rules::Rule;
+
+/// Rule that flags async functions that never `await`
+///
+/// Like ESLint's `require-await`: an `async` function whose body contains no `await`
+/// and no `for await` loop only wraps its result in a promise. The keyword then
+/// suggests asynchronous work that does not happen, and turns synchronous exceptions
+/// into rejections. Awaits in nested functions do not count for the enclosing one.
+/// Async generators and functions without a body (overloads, abstract methods) are not
+/// reported. The `async` keyword is labeled.
+///
+/// Some functions have to be async to satisfy an interface or a base class, such as a
+/// guard returning `Promise<boolean>`. List their names in `ignoreNames`, or enable
+/// `ignoreImplementations` to skip the methods of classes with an `implements` clause
+/// and methods marked `override`.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code:
+///
+/// ```typescript
+/// async function getDefaults() {
+///   return { theme: 'dark' };
+/// }
+/// ```
+///
+/// Examples of **correct** code:
+///
+/// ```typescript
+/// function getDefaults() {
+///   return { theme: 'dark' };
+/// }
+///
+/// async function getSettings() {
+///   return await this.api.loadSettings();
+/// }
+/// ```
+///
+/// ## Rule Options
+///
+/// - `ignoreNames`: Names of functions, methods and function-valued variables or
+///   properties that may be async without awaiting (default: `[]`)
+/// - `ignoreImplementations`: Skip methods of classes implementing an interface and
+///   methods marked `override` (default: false)
+pub struct NoRedundantAsyncRule {
+    /// Names of functions that may be async without awaiting
+    ignore_names: HashSet<String>,
+    /// Skip methods of classes with an `implements` clause and `override` methods
+    ignore_implementations: bool,
+}
+
+impl Default for NoRedundantAsyncRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NoRedundantAsyncRule {
+    pub fn new() -> Self {
+        Self {
+            ignore_names: HashSet::new(),
+            ignore_implementations: false,
+        }
+    }
+
+    fn create_diagnostic(async_keyword: Span) -> OxcDiagnostic {
+        OxcDiagnostic::warn("Async function never awaits")
+            .with_help(
+                "Remove `async`, or suppress the rule if the function has to return a promise",
+            )
+            .with_label(async_keyword.label("Unnecessary `async`"))
+    }
+}
+
+/// Visitor tracking, per function scope, whether an `await` was seen
+struct RedundantAsyncVisitor<'r> {
+    rule: &'r NoRedundantAsyncRule,
+    source: &'r str,
+    /// Whether the current function awaits
+    has_await: bool,
+    /// Whether the class being visited implements an interface
+    in_implementing_class: bool,
+    /// Start offsets of the functions that are never reported
+    ignored: HashSet<u32>,
+    /// Start of a method's function, and the modifiers before its key holding `async`
+    method_start: Option<(u32, Span)>,
+    diagnostics: Vec<OxcDiagnostic>,
+}
+
+impl RedundantAsyncVisitor<'_> {
+    /// Ignore the function starting at `function_start` if its name is in `ignoreNames`
+    fn ignore_named(&mut self, name: Option<&str>, function_start: u32) {
+        if name.is_some_and(|name| self.rule.ignore_names.contains(name)) {
+            self.ignored.insert(function_start);
+        }
+    }
+
+    /// Span of the `async` keyword in a function's text, the last one for modifiers
+    ///
+    /// The last match skips decorators such as `@Async()` before a method's modifiers.
+    fn async_keyword(&self, within: Span, last: bool) -> Span {
+        let text = within.source_text(self.source);
+        let offset = if last {
+            text.rfind("async")
+        } else {
+            text.find("async")
+        };
+        match offset {
+            Some(offset) => {
+                let start = within.start + offset as u32;
+                Span::new(start, start + "async".len() as u32)
+            }
+            None => within,
+        }
+    }
+
+    /// Visit a function body on its own, reporting it if it is async and never awaits
+    fn check_function(
+        &mut self,
+        reported: bool,
+        span: Span,
+        params_start: u32,
+        visit: impl FnOnce(&mut Self),
+    ) {
+        let outer_await = std::mem::replace(&mut self.has_await, false);
+        let method_start = self.method_start.take();
+
+        visit(self);
+
+        if reported && !self.has_await && !self.ignored.contains(&span.start) {
+            let keyword = match method_start {
+                Some((function_start, modifiers)) if function_start == span.start => {
+                    self.async_keyword(modifiers, true)
+                }
+                _ => self.async_keyword(Span::new(span.start, params_start), false),
+            };
+            self.diagnostics
+                .push(NoRedundantAsyncRule::create_diagnostic(keyword));
+        }
+
+        self.has_await = outer_await;
+    }
+}
+
+impl<'a> Visit<'a> for RedundantAsyncVisitor<'_> {
+    fn visit_await_expression(&mut self, it: &AwaitExpression<'a>) {
+        self.has_await = true;
+        walk::walk_await_expression(self, it);
+    }
+
+    fn visit_for_of_statement(&mut self, it: &ForOfStatement<'a>) {
+        if it.r#await {
+            self.has_await = true;
+        }
+        walk::walk_for_of_statement(self, it);
+    }
+
+    fn visit_class(&mut self, it: &Class<'a>) {
+        let outer = self.in_implementing_class;
+        self.in_implementing_class = it.implements.as_ref().is_some_and(|i| !i.is_empty());
+        walk::walk_class(self, it);
+        self.in_implementing_class = outer;
+    }
+
+    fn visit_method_definition(&mut self, it: &MethodDefinition<'a>) {
+        let function_start = it.value.span.start;
+        if self.rule.ignore_implementations && (self.in_implementing_class || it.r#override) {
+            self.ignored.insert(function_start);
+        }
+        self.ignore_named(it.key.static_name().as_deref(), function_start);
+        self.method_start = Some((
+            function_start,
+            Span::new(it.span.start, it.key.span().start),
+        ));
+        walk::walk_method_definition(self, it);
+    }
+
+    fn visit_object_property(&mut self, it: &ObjectProperty<'a>) {
+        let function_start = it.value.span().start;
+        self.ignore_named(it.key.static_name().as_deref(), function_start);
+        if it.method {
+            self.method_start = Some((
+                function_start,
+                Span::new(it.span.start, it.key.span().start),
+            ));
+        }
+        walk::walk_object_property(self, it);
+    }
+
+    fn visit_property_definition(&mut self, it: &PropertyDefinition<'a>) {
+        if let Some(value) = &it.value {
+            let function_start = value.without_parentheses().span().start;
+            if self.rule.ignore_implementations && (self.in_implementing_class || it.r#override) {
+                self.ignored.insert(function_start);
+            }
+            self.ignore_named(it.key.static_name().as_deref(), function_start);
+        }
+        walk::walk_property_definition(self, it);
+    }
+
+    fn visit_variable_declarator(&mut self, it: &VariableDeclarator<'a>) {
+        if let (BindingPatternKind::BindingIdentifier(id), Some(init)) = (&it.id.kind, &it.init)
+            && matches!(
+                init.without_parentheses(),
+                Expression::FunctionExpression(_) | Expression::ArrowFunctionExpression(_)
+            )
+        {
+            self.ignore_named(
+                Some(id.name.as_str()),
+                init.without_parentheses().span().start,
+            );
+        }
+        walk::walk_variable_declarator(self, it);
+    }
+
+    fn visit_function(&mut self, it: &Function<'a>, flags: ScopeFlags) {
+        let function_start = it.span.start;
+        self.ignore_named(it.id.as_ref().map(|id| id.name.as_str()), function_start);
+        let reported = it.r#async && !it.generator && it.body.is_some();
+        self.check_function(reported, it.span, it.params.span.start, |visitor| {
+            walk::walk_function(visitor, it, flags);
+        });
+    }
+
+    fn visit_arrow_function_expression(&mut self, it: &ArrowFunctionExpression<'a>) {
+        self.check_function(it.r#async, it.span, it.params.span.start, |visitor| {
+            walk::walk_arrow_function_expression(visitor, it);
+        });
+    }
+}
+
+impl Rule for NoRedundantAsyncRule {
+    fn name(&self) -> &'static str {
+        "no-redundant-async"
+    }
+
+    fn description(&self) -> &'static str {
+        "Disallows async functions that never await"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["correctness"]
+    }
+
+    fn applies_to_source(&self, source: &str) -> bool {
+        source.contains("async")
+    }
+
+    fn config_schema(&self) -> Option<Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "ignoreNames": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "default": []
+                },
+                "ignoreImplementations": { "type": "boolean", "default": false }
+            }
+        }))
+    }
+
+    fn set_config(&mut self, config: Value) {
+        if let Some(names) = config.get("ignoreNames").and_then(Value::as_array) {
+            self.ignore_names = names
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect();
+        }
+        if let Some(ignore) = config.get("ignoreImplementations").and_then(Value::as_bool) {
+            self.ignore_implementations = ignore;
+        }
+    }
+
+    fn run_on_semantic(
+        &self,
+        semantic_result: &SemanticBuilderReturn,
+        _file_path: &str,
+    ) -> Vec<OxcDiagnostic> {
+        let Some(AstKind::Program(program)) = semantic_result
+            .semantic
+            .nodes()
+            .root_node()
+            .map(|node| node.kind())
+        else {
+            return Vec::new();
+        };
+
+        let mut visitor = RedundantAsyncVisitor {
+            rule: self,
+            source: semantic_result.semantic.source_text(),
+            has_await: false,
+            in_implementing_class: false,
+            ignored: HashSet::new(),
+            method_start: None,
+            diagnostics: Vec::new(),
+        };
+        visitor.visit_program(program);
+        visitor.diagnostics
+    }
+}
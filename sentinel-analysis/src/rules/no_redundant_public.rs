@@ -0,0 +1,245 @@
+use oxc_ast::AstKind;
+use oxc_ast::ast::{
+    AccessorProperty, Decorator, FormalParameter, MethodDefinition, PropertyDefinition,
+    TSAccessibility,
+};
+use oxc_ast_visit::{Visit, walk};
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_semantic::SemanticBuilderReturn;
+use oxc_span::{GetSpan, Span};
+use serde_json::{Value, json};
+
+use crate::rules::{Rule, RuleFix};
+
+/// Message of findings on constructor parameter properties
+const PARAMETER_PROPERTY_MESSAGE: &str = "Parameter property declared `public`";
+
+/// Modifiers that keep a parameter a parameter property once `public` is removed
+const PARAMETER_PROPERTY_MODIFIERS: [&str; 2] = ["readonly", "override"];
+
+/// Rule that flags explicit `public` modifiers on class members
+///
+/// Class members in TypeScript are public unless marked otherwise, so `public` only
+/// adds noise. Methods, properties and accessors are reported.
+///
+/// On a constructor parameter, `public` is what turns the parameter into a property,
+/// so parameter properties are allowed by default. With `allowParameterProperties`
+/// disabled they are reported as well, but the fix only removes `public` when a
+/// `readonly` or `override` modifier keeps the parameter a property.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code:
+///
+/// ```typescript
+/// class UserService {
+///   public users: User[] = [];
+///   public load() {}
+/// }
+/// ```
+///
+/// Examples of **correct** code:
+///
+/// ```typescript
+/// class UserService {
+///   users: User[] = [];
+///   constructor(public http: HttpClient) {}
+///   load() {}
+/// }
+/// ```
+///
+/// ## Rule Options
+///
+/// - `allowParameterProperties`: Allow `public` on constructor parameter properties
+///   (default: true)
+pub struct NoRedundantPublicRule {
+    /// Allow `constructor(public x)`
+    allow_parameter_properties: bool,
+}
+
+impl Default for NoRedundantPublicRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NoRedundantPublicRule {
+    pub fn new() -> Self {
+        Self {
+            allow_parameter_properties: true,
+        }
+    }
+}
+
+/// Visitor reporting `public` modifiers, found in the source between decorators and name
+struct PublicModifierVisitor<'s> {
+    source: &'s str,
+    allow_parameter_properties: bool,
+    diagnostics: Vec<OxcDiagnostic>,
+}
+
+impl PublicModifierVisitor<'_> {
+    /// Span of the `public` keyword among the modifiers before `name_start`
+    fn keyword_span(&self, span: Span, decorators: &[Decorator], name_start: u32) -> Option<Span> {
+        let start = decorators
+            .last()
+            .map_or(span.start, |decorator| decorator.span.end);
+        let modifiers = self.source.get(start as usize..name_start as usize)?;
+
+        let offset = modifiers.match_indices("public").find_map(|(offset, _)| {
+            let before = modifiers[..offset].chars().next_back();
+            let after = modifiers[offset + "public".len()..].chars().next();
+            let is_word = !before.is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '$')
+                && after.is_some_and(char::is_whitespace);
+            is_word.then_some(offset)
+        })?;
+        let keyword_start = start + offset as u32;
+        Some(Span::new(
+            keyword_start,
+            keyword_start + "public".len() as u32,
+        ))
+    }
+
+    fn check_member(
+        &mut self,
+        accessibility: Option<TSAccessibility>,
+        span: Span,
+        decorators: &[Decorator],
+        name_start: u32,
+    ) {
+        if accessibility != Some(TSAccessibility::Public) {
+            return;
+        }
+        if let Some(keyword) = self.keyword_span(span, decorators, name_start) {
+            self.diagnostics.push(
+                OxcDiagnostic::warn("Redundant `public` modifier")
+                    .with_help("Remove `public`: class members are public by default")
+                    .with_label(keyword.label("Members are public by default")),
+            );
+        }
+    }
+}
+
+impl<'a> Visit<'a> for PublicModifierVisitor<'_> {
+    fn visit_method_definition(&mut self, it: &MethodDefinition<'a>) {
+        self.check_member(
+            it.accessibility,
+            it.span,
+            &it.decorators,
+            it.key.span().start,
+        );
+        walk::walk_method_definition(self, it);
+    }
+
+    fn visit_property_definition(&mut self, it: &PropertyDefinition<'a>) {
+        self.check_member(
+            it.accessibility,
+            it.span,
+            &it.decorators,
+            it.key.span().start,
+        );
+        walk::walk_property_definition(self, it);
+    }
+
+    fn visit_accessor_property(&mut self, it: &AccessorProperty<'a>) {
+        self.check_member(
+            it.accessibility,
+            it.span,
+            &it.decorators,
+            it.key.span().start,
+        );
+        walk::walk_accessor_property(self, it);
+    }
+
+    fn visit_formal_parameter(&mut self, it: &FormalParameter<'a>) {
+        if it.accessibility == Some(TSAccessibility::Public) && !self.allow_parameter_properties {
+            let name_start = it.pattern.kind.span().start;
+            if let Some(keyword) = self.keyword_span(it.span, &it.decorators, name_start) {
+                self.diagnostics.push(
+                    OxcDiagnostic::warn(PARAMETER_PROPERTY_MESSAGE)
+                        .with_help("Declare the property in the class body, or keep it `readonly` without `public`")
+                        .with_label(keyword.label("Parameter property modifier")),
+                );
+            }
+        }
+        walk::walk_formal_parameter(self, it);
+    }
+}
+
+impl Rule for NoRedundantPublicRule {
+    fn name(&self) -> &'static str {
+        "no-redundant-public"
+    }
+
+    fn description(&self) -> &'static str {
+        "Disallows the redundant `public` modifier on class members"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["style"]
+    }
+
+    fn config_schema(&self) -> Option<Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "allowParameterProperties": { "type": "boolean", "default": true }
+            }
+        }))
+    }
+
+    fn set_config(&mut self, config: Value) {
+        if let Some(allow) = config
+            .get("allowParameterProperties")
+            .and_then(Value::as_bool)
+        {
+            self.allow_parameter_properties = allow;
+        }
+    }
+
+    fn run_on_semantic(
+        &self,
+        semantic_result: &SemanticBuilderReturn,
+        _file_path: &str,
+    ) -> Vec<OxcDiagnostic> {
+        let semantic = &semantic_result.semantic;
+        let Some(AstKind::Program(program)) = semantic.nodes().root_node().map(|node| node.kind())
+        else {
+            return Vec::new();
+        };
+
+        let mut visitor = PublicModifierVisitor {
+            source: semantic.source_text(),
+            allow_parameter_properties: self.allow_parameter_properties,
+            diagnostics: Vec::new(),
+        };
+        visitor.visit_program(program);
+        visitor.diagnostics
+    }
+
+    fn fix(&self, diagnostic: &OxcDiagnostic, source: &str) -> Option<RuleFix> {
+        let label = diagnostic.labels.as_ref()?.first()?;
+        let start = label.offset();
+        let rest = source.get(start + label.len()..)?;
+        let following = rest.trim_start_matches([' ', '\t']);
+
+        // Without another modifier, the parameter would stop being a property
+        if diagnostic.message == PARAMETER_PROPERTY_MESSAGE
+            && !PARAMETER_PROPERTY_MODIFIERS
+                .iter()
+                .any(|modifier| following.starts_with(&format!("{} ", modifier)))
+        {
+            return None;
+        }
+
+        let end = source.len() - following.len();
+        Some(RuleFix {
+            span: Span::new(u32::try_from(start).ok()?, u32::try_from(end).ok()?),
+            replacement: String::new(),
+        })
+    }
+}
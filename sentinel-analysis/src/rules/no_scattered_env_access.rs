@@ -0,0 +1,116 @@
+use globset::GlobSet;
+use oxc_ast::AstKind;
+use oxc_ast::ast::{Expression, MemberExpression};
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_span::Span;
+use serde_json::{Value, json};
+
+use crate::rules::{Rule, build_glob_set, matches_file_glob};
+
+/// Default location of modules allowed to read the environment
+const DEFAULT_ALLOWED_FILES: &str = "**/config/*.ts";
+
+/// Rule that flags `process.env` reads outside of central configuration modules
+///
+/// Reading environment variables all over the codebase hides the configuration surface
+/// of an application. Every `process.env` access (`process.env.X`, `process.env['X']`,
+/// `const { X } = process.env`) is reported unless the file matches one of the
+/// `allowedFiles` globs.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code (in `src/app/user.component.ts`):
+///
+/// ```typescript
+/// const apiUrl = process.env.API_URL;
+/// ```
+///
+/// Examples of **correct** code (in `src/config/env.ts`):
+///
+/// ```typescript
+/// export const apiUrl = process.env.API_URL;
+/// ```
+///
+/// ## Rule Options
+///
+/// - `allowedFiles`: Globs of files allowed to read `process.env` (default: `["**/config/*.ts"]`).
+///   `*` does not cross directory boundaries; use `**` for that.
+pub struct NoScatteredEnvAccessRule {
+    /// Files allowed to access `process.env`
+    allowed_files: GlobSet,
+}
+
+impl Default for NoScatteredEnvAccessRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NoScatteredEnvAccessRule {
+    pub fn new() -> Self {
+        Self {
+            allowed_files: build_glob_set(&[DEFAULT_ALLOWED_FILES]),
+        }
+    }
+
+    fn is_allowed_file(&self, file_path: &str) -> bool {
+        matches_file_glob(&self.allowed_files, file_path)
+    }
+}
+
+impl Rule for NoScatteredEnvAccessRule {
+    fn name(&self) -> &'static str {
+        "no-scattered-env-access"
+    }
+
+    fn description(&self) -> &'static str {
+        "Disallow process.env access outside of central configuration modules"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["maintainability"]
+    }
+
+    fn config_schema(&self) -> Option<Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "allowedFiles": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "default": [DEFAULT_ALLOWED_FILES]
+                }
+            }
+        }))
+    }
+
+    fn set_config(&mut self, config: Value) {
+        if let Some(patterns) = config.get("allowedFiles").and_then(Value::as_array) {
+            let patterns: Vec<&str> = patterns.iter().filter_map(Value::as_str).collect();
+            self.allowed_files = build_glob_set(&patterns);
+        }
+    }
+
+    fn run_on_node(&self, node: &AstKind, span: Span, file_path: &str) -> Vec<OxcDiagnostic> {
+        let AstKind::MemberExpression(MemberExpression::StaticMemberExpression(member)) = node
+        else {
+            return Vec::new();
+        };
+
+        let is_process_env = member.property.name == "env"
+            && matches!(&member.object, Expression::Identifier(object) if object.name == "process");
+        if !is_process_env || self.is_allowed_file(file_path) {
+            return Vec::new();
+        }
+
+        vec![
+            OxcDiagnostic::warn("process.env accessed outside of a configuration module")
+                .with_help("Read environment variables in a central config module and import the values from there")
+                .with_label(span.label("process.env access")),
+        ]
+    }
+}
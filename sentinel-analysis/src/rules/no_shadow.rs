@@ -0,0 +1,200 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_semantic::{Semantic, SemanticBuilderReturn, SymbolFlags, SymbolId};
+use serde_json::{Value, json};
+
+use crate::rules::Rule;
+
+/// Built-in globals that are reported when a declaration reuses their name
+const BUILTIN_GLOBALS: &[&str] = &[
+    "Array",
+    "ArrayBuffer",
+    "BigInt",
+    "Boolean",
+    "DataView",
+    "Date",
+    "Error",
+    "Function",
+    "Infinity",
+    "JSON",
+    "Map",
+    "Math",
+    "NaN",
+    "Number",
+    "Object",
+    "Promise",
+    "Proxy",
+    "Reflect",
+    "RegExp",
+    "Set",
+    "String",
+    "Symbol",
+    "WeakMap",
+    "WeakSet",
+    "console",
+    "globalThis",
+    "isFinite",
+    "isNaN",
+    "parseFloat",
+    "parseInt",
+    "undefined",
+];
+
+/// Rule that disallows declarations shadowing a binding of an enclosing scope
+///
+/// Uses the scope and symbol tables of the semantic model: for every value binding
+/// (variables, parameters, functions, classes, catch parameters) the enclosing scopes
+/// are searched for a binding with the same name. The inner declaration is reported,
+/// with a second label pointing at the outer declaration.
+///
+/// The names of function and class expressions are not reported, since they are only
+/// visible inside the expression itself (`const foo = function foo() {}`).
+///
+/// ## Rule Options
+///
+/// - `ignoreParameters`: Do not report function parameters (default: false)
+/// - `ignoreBuiltinGlobals`: Do not report declarations named like a built-in global
+///   such as `Promise` or `Map` (default: false)
+pub struct NoShadowRule {
+    /// Skip shadowing function parameters
+    ignore_parameters: bool,
+    /// Skip declarations shadowing built-in globals
+    ignore_builtin_globals: bool,
+}
+
+impl Default for NoShadowRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NoShadowRule {
+    pub fn new() -> Self {
+        Self {
+            ignore_parameters: false,
+            ignore_builtin_globals: false,
+        }
+    }
+
+    /// Symbol kinds that introduce a value binding
+    fn value_flags() -> SymbolFlags {
+        SymbolFlags::Variable
+            | SymbolFlags::CatchVariable
+            | SymbolFlags::Function
+            | SymbolFlags::Class
+    }
+
+    /// Check whether the symbol is the name of a function or class expression
+    fn is_expression_name(semantic: &Semantic, symbol_id: SymbolId) -> bool {
+        let node_id = semantic.scoping().symbol_declaration(symbol_id);
+        match semantic.nodes().kind(node_id) {
+            AstKind::Function(function) => function.is_expression(),
+            AstKind::Class(class) => class.is_expression(),
+            _ => false,
+        }
+    }
+
+    /// Check whether the symbol is declared by a function parameter
+    fn is_parameter(semantic: &Semantic, symbol_id: SymbolId) -> bool {
+        let node_id = semantic.scoping().symbol_declaration(symbol_id);
+        matches!(semantic.nodes().kind(node_id), AstKind::FormalParameter(_))
+    }
+
+    /// Find the closest binding with the same name in an enclosing scope
+    fn find_outer_binding(semantic: &Semantic, symbol_id: SymbolId) -> Option<SymbolId> {
+        let scoping = semantic.scoping();
+        let name = scoping.symbol_name(symbol_id);
+        let scope_id = scoping.symbol_scope_id(symbol_id);
+
+        scoping
+            .scope_ancestors(scope_id)
+            .skip(1)
+            .filter_map(|ancestor| scoping.get_binding(ancestor, name))
+            .find(|outer| {
+                scoping
+                    .symbol_flags(*outer)
+                    .intersects(Self::value_flags() | SymbolFlags::Import)
+            })
+    }
+}
+
+impl Rule for NoShadowRule {
+    fn name(&self) -> &'static str {
+        "no-shadow"
+    }
+
+    fn description(&self) -> &'static str {
+        "Disallow variable declarations from shadowing variables declared in an outer scope"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn config_schema(&self) -> Option<Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "ignoreParameters": { "type": "boolean", "default": false },
+                "ignoreBuiltinGlobals": { "type": "boolean", "default": false }
+            }
+        }))
+    }
+
+    fn set_config(&mut self, config: Value) {
+        if let Some(ignore) = config.get("ignoreParameters").and_then(Value::as_bool) {
+            self.ignore_parameters = ignore;
+        }
+        if let Some(ignore) = config.get("ignoreBuiltinGlobals").and_then(Value::as_bool) {
+            self.ignore_builtin_globals = ignore;
+        }
+    }
+
+    fn run_on_semantic(
+        &self,
+        semantic_result: &SemanticBuilderReturn,
+        _file_path: &str,
+    ) -> Vec<OxcDiagnostic> {
+        let semantic = &semantic_result.semantic;
+        let scoping = semantic.scoping();
+        let mut diagnostics = Vec::new();
+
+        for symbol_id in scoping.symbol_ids() {
+            if !scoping
+                .symbol_flags(symbol_id)
+                .intersects(Self::value_flags())
+                || Self::is_expression_name(semantic, symbol_id)
+                || (self.ignore_parameters && Self::is_parameter(semantic, symbol_id))
+            {
+                continue;
+            }
+
+            let name = scoping.symbol_name(symbol_id);
+            let span = scoping.symbol_span(symbol_id);
+
+            if let Some(outer_id) = Self::find_outer_binding(semantic, symbol_id) {
+                diagnostics.push(
+                    OxcDiagnostic::warn(format!(
+                        "`{}` is already declared in an outer scope",
+                        name
+                    ))
+                    .with_help("Rename the inner declaration to avoid confusion")
+                    .with_labels([
+                        span.label(format!("`{}` shadows an outer declaration", name)),
+                        scoping
+                            .symbol_span(outer_id)
+                            .label(format!("`{}` is first declared here", name)),
+                    ]),
+                );
+            } else if !self.ignore_builtin_globals && BUILTIN_GLOBALS.contains(&name) {
+                diagnostics.push(
+                    OxcDiagnostic::warn(format!("`{}` shadows a built-in global", name))
+                        .with_help("Rename the declaration to keep the global accessible")
+                        .with_label(span.label(format!("`{}` shadows the global", name))),
+                );
+            }
+        }
+
+        diagnostics
+    }
+}
@@ -0,0 +1,171 @@
+use oxc_ast::Comment;
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_semantic::SemanticBuilderReturn;
+use serde_json::{Value, json};
+
+use crate::rules::Rule;
+
+/// Directives reported by default, without their `@` prefix
+const DEFAULT_DIRECTIVES: [&str; 3] = ["ts-ignore", "ts-nocheck", "ts-expect-error"];
+
+/// Rule that flags comments suppressing TypeScript errors
+///
+/// `// @ts-ignore` and `// @ts-expect-error` hide the error on the next line,
+/// `// @ts-nocheck` switches off type checking for the whole file. The suppressed error
+/// usually points at a real problem that is better fixed at its source.
+///
+/// With `requireDescription`, `@ts-expect-error` directives followed by an explanation
+/// (`// @ts-expect-error: the typings of lib X are wrong`) are allowed and only
+/// undocumented ones are reported. `@ts-ignore` and `@ts-nocheck` stay reported either
+/// way: unlike `@ts-expect-error`, they keep suppressing errors once the cause is fixed.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code:
+///
+/// ```typescript
+/// // @ts-ignore
+/// const user: User = loadUser();
+/// ```
+///
+/// Examples of **correct** code:
+///
+/// ```typescript
+/// const user: User | undefined = loadUser();
+/// ```
+///
+/// ## Rule Options
+///
+/// - `directives`: Directives to report (default: `["ts-ignore", "ts-nocheck", "ts-expect-error"]`)
+/// - `requireDescription`: Only report `@ts-expect-error` without a description (default: false)
+pub struct NoTsIgnoreRule {
+    /// Reported directives, without their `@` prefix
+    directives: Vec<String>,
+    /// Allow `@ts-expect-error` directives that explain why the error is suppressed
+    require_description: bool,
+}
+
+impl Default for NoTsIgnoreRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NoTsIgnoreRule {
+    pub fn new() -> Self {
+        Self {
+            directives: DEFAULT_DIRECTIVES.iter().map(|d| d.to_string()).collect(),
+            require_description: false,
+        }
+    }
+
+    /// Directive a comment starts with, and the description following it
+    fn parse_directive<'s>(source: &'s str, comment: &Comment) -> Option<(&'s str, &'s str)> {
+        let content = comment.content_span().source_text(source);
+        let text = content.trim_start_matches(['/', '*']).trim_start();
+        let text = text.strip_prefix('@')?;
+
+        let end = text
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+            .unwrap_or(text.len());
+        let description = text[end..]
+            .trim()
+            .trim_end_matches('/')
+            .trim_end_matches('*')
+            .trim_start_matches([':', '-'])
+            .trim();
+        Some((&text[..end], description))
+    }
+}
+
+impl Rule for NoTsIgnoreRule {
+    fn name(&self) -> &'static str {
+        "no-ts-ignore"
+    }
+
+    fn description(&self) -> &'static str {
+        "Disallows `@ts-ignore`, `@ts-nocheck` and `@ts-expect-error` comments"
+    }
+
+    fn applies_to_source(&self, source: &str) -> bool {
+        source.contains("@ts-")
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["typescript"]
+    }
+
+    fn config_schema(&self) -> Option<Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "directives": {
+                    "type": "array",
+                    "items": { "enum": DEFAULT_DIRECTIVES },
+                    "default": DEFAULT_DIRECTIVES
+                },
+                "requireDescription": { "type": "boolean", "default": false }
+            }
+        }))
+    }
+
+    fn set_config(&mut self, config: Value) {
+        if let Some(directives) = config.get("directives").and_then(Value::as_array) {
+            self.directives = directives
+                .iter()
+                .filter_map(Value::as_str)
+                .map(|directive| directive.trim_start_matches('@'))
+                .filter(|directive| DEFAULT_DIRECTIVES.contains(directive))
+                .map(str::to_string)
+                .collect();
+        }
+        if let Some(require) = config.get("requireDescription").and_then(Value::as_bool) {
+            self.require_description = require;
+        }
+    }
+
+    fn run_on_semantic(
+        &self,
+        semantic_result: &SemanticBuilderReturn,
+        _file_path: &str,
+    ) -> Vec<OxcDiagnostic> {
+        let semantic = &semantic_result.semantic;
+        let source = semantic.source_text();
+
+        semantic
+            .comments()
+            .iter()
+            .filter_map(|comment| {
+                let (directive, description) = Self::parse_directive(source, comment)?;
+                if !self.directives.iter().any(|d| d == directive) {
+                    return None;
+                }
+                let description_required =
+                    self.require_description && directive == "ts-expect-error";
+                if description_required && !description.is_empty() {
+                    return None;
+                }
+
+                let help = if directive == "ts-nocheck" {
+                    "Fix the type errors in this file instead of disabling type checking"
+                } else {
+                    "Fix the underlying type error instead of suppressing it"
+                };
+                let label = if description_required {
+                    "Directive without a description"
+                } else {
+                    "TypeScript error suppressed here"
+                };
+                Some(
+                    OxcDiagnostic::warn(format!("Unexpected `@{}` comment", directive))
+                        .with_help(help)
+                        .with_label(comment.span.label(label)),
+                )
+            })
+            .collect()
+    }
+}
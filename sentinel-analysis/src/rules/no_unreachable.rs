@@ -0,0 +1,90 @@
+use oxc_ast::AstKind;
+use oxc_ast::ast::Statement;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{GetSpan, Span};
+
+use crate::rules::Rule;
+
+/// Rule that disallows unreachable statements after `return`, `throw`, `break` or `continue`
+///
+/// The check is deliberately conservative and works on a single statement list at a time
+/// (program body, blocks, function bodies, switch cases and static blocks). A statement is
+/// treated as a terminator when it is a `return`/`throw`/`break`/`continue`, a block that
+/// directly contains one, or an `if`/`else` whose branches both terminate. Loops, `try`
+/// statements and labeled statements are never treated as terminators.
+///
+/// Hoisted function declarations and type-only declarations are legal after a terminator
+/// and are skipped. Only the first unreachable statement of each list is reported.
+pub struct NoUnreachableRule;
+
+impl NoUnreachableRule {
+    fn check_statements(statements: &[Statement]) -> Vec<OxcDiagnostic> {
+        let Some(terminator) = statements.iter().position(is_terminator) else {
+            return Vec::new();
+        };
+
+        let Some(unreachable) = statements[terminator + 1..]
+            .iter()
+            .find(|statement| !is_hoisted_declaration(statement))
+        else {
+            return Vec::new();
+        };
+
+        vec![
+            OxcDiagnostic::error("Unreachable code detected")
+                .with_help("Remove this code or move it before the preceding terminator")
+                .with_label(
+                    unreachable
+                        .span()
+                        .label("This statement can never be executed"),
+                ),
+        ]
+    }
+}
+
+/// Check whether control can never continue past this statement
+fn is_terminator(statement: &Statement) -> bool {
+    match statement {
+        Statement::ReturnStatement(_)
+        | Statement::ThrowStatement(_)
+        | Statement::BreakStatement(_)
+        | Statement::ContinueStatement(_) => true,
+        Statement::BlockStatement(block) => block.body.iter().any(is_terminator),
+        Statement::IfStatement(if_stmt) => if_stmt.alternate.as_ref().is_some_and(|alternate| {
+            is_terminator(&if_stmt.consequent) && is_terminator(alternate)
+        }),
+        _ => false,
+    }
+}
+
+/// Declarations that are hoisted or erased and may legally appear after a terminator
+fn is_hoisted_declaration(statement: &Statement) -> bool {
+    matches!(
+        statement,
+        Statement::FunctionDeclaration(_)
+            | Statement::TSTypeAliasDeclaration(_)
+            | Statement::TSInterfaceDeclaration(_)
+            | Statement::EmptyStatement(_)
+    )
+}
+
+impl Rule for NoUnreachableRule {
+    fn name(&self) -> &'static str {
+        "no-unreachable"
+    }
+
+    fn description(&self) -> &'static str {
+        "Disallow unreachable code after return, throw, break and continue statements"
+    }
+
+    fn run_on_node(&self, node: &AstKind, _span: Span, _file_path: &str) -> Vec<OxcDiagnostic> {
+        match node {
+            AstKind::Program(program) => Self::check_statements(&program.body),
+            AstKind::BlockStatement(block) => Self::check_statements(&block.body),
+            AstKind::FunctionBody(body) => Self::check_statements(&body.statements),
+            AstKind::SwitchCase(case) => Self::check_statements(&case.consequent),
+            AstKind::StaticBlock(block) => Self::check_statements(&block.body),
+            _ => Vec::new(),
+        }
+    }
+}
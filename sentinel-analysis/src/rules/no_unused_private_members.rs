@@ -0,0 +1,304 @@
+use std::collections::HashSet;
+
+use oxc_ast::AstKind;
+use oxc_ast::ast::{
+    AssignmentOperator, BindingPatternKind, Class, ClassElement, Decorator, Expression,
+    MemberExpression, MethodDefinitionKind, ObjectPropertyKind, PropertyKey, TSAccessibility,
+};
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_semantic::{NodeId, Semantic, SemanticBuilderReturn};
+use oxc_span::{GetSpan, Span};
+
+use crate::rules::Rule;
+
+/// Rule that flags private class members that are never read inside their class
+///
+/// Members declared `private` or with a `#name` can only be used by their own class, so
+/// a member the class body never reads is dead code. Uses are found with the semantic
+/// model: every member access (`this.name`, `other.#name`, `this['name']`), `#name in`
+/// check and destructuring of `this` inside the class counts, whatever its object. A
+/// member that is only assigned is reported as well. Constructor parameter properties
+/// (`constructor(private http: HttpClient)`) are checked too and are also used by plain
+/// references to the parameter.
+///
+/// Code outside the class body is treated conservatively: decorated members are never
+/// reported, names mentioned in the decorators of the class or its members (such as an
+/// inline Angular `template` or `host` bindings) count as used, and classes whose
+/// `@Component` loads its template from a `templateUrl` are skipped entirely.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code:
+///
+/// ```typescript
+/// class Cart {
+///   private items: Item[] = [];
+///   private legacyTotal() { return 0; }
+///
+///   add(item: Item) { this.items = [...this.items, item]; }
+/// }
+/// ```
+///
+/// Examples of **correct** code:
+///
+/// ```typescript
+/// class Cart {
+///   private items: Item[] = [];
+///
+///   add(item: Item) { this.items = [...this.items, item]; }
+///   get size() { return this.items.length; }
+/// }
+/// ```
+pub struct NoUnusedPrivateMembersRule;
+
+impl Default for NoUnusedPrivateMembersRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A private member of a class, as declared
+struct PrivateMember<'a> {
+    name: &'a str,
+    kind: &'static str,
+    span: Span,
+}
+
+impl NoUnusedPrivateMembersRule {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Name of a member key that can be matched against member accesses
+    fn key_name<'a>(key: &PropertyKey<'a>) -> Option<&'a str> {
+        match key {
+            PropertyKey::StaticIdentifier(ident) => Some(ident.name.as_str()),
+            PropertyKey::PrivateIdentifier(ident) => Some(ident.name.as_str()),
+            _ => None,
+        }
+    }
+
+    fn is_private(key: &PropertyKey, accessibility: Option<TSAccessibility>) -> bool {
+        accessibility == Some(TSAccessibility::Private)
+            || matches!(key, PropertyKey::PrivateIdentifier(_))
+    }
+
+    /// Private members of the class that are not decorated
+    fn private_members<'a>(class: &Class<'a>) -> Vec<PrivateMember<'a>> {
+        let mut members = Vec::new();
+        for element in &class.body.body {
+            let (key, kind, span) = match element {
+                ClassElement::PropertyDefinition(property)
+                    if property.decorators.is_empty()
+                        && !property.declare
+                        && Self::is_private(&property.key, property.accessibility) =>
+                {
+                    (&property.key, "field", property.span)
+                }
+                ClassElement::AccessorProperty(property)
+                    if property.decorators.is_empty()
+                        && Self::is_private(&property.key, property.accessibility) =>
+                {
+                    (&property.key, "field", property.span)
+                }
+                ClassElement::MethodDefinition(method)
+                    if method.kind == MethodDefinitionKind::Constructor =>
+                {
+                    members.extend(method.value.params.items.iter().filter_map(|param| {
+                        let BindingPatternKind::BindingIdentifier(ident) = &param.pattern.kind
+                        else {
+                            return None;
+                        };
+                        (param.accessibility == Some(TSAccessibility::Private)
+                            && param.decorators.is_empty())
+                        .then_some(PrivateMember {
+                            name: ident.name.as_str(),
+                            kind: "field",
+                            span: param.span,
+                        })
+                    }));
+                    continue;
+                }
+                ClassElement::MethodDefinition(method)
+                    if method.decorators.is_empty()
+                        && Self::is_private(&method.key, method.accessibility) =>
+                {
+                    (&method.key, "method", method.span)
+                }
+                _ => continue,
+            };
+            if let Some(name) = Self::key_name(key) {
+                members.push(PrivateMember { name, kind, span });
+            }
+        }
+        members
+    }
+
+    /// Whether the class is an Angular component with an external template
+    fn has_external_template(class: &Class) -> bool {
+        class.decorators.iter().any(|decorator| {
+            let Expression::CallExpression(call) = &decorator.expression else {
+                return false;
+            };
+            call.arguments.iter().any(|argument| {
+                argument.as_expression().is_some_and(|expression| {
+                    let Expression::ObjectExpression(metadata) = expression else {
+                        return false;
+                    };
+                    metadata.properties.iter().any(|property| {
+                        matches!(
+                            property,
+                            ObjectPropertyKind::ObjectProperty(property)
+                                if Self::key_name(&property.key) == Some("templateUrl")
+                        )
+                    })
+                })
+            })
+        })
+    }
+
+    /// Source text of every decorator of the class and its members
+    fn decorator_texts<'a>(semantic: &Semantic<'a>, class: &Class) -> Vec<&'a str> {
+        let member_decorators = class.body.body.iter().flat_map(|element| match element {
+            ClassElement::PropertyDefinition(property) => property.decorators.iter(),
+            ClassElement::AccessorProperty(property) => property.decorators.iter(),
+            ClassElement::MethodDefinition(method) => method.decorators.iter(),
+            _ => [].iter(),
+        });
+        class
+            .decorators
+            .iter()
+            .chain(member_decorators)
+            .map(|decorator: &Decorator| decorator.span.source_text(semantic.source_text()))
+            .collect()
+    }
+
+    /// Whether a member expression is the target of a plain `=` assignment, a write only
+    fn is_assigned_only(semantic: &Semantic, node_id: NodeId) -> bool {
+        // The iterator starts with the node itself
+        let mut ancestors = semantic.nodes().ancestor_kinds(node_id).skip(1);
+        matches!(
+            (ancestors.next(), ancestors.next(), ancestors.next()),
+            (
+                Some(AstKind::SimpleAssignmentTarget(_)),
+                Some(AstKind::AssignmentTarget(_)),
+                Some(AstKind::AssignmentExpression(assignment)),
+            ) if assignment.operator == AssignmentOperator::Assign
+        )
+    }
+
+    /// Names of members read anywhere in the class
+    fn used_names<'a>(semantic: &Semantic<'a>, class: &Class) -> HashSet<&'a str> {
+        let mut used = HashSet::new();
+        for node in semantic.nodes().iter() {
+            let span = node.kind().span();
+            if span.start < class.span.start || span.end > class.span.end {
+                continue;
+            }
+            match node.kind() {
+                AstKind::MemberExpression(_) if Self::is_assigned_only(semantic, node.id()) => {}
+                AstKind::MemberExpression(member) => match member {
+                    MemberExpression::StaticMemberExpression(member) => {
+                        used.insert(member.property.name.as_str());
+                    }
+                    MemberExpression::PrivateFieldExpression(member) => {
+                        used.insert(member.field.name.as_str());
+                    }
+                    MemberExpression::ComputedMemberExpression(member) => {
+                        if let Expression::StringLiteral(literal) = &member.expression {
+                            used.insert(literal.value.as_str());
+                        }
+                    }
+                },
+                AstKind::PrivateInExpression(expr) => {
+                    used.insert(expr.left.name.as_str());
+                }
+                // Parameter properties are also read through the parameter binding
+                AstKind::IdentifierReference(ident) => {
+                    used.insert(ident.name.as_str());
+                }
+                // const { items } = this;
+                AstKind::VariableDeclarator(declarator)
+                    if matches!(declarator.init, Some(Expression::ThisExpression(_))) =>
+                {
+                    if let BindingPatternKind::ObjectPattern(pattern) = &declarator.id.kind {
+                        used.extend(
+                            pattern
+                                .properties
+                                .iter()
+                                .filter_map(|property| Self::key_name(&property.key)),
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+        used
+    }
+
+    fn check_class(semantic: &Semantic, class: &Class) -> Vec<OxcDiagnostic> {
+        let members = Self::private_members(class);
+        if members.is_empty() || Self::has_external_template(class) {
+            return Vec::new();
+        }
+
+        let used = Self::used_names(semantic, class);
+        let decorator_texts = Self::decorator_texts(semantic, class);
+        members
+            .into_iter()
+            .filter(|member| {
+                !used.contains(member.name)
+                    && !decorator_texts
+                        .iter()
+                        .any(|text| text.contains(member.name))
+            })
+            .map(|member| {
+                OxcDiagnostic::warn(format!(
+                    "Private {} `{}` is never used",
+                    member.kind, member.name
+                ))
+                .with_help("Remove the unused member, or use it inside the class")
+                .with_label(member.span.label(format!("Unused private {}", member.kind)))
+            })
+            .collect()
+    }
+}
+
+impl Rule for NoUnusedPrivateMembersRule {
+    fn name(&self) -> &'static str {
+        "no-unused-private-members"
+    }
+
+    fn description(&self) -> &'static str {
+        "Disallows private class members that are never used inside their class"
+    }
+
+    fn applies_to_source(&self, source: &str) -> bool {
+        source.contains("private") || source.contains('#')
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["maintainability"]
+    }
+
+    fn run_on_semantic(
+        &self,
+        semantic_result: &SemanticBuilderReturn,
+        _file_path: &str,
+    ) -> Vec<OxcDiagnostic> {
+        let semantic = &semantic_result.semantic;
+
+        semantic
+            .nodes()
+            .iter()
+            .flat_map(|node| match node.kind() {
+                AstKind::Class(class) => Self::check_class(semantic, class),
+                _ => Vec::new(),
+            })
+            .collect()
+    }
+}
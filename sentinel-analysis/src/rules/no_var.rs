@@ -0,0 +1,183 @@
+use oxc_ast::AstKind;
+use oxc_ast::ast::{VariableDeclaration, VariableDeclarationKind};
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_semantic::{AstNode, Semantic, SemanticBuilderReturn};
+use oxc_span::{GetSpan, Span};
+
+use crate::rules::{Rule, RuleFix};
+
+/// Message of findings whose bindings are never reassigned, fixed with `const`
+const USE_CONST_MESSAGE: &str = "Unexpected `var`, use `const` instead";
+/// Message of findings whose bindings are reassigned, fixed with `let`
+const USE_LET_MESSAGE: &str = "Unexpected `var`, use `let` instead";
+
+/// Rule that flags `var` declarations
+///
+/// `var` is scoped to the whole function rather than to its block and can be used
+/// before its declaration, which `let` and `const` do not allow. `const` also shows
+/// at the declaration that the binding is never reassigned.
+///
+/// The fix replaces `var` with `const` when no binding of the declaration is
+/// reassigned and every binding is initialized, and with `let` otherwise. It is only
+/// offered when no binding is declared twice or used before its declaration or outside
+/// the block it is declared in. Inside loops, review fixed code: each iteration then
+/// gets its own binding. Ambient `declare var` declarations are not reported.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code:
+///
+/// ```typescript
+/// var count = 0;
+/// for (var i = 0; i < items.length; i++) {}
+/// ```
+///
+/// Examples of **correct** code:
+///
+/// ```typescript
+/// const count = 0;
+/// for (let i = 0; i < items.length; i++) {}
+/// ```
+pub struct NoVarRule;
+
+impl Default for NoVarRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NoVarRule {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Span of the statements a block-scoped binding of the declaration could be used in
+    fn block_span(semantic: &Semantic, node: &AstNode) -> Option<Span> {
+        let parent = semantic.nodes().parent_node(node.id())?;
+        let block = match parent.kind() {
+            // The statement around the declaration, for `export var` and `for (var ...;;)`
+            AstKind::ExportNamedDeclaration(_) | AstKind::ForStatementInit(_) => {
+                semantic.nodes().parent_node(parent.id())?
+            }
+            _ => parent,
+        };
+        Some(block.kind().span())
+    }
+
+    /// Keyword that can replace `var` without changing what the code does, if any
+    fn replacement(
+        semantic: &Semantic,
+        node: &AstNode,
+        decl: &VariableDeclaration,
+    ) -> Option<&'static str> {
+        let block = Self::block_span(semantic, node)?;
+        let scoping = semantic.scoping();
+        let in_for_in_of = matches!(
+            semantic.nodes().parent_kind(node.id()),
+            Some(AstKind::ForInStatement(_) | AstKind::ForOfStatement(_))
+        );
+
+        let mut reassigned = false;
+        for declarator in &decl.declarations {
+            if declarator.init.is_none() && !in_for_in_of {
+                reassigned = true;
+            }
+            for ident in declarator.id.get_binding_identifiers() {
+                let symbol_id = ident.symbol_id.get()?;
+                if !scoping.symbol_redeclarations(symbol_id).is_empty() {
+                    return None;
+                }
+                for reference in scoping.get_resolved_references(symbol_id) {
+                    let span = semantic.nodes().kind(reference.node_id()).span();
+                    if span.start < decl.span.start || span.end > block.end {
+                        return None;
+                    }
+                    reassigned |= reference.is_write();
+                }
+            }
+        }
+        Some(if reassigned { "let" } else { "const" })
+    }
+
+    fn check_declaration(
+        semantic: &Semantic,
+        node: &AstNode,
+        decl: &VariableDeclaration,
+    ) -> Option<OxcDiagnostic> {
+        if decl.kind != VariableDeclarationKind::Var || decl.declare {
+            return None;
+        }
+
+        let keyword = Span::new(decl.span.start, decl.span.start + 3);
+        let diagnostic = match Self::replacement(semantic, node, decl) {
+            Some("const") => OxcDiagnostic::warn(USE_CONST_MESSAGE)
+                .with_help("The bindings are never reassigned, so declare them with `const`"),
+            Some(_) => OxcDiagnostic::warn(USE_LET_MESSAGE)
+                .with_help("Declare reassigned bindings with `let`"),
+            None => OxcDiagnostic::warn("Unexpected `var`").with_help(
+                "Declare the binding with `let` or `const`, moving it to where it is used first",
+            ),
+        };
+        Some(diagnostic.with_label(keyword.label("`var` declaration")))
+    }
+}
+
+impl Rule for NoVarRule {
+    fn name(&self) -> &'static str {
+        "no-var"
+    }
+
+    fn description(&self) -> &'static str {
+        "Disallows `var` declarations in favor of `let` and `const`"
+    }
+
+    fn applies_to_source(&self, source: &str) -> bool {
+        source.contains("var")
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["style"]
+    }
+
+    fn run_on_semantic(
+        &self,
+        semantic_result: &SemanticBuilderReturn,
+        _file_path: &str,
+    ) -> Vec<OxcDiagnostic> {
+        let semantic = &semantic_result.semantic;
+
+        semantic
+            .nodes()
+            .iter()
+            .filter_map(|node| match node.kind() {
+                AstKind::VariableDeclaration(decl) => Self::check_declaration(semantic, node, decl),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn fix(&self, diagnostic: &OxcDiagnostic, source: &str) -> Option<RuleFix> {
+        let replacement = match diagnostic.message.as_ref() {
+            USE_CONST_MESSAGE => "const",
+            USE_LET_MESSAGE => "let",
+            _ => return None,
+        };
+        let label = diagnostic.labels.as_ref()?.first()?;
+        let start = label.offset();
+        if source.get(start..start + label.len())? != "var" {
+            return None;
+        }
+
+        Some(RuleFix {
+            span: Span::new(
+                u32::try_from(start).ok()?,
+                u32::try_from(start + label.len()).ok()?,
+            ),
+            replacement: replacement.to_string(),
+        })
+    }
+}
@@ -0,0 +1,149 @@
+use oxc_ast::AstKind;
+use oxc_ast::ast::{Argument, CallExpression, Expression};
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_span::Span;
+use serde_json::{Value, json};
+
+use crate::rules::Rule;
+
+/// Shortest `setInterval` delay in milliseconds that is not reported, by default
+const DEFAULT_MIN_INTERVAL: f64 = 10.0;
+
+/// Global objects timers are also called on, as in `window.setTimeout(...)`
+const GLOBAL_OBJECTS: [&str; 3] = ["window", "globalThis", "self"];
+
+/// Rule that flags timers with zero or very small delays
+///
+/// `setTimeout(fn, 0)` is usually meant as "run after the current task", which
+/// `queueMicrotask(fn)` or `Promise.resolve().then(fn)` express directly and without
+/// the minimum delay browsers apply to nested timers. An interval below a few
+/// milliseconds keeps the event loop busy and is clamped by browsers anyway.
+///
+/// Only delays written as number literals are checked.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code:
+///
+/// ```typescript
+/// setTimeout(() => this.focus(), 0);
+/// setInterval(poll, 5);
+/// ```
+///
+/// Examples of **correct** code:
+///
+/// ```typescript
+/// queueMicrotask(() => this.focus());
+/// setTimeout(retry, 500);
+/// setInterval(poll, 1000);
+/// ```
+///
+/// ## Rule Options
+///
+/// - `minInterval`: Shortest `setInterval` delay in milliseconds that is allowed (default: 10)
+pub struct NoZeroDelayTimerRule {
+    /// Intervals with a delay below this are reported
+    min_interval: f64,
+}
+
+impl Default for NoZeroDelayTimerRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NoZeroDelayTimerRule {
+    pub fn new() -> Self {
+        Self {
+            min_interval: DEFAULT_MIN_INTERVAL,
+        }
+    }
+
+    /// Name of the timer function called, for `setTimeout(...)` and `window.setTimeout(...)`
+    fn timer_name<'a>(call: &CallExpression<'a>) -> Option<&'a str> {
+        let name = match &call.callee {
+            Expression::Identifier(ident) => ident.name.as_str(),
+            callee => {
+                let member = callee.as_member_expression()?;
+                if !GLOBAL_OBJECTS
+                    .iter()
+                    .any(|global| member.object().is_specific_id(global))
+                {
+                    return None;
+                }
+                member.static_property_name()?
+            }
+        };
+        matches!(name, "setTimeout" | "setInterval").then_some(name)
+    }
+
+    fn check_call(&self, call: &CallExpression, span: Span) -> Option<OxcDiagnostic> {
+        let timer = Self::timer_name(call)?;
+        let Some(Argument::NumericLiteral(delay)) = call.arguments.get(1) else {
+            return None;
+        };
+
+        if timer == "setTimeout" && delay.value == 0.0 {
+            return Some(
+                OxcDiagnostic::warn("`setTimeout` with a zero delay")
+                    .with_help("Use `queueMicrotask(fn)` or `Promise.resolve().then(fn)` to run code after the current task")
+                    .with_label(span.label("Deferred with a zero delay")),
+            );
+        }
+        if timer == "setInterval" && delay.value < self.min_interval {
+            return Some(
+                OxcDiagnostic::warn(format!(
+                    "`setInterval` with a delay of {}ms, below the minimum of {}ms",
+                    delay.value, self.min_interval
+                ))
+                .with_help("Use a longer interval, or react to an event instead of polling")
+                .with_label(span.label("Runs almost continuously")),
+            );
+        }
+        None
+    }
+}
+
+impl Rule for NoZeroDelayTimerRule {
+    fn name(&self) -> &'static str {
+        "no-zero-delay-timer"
+    }
+
+    fn description(&self) -> &'static str {
+        "Disallows `setTimeout` with a zero delay and `setInterval` with very small delays"
+    }
+
+    fn applies_to_source(&self, source: &str) -> bool {
+        source.contains("setTimeout") || source.contains("setInterval")
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["performance"]
+    }
+
+    fn config_schema(&self) -> Option<Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "minInterval": { "type": "number", "minimum": 0, "default": DEFAULT_MIN_INTERVAL }
+            }
+        }))
+    }
+
+    fn set_config(&mut self, config: Value) {
+        if let Some(min_interval) = config.get("minInterval").and_then(Value::as_f64) {
+            self.min_interval = min_interval;
+        }
+    }
+
+    fn run_on_node(&self, node: &AstKind, span: Span, _file_path: &str) -> Vec<OxcDiagnostic> {
+        match node {
+            AstKind::CallExpression(call) => self.check_call(call, span).into_iter().collect(),
+            _ => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use oxc_ast::AstKind;
+use oxc_ast::ast::{NumberBase, NumericLiteral};
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_span::Span;
+use serde_json::{Value, json};
+
+use crate::rules::{Rule, RuleFix};
+
+/// Smallest value written without separators that is reported, by default
+const DEFAULT_THRESHOLD: f64 = 10_000.0;
+
+/// Rule that flags large numeric literals written without `_` digit separators
+///
+/// `1000000` and `10000000` are hard to tell apart at a glance, `1_000_000` and
+/// `10_000_000` are not. Decimal literals whose value is at least `threshold` are
+/// reported when they contain no separator at all. Hexadecimal, binary and octal
+/// literals, exponent notation and BigInt literals are left alone.
+///
+/// The fix groups the integer part in threes, keeping any fraction as written.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code:
+///
+/// ```typescript
+/// const timeout = 100000;
+/// const price = 1999999.99;
+/// ```
+///
+/// Examples of **correct** code:
+///
+/// ```typescript
+/// const timeout = 100_000;
+/// const price = 1_999_999.99;
+/// const port = 8080;
+/// ```
+///
+/// ## Rule Options
+///
+/// - `threshold`: Smallest value that needs separators (default: 10000)
+pub struct NumericSeparatorsRule {
+    /// Literals with a value of at least this are reported
+    threshold: f64,
+}
+
+impl Default for NumericSeparatorsRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NumericSeparatorsRule {
+    pub fn new() -> Self {
+        Self {
+            threshold: DEFAULT_THRESHOLD,
+        }
+    }
+
+    /// Insert separators into the integer part of a decimal literal, e.g. `1234567.5`
+    /// becomes `1_234_567.5`
+    fn with_separators(raw: &str) -> String {
+        let (integer, fraction) = raw.split_at(raw.find('.').unwrap_or(raw.len()));
+
+        let mut grouped = String::with_capacity(raw.len() + integer.len() / 3);
+        for (index, digit) in integer.chars().enumerate() {
+            if index > 0 && (integer.len() - index) % 3 == 0 {
+                grouped.push('_');
+            }
+            grouped.push(digit);
+        }
+        grouped.push_str(fraction);
+        grouped
+    }
+
+    fn check_literal(&self, literal: &NumericLiteral) -> Vec<OxcDiagnostic> {
+        let Some(raw) = literal.raw.as_deref() else {
+            return Vec::new();
+        };
+        let is_plain_decimal = matches!(literal.base, NumberBase::Decimal | NumberBase::Float)
+            && !raw.contains(['e', 'E', '_']);
+        if !is_plain_decimal || literal.value < self.threshold {
+            return Vec::new();
+        }
+
+        vec![
+            OxcDiagnostic::warn(format!("Numeric literal `{}` has no digit separators", raw))
+                .with_severity(Severity::Advice)
+                .with_help(format!(
+                    "Write `{}` to make the magnitude readable",
+                    Self::with_separators(raw)
+                ))
+                .with_label(literal.span.label("Large number without separators")),
+        ]
+    }
+}
+
+impl Rule for NumericSeparatorsRule {
+    fn name(&self) -> &'static str {
+        "numeric-separators"
+    }
+
+    fn description(&self) -> &'static str {
+        "Suggests `_` digit separators in large numeric literals"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Advice
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["style"]
+    }
+
+    fn config_schema(&self) -> Option<Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "threshold": {
+                    "type": "number",
+                    "minimum": 0,
+                    "default": DEFAULT_THRESHOLD
+                }
+            }
+        }))
+    }
+
+    fn set_config(&mut self, config: Value) {
+        if let Some(threshold) = config.get("threshold").and_then(Value::as_f64) {
+            self.threshold = threshold;
+        }
+    }
+
+    fn run_on_node(&self, node: &AstKind, _span: Span, _file_path: &str) -> Vec<OxcDiagnostic> {
+        match node {
+            AstKind::NumericLiteral(literal) => self.check_literal(literal),
+            _ => Vec::new(),
+        }
+    }
+
+    fn fix(&self, diagnostic: &OxcDiagnostic, source: &str) -> Option<RuleFix> {
+        let label = diagnostic.labels.as_ref()?.first()?;
+        let start = label.offset();
+        let raw = source.get(start..start + label.len())?;
+
+        Some(RuleFix {
+            span: Span::new(
+                u32::try_from(start).ok()?,
+                u32::try_from(start + raw.len()).ok()?,
+            ),
+            replacement: Self::with_separators(raw),
+        })
+    }
+}
@@ -0,0 +1,272 @@
+use std::collections::HashSet;
+
+use oxc_ast::AstKind;
+use oxc_ast::ast::{
+    ArrayExpressionElement, AssignmentExpression, BindingPatternKind, CallExpression, Declaration,
+    Expression, MemberExpression, ObjectPropertyKind, PropertyKind, Statement, UnaryExpression,
+    UnaryOperator, UpdateExpression, VariableDeclarationKind,
+};
+use oxc_ast_visit::{Visit, walk};
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_semantic::{Scoping, SemanticBuilderReturn, SymbolId};
+use oxc_span::GetSpan;
+
+use crate::rules::Rule;
+
+/// Array methods that modify the array they are called on
+const MUTATING_METHODS: &[&str] = &[
+    "copyWithin",
+    "fill",
+    "pop",
+    "push",
+    "reverse",
+    "shift",
+    "sort",
+    "splice",
+    "unshift",
+];
+
+/// Rule that suggests `as const` on exported literal constants
+///
+/// `export const ROLES = ['admin', 'user']` is typed as `string[]`; with `as const` it
+/// becomes `readonly ['admin', 'user']`, so the values can be used as literal types
+/// (e.g. `typeof ROLES[number]`) and cannot be modified by accident.
+///
+/// The rule is deliberately conservative and only reports top-level `export const`
+/// declarations without a type annotation whose initializer is a non-empty object or
+/// array literal containing nothing but literals. It is skipped when the file mutates
+/// the constant: assignments or `delete` through a member (`ROLES[0] = ...`), `++`/`--`
+/// on a member, calls of mutating array methods (`ROLES.push(...)`) and
+/// `Object.assign(ROLES, ...)`. Mutations in other files, or through functions the
+/// constant is passed to, are not detected, which is why the rule only makes
+/// suggestions and defaults to the `info` severity.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code:
+///
+/// ```typescript
+/// export const ROLES = ['admin', 'user'];
+/// export const DEFAULTS = { retries: 3, verbose: false };
+/// ```
+///
+/// Examples of **correct** code:
+///
+/// ```typescript
+/// export const ROLES = ['admin', 'user'] as const;
+/// export const DEFAULTS: Options = { retries: 3, verbose: false };
+/// ```
+pub struct PreferConstAssertionRule;
+
+impl PreferConstAssertionRule {
+    /// Check whether an expression is built from literals only
+    fn is_literal(expression: &Expression) -> bool {
+        match expression.without_parentheses() {
+            Expression::StringLiteral(_)
+            | Expression::NumericLiteral(_)
+            | Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_)
+            | Expression::BigIntLiteral(_) => true,
+            Expression::TemplateLiteral(template) => template.expressions.is_empty(),
+            Expression::UnaryExpression(unary) => {
+                unary.operator == UnaryOperator::UnaryNegation
+                    && matches!(unary.argument, Expression::NumericLiteral(_))
+            }
+            Expression::ObjectExpression(_) | Expression::ArrayExpression(_) => {
+                Self::is_literal_container(expression)
+            }
+            _ => false,
+        }
+    }
+
+    /// Check whether an expression is an object or array literal containing only literals
+    fn is_literal_container(expression: &Expression) -> bool {
+        match expression.without_parentheses() {
+            Expression::ObjectExpression(object) => {
+                object.properties.iter().all(|property| match property {
+                    ObjectPropertyKind::ObjectProperty(property) => {
+                        property.kind == PropertyKind::Init
+                            && !property.method
+                            && !property.computed
+                            && Self::is_literal(&property.value)
+                    }
+                    ObjectPropertyKind::SpreadProperty(_) => false,
+                })
+            }
+            Expression::ArrayExpression(array) => array.elements.iter().all(|element| {
+                !matches!(
+                    element,
+                    ArrayExpressionElement::SpreadElement(_) | ArrayExpressionElement::Elision(_)
+                ) && element.as_expression().is_some_and(Self::is_literal)
+            }),
+            _ => false,
+        }
+    }
+
+    /// Check whether an initializer is a non-empty object or array literal of literals
+    fn is_candidate_initializer(expression: &Expression) -> bool {
+        let non_empty = match expression.without_parentheses() {
+            Expression::ObjectExpression(object) => !object.properties.is_empty(),
+            Expression::ArrayExpression(array) => !array.elements.is_empty(),
+            _ => false,
+        };
+        non_empty && Self::is_literal_container(expression)
+    }
+}
+
+/// Visitor collecting the symbols whose value is modified in place
+struct MutationVisitor<'s> {
+    scoping: &'s Scoping,
+    mutated: HashSet<SymbolId>,
+}
+
+impl MutationVisitor<'_> {
+    /// Record the symbol at the root of a member chain (`a` in `a.b[0].c`)
+    fn mark_root(&mut self, mut expression: &Expression) {
+        loop {
+            expression = match expression.without_parentheses() {
+                Expression::Identifier(identifier) => {
+                    if let Some(symbol_id) = identifier
+                        .reference_id
+                        .get()
+                        .and_then(|id| self.scoping.get_reference(id).symbol_id())
+                    {
+                        self.mutated.insert(symbol_id);
+                    }
+                    return;
+                }
+                Expression::StaticMemberExpression(member) => &member.object,
+                Expression::ComputedMemberExpression(member) => &member.object,
+                Expression::PrivateFieldExpression(member) => &member.object,
+                _ => return,
+            };
+        }
+    }
+
+    fn mark_member(&mut self, member: &MemberExpression) {
+        self.mark_root(member.object());
+    }
+}
+
+impl<'a> Visit<'a> for MutationVisitor<'_> {
+    fn visit_assignment_expression(&mut self, expr: &AssignmentExpression<'a>) {
+        if let Some(member) = expr.left.as_member_expression() {
+            self.mark_member(member);
+        }
+        walk::walk_assignment_expression(self, expr);
+    }
+
+    fn visit_update_expression(&mut self, expr: &UpdateExpression<'a>) {
+        if let Some(member) = expr.argument.as_member_expression() {
+            self.mark_member(member);
+        }
+        walk::walk_update_expression(self, expr);
+    }
+
+    fn visit_unary_expression(&mut self, expr: &UnaryExpression<'a>) {
+        if expr.operator == UnaryOperator::Delete
+            && let Some(member) = expr.argument.as_member_expression()
+        {
+            self.mark_member(member);
+        }
+        walk::walk_unary_expression(self, expr);
+    }
+
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        if let Some(callee) = call.callee.as_member_expression() {
+            match callee.static_property_name() {
+                Some(method) if MUTATING_METHODS.contains(&method) => {
+                    self.mark_root(callee.object())
+                }
+                Some("assign") if callee.object().is_specific_id("Object") => {
+                    if let Some(target) = call.arguments.first().and_then(|arg| arg.as_expression())
+                    {
+                        self.mark_root(target);
+                    }
+                }
+                _ => {}
+            }
+        }
+        walk::walk_call_expression(self, call);
+    }
+}
+
+impl Rule for PreferConstAssertionRule {
+    fn name(&self) -> &'static str {
+        "prefer-const-assertion"
+    }
+
+    fn description(&self) -> &'static str {
+        "Suggests `as const` on exported object and array literals that are never mutated"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Advice
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["style"]
+    }
+
+    fn run_on_semantic(
+        &self,
+        semantic_result: &SemanticBuilderReturn,
+        _file_path: &str,
+    ) -> Vec<OxcDiagnostic> {
+        let semantic = &semantic_result.semantic;
+        let Some(AstKind::Program(program)) = semantic.nodes().root_node().map(|node| node.kind())
+        else {
+            return Vec::new();
+        };
+
+        let mut visitor = MutationVisitor {
+            scoping: semantic.scoping(),
+            mutated: HashSet::new(),
+        };
+        visitor.visit_program(program);
+
+        let mut diagnostics = Vec::new();
+        for statement in &program.body {
+            let Statement::ExportNamedDeclaration(export) = statement else {
+                continue;
+            };
+            let Some(Declaration::VariableDeclaration(declaration)) = &export.declaration else {
+                continue;
+            };
+            if declaration.kind != VariableDeclarationKind::Const {
+                continue;
+            }
+
+            for declarator in &declaration.declarations {
+                let (BindingPatternKind::BindingIdentifier(identifier), Some(init)) =
+                    (&declarator.id.kind, &declarator.init)
+                else {
+                    continue;
+                };
+                let mutated = identifier
+                    .symbol_id
+                    .get()
+                    .is_some_and(|symbol_id| visitor.mutated.contains(&symbol_id));
+                if declarator.id.type_annotation.is_some()
+                    || mutated
+                    || !Self::is_candidate_initializer(init)
+                {
+                    continue;
+                }
+
+                diagnostics.push(
+                    OxcDiagnostic::warn(format!(
+                        "Exported constant `{}` could be declared `as const`",
+                        identifier.name
+                    ))
+                    .with_severity(Severity::Advice)
+                    .with_help(
+                        "Append `as const` to keep the literal types and make the value readonly",
+                    )
+                    .with_label(init.span().label("Literal initializer")),
+                );
+            }
+        }
+        diagnostics
+    }
+}
@@ -0,0 +1,321 @@
+use std::collections::HashSet;
+
+use oxc_ast::AstKind;
+use oxc_ast::ast::{
+    ArrowFunctionExpression, AwaitExpression, Declaration, ExportDefaultDeclarationKind,
+    Expression, ForOfStatement, Function, MethodDefinition, ObjectProperty, Program, Statement,
+    TryStatement,
+};
+use oxc_ast_visit::{Visit, walk};
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_semantic::{ScopeFlags, SemanticBuilderReturn};
+use oxc_span::{GetSpan, Span};
+use serde_json::{Value, json};
+
+use crate::rules::Rule;
+
+/// Rule that flags async functions awaiting outside of any `try`/`catch`
+///
+/// A rejected promise turns into an exception at the `await`. Without a `catch`, it
+/// rejects the promise of the async function, and callers that do not await it, such
+/// as event handlers, lose the error. An async function is reported once, on its
+/// signature, when at least one `await` (or `for await`) is not inside the `try` block
+/// of a `try`/`catch` in the same function. Awaiting `promise.catch(...)` counts as
+/// handled.
+///
+/// This is a heuristic: functions that intentionally let errors propagate to a caller
+/// that handles them are reported too. Enable `onlyExported` to check only the entry
+/// points of a module, or wrap such functions in `// sentinel-disable
+/// require-async-error-handling` and `// sentinel-enable require-async-error-handling`
+/// comments.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code:
+///
+/// ```typescript
+/// async function loadUser(id: string) {
+///   const response = await fetch(`/api/users/${id}`);
+///   return response.json();
+/// }
+/// ```
+///
+/// Examples of **correct** code:
+///
+/// ```typescript
+/// async function loadUser(id: string) {
+///   try {
+///     const response = await fetch(`/api/users/${id}`);
+///     return await response.json();
+///   } catch (error) {
+///     return null;
+///   }
+/// }
+/// ```
+///
+/// ## Rule Options
+///
+/// - `onlyExported`: Only check exported functions, declared with `export function`,
+///   `export default` or `export const f = async () => ...` (default: false)
+pub struct RequireAsyncErrorHandlingRule {
+    /// Check only functions exported from the module
+    only_exported: bool,
+}
+
+impl Default for RequireAsyncErrorHandlingRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequireAsyncErrorHandlingRule {
+    pub fn new() -> Self {
+        Self {
+            only_exported: false,
+        }
+    }
+
+    /// Start offsets of the functions exported at the top level of a module
+    fn exported_functions(program: &Program) -> HashSet<u32> {
+        let function_start = |expression: &Expression| match expression.without_parentheses() {
+            Expression::FunctionExpression(function) => Some(function.span.start),
+            Expression::ArrowFunctionExpression(arrow) => Some(arrow.span.start),
+            _ => None,
+        };
+
+        let mut exported = HashSet::new();
+        for statement in &program.body {
+            match statement {
+                Statement::ExportNamedDeclaration(export) => {
+                    match &export.declaration {
+                        Some(Declaration::FunctionDeclaration(function)) => {
+                            exported.insert(function.span.start);
+                        }
+                        Some(Declaration::VariableDeclaration(declaration)) => {
+                            exported.extend(declaration.declarations.iter().filter_map(
+                                |declarator| function_start(declarator.init.as_ref()?),
+                            ));
+                        }
+                        _ => {}
+                    }
+                }
+                Statement::ExportDefaultDeclaration(export) => match &export.declaration {
+                    ExportDefaultDeclarationKind::FunctionDeclaration(function) => {
+                        exported.insert(function.span.start);
+                    }
+                    declaration => {
+                        if let Some(start) = declaration.as_expression().and_then(function_start) {
+                            exported.insert(start);
+                        }
+                    }
+                },
+                _ => {}
+            }
+        }
+        exported
+    }
+
+    fn create_diagnostic(signature: Span, first_await: Span) -> OxcDiagnostic {
+        OxcDiagnostic::warn("Async function awaits without error handling")
+            .with_help("Wrap the awaited calls in try/catch, or suppress the rule if errors are meant to propagate to the caller")
+            .with_labels([
+                signature.primary_label("Rejections are not handled in this function"),
+                first_await.label("Awaited outside of try/catch"),
+            ])
+    }
+}
+
+/// Visitor finding async functions with an `await` outside of `try`/`catch`
+struct AsyncFunctionVisitor {
+    /// Start offsets of the functions to check, `None` to check all
+    exported: Option<HashSet<u32>>,
+    /// Number of enclosing `try` blocks with a `catch` clause, in the current function
+    try_depth: usize,
+    /// First unhandled `await` of the current function
+    unhandled_await: Option<Span>,
+    /// Start of a method's function and of its key, where the method's signature starts
+    method_start: Option<(u32, u32)>,
+    diagnostics: Vec<OxcDiagnostic>,
+}
+
+impl AsyncFunctionVisitor {
+    /// Visit a function body on its own, reporting it if it is async and unhandled
+    fn check_function(
+        &mut self,
+        is_async: bool,
+        span: Span,
+        signature_end: u32,
+        visit: impl FnOnce(&mut Self),
+    ) {
+        let signature_start = match self.method_start {
+            Some((function_start, key_start)) if function_start == span.start => key_start,
+            _ => span.start,
+        };
+        let outer_depth = std::mem::take(&mut self.try_depth);
+        let outer_await = self.unhandled_await.take();
+
+        visit(self);
+
+        let checked = self
+            .exported
+            .as_ref()
+            .is_none_or(|exported| exported.contains(&span.start));
+        if let Some(first_await) = self.unhandled_await
+            && is_async
+            && checked
+        {
+            let signature = Span::new(signature_start, signature_end);
+            self.diagnostics
+                .push(RequireAsyncErrorHandlingRule::create_diagnostic(
+                    signature,
+                    first_await,
+                ));
+        }
+
+        self.try_depth = outer_depth;
+        self.unhandled_await = outer_await;
+    }
+
+    fn record_await(&mut self, span: Span) {
+        if self.try_depth == 0 && self.unhandled_await.is_none() {
+            self.unhandled_await = Some(span);
+        }
+    }
+
+    /// Whether an awaited expression handles its own rejection, e.g. `p.catch(...)`
+    fn is_caught(expression: &Expression) -> bool {
+        match expression.without_parentheses() {
+            Expression::CallExpression(call) => call
+                .callee
+                .as_member_expression()
+                .is_some_and(|callee| callee.static_property_name() == Some("catch")),
+            _ => false,
+        }
+    }
+}
+
+impl<'a> Visit<'a> for AsyncFunctionVisitor {
+    fn visit_try_statement(&mut self, it: &TryStatement<'a>) {
+        let catches = it.handler.is_some();
+        if catches {
+            self.try_depth += 1;
+        }
+        self.visit_block_statement(&it.block);
+        if catches {
+            self.try_depth -= 1;
+        }
+
+        if let Some(handler) = &it.handler {
+            self.visit_catch_clause(handler);
+        }
+        if let Some(finalizer) = &it.finalizer {
+            self.visit_block_statement(finalizer);
+        }
+    }
+
+    fn visit_await_expression(&mut self, it: &AwaitExpression<'a>) {
+        if !Self::is_caught(&it.argument) {
+            self.record_await(it.span);
+        }
+        walk::walk_await_expression(self, it);
+    }
+
+    fn visit_for_of_statement(&mut self, it: &ForOfStatement<'a>) {
+        if it.r#await {
+            self.record_await(it.span);
+        }
+        walk::walk_for_of_statement(self, it);
+    }
+
+    fn visit_method_definition(&mut self, it: &MethodDefinition<'a>) {
+        self.method_start = Some((it.value.span.start, it.key.span().start));
+        walk::walk_method_definition(self, it);
+    }
+
+    fn visit_object_property(&mut self, it: &ObjectProperty<'a>) {
+        if it.method {
+            self.method_start = Some((it.value.span().start, it.key.span().start));
+        }
+        walk::walk_object_property(self, it);
+    }
+
+    fn visit_function(&mut self, it: &Function<'a>, flags: ScopeFlags) {
+        let signature_end = it
+            .return_type
+            .as_ref()
+            .map_or(it.params.span.end, |return_type| return_type.span.end);
+        self.check_function(it.r#async, it.span, signature_end, |visitor| {
+            walk::walk_function(visitor, it, flags);
+        });
+    }
+
+    fn visit_arrow_function_expression(&mut self, it: &ArrowFunctionExpression<'a>) {
+        let signature_end = it
+            .return_type
+            .as_ref()
+            .map_or(it.params.span.end, |return_type| return_type.span.end);
+        self.check_function(it.r#async, it.span, signature_end, |visitor| {
+            walk::walk_arrow_function_expression(visitor, it);
+        });
+    }
+}
+
+impl Rule for RequireAsyncErrorHandlingRule {
+    fn name(&self) -> &'static str {
+        "require-async-error-handling"
+    }
+
+    fn description(&self) -> &'static str {
+        "Requires async functions to handle errors of awaited promises"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["correctness"]
+    }
+
+    fn config_schema(&self) -> Option<Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "onlyExported": { "type": "boolean", "default": false }
+            }
+        }))
+    }
+
+    fn set_config(&mut self, config: Value) {
+        if let Some(only_exported) = config.get("onlyExported").and_then(Value::as_bool) {
+            self.only_exported = only_exported;
+        }
+    }
+
+    fn run_on_semantic(
+        &self,
+        semantic_result: &SemanticBuilderReturn,
+        _file_path: &str,
+    ) -> Vec<OxcDiagnostic> {
+        let Some(AstKind::Program(program)) = semantic_result
+            .semantic
+            .nodes()
+            .root_node()
+            .map(|node| node.kind())
+        else {
+            return Vec::new();
+        };
+
+        let mut visitor = AsyncFunctionVisitor {
+            exported: self
+                .only_exported
+                .then(|| Self::exported_functions(program)),
+            try_depth: 0,
+            unhandled_await: None,
+            method_start: None,
+            diagnostics: Vec::new(),
+        };
+        visitor.visit_program(program);
+        visitor.diagnostics
+    }
+}
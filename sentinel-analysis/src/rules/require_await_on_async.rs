@@ -0,0 +1,135 @@
+use oxc_ast::AstKind;
+use oxc_ast::ast::{CallExpression, Expression};
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_span::{GetSpan, Span};
+use serde_json::{Value, json};
+
+use crate::rules::Rule;
+
+/// Promise-returning functions checked by default
+const DEFAULT_FUNCTIONS: &[&str] = &["firstValueFrom", "lastValueFrom", "toPromise"];
+
+/// Rule that flags floating calls to known promise-returning functions
+///
+/// Calls such as `firstValueFrom(obs$)` return a promise. When the call is used as a
+/// statement on its own, the result is never awaited: errors are lost and the code
+/// after it runs before the value arrives.
+///
+/// A call is reported when it forms an expression statement by itself, either as a plain
+/// call (`firstValueFrom(x)`) or as a method call (`obs$.toPromise()`). Awaited calls,
+/// calls chained with `.then(...)`, returned or assigned promises and calls explicitly
+/// discarded with `void` are not reported.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code:
+///
+/// ```typescript
+/// async save() {
+///   firstValueFrom(this.http.post('/api/users', user));
+/// }
+/// ```
+///
+/// Examples of **correct** code:
+///
+/// ```typescript
+/// async save() {
+///   await firstValueFrom(this.http.post('/api/users', user));
+///   lastValueFrom(this.events$).then(events => this.log(events));
+/// }
+/// ```
+///
+/// ## Rule Options
+///
+/// - `functions`: Names of promise-returning functions or methods
+///   (default: `["firstValueFrom", "lastValueFrom", "toPromise"]`)
+pub struct RequireAwaitOnAsyncRule {
+    /// Function and method names returning a promise
+    functions: Vec<String>,
+}
+
+impl Default for RequireAwaitOnAsyncRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequireAwaitOnAsyncRule {
+    pub fn new() -> Self {
+        Self {
+            functions: DEFAULT_FUNCTIONS
+                .iter()
+                .map(|name| name.to_string())
+                .collect(),
+        }
+    }
+
+    /// Name of the called function or method
+    fn callee_name<'a>(call: &'a CallExpression) -> Option<&'a str> {
+        match call.callee.get_inner_expression() {
+            Expression::Identifier(ident) => Some(ident.name.as_str()),
+            Expression::StaticMemberExpression(member) => Some(member.property.name.as_str()),
+            _ => None,
+        }
+    }
+}
+
+impl Rule for RequireAwaitOnAsyncRule {
+    fn name(&self) -> &'static str {
+        "require-await-on-async"
+    }
+
+    fn description(&self) -> &'static str {
+        "Require promise-returning calls such as firstValueFrom() to be awaited"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["angular"]
+    }
+
+    fn config_schema(&self) -> Option<Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "functions": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "default": DEFAULT_FUNCTIONS
+                }
+            }
+        }))
+    }
+
+    fn set_config(&mut self, config: Value) {
+        if let Some(functions) = config.get("functions").and_then(Value::as_array) {
+            self.functions = functions
+                .iter()
+                .filter_map(|name| name.as_str().map(str::to_string))
+                .collect();
+        }
+    }
+
+    fn run_on_node(&self, node: &AstKind, _span: Span, _file_path: &str) -> Vec<OxcDiagnostic> {
+        let AstKind::ExpressionStatement(statement) = node else {
+            return Vec::new();
+        };
+        let Expression::CallExpression(call) = statement.expression.without_parentheses() else {
+            return Vec::new();
+        };
+
+        match Self::callee_name(call) {
+            Some(name) if self.functions.iter().any(|function| function == name) => {
+                vec![
+                    OxcDiagnostic::warn(format!("Promise returned by `{}` is not awaited", name))
+                        .with_help("Add `await`, handle the result with `.then(...)`, or discard it explicitly with `void`")
+                        .with_label(call.span().label("This promise is never awaited")),
+                ]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
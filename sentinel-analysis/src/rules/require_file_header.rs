@@ -0,0 +1,213 @@
+use globset::GlobSet;
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_semantic::SemanticBuilderReturn;
+use oxc_span::Span;
+use regex::Regex;
+use serde_json::{Value, json};
+
+use crate::rules::{Rule, RuleFix, build_glob_set, matches_file_glob};
+use crate::utilities::{DebugLevel, log};
+
+/// Message of findings on files without any header comment; only these are fixed
+const MISSING_HEADER_MESSAGE: &str = "File is missing the required header";
+
+/// Rule that requires every file to start with a header, such as a copyright notice
+///
+/// The header is configured either as exact `header` lines or as a regular expression
+/// `pattern` matched at the start of the file. Trailing whitespace and line endings are
+/// ignored when comparing lines, and a leading `#!` line is skipped. Without a
+/// configured header the rule reports nothing.
+///
+/// Files starting with a different comment are reported as having a mismatched header
+/// and are not fixed, as the comment may be an outdated header or something else. The
+/// fix inserts the `header` lines into files without a leading comment.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code (with `header: "// Copyright (c) Acme Corp."`):
+///
+/// ```typescript
+/// export const a = 1;
+/// ```
+///
+/// Examples of **correct** code:
+///
+/// ```typescript
+/// // Copyright (c) Acme Corp.
+/// export const a = 1;
+/// ```
+///
+/// ## Rule Options
+///
+/// - `header`: The required header, as a string or an array of lines (default: none)
+/// - `pattern`: Regular expression the start of the file must match, used instead of
+///   `header` for the check; `header` is still used by the fix. An invalid pattern is
+///   ignored with a warning (default: none)
+/// - `files`: Globs of the files that need the header, relative to the scan root
+///   (default: `[]`, all files)
+pub struct RequireFileHeaderRule {
+    /// Required header lines
+    header: Vec<String>,
+    /// Pattern anchored at the start of the file
+    pattern: Option<Regex>,
+    /// Files that need the header, `None` for all files
+    files: Option<GlobSet>,
+}
+
+impl Default for RequireFileHeaderRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequireFileHeaderRule {
+    pub fn new() -> Self {
+        Self {
+            header: Vec::new(),
+            pattern: None,
+            files: None,
+        }
+    }
+
+    /// Offset where the header starts: after a `#!` line, or at the start of the file
+    fn header_start(source: &str) -> usize {
+        if !source.starts_with("#!") {
+            return 0;
+        }
+        source.find('\n').map_or(source.len(), |end| end + 1)
+    }
+
+    /// Check whether the text starts with the configured header
+    fn has_header(&self, text: &str) -> bool {
+        if let Some(pattern) = &self.pattern {
+            return pattern.is_match(text);
+        }
+        let mut lines = text.lines();
+        self.header.iter().all(|expected| {
+            lines
+                .next()
+                .is_some_and(|line| line.trim_end() == expected.trim_end())
+        })
+    }
+}
+
+impl Rule for RequireFileHeaderRule {
+    fn name(&self) -> &'static str {
+        "require-file-header"
+    }
+
+    fn description(&self) -> &'static str {
+        "Requires files to start with a configured header, such as a copyright notice"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["maintainability"]
+    }
+
+    fn config_schema(&self) -> Option<Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "header": {
+                    "oneOf": [
+                        { "type": "string" },
+                        { "type": "array", "items": { "type": "string" } }
+                    ],
+                    "default": ""
+                },
+                "pattern": { "type": "string", "format": "regex", "default": "" },
+                "files": { "type": "array", "items": { "type": "string" }, "default": [] }
+            }
+        }))
+    }
+
+    fn set_config(&mut self, config: Value) {
+        match config.get("header") {
+            Some(Value::String(header)) => {
+                self.header = header.lines().map(str::to_string).collect();
+            }
+            Some(Value::Array(lines)) => {
+                self.header = lines
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(str::to_string)
+                    .collect();
+            }
+            _ => {}
+        }
+        if let Some(pattern) = config.get("pattern").and_then(Value::as_str) {
+            self.pattern = None;
+            if !pattern.is_empty() {
+                match Regex::new(&format!(r"\A(?:{})", pattern)) {
+                    Ok(regex) => self.pattern = Some(regex),
+                    // Rules do not know the run's debug level, so warn at the default one
+                    Err(e) => log(
+                        DebugLevel::Warn,
+                        DebugLevel::default(),
+                        &format!(
+                            "{}: ignoring invalid pattern '{}', checking `header` instead: {}",
+                            self.name(),
+                            pattern,
+                            e
+                        ),
+                    ),
+                }
+            }
+        }
+        if let Some(patterns) = config.get("files").and_then(Value::as_array) {
+            let patterns: Vec<&str> = patterns.iter().filter_map(Value::as_str).collect();
+            self.files = (!patterns.is_empty()).then(|| build_glob_set(&patterns));
+        }
+    }
+
+    fn run_on_semantic(
+        &self,
+        semantic_result: &SemanticBuilderReturn,
+        file_path: &str,
+    ) -> Vec<OxcDiagnostic> {
+        if self.header.is_empty() && self.pattern.is_none() {
+            return Vec::new();
+        }
+        if let Some(files) = &self.files
+            && !matches_file_glob(files, file_path)
+        {
+            return Vec::new();
+        }
+
+        let source = semantic_result.semantic.source_text();
+        let start = Self::header_start(source);
+        let text = &source[start..];
+        if self.has_header(text) {
+            return Vec::new();
+        }
+
+        let start = start as u32;
+        let diagnostic = if text.starts_with("//") || text.starts_with("/*") {
+            let first_line = text.lines().next().unwrap_or_default();
+            let span = Span::new(start, start + first_line.len() as u32);
+            OxcDiagnostic::warn("File header does not match the required header")
+                .with_help("Update the header comment to the configured header")
+                .with_label(span.label("Different header"))
+        } else {
+            OxcDiagnostic::warn(MISSING_HEADER_MESSAGE)
+                .with_help("Add the configured header at the top of the file")
+                .with_label(Span::empty(start).label("Header expected here"))
+        };
+        vec![diagnostic]
+    }
+
+    fn fix(&self, diagnostic: &OxcDiagnostic, _source: &str) -> Option<RuleFix> {
+        if diagnostic.message != MISSING_HEADER_MESSAGE || self.header.is_empty() {
+            return None;
+        }
+        let offset = diagnostic.labels.as_ref()?.first()?.offset();
+        Some(RuleFix {
+            span: Span::empty(u32::try_from(offset).ok()?),
+            replacement: format!("{}\n\n", self.header.join("\n")),
+        })
+    }
+}
@@ -0,0 +1,190 @@
+use oxc_ast::AstKind;
+use oxc_ast::ast::{Argument, ArrowFunctionExpression, CallExpression, Function, TryStatement};
+use oxc_ast_visit::{Visit, walk};
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_semantic::{ScopeFlags, SemanticBuilderReturn};
+use oxc_span::Span;
+use serde_json::{Value, json};
+
+use crate::rules::Rule;
+
+/// Rule that flags `JSON.parse` calls without error handling
+///
+/// `JSON.parse` throws a `SyntaxError` on malformed input, which is easy to forget when
+/// the input comes from storage, the network or the user. A call is fine inside the
+/// `try` block of a `try`/`catch`; `try`/`finally` without a `catch` does not count.
+/// Functions start without protection, since a callback declared inside a `try` block
+/// usually runs after the block was left.
+///
+/// ## Rule Details
+///
+/// Examples of **incorrect** code:
+///
+/// ```typescript
+/// const settings = JSON.parse(localStorage.getItem('settings'));
+/// ```
+///
+/// Examples of **correct** code:
+///
+/// ```typescript
+/// try {
+///   settings = JSON.parse(localStorage.getItem('settings'));
+/// } catch {
+///   settings = DEFAULT_SETTINGS;
+/// }
+/// ```
+///
+/// ## Rule Options
+///
+/// - `ignoreStringLiterals`: Allow calls whose argument is a string literal, since their
+///   outcome does not depend on runtime input (default: false)
+pub struct SafeJsonParseRule {
+    /// Allow `JSON.parse('...')` with a literal argument
+    ignore_string_literals: bool,
+}
+
+impl Default for SafeJsonParseRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SafeJsonParseRule {
+    pub fn new() -> Self {
+        Self {
+            ignore_string_literals: false,
+        }
+    }
+
+    fn create_diagnostic(span: Span) -> OxcDiagnostic {
+        OxcDiagnostic::warn("`JSON.parse` call without error handling")
+            .with_help("Wrap the call in try/catch, or validate the input first: JSON.parse throws on malformed input")
+            .with_label(span.label("May throw a SyntaxError"))
+    }
+}
+
+/// Visitor tracking whether calls are inside the `try` block of a `try`/`catch`
+struct JsonParseVisitor {
+    ignore_string_literals: bool,
+    /// Number of enclosing `try` blocks with a `catch` clause, in the current function
+    try_depth: usize,
+    diagnostics: Vec<OxcDiagnostic>,
+}
+
+impl JsonParseVisitor {
+    fn is_json_parse(call: &CallExpression) -> bool {
+        call.callee.as_member_expression().is_some_and(|callee| {
+            callee.object().is_specific_id("JSON") && callee.static_property_name() == Some("parse")
+        })
+    }
+
+    fn has_literal_argument(call: &CallExpression) -> bool {
+        match call.arguments.first() {
+            Some(Argument::StringLiteral(_)) => true,
+            Some(Argument::TemplateLiteral(template)) => template.expressions.is_empty(),
+            _ => false,
+        }
+    }
+
+    /// Visit a function body without the protection of the surrounding `try` blocks
+    fn with_reset_depth(&mut self, visit: impl FnOnce(&mut Self)) {
+        let outer_depth = std::mem::take(&mut self.try_depth);
+        visit(self);
+        self.try_depth = outer_depth;
+    }
+}
+
+impl<'a> Visit<'a> for JsonParseVisitor {
+    fn visit_try_statement(&mut self, it: &TryStatement<'a>) {
+        let catches = it.handler.is_some();
+        if catches {
+            self.try_depth += 1;
+        }
+        self.visit_block_statement(&it.block);
+        if catches {
+            self.try_depth -= 1;
+        }
+
+        if let Some(handler) = &it.handler {
+            self.visit_catch_clause(handler);
+        }
+        if let Some(finalizer) = &it.finalizer {
+            self.visit_block_statement(finalizer);
+        }
+    }
+
+    fn visit_function(&mut self, it: &Function<'a>, flags: ScopeFlags) {
+        self.with_reset_depth(|visitor| walk::walk_function(visitor, it, flags));
+    }
+
+    fn visit_arrow_function_expression(&mut self, it: &ArrowFunctionExpression<'a>) {
+        self.with_reset_depth(|visitor| walk::walk_arrow_function_expression(visitor, it));
+    }
+
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        if Self::is_json_parse(call)
+            && self.try_depth == 0
+            && !(self.ignore_string_literals && Self::has_literal_argument(call))
+        {
+            self.diagnostics
+                .push(SafeJsonParseRule::create_diagnostic(call.span));
+        }
+        walk::walk_call_expression(self, call);
+    }
+}
+
+impl Rule for SafeJsonParseRule {
+    fn name(&self) -> &'static str {
+        "safe-json-parse"
+    }
+
+    fn description(&self) -> &'static str {
+        "Requires `JSON.parse` calls to be wrapped in try/catch"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        &["correctness"]
+    }
+
+    fn config_schema(&self) -> Option<Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "ignoreStringLiterals": { "type": "boolean", "default": false }
+            }
+        }))
+    }
+
+    fn set_config(&mut self, config: Value) {
+        if let Some(ignore) = config.get("ignoreStringLiterals").and_then(Value::as_bool) {
+            self.ignore_string_literals = ignore;
+        }
+    }
+
+    fn run_on_semantic(
+        &self,
+        semantic_result: &SemanticBuilderReturn,
+        _file_path: &str,
+    ) -> Vec<OxcDiagnostic> {
+        let Some(AstKind::Program(program)) = semantic_result
+            .semantic
+            .nodes()
+            .root_node()
+            .map(|node| node.kind())
+        else {
+            return Vec::new();
+        };
+
+        let mut visitor = JsonParseVisitor {
+            ignore_string_literals: self.ignore_string_literals,
+            try_depth: 0,
+            diagnostics: Vec::new(),
+        };
+        visitor.visit_program(program);
+        visitor.diagnostics
+    }
+}
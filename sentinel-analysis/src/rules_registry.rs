@@ -1,14 +1,57 @@
-use oxc_diagnostics::Error;
 use oxc_diagnostics::reporter::Info;
+use oxc_diagnostics::{Error, OxcDiagnostic, Severity};
 use oxc_semantic::SemanticBuilderReturn;
 use oxc_span::GetSpan;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::panic::AssertUnwindSafe;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use std::time::Instant;
 // Import the Rule trait and rule implementations
 use crate::RuleDiagnostic;
+use crate::error::SentinelError;
+use crate::profiling;
 pub use crate::rules::Rule;
-pub use crate::rules::{NoDebuggerRule, NoEmptyPatternRule};
+pub use crate::rules::{
+    EqeqeqRule, IdentifierLengthRule, ImportsFirstRule, MaxExportsPerFileRule, MemberOrderingRule,
+    NoAsAnyRule, NoCircularTypeRefRule, NoCommentedCodeRule, NoDebuggerRule, NoDefaultExportRule,
+    NoDeprecatedRxjsRule, NoDirectDateRule, NoDuplicateKeysRule, NoEmptyModuleRule,
+    NoEmptyPatternRule, NoEnumRule, NoHardcodedSecretsRule, NoMisusedPromiseAllRule,
+    NoPermissiveCorsRule, NoRedundantAsyncRule, NoRedundantPublicRule, NoScatteredEnvAccessRule,
+    NoShadowRule, NoTsIgnoreRule, NoUnreachableRule, NoUnusedPrivateMembersRule, NoVarRule,
+    NoZeroDelayTimerRule, NumericSeparatorsRule, PreferConstAssertionRule,
+    RequireAsyncErrorHandlingRule, RequireAwaitOnAsyncRule, RequireFileHeaderRule,
+    SafeJsonParseRule,
+};
+use crate::utilities::file_utils::to_relative_path;
+
+/// Rule ID of the diagnostic reported when a rule panics on a file
+pub const RULE_ERROR_ID: &str = "rule-error";
+
+/// Run a rule, turning a panic into its message so the other rules can still run
+fn catch_rule_panic<T>(run: impl FnOnce() -> T) -> Result<T, String> {
+    std::panic::catch_unwind(AssertUnwindSafe(run)).map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string())
+    })
+}
+
+/// Diagnostic standing in for the findings of a rule that panicked on a file
+fn rule_error_diagnostic(rule_name: &str, message: &str, source_code: &str) -> RuleDiagnostic {
+    RuleDiagnostic {
+        rule_id: RULE_ERROR_ID.to_string(),
+        diagnostic: OxcDiagnostic::error(format!("Rule `{}` panicked: {}", rule_name, message))
+            .with_help("The rule was skipped for the rest of this file; this is a bug in the rule"),
+        source_code: source_code.to_string(),
+        line_number: 0,
+        column_number: 0,
+    }
+}
 
 /// The result of running a rule on a file
 pub struct RuleResult {
@@ -20,8 +63,21 @@ pub struct RuleResult {
 /// A registry for all available rules
 pub struct RulesRegistry {
     rules: HashMap<&'static str, Box<dyn Rule>>,
-    enabled_rules: HashSet<String>,
+    /// Enabled rule names, kept sorted so iteration is stable across runs
+    enabled_rules: BTreeSet<String>,
     rule_severity: HashMap<String, String>,
+    /// Execution priority per rule (higher runs first, default 0)
+    rule_priority: HashMap<String, i64>,
+    /// Enabled rules in execution order, sorted by priority and then by name
+    execution_order: Vec<String>,
+    /// Stop running further rules on a file after its first error-severity diagnostic
+    fail_fast_per_file: bool,
+    /// Run rules on the recovered AST of files with syntax errors
+    continue_on_parse_error: bool,
+    /// Skip files none of the enabled rules applies to, before parsing them
+    prefilter: bool,
+    /// Scan root the file paths passed to rules are made relative to
+    path_root: Option<String>,
 }
 
 impl RulesRegistry {
@@ -29,11 +85,28 @@ impl RulesRegistry {
     pub fn new() -> Self {
         Self {
             rules: HashMap::new(),
-            enabled_rules: HashSet::new(),
+            enabled_rules: BTreeSet::new(),
             rule_severity: HashMap::new(),
+            rule_priority: HashMap::new(),
+            execution_order: Vec::new(),
+            fail_fast_per_file: false,
+            continue_on_parse_error: false,
+            prefilter: false,
+            path_root: None,
         }
     }
 
+    /// Recompute the execution order after the enabled set or priorities changed
+    fn refresh_execution_order(&mut self) {
+        let mut order: Vec<String> = self.enabled_rules.iter().cloned().collect();
+        order.sort_by(|a, b| {
+            let priority_a = self.rule_priority.get(a).copied().unwrap_or(0);
+            let priority_b = self.rule_priority.get(b).copied().unwrap_or(0);
+            priority_b.cmp(&priority_a).then_with(|| a.cmp(b))
+        });
+        self.execution_order = order;
+    }
+
     /// Register a rule with the registry
     pub fn register_rule(&mut self, rule: Box<dyn Rule>) {
         let rule_name = rule.name();
@@ -43,6 +116,7 @@ impl RulesRegistry {
     /// Enable a rule by name
     pub fn enable_rule(&mut self, rule_name: &str) {
         self.enabled_rules.insert(rule_name.to_string());
+        self.refresh_execution_order();
     }
 
     /// Enable multiple rules by name
@@ -55,6 +129,7 @@ impl RulesRegistry {
     /// Disable a rule by name
     pub fn disable_rule(&mut self, rule_name: &str) {
         self.enabled_rules.remove(rule_name);
+        self.refresh_execution_order();
     }
 
     /// Check if a rule is enabled
@@ -69,6 +144,11 @@ impl RulesRegistry {
         self.rules.keys().cloned().collect()
     }
 
+    /// Get a registered rule by name
+    pub fn get_rule(&self, rule_name: &str) -> Option<&dyn Rule> {
+        self.rules.get(rule_name).map(|rule| rule.as_ref())
+    }
+
     /// Set the severity for a rule
     pub fn set_rule_severity(&mut self, rule_name: &str, severity: &str) {
         self.rule_severity
@@ -80,11 +160,74 @@ impl RulesRegistry {
         self.rule_severity.get(rule_name)
     }
 
-    /// Get all enabled rules
+    /// Get all enabled rules, sorted by name
     pub fn get_enabled_rules(&self) -> Vec<String> {
         self.enabled_rules.iter().cloned().collect()
     }
 
+    /// Set the execution priority for a rule (higher runs first)
+    ///
+    /// Node-based rules are still dispatched node by node, so priority orders
+    /// rules within each node rather than across the whole file.
+    pub fn set_rule_priority(&mut self, rule_name: &str, priority: i64) {
+        self.rule_priority.insert(rule_name.to_string(), priority);
+        self.refresh_execution_order();
+    }
+
+    /// Get the enabled rules in the order they are executed
+    pub fn get_execution_order(&self) -> &[String] {
+        &self.execution_order
+    }
+
+    /// Stop running further rules on a file once an error-severity diagnostic is produced
+    pub fn set_fail_fast_per_file(&mut self, enabled: bool) {
+        self.fail_fast_per_file = enabled;
+    }
+
+    /// Check whether fail-fast per file is enabled
+    pub fn is_fail_fast_per_file(&self) -> bool {
+        self.fail_fast_per_file
+    }
+
+    /// Run rules on files with recoverable syntax errors instead of skipping them
+    pub fn set_continue_on_parse_error(&mut self, enabled: bool) {
+        self.continue_on_parse_error = enabled;
+    }
+
+    /// Check whether rules run on files with recoverable syntax errors
+    pub fn is_continue_on_parse_error(&self) -> bool {
+        self.continue_on_parse_error
+    }
+
+    /// Skip files that no enabled rule applies to, see [`Rule::applies_to_source`]
+    pub fn set_prefilter(&mut self, enabled: bool) {
+        self.prefilter = enabled;
+    }
+
+    /// Check whether files are prefiltered by the applicability of the enabled rules
+    pub fn is_prefilter(&self) -> bool {
+        self.prefilter
+    }
+
+    /// Pass file paths to rules relative to the scan root, so their file globs match
+    /// root-relative paths such as `src/**`
+    pub fn set_path_root(&mut self, path_root: Option<String>) {
+        self.path_root = path_root;
+    }
+
+    /// Get the scan root the file paths passed to rules are relative to
+    pub fn path_root(&self) -> Option<&str> {
+        self.path_root.as_deref()
+    }
+
+    /// Check whether any enabled rule may report on a source text
+    pub fn any_rule_applies_to(&self, source: &str) -> bool {
+        self.execution_order
+            .iter()
+            .filter_map(|rule_name| self.rules.get(rule_name.as_str()))
+            .any(|rule| rule.applies_to_source(source))
+    }
+
     /// Run all enabled rules on a file's semantic analysis and get metrics by rule
     pub fn run_rules_with_metrics(
         &self,
@@ -95,31 +238,66 @@ impl RulesRegistry {
         let mut diagnostics = Vec::new();
         let mut rule_durations = HashMap::new();
 
+        // Rules see the path relative to the scan root, like the exported findings
+        let relative_path = self
+            .path_root
+            .as_deref()
+            .map(|root| to_relative_path(file_path, root));
+        let file_path = relative_path.as_deref().unwrap_or(file_path);
+
+        // Set once a rule reports an error and fail-fast is enabled
+        let mut stop = false;
+
+        // Rules that panicked on this file are not run again on it
+        let mut failed_rules: HashSet<&str> = HashSet::new();
+
         // Only process if we have rules enabled
         if !self.enabled_rules.is_empty() {
             // First, run visitor-based rules
-            for rule_name in &self.enabled_rules {
+            for rule_name in &self.execution_order {
                 if let Some(rule) = self.rules.get(rule_name.as_str()) {
                     // Time the rule execution
                     let rule_start = Instant::now();
 
                     // Run visitor-based analysis
-                    let visitor_diagnostics = rule.run_on_semantic(semantic_result, file_path);
+                    let visitor_diagnostics = match catch_rule_panic(|| {
+                        profiling::in_span(rule.name(), || {
+                            rule.run_on_semantic(semantic_result, file_path)
+                        })
+                    }) {
+                        Ok(visitor_diagnostics) => visitor_diagnostics,
+                        Err(message) => {
+                            diagnostics.push(rule_error_diagnostic(
+                                rule_name,
+                                &message,
+                                source_code,
+                            ));
+                            failed_rules.insert(rule_name);
+                            Vec::new()
+                        }
+                    };
 
                     // Wrap each diagnostic with rule ID
                     for diagnostic in visitor_diagnostics {
+                        stop |= self.fail_fast_per_file && diagnostic.severity == Severity::Error;
+                        let error = diagnostic.clone().with_source_code(source_code.to_string());
+                        let (line, column) = extract_position_info(&error);
                         diagnostics.push(RuleDiagnostic {
                             rule_id: rule_name.clone(),
                             diagnostic,
                             source_code: source_code.to_string(),
-                            column_number: 0,
-                            line_number: 0,
+                            column_number: column,
+                            line_number: line,
                         });
                     }
 
-                    // Record the time taken locally
+                    // Record the time taken locally; node-based runs add to it below
                     let duration = rule_start.elapsed();
                     rule_durations.insert(rule_name.to_string(), duration);
+
+                    if stop {
+                        return (diagnostics, rule_durations);
+                    }
                 }
             }
 
@@ -140,41 +318,65 @@ impl RulesRegistry {
 
             // >>> Section 2: Run traditional node-based rules (Conditionally) <<<
             if has_node_based_rules {
+                // Node-based rules run interleaved, so they share one span for all nodes
+                let _span = profiling::span("node_rules");
                 for node in semantic_result.semantic.nodes() {
                     let node_kind = node.kind();
                     let span = node.span();
 
                     // Run each enabled rule on this node
-                    for rule_name in &self.enabled_rules {
+                    for rule_name in &self.execution_order {
+                        if failed_rules.contains(rule_name.as_str()) {
+                            continue;
+                        }
                         if let Some(rule) = self.rules.get(rule_name.as_str()) {
                             // Time the rule execution
                             let rule_start = Instant::now();
 
                             // Run the rule
-                            let diagnostics_vec = rule.run_on_node(&node_kind, span, &file_path);
+                            let diagnostics_vec = match catch_rule_panic(|| {
+                                rule.run_on_node(&node_kind, span, file_path)
+                            }) {
+                                Ok(diagnostics_vec) => diagnostics_vec,
+                                Err(message) => {
+                                    diagnostics.push(rule_error_diagnostic(
+                                        rule_name,
+                                        &message,
+                                        source_code,
+                                    ));
+                                    failed_rules.insert(rule_name);
+                                    Vec::new()
+                                }
+                            };
 
-                            // Record the time taken *only if* a diagnostic was produced
+                            // Add the time of every node to the rule's total for the file
                             let duration = rule_start.elapsed();
-
-                            if !diagnostics_vec.is_empty() {
-                                // Record time only when rule yielded results for this node
-                                rule_durations.insert(rule_name.to_string(), duration);
-
-                                // Add all diagnostics from the Vec to your collection
-                                for diagnostic in diagnostics_vec {
-                                    let error = diagnostic
-                                        .clone()
-                                        .with_source_code(source_code.to_string());
-                                    let (line, column) = extract_position_info(&error);
-                                    diagnostics.push(RuleDiagnostic {
-                                        rule_id: rule_name.clone(),
-                                        diagnostic,
-                                        source_code: source_code.to_string(),
-                                        line_number: line,
-                                        column_number: column,
-                                    });
+                            match rule_durations.get_mut(rule_name.as_str()) {
+                                Some(total) => *total += duration,
+                                None => {
+                                    rule_durations.insert(rule_name.to_string(), duration);
                                 }
                             }
+
+                            // Add all diagnostics from the Vec to your collection
+                            for diagnostic in diagnostics_vec {
+                                stop |= self.fail_fast_per_file
+                                    && diagnostic.severity == Severity::Error;
+                                let error =
+                                    diagnostic.clone().with_source_code(source_code.to_string());
+                                let (line, column) = extract_position_info(&error);
+                                diagnostics.push(RuleDiagnostic {
+                                    rule_id: rule_name.clone(),
+                                    diagnostic,
+                                    source_code: source_code.to_string(),
+                                    line_number: line,
+                                    column_number: column,
+                                });
+                            }
+                        }
+
+                        if stop {
+                            return (diagnostics, rule_durations);
                         }
                     }
                 }
@@ -192,6 +394,38 @@ pub fn create_default_registry() -> RulesRegistry {
     // Register built-in rules
     registry.register_rule(Box::new(NoDebuggerRule));
     registry.register_rule(Box::new(NoEmptyPatternRule));
+    registry.register_rule(Box::new(NoUnreachableRule));
+    registry.register_rule(Box::new(MaxExportsPerFileRule::new()));
+    registry.register_rule(Box::new(NoShadowRule::new()));
+    registry.register_rule(Box::new(NoScatteredEnvAccessRule::new()));
+    registry.register_rule(Box::new(NoAsAnyRule));
+    registry.register_rule(Box::new(RequireAwaitOnAsyncRule::new()));
+    registry.register_rule(Box::new(MemberOrderingRule::new()));
+    registry.register_rule(Box::new(NoEmptyModuleRule::new()));
+    registry.register_rule(Box::new(NoDefaultExportRule::new()));
+    registry.register_rule(Box::new(NoCommentedCodeRule::new()));
+    registry.register_rule(Box::new(NoDeprecatedRxjsRule::new()));
+    registry.register_rule(Box::new(PreferConstAssertionRule));
+    registry.register_rule(Box::new(NoEnumRule::new()));
+    registry.register_rule(Box::new(SafeJsonParseRule::new()));
+    registry.register_rule(Box::new(IdentifierLengthRule::new()));
+    registry.register_rule(Box::new(NoDirectDateRule::new()));
+    registry.register_rule(Box::new(NumericSeparatorsRule::new()));
+    registry.register_rule(Box::new(RequireAsyncErrorHandlingRule::new()));
+    registry.register_rule(Box::new(NoRedundantPublicRule::new()));
+    registry.register_rule(Box::new(NoTsIgnoreRule::new()));
+    registry.register_rule(Box::new(NoZeroDelayTimerRule::new()));
+    registry.register_rule(Box::new(RequireFileHeaderRule::new()));
+    registry.register_rule(Box::new(ImportsFirstRule::new()));
+    registry.register_rule(Box::new(EqeqeqRule::new()));
+    registry.register_rule(Box::new(NoVarRule::new()));
+    registry.register_rule(Box::new(NoUnusedPrivateMembersRule::new()));
+    registry.register_rule(Box::new(NoHardcodedSecretsRule::new()));
+    registry.register_rule(Box::new(NoMisusedPromiseAllRule::new()));
+    registry.register_rule(Box::new(NoRedundantAsyncRule::new()));
+    registry.register_rule(Box::new(NoCircularTypeRefRule::new()));
+    registry.register_rule(Box::new(NoDuplicateKeysRule));
+    registry.register_rule(Box::new(NoPermissiveCorsRule::new()));
 
     // Register custom rules if the feature is enabled
     #[cfg(feature = "custom_rules")]
@@ -208,16 +442,12 @@ pub fn create_default_registry() -> RulesRegistry {
 /// Load a rule configuration from a JSON file
 pub fn load_rule_config(
     path: &str,
-) -> Result<Vec<(String, Option<serde_json::Value>, String)>, String> {
-    let content = match std::fs::read_to_string(path) {
-        Ok(content) => content,
-        Err(err) => return Err(format!("Failed to read config file: {}", err)),
-    };
+) -> Result<Vec<(String, Option<serde_json::Value>, String)>, SentinelError> {
+    let content =
+        std::fs::read_to_string(path).map_err(|err| SentinelError::io("read", path, err))?;
 
-    let config: serde_json::Value = match serde_json::from_str(&content) {
-        Ok(config) => config,
-        Err(err) => return Err(format!("Failed to parse config file: {}", err)),
-    };
+    let config: serde_json::Value =
+        serde_json::from_str(&content).map_err(|err| SentinelError::parse(path, err))?;
 
     if let Some(rules) = config.get("rules") {
         if let Some(rules_obj) = rules.as_object() {
@@ -247,7 +477,10 @@ pub fn load_rule_config(
                     }
                     // Invalid format
                     _ => {
-                        return Err(format!("Invalid rule configuration for '{}'", rule_name));
+                        return Err(SentinelError::ConfigValidation(format!(
+                            "invalid rule configuration for '{}'",
+                            rule_name
+                        )));
                     }
                 }
             }
@@ -255,7 +488,40 @@ pub fn load_rule_config(
         }
     }
 
-    Err("Config file does not contain a valid 'rules' object".to_string())
+    Err(SentinelError::ConfigValidation(format!(
+        "{} does not contain a valid 'rules' object",
+        path
+    )))
+}
+
+/// Load the per-rule finding budgets of a rule configuration file
+///
+/// `budgets` maps rule names to the maximum number of findings the rule may report in a
+/// run. A configuration without budgets yields an empty map.
+pub fn load_rule_budgets(path: &str) -> Result<HashMap<String, usize>, SentinelError> {
+    let content =
+        std::fs::read_to_string(path).map_err(|err| SentinelError::io("read", path, err))?;
+
+    let config: serde_json::Value =
+        serde_json::from_str(&content).map_err(|err| SentinelError::parse(path, err))?;
+
+    let Some(budgets) = config.get("budgets") else {
+        return Ok(HashMap::new());
+    };
+    let budgets = budgets.as_object().ok_or_else(|| {
+        SentinelError::ConfigValidation(format!("{}: 'budgets' must be an object", path))
+    })?;
+
+    budgets
+        .iter()
+        .map(|(rule_name, budget)| match budget.as_u64() {
+            Some(budget) => Ok((rule_name.clone(), budget as usize)),
+            None => Err(SentinelError::ConfigValidation(format!(
+                "invalid budget for '{}': expected a non-negative integer",
+                rule_name
+            ))),
+        })
+        .collect()
 }
 
 /// Configure a registry from a list of rule names, configs, and severities
@@ -275,13 +541,227 @@ pub fn configure_registry(
 
         // If configuration is provided, set it on the rule
         if let Some(config) = rule_config {
-            if let Some(rule) = registry.rules.get_mut(rule_name.as_str()) {
-                rule.set_config(config.clone());
+            apply_rule_config(registry, rule_name, config);
+        }
+    }
+}
+
+/// Pass the options of a rule to the rule, and its `priority` to the registry
+fn apply_rule_config(registry: &mut RulesRegistry, rule_name: &str, config: &serde_json::Value) {
+    // `priority` is handled by the registry rather than the rule itself
+    if let Some(priority) = config.get("priority").and_then(|p| p.as_i64()) {
+        registry.set_rule_priority(rule_name, priority);
+    }
+
+    if let Some(rule) = registry.rules.get_mut(rule_name) {
+        rule.set_config(config.clone());
+    }
+}
+
+/// Options for one rule passed on the command line as `rule=json`
+#[derive(Debug, Clone, PartialEq)]
+pub struct InlineRuleConfig {
+    pub rule: String,
+    pub config: serde_json::Value,
+}
+
+impl FromStr for InlineRuleConfig {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (rule, config) = s
+            .split_once('=')
+            .ok_or_else(|| format!("Invalid rule config '{}', expected <rule>=<json>", s))?;
+        let rule = rule.trim();
+        if rule.is_empty() {
+            return Err(format!(
+                "Invalid rule config '{}', the rule name is missing",
+                s
+            ));
+        }
+
+        let config: serde_json::Value = serde_json::from_str(config)
+            .map_err(|e| format!("Invalid JSON in rule config for {}: {}", rule, e))?;
+        if !config.is_object() {
+            return Err(format!(
+                "Invalid rule config for {}, expected a JSON object",
+                rule
+            ));
+        }
+
+        Ok(InlineRuleConfig {
+            rule: rule.to_string(),
+            config,
+        })
+    }
+}
+
+/// Apply rule options passed on the command line, enabling rules that are not enabled yet
+///
+/// Returns the names of rules that are not registered; their options are ignored.
+pub fn apply_inline_rule_configs(
+    registry: &mut RulesRegistry,
+    inline_configs: &[InlineRuleConfig],
+) -> Vec<String> {
+    let mut unknown = Vec::new();
+    for inline in inline_configs {
+        if registry.get_rule(&inline.rule).is_none() {
+            unknown.push(inline.rule.clone());
+            continue;
+        }
+        if !registry.is_rule_enabled(&inline.rule) {
+            registry.enable_rule(&inline.rule);
+            registry.set_rule_severity(&inline.rule, "error");
+        }
+        apply_rule_config(registry, &inline.rule, &inline.config);
+    }
+    unknown
+}
+
+/// Rule configuration files looked up in each directory, in order of precedence
+pub const NESTED_CONFIG_FILE_NAMES: &[&str] = &["sentinel.json", "rules.json"];
+
+/// Resolves the registry used for a file from the nearest directory rule configuration
+///
+/// Without nested configs every file uses the root registry. With nested configs the
+/// directories between a file and the scan root are searched for a rule configuration
+/// file; the nearest one replaces the root configuration entirely for that subtree.
+/// Files without a `rules` object, such as a `sentinel.json` holding only tool settings,
+/// are passed over. The scan root itself is covered by the root configuration.
+/// Each directory is resolved once and cached, so a configuration file is parsed a
+/// single time no matter how many files it applies to.
+pub struct RegistryResolver {
+    root_registry: Arc<RulesRegistry>,
+    /// Scan root bounding the upward search, `None` when nested configs are disabled
+    scan_root: Option<PathBuf>,
+    /// Resolved registry per directory
+    cache: Mutex<HashMap<PathBuf, Arc<RulesRegistry>>>,
+    /// `--rule-config` options, applied on top of every nested configuration
+    inline_rule_configs: Vec<InlineRuleConfig>,
+    debug_level: DebugLevel,
+}
+
+impl RegistryResolver {
+    /// Create a resolver that uses the root registry for every file
+    pub fn single(root_registry: Arc<RulesRegistry>) -> Self {
+        Self {
+            root_registry,
+            scan_root: None,
+            cache: Mutex::new(HashMap::new()),
+            inline_rule_configs: Vec::new(),
+            debug_level: DebugLevel::None,
+        }
+    }
+
+    /// Create a resolver honoring rule configuration files below the scan root
+    pub fn nested(
+        root_registry: Arc<RulesRegistry>,
+        scan_root: &str,
+        debug_level: DebugLevel,
+    ) -> Self {
+        Self {
+            root_registry,
+            scan_root: Some(PathBuf::from(scan_root)),
+            cache: Mutex::new(HashMap::new()),
+            inline_rule_configs: Vec::new(),
+            debug_level,
+        }
+    }
+
+    /// Apply `--rule-config` options to the registries built from nested configurations
+    pub fn with_inline_rule_configs(mut self, inline_rule_configs: Vec<InlineRuleConfig>) -> Self {
+        self.inline_rule_configs = inline_rule_configs;
+        self
+    }
+
+    /// Get the registry that applies to a file
+    pub fn registry_for(&self, file_path: &str) -> Arc<RulesRegistry> {
+        let Some(scan_root) = &self.scan_root else {
+            return Arc::clone(&self.root_registry);
+        };
+        let Some(dir) = Path::new(file_path).parent() else {
+            return Arc::clone(&self.root_registry);
+        };
+
+        let mut cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+
+        // Walk up until a cached directory, a configuration file or the scan root is found
+        let mut unresolved = Vec::new();
+        let mut current = Some(dir);
+        let resolved = loop {
+            let Some(dir) = current.filter(|dir| dir.starts_with(scan_root) && *dir != scan_root)
+            else {
+                break Arc::clone(&self.root_registry);
+            };
+            if let Some(registry) = cache.get(dir) {
+                break Arc::clone(registry);
+            }
+            unresolved.push(dir.to_path_buf());
+
+            if let Some(config_path) = NESTED_CONFIG_FILE_NAMES
+                .iter()
+                .map(|name| dir.join(name))
+                .find(|path| defines_rules(path))
+            {
+                break self.load_registry(&config_path);
+            }
+            current = dir.parent();
+        };
+
+        for dir in unresolved {
+            cache.insert(dir, Arc::clone(&resolved));
+        }
+        resolved
+    }
+
+    /// Build a registry from a nested configuration file, falling back to the root registry
+    fn load_registry(&self, config_path: &Path) -> Arc<RulesRegistry> {
+        let path = config_path.to_string_lossy();
+        match load_rule_config(&path) {
+            Ok(enabled_rules) => {
+                log(
+                    DebugLevel::Debug,
+                    self.debug_level,
+                    &format!("Using nested rules configuration {}", path),
+                );
+                let mut registry = create_default_registry();
+                registry.set_fail_fast_per_file(self.root_registry.is_fail_fast_per_file());
+                registry
+                    .set_continue_on_parse_error(self.root_registry.is_continue_on_parse_error());
+                registry.set_prefilter(self.root_registry.is_prefilter());
+                registry.set_path_root(self.root_registry.path_root().map(str::to_string));
+                configure_registry(&mut registry, &enabled_rules);
+                // Unknown rules were already reported for the root registry
+                apply_inline_rule_configs(&mut registry, &self.inline_rule_configs);
+                Arc::new(registry)
+            }
+            Err(err) => {
+                log(
+                    DebugLevel::Error,
+                    self.debug_level,
+                    &format!("Ignoring nested rules configuration: {}", err),
+                );
+                Arc::clone(&self.root_registry)
             }
         }
     }
 }
 
+/// Check whether a file may hold a rule configuration
+///
+/// Files that are not valid JSON count, so their error is reported when loading them.
+fn defines_rules(path: &Path) -> bool {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return false;
+    };
+    let Ok(config) = serde_json::from_str::<serde_json::Value>(&content) else {
+        return true;
+    };
+    config
+        .get("rules")
+        .is_some_and(serde_json::Value::is_object)
+}
+
 use crate::utilities::config::Config;
 /// Add the rule registry setup functions from main.rs at the end of the file
 use crate::utilities::{DebugLevel, log};
@@ -293,6 +773,10 @@ pub fn setup_rules_registry(
     debug_level: DebugLevel,
 ) -> RulesRegistry {
     let mut registry = create_default_registry();
+    registry.set_fail_fast_per_file(config.fail_fast_per_file.unwrap_or(false));
+    registry.set_continue_on_parse_error(config.continue_on_parse_error.unwrap_or(false));
+    registry.set_prefilter(config.prefilter.unwrap_or(false));
+    registry.set_path_root(config.path.clone());
 
     // Apply configuration in order of priority
     if let Some(rules) = super::utilities::config::get_enabled_rules(args) {
@@ -318,10 +802,45 @@ pub fn setup_rules_registry(
         );
     }
 
+    // Inline rule options override those of the configuration file
+    for rule in apply_inline_rule_configs(&mut registry, &config.rule_configs) {
+        log(
+            DebugLevel::Warn,
+            debug_level,
+            &format!("Ignoring --rule-config for unknown rule {}", rule),
+        );
+    }
+
     registry
 }
 
-fn extract_position_info(error: &Error) -> (usize, usize) {
+/// Set up how each file's registry is resolved, honoring nested configs when enabled
+pub fn setup_registry_resolver(
+    config: &Config,
+    root_registry: Arc<RulesRegistry>,
+    scan_root: &str,
+    args: &[String],
+    debug_level: DebugLevel,
+) -> RegistryResolver {
+    if !config.nested_configs.unwrap_or(false) {
+        return RegistryResolver::single(root_registry);
+    }
+
+    // Rules passed on the command line apply to every file
+    if super::utilities::config::get_enabled_rules(args).is_some() {
+        log(
+            DebugLevel::Info,
+            debug_level,
+            "Command line rules take precedence: ignoring nested rule configurations",
+        );
+        return RegistryResolver::single(root_registry);
+    }
+
+    RegistryResolver::nested(root_registry, scan_root, debug_level)
+        .with_inline_rule_configs(config.rule_configs.clone())
+}
+
+pub(crate) fn extract_position_info(error: &Error) -> (usize, usize) {
     let info = Info::new(error);
     return (info.start.line, info.start.column);
 }
@@ -0,0 +1,39 @@
+use crate::error::SentinelError;
+use flate2::read::GzDecoder;
+use std::fs::File;
+use std::io::Read;
+use tempfile::TempDir;
+
+/// Archive suffixes accepted as scan path, gzip-compressed ones first
+const GZIP_SUFFIXES: &[&str] = &[".tar.gz", ".tgz"];
+const TAR_SUFFIX: &str = ".tar";
+
+/// Check whether a scan path names a tar archive (`.tar`, `.tar.gz` or `.tgz`)
+pub fn is_archive_path(path: &str) -> bool {
+    let lower = path.to_lowercase();
+    GZIP_SUFFIXES.iter().any(|suffix| lower.ends_with(suffix)) || lower.ends_with(TAR_SUFFIX)
+}
+
+/// Extract a tar archive into a new temporary directory
+///
+/// The directory is removed when the returned `TempDir` is dropped. Entries that would be
+/// written outside the directory (absolute paths or `..` components) are skipped.
+pub fn extract_archive(path: &str) -> Result<TempDir, SentinelError> {
+    let file = File::open(path).map_err(|e| SentinelError::io("open archive", path, e))?;
+    let lower = path.to_lowercase();
+    let reader: Box<dyn Read> = if GZIP_SUFFIXES.iter().any(|suffix| lower.ends_with(suffix)) {
+        Box::new(GzDecoder::new(file))
+    } else {
+        Box::new(file)
+    };
+
+    let dir = tempfile::Builder::new()
+        .prefix("sentinel-archive-")
+        .tempdir()
+        .map_err(|e| SentinelError::io("create temporary directory for", path, e))?;
+    tar::Archive::new(reader)
+        .unpack(dir.path())
+        .map_err(|e| SentinelError::io("extract archive", path, e))?;
+
+    Ok(dir)
+}
@@ -1,5 +1,9 @@
+use crate::ast_stats::AstStatsFormat;
+use crate::exporter::{OutputFormat, SeverityLevel, TableStyle};
+use crate::rules_registry::InlineRuleConfig;
 use crate::utilities::DebugLevel;
-use clap::{Arg, ArgAction, Command};
+use crate::utilities::file_utils::Shard;
+use clap::{Arg, ArgAction, ArgGroup, Command};
 
 /// Parse command-line arguments using clap
 pub fn parse_args() -> Command {
@@ -37,7 +41,7 @@ pub fn parse_args() -> Command {
             Arg::new("output-dir")
                 .short('o')
                 .long("output-dir")
-                .help("Directory to store findings.json and other output files")
+                .help("Directory to store findings.json and other output files (`-` prints findings to stdout)")
                 .value_name("DIR"),
         )
         .arg(
@@ -56,8 +60,9 @@ pub fn parse_args() -> Command {
             Arg::new("severity")
                 .short('s')
                 .long("severity")
-                .help("Minimum severity level to report (error, warning, info)")
-                .value_name("LEVEL"),
+                .help("Minimum severity of the findings printed by --format github (error, warning, info)")
+                .value_name("LEVEL")
+                .value_parser(clap::value_parser!(SeverityLevel)),
         )
         .arg(
             Arg::new("enable-rule")
@@ -93,6 +98,80 @@ pub fn parse_args() -> Command {
                 .help("Export rule findings to a JSON file")
                 .value_name("FILE"),
         )
+        .arg(
+            Arg::new("combined-report")
+                .long("combined-report")
+                .help("Write findings, summary and performance metrics into one JSON file")
+                .value_name("FILE"),
+        )
+        .arg(
+            Arg::new("export-graph")
+                .long("export-graph")
+                .help("Write the import graph of the analyzed files as JSON, or DOT for .dot/.gv files")
+                .value_name("FILE"),
+        )
+        .arg(
+            Arg::new("metrics-environment")
+                .long("metrics-environment")
+                .help("Record CPU model, core count, total memory and OS in the metrics JSON")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("rule-file-timings")
+                .long("rule-file-timings")
+                .help("Write the N slowest rule and file combinations to rule_file_timings.json")
+                .value_name("N")
+                .value_parser(clap::value_parser!(u64).range(1..)),
+        )
+        .arg(
+            Arg::new("table-style")
+                .long("table-style")
+                .help("Style of the rule hit summary table: ascii (default), markdown or minimal")
+                .value_name("STYLE")
+                .value_parser(clap::value_parser!(TableStyle)),
+        )
+        .arg(
+            Arg::new("top-files")
+                .long("top-files")
+                .help("Number of files in the most violated files table (default: 10, 0 to hide it)")
+                .value_name("N")
+                .value_parser(clap::value_parser!(u64)),
+        )
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .help("Also print findings to stdout: text (default, summary only) or github annotations")
+                .value_name("FORMAT")
+                .value_parser(clap::value_parser!(OutputFormat)),
+        )
+        .arg(
+            Arg::new("ast-stats")
+                .long("ast-stats")
+                .help("Count the AST node kinds of the analyzed files instead of running rules, printed as a table or with --ast-stats=json as JSON")
+                .value_name("FORMAT")
+                .num_args(0..=1)
+                .require_equals(true)
+                .default_missing_value("table")
+                .value_parser(clap::value_parser!(AstStatsFormat)),
+        )
+        .arg(
+            Arg::new("porcelain")
+                .long("porcelain")
+                .help("Print findings and the summary to stdout as stable, versioned SENTINEL<TAB><kind><TAB><json> lines")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("errors-to-stderr")
+                .long("errors-to-stderr")
+                .help("Print error-severity findings of the summary and annotations to stderr")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("include-snippets")
+                .long("include-snippets")
+                .help("Attach the offending source lines and some context to each finding")
+                .action(ArgAction::SetTrue),
+        )
         .arg(
             Arg::new("rules")
                 .short('r')
@@ -100,18 +179,221 @@ pub fn parse_args() -> Command {
                 .help("Comma-separated list of rules to enable")
                 .value_name("RULES"),
         )
+        .arg(
+            Arg::new("config")
+                .long("config")
+                .help("Path to sentinel.json, instead of searching the default locations")
+                .value_name("FILE")
+                .global(true),
+        )
         .arg(
             Arg::new("rules-config")
                 .long("rules-config")
                 .help("Path to rules configuration file")
                 .value_name("FILE"),
         )
+        .arg(
+            Arg::new("rule-config")
+                .long("rule-config")
+                .help("Options for one rule as <rule>=<json>, e.g. 'angular-input-count={\"maxInputs\":8}' (can be used multiple times)")
+                .value_name("RULE=JSON")
+                .value_parser(clap::value_parser!(InlineRuleConfig))
+                .action(ArgAction::Append),
+        )
+        .arg(
+            Arg::new("relative-paths")
+                .long("relative-paths")
+                .help("Emit file paths in findings relative to the scan root (default: true)")
+                .value_name("BOOL")
+                .value_parser(clap::value_parser!(bool)),
+        )
+        .arg(
+            Arg::new("fail-fast-per-file")
+                .long("fail-fast-per-file")
+                .help("Stop running further rules on a file after its first error-severity finding")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("continue-on-parse-error")
+                .long("continue-on-parse-error")
+                .help("Run rules on files with syntax errors, using the parser's recovered AST")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("prefilter")
+                .long("prefilter")
+                .help("Skip files none of the enabled rules applies to, without parsing them")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("experimental-sfc")
+                .long("experimental-sfc")
+                .help("Also analyze the <script> block of .vue and .svelte files (experimental)")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("follow-symlinks")
+                .long("follow-symlinks")
+                .help("Follow symbolic links while scanning (cycles and duplicate files are skipped)")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("incremental-findings")
+                .long("incremental-findings")
+                .help("Append the findings of each finished batch to findings.ndjson, so a crash loses at most one batch")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("no-sort-files")
+                .long("no-sort-files")
+                .help("Analyze files in discovery order instead of sorting them by path")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("nested-configs")
+                .long("nested-configs")
+                .help("Apply the nearest sentinel.json/rules.json below the scan root to each file")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("shard")
+                .long("shard")
+                .help("Analyze only one deterministic slice of the files, e.g. 2/4")
+                .value_name("INDEX/TOTAL")
+                .value_parser(clap::value_parser!(Shard)),
+        )
+        .arg(
+            Arg::new("files-from")
+                .long("files-from")
+                .help("Analyze the files listed in this file, one path per line, instead of scanning PATH")
+                .value_name("FILE"),
+        )
         .arg(
             Arg::new("threads")
                 .long("threads")
                 .help("Number of threads to use for parallel processing")
                 .value_name("NUM"),
         )
+        .arg(
+            Arg::new("read-concurrency")
+                .long("read-concurrency")
+                .help("Maximum number of files read at the same time, e.g. for network filesystems")
+                .value_name("NUM")
+                .value_parser(clap::value_parser!(u64).range(1..)),
+        )
+        .subcommand(
+            Command::new("diff")
+                .about("Compare two findings.json files and report added/removed findings")
+                .arg(
+                    Arg::new("OLD")
+                        .help("Path to the baseline findings.json")
+                        .required(true)
+                        .index(1),
+                )
+                .arg(
+                    Arg::new("NEW")
+                        .help("Path to the findings.json to compare against the baseline")
+                        .required(true)
+                        .index(2),
+                )
+                .arg(
+                    Arg::new("format")
+                        .long("format")
+                        .help("Output format")
+                        .value_name("FORMAT")
+                        .value_parser(["text", "json"])
+                        .default_value("text"),
+                ),
+        )
+        .subcommand(
+            Command::new("doctor")
+                .about("Check the configuration, output directory and API endpoint before a run"),
+        )
+        .subcommand(
+            Command::new("merge")
+                .about("Combine the findings files of sharded runs into one findings.json")
+                .arg(
+                    Arg::new("FILES")
+                        .help("Shard findings files to merge")
+                        .required(true)
+                        .num_args(1..),
+                )
+                .arg(
+                    Arg::new("output")
+                        .short('o')
+                        .long("output")
+                        .help("Path of the merged findings file")
+                        .value_name("FILE")
+                        .default_value("findings/findings.json"),
+                )
+                .arg(
+                    Arg::new("top-files")
+                        .long("top-files")
+                        .help("Number of files in the most violated files table (default: 10, 0 to hide it)")
+                        .value_name("N")
+                        .value_parser(clap::value_parser!(u64)),
+                ),
+        )
+        .subcommand(
+            Command::new("print-ast")
+                .about("Print the AST node kinds and spans of a file, for rule authoring")
+                .arg(
+                    Arg::new("FILE")
+                        .help("File to parse")
+                        .required(true)
+                        .index(1),
+                ),
+        )
+        .subcommand(
+            Command::new("rules-catalog")
+                .about("List every registered rule with its severity, tags and options schema")
+                .arg(
+                    Arg::new("json")
+                        .long("json")
+                        .help("Print the catalog as JSON")
+                        .action(ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("test-rule")
+                .about("Run a single rule on a code snippet and print its findings, for rule authoring")
+                .arg(
+                    Arg::new("rule")
+                        .long("rule")
+                        .help("Name of the rule to run")
+                        .value_name("RULE")
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("code")
+                        .long("code")
+                        .help("Source code to analyze")
+                        .value_name("SNIPPET"),
+                )
+                .arg(
+                    Arg::new("file")
+                        .long("file")
+                        .help("Read the source code to analyze from a file")
+                        .value_name("FILE"),
+                )
+                .group(
+                    ArgGroup::new("snippet")
+                        .args(["code", "file"])
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("file-name")
+                        .long("file-name")
+                        .help("File name the snippet is analyzed as (default: snippet.ts, or the --file path)")
+                        .value_name("NAME"),
+                )
+                .arg(
+                    Arg::new("options")
+                        .long("options")
+                        .help("Rule options as a JSON object")
+                        .value_name("JSON"),
+                ),
+        )
 }
 
 /// Get debug level from parsed arguments
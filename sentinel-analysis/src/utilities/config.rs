@@ -1,7 +1,19 @@
+use crate::ast_stats::AstStatsFormat;
+use crate::error::SentinelError;
+use crate::exporter::{OutputFormat, SeverityLevel, TableStyle};
+use crate::rules_registry::InlineRuleConfig;
 use crate::utilities::DebugLevel;
+use crate::utilities::file_utils::Shard;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
-use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Directory findings, metrics and other outputs are written to unless configured
+pub const DEFAULT_OUTPUT_DIR: &str = "findings";
+
+/// Endpoint analysis results are submitted to when `api_url` is not configured
+pub const DEFAULT_API_URL: &str = "https://api.scoper.cloud/api/v1/projects/3/analysis_submissions";
 
 /// Configuration structure for the TypeScript analyzer
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
@@ -9,8 +21,32 @@ pub struct Config {
     pub path: Option<String>,
     pub export_metrics_json: Option<String>,
     pub export_metrics_csv: Option<String>,
+    /// Write findings, summary and performance metrics into this single JSON file
+    pub combined_report: Option<String>,
+    /// Write the import graph of the analyzed files to this file, as DOT for `.dot`/`.gv`
+    pub export_graph: Option<String>,
+    /// Style of the rule hit summary table: `ascii` (default), `markdown` or `minimal`
+    pub table_style: Option<TableStyle>,
+    /// Number of files in the most violated files table (default: 10, 0 to leave it out)
+    pub top_files: Option<usize>,
+    /// Attach the source lines around each finding to findings.json
+    pub include_snippets: Option<bool>,
+    /// Format of the findings printed to stdout: `text` (default) or `github`
+    pub format: Option<OutputFormat>,
+    /// Minimum severity of the findings printed to stdout (default: `info`)
+    pub severity: Option<SeverityLevel>,
+    /// Severity of the findings in files matching each glob, overriding the rule severity
+    pub path_severities: Option<HashMap<String, SeverityLevel>>,
+    /// Print error-severity findings of the human-readable report to stderr
+    pub errors_to_stderr: Option<bool>,
     /// Number of threads to use for parallel processing (default: all available)
     pub threads: Option<usize>,
+    /// Maximum number of files read concurrently (default: one per analysis thread)
+    pub read_concurrency: Option<usize>,
+    /// Record the CPU, memory and OS of the scanning machine in the exported metrics
+    pub metrics_environment: Option<bool>,
+    /// Write the slowest (rule, file) combinations, up to this many, to rule_file_timings.json
+    pub rule_file_timings: Option<usize>,
     /// Path to rules configuration file
     pub rules_config: Option<String>,
     /// Debug level for controlling output verbosity
@@ -19,6 +55,40 @@ pub struct Config {
     pub output_dir: Option<String>,
     /// API URL for submitting analysis results
     pub api_url: Option<String>,
+    /// Emit file paths in findings relative to the scan root (default: true)
+    pub relative_paths: Option<bool>,
+    /// Stop running further rules on a file after its first error-severity finding
+    pub fail_fast_per_file: Option<bool>,
+    /// Run rules on the recovered AST of files with syntax errors
+    pub continue_on_parse_error: Option<bool>,
+    /// Skip files none of the enabled rules applies to, without parsing them
+    pub prefilter: Option<bool>,
+    /// Also analyze the script blocks of `.vue`/`.svelte` files
+    pub experimental_sfc: Option<bool>,
+    /// Follow symbolic links while scanning, skipping cycles and duplicate files
+    pub follow_symlinks: Option<bool>,
+    /// Use the nearest `sentinel.json`/`rules.json` below the scan root for each file
+    pub nested_configs: Option<bool>,
+    /// Analyze the discovered files in path order, for reproducible runs (default: true)
+    pub sort_files: Option<bool>,
+    /// Append the findings of each finished batch to `findings.ndjson` as the run goes
+    pub incremental_findings: Option<bool>,
+    /// Rule options passed with `--rule-config`, overriding the rules config (command line only)
+    #[serde(skip)]
+    pub rule_configs: Vec<InlineRuleConfig>,
+    /// Analyze only the files assigned to this shard (command line only)
+    #[serde(skip)]
+    pub shard: Option<Shard>,
+    /// Analyze the files listed in this newline-delimited manifest instead of walking the
+    /// scan root (command line only)
+    #[serde(skip)]
+    pub files_from: Option<String>,
+    /// Print findings and the summary as versioned porcelain records (command line only)
+    #[serde(skip)]
+    pub porcelain: Option<bool>,
+    /// Print the distribution of AST node kinds instead of running rules (command line only)
+    #[serde(skip)]
+    pub ast_stats: Option<AstStatsFormat>,
 }
 
 impl Config {
@@ -35,65 +105,79 @@ impl Config {
             );
         }
 
-        // Try current directory
-        if let Some(config) = Self::try_load_from_path("sentinel.json") {
-            return config;
+        for path in Self::default_locations() {
+            if let Some(config) = Self::try_load_from_path(&path.to_string_lossy()) {
+                return config;
+            }
         }
 
-        // Try executable directory
+        // No config found, return default
+        eprintln!("No configuration file found, using defaults");
+        Config::default()
+    }
+
+    /// Find the sentinel.json that [`Config::load`] would read first, if any
+    pub fn find_config_file() -> Option<PathBuf> {
+        std::env::var("SENTINEL_CONFIG")
+            .ok()
+            .map(PathBuf::from)
+            .into_iter()
+            .chain(Self::default_locations())
+            .find(|path| path.exists())
+    }
+
+    /// Locations searched for sentinel.json when `SENTINEL_CONFIG` is not usable
+    fn default_locations() -> Vec<PathBuf> {
+        // Current directory
+        let mut locations = vec![PathBuf::from("sentinel.json")];
+
+        // Executable directory
         if let Ok(exe_path) = std::env::current_exe() {
             if let Some(exe_dir) = exe_path.parent() {
-                let exe_config_path = exe_dir.join("sentinel.json");
-                if let Some(config) = Self::try_load_from_path(&exe_config_path.to_string_lossy()) {
-                    return config;
-                }
+                locations.push(exe_dir.join("sentinel.json"));
             }
         }
 
-        // Try user config directory
+        // User config directory
         if let Some(home_dir) = dirs::home_dir() {
-            let home_config = home_dir
-                .join(".config")
-                .join("sentinel")
-                .join("sentinel.json");
-            if let Some(config) = Self::try_load_from_path(&home_config.to_string_lossy()) {
-                return config;
-            }
+            locations.push(
+                home_dir
+                    .join(".config")
+                    .join("sentinel")
+                    .join("sentinel.json"),
+            );
         }
 
-        // Try system-wide config directory
+        // System-wide config directory
         #[cfg(not(windows))]
-        {
-            let system_config = std::path::Path::new("/etc/sentinel/sentinel.json");
-            if let Some(config) = Self::try_load_from_path(&system_config.to_string_lossy()) {
-                return config;
-            }
-        }
+        locations.push(PathBuf::from("/etc/sentinel/sentinel.json"));
 
-        // No config found, return default
-        eprintln!("No configuration file found, using defaults");
-        Config::default()
+        locations
+    }
+
+    /// Load config from an explicitly given file
+    ///
+    /// Unlike [`Config::load`], a missing or invalid file is an error instead of a
+    /// reason to fall back to the next location.
+    pub fn load_from_file(path: &str) -> Result<Self, SentinelError> {
+        let contents =
+            fs::read_to_string(path).map_err(|e| SentinelError::io("read config file", path, e))?;
+        serde_json::from_str(&contents).map_err(|e| SentinelError::parse(path, e))
     }
 
     /// Try to load config from a specific path
     fn try_load_from_path(path: &str) -> Option<Self> {
-        match fs::File::open(path) {
-            Ok(mut file) => {
-                let mut contents = String::new();
-                if let Err(err) = file.read_to_string(&mut contents) {
-                    eprintln!("Could not read {}: {}", path, err);
-                    return None;
-                }
-
-                match serde_json::from_str(&contents) {
-                    Ok(config) => Some(config),
-                    Err(err) => {
-                        eprintln!("Could not parse {}: {}", path, err);
-                        None
-                    }
-                }
+        // Silently skip missing files, as we try multiple locations
+        if !Path::new(path).exists() {
+            return None;
+        }
+
+        match Self::load_from_file(path) {
+            Ok(config) => Some(config),
+            Err(err) => {
+                eprintln!("{}", err);
+                None
             }
-            Err(_) => None, // Silently fail, as we try multiple locations
         }
     }
 }
@@ -177,7 +261,7 @@ pub fn get_output_dir(config: &Config, args: &[String]) -> String {
     config
         .output_dir
         .clone()
-        .unwrap_or_else(|| "findings".to_string())
+        .unwrap_or_else(|| DEFAULT_OUTPUT_DIR.to_string())
 }
 
 /// Helper function to get metrics JSON path based on output directory
@@ -190,7 +274,7 @@ pub fn get_metrics_json_path(config: &Config, output_dir: Option<&String>) -> Op
         Some(format!("{}/metrics.json", dir))
     } else {
         // Default path if no output directory specified
-        Some("findings/metrics.json".to_string())
+        Some(format!("{}/metrics.json", DEFAULT_OUTPUT_DIR))
     }
 }
 
@@ -204,6 +288,6 @@ pub fn get_metrics_csv_path(config: &Config, output_dir: Option<&String>) -> Opt
         Some(format!("{}/metrics.csv", dir))
     } else {
         // Default path if no output directory specified
-        Some("findings/metrics.csv".to_string())
+        Some(format!("{}/metrics.csv", DEFAULT_OUTPUT_DIR))
     }
 }
@@ -0,0 +1,154 @@
+use oxc_ast::Comment;
+use oxc_diagnostics::OxcDiagnostic;
+
+/// Comment prefix that turns rules off from the comment onward
+pub const DISABLE_DIRECTIVE: &str = "sentinel-disable";
+/// Comment prefix that turns rules back on from the comment onward
+pub const ENABLE_DIRECTIVE: &str = "sentinel-enable";
+/// Comment prefix that turns rules off for the comment's line and the next one
+pub const IGNORE_DIRECTIVE: &str = "sentinel-ignore";
+
+/// A single `sentinel-disable`/`sentinel-enable` comment
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Directive {
+    /// Byte offset from which the directive applies
+    offset: u32,
+    /// Whether the directive disables (rather than enables) its rules
+    disable: bool,
+    /// Rules the directive applies to; empty means all rules
+    rules: Vec<String>,
+}
+
+impl Directive {
+    fn applies_to(&self, rule_id: &str) -> bool {
+        applies_to(&self.rules, rule_id)
+    }
+}
+
+/// A single `sentinel-ignore` comment
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LineDirective {
+    /// Byte offset of the start of the comment's line
+    start: u32,
+    /// Byte offset of the end of the line after the comment
+    end: u32,
+    /// Rules the directive applies to; empty means all rules
+    rules: Vec<String>,
+}
+
+fn applies_to(rules: &[String], rule_id: &str) -> bool {
+    rules.is_empty() || rules.iter().any(|rule| rule == rule_id)
+}
+
+/// Byte range from the start of the line containing `offset` to the end of the next line
+fn line_and_next(source: &str, offset: u32) -> (u32, u32) {
+    let offset = offset as usize;
+    let start = source[..offset]
+        .rfind('\n')
+        .map_or(0, |newline| newline + 1);
+    let line_end = source[offset..]
+        .find('\n')
+        .map_or(source.len(), |newline| offset + newline);
+    let end = source
+        .get(line_end + 1..)
+        .and_then(|rest| rest.find('\n'))
+        .map_or(source.len(), |newline| line_end + 1 + newline);
+    (start as u32, end as u32)
+}
+
+/// Inline enable/disable directives of one file
+///
+/// `// sentinel-disable rule-a, rule-b` turns the listed rules off from the comment
+/// to the end of the file or to a matching `// sentinel-enable rule-a`; without rule
+/// IDs the directive applies to all rules. Block comments work the same way. A
+/// directive placed before any code applies from the start of the file, which also
+/// covers findings reported for the file as a whole.
+///
+/// `// sentinel-ignore: rule-a` only turns the listed rules off for findings on the
+/// comment's line and the next one, so it can be placed above a statement or at the end
+/// of its line.
+#[derive(Debug, Clone, Default)]
+pub struct FileDirectives {
+    /// Directives in source order
+    directives: Vec<Directive>,
+    /// `sentinel-ignore` directives in source order
+    line_directives: Vec<LineDirective>,
+}
+
+impl FileDirectives {
+    /// Collect the directives among a file's comments
+    ///
+    /// `code_start` is the offset of the first statement; directives before it apply
+    /// from offset 0.
+    pub fn parse(comments: &[Comment], source: &str, code_start: u32) -> Self {
+        let mut directives = Vec::new();
+        let mut line_directives = Vec::new();
+        for comment in comments {
+            let text = comment.content_span().source_text(source).trim();
+            let (keyword, rest) = text
+                .split_once(|c: char| c == ':' || c.is_whitespace())
+                .unwrap_or((text, ""));
+            let rules = rest
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|rule| !rule.is_empty())
+                .map(str::to_string)
+                .collect();
+            let disable = match keyword {
+                DISABLE_DIRECTIVE => true,
+                ENABLE_DIRECTIVE => false,
+                IGNORE_DIRECTIVE => {
+                    let (start, end) = line_and_next(source, comment.span.start);
+                    line_directives.push(LineDirective { start, end, rules });
+                    continue;
+                }
+                _ => continue,
+            };
+            let offset = if comment.span.start < code_start {
+                0
+            } else {
+                comment.span.start
+            };
+            directives.push(Directive {
+                offset,
+                disable,
+                rules,
+            });
+        }
+
+        Self {
+            directives,
+            line_directives,
+        }
+    }
+
+    /// Check whether the file has no directives at all
+    pub fn is_empty(&self) -> bool {
+        self.directives.is_empty() && self.line_directives.is_empty()
+    }
+
+    /// Check whether `rule_id` is disabled at the given byte offset
+    pub fn is_disabled(&self, rule_id: &str, offset: u32) -> bool {
+        let ignored = self.line_directives.iter().any(|directive| {
+            (directive.start..=directive.end).contains(&offset)
+                && applies_to(&directive.rules, rule_id)
+        });
+        ignored
+            || self
+                .directives
+                .iter()
+                .take_while(|directive| directive.offset <= offset)
+                .filter(|directive| directive.applies_to(rule_id))
+                .last()
+                .is_some_and(|directive| directive.disable)
+    }
+
+    /// Check whether a diagnostic of `rule_id` is suppressed, using its primary label
+    pub fn suppresses(&self, rule_id: &str, diagnostic: &OxcDiagnostic) -> bool {
+        let offset = diagnostic
+            .labels
+            .as_ref()
+            .and_then(|labels| labels.first())
+            .map_or(0, |label| label.offset() as u32);
+        self.is_disabled(rule_id, offset)
+    }
+}
@@ -1,18 +1,82 @@
+use crate::error::SentinelError;
+use crate::profiling;
 use crate::utilities::{DebugLevel, log};
+use ignore::WalkBuilder;
+use std::collections::HashSet;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use std::time::{Duration, Instant};
-use walkdir::WalkDir;
+
+/// Name of the tool-specific ignore file, using gitignore syntax
+pub const SENTINEL_IGNORE_FILE: &str = ".sentinelignore";
+
+/// File extensions analyzed by default
+pub const DEFAULT_EXTENSIONS: [&str; 2] = ["ts", "tsx"];
 
 /// Find all TypeScript files in the given directory and subdirectories
 pub fn find_typescript_files(dir: &str) -> Vec<String> {
-    WalkDir::new(dir)
-        .into_iter()
+    find_source_files(dir, &DEFAULT_EXTENSIONS)
+}
+
+/// Find all files with one of the given extensions in the directory and subdirectories
+///
+/// Paths matched by a `.sentinelignore` file in the scan root, any nested
+/// directory or any parent directory are skipped. Other ignore files such
+/// as `.gitignore` are not consulted. Symbolic links are not followed.
+pub fn find_source_files(dir: &str, extensions: &[&str]) -> Vec<String> {
+    walk_source_files(dir, extensions, false)
+}
+
+/// Find all files with one of the given extensions, optionally following symbolic links
+///
+/// Without `follow_symlinks`, symlinked files and directories are skipped. With it,
+/// links are resolved; directory cycles are detected by the walker and not entered,
+/// and a file reached through several paths is only returned for the first one.
+pub fn walk_source_files(dir: &str, extensions: &[&str], follow_symlinks: bool) -> Vec<String> {
+    walk_source_files_excluding(dir, extensions, follow_symlinks, &[])
+}
+
+/// Like [`walk_source_files`], but without descending into the given directories
+///
+/// `excluded_dirs` are compared by canonical path, so they may be given relative to the
+/// working directory rather than to `dir`.
+pub fn walk_source_files_excluding(
+    dir: &str,
+    extensions: &[&str],
+    follow_symlinks: bool,
+    excluded_dirs: &[PathBuf],
+) -> Vec<String> {
+    let mut visited = HashSet::new();
+    let excluded: Vec<PathBuf> = excluded_dirs
+        .iter()
+        .filter_map(|dir| fs::canonicalize(dir).ok())
+        .collect();
+
+    WalkBuilder::new(dir)
+        .standard_filters(false)
+        .parents(false)
+        .add_custom_ignore_filename(SENTINEL_IGNORE_FILE)
+        .follow_links(follow_symlinks)
+        .filter_entry(move |e| {
+            excluded.is_empty()
+                || !e.file_type().is_some_and(|file_type| file_type.is_dir())
+                || fs::canonicalize(e.path()).map_or(true, |path| !excluded.contains(&path))
+        })
+        .build()
         .filter_map(Result::ok)
         .filter(|e| {
-            let path = e.path();
-            path.is_file()
-                && path
+            // Entries report the link target's type only when links are followed
+            e.file_type().is_some_and(|file_type| file_type.is_file())
+                && e.path()
                     .extension()
-                    .map_or(false, |ext| ext == "ts" || ext == "tsx")
+                    .and_then(|ext| ext.to_str())
+                    .is_some_and(|ext| extensions.contains(&ext))
+        })
+        .filter(|e| {
+            !follow_symlinks
+                || visited.insert(fs::canonicalize(e.path()).unwrap_or_else(|_| e.path().into()))
         })
         .map(|e| e.path().to_string_lossy().to_string())
         .collect()
@@ -20,20 +84,74 @@ pub fn find_typescript_files(dir: &str) -> Vec<String> {
 
 /// Find TypeScript files in the given directory and return them with timing information
 pub fn find_files(dir_path: &str, debug_level: DebugLevel) -> (Vec<String>, Duration) {
+    find_files_with_extensions(dir_path, &DEFAULT_EXTENSIONS, false, &[], debug_level)
+}
+
+/// Return the canonical path of `dir` if it exists inside `scan_root`
+pub fn dir_inside_scan_root(scan_root: &str, dir: &str) -> Option<PathBuf> {
+    let scan_root = fs::canonicalize(scan_root).ok()?;
+    let dir = fs::canonicalize(dir).ok()?;
+    dir.starts_with(&scan_root).then_some(dir)
+}
+
+/// Find files with the given extensions and return them with timing information
+///
+/// `output_dirs` are directories the analyzer writes to (such as the findings output
+/// directory). When one of them lies inside the scan root it is skipped with a warning,
+/// so generated files are never analyzed by a later run. An output directory that is the
+/// scan root itself is only warned about, since skipping it would skip every file.
+pub fn find_files_with_extensions(
+    dir_path: &str,
+    extensions: &[&str],
+    follow_symlinks: bool,
+    output_dirs: &[&str],
+    debug_level: DebugLevel,
+) -> (Vec<String>, Duration) {
     log(
         DebugLevel::Info,
         debug_level,
         &format!("Scanning directory: \x1b[93m{}\x1b[0m", dir_path),
     );
 
+    let scan_root = fs::canonicalize(dir_path).ok();
+    let mut excluded_dirs: Vec<PathBuf> = Vec::new();
+    for output_dir in output_dirs {
+        let Some(dir) = dir_inside_scan_root(dir_path, output_dir) else {
+            continue;
+        };
+        // The scan root itself cannot be skipped without skipping everything
+        if scan_root.as_ref() == Some(&dir) {
+            log(
+                DebugLevel::Warn,
+                debug_level,
+                &format!(
+                    "Output directory {} is the scan path and cannot be excluded from analysis; source files written to it will be analyzed",
+                    dir.display()
+                ),
+            );
+            continue;
+        }
+        log(
+            DebugLevel::Warn,
+            debug_level,
+            &format!(
+                "Output directory {} is inside the scan path and is excluded from analysis",
+                dir.display()
+            ),
+        );
+        excluded_dirs.push(dir);
+    }
+
     let scan_start = Instant::now();
-    let files = find_typescript_files(dir_path);
+    let files = profiling::in_span("discover", || {
+        walk_source_files_excluding(dir_path, extensions, follow_symlinks, &excluded_dirs)
+    });
     let scan_duration = scan_start.elapsed();
 
     log(
         DebugLevel::Info,
         debug_level,
-        &format!("Found {} TypeScript files", files.len()),
+        &format!("Found {} source files", files.len()),
     );
     log(
         DebugLevel::Trace,
@@ -43,3 +161,180 @@ pub fn find_files(dir_path: &str, debug_level: DebugLevel) -> (Vec<String>, Dura
 
     (files, scan_duration)
 }
+
+/// Files listed in a `--files-from` manifest, split by whether they can be analyzed
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FileList {
+    /// Existing files with an analyzable extension, in manifest order
+    pub files: Vec<String>,
+    /// Listed paths that do not exist or are not regular files
+    pub missing: Vec<String>,
+    /// Listed files whose extension is not analyzed
+    pub unsupported: Vec<String>,
+}
+
+/// Read a newline-delimited list of files to analyze, instead of walking a directory
+///
+/// Paths are used as written, so relative paths resolve against the working directory.
+/// Blank lines are skipped and a path listed twice is only returned once. Ignore files
+/// are not consulted: every listed file is analyzed.
+pub fn read_file_list(manifest: &str, extensions: &[&str]) -> Result<FileList, SentinelError> {
+    let content =
+        fs::read_to_string(manifest).map_err(|e| SentinelError::io("read", manifest, e))?;
+
+    let mut seen = HashSet::new();
+    let mut list = FileList::default();
+    for line in content.lines() {
+        let path = line.trim();
+        if path.is_empty() || !seen.insert(path) {
+            continue;
+        }
+
+        let analyzable = Path::new(path)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| extensions.contains(&ext));
+        if !Path::new(path).is_file() {
+            list.missing.push(path.to_string());
+        } else if !analyzable {
+            list.unsupported.push(path.to_string());
+        } else {
+            list.files.push(path.to_string());
+        }
+    }
+    Ok(list)
+}
+
+/// Read the files of a `--files-from` manifest and return them with timing information
+///
+/// Listed files that are missing or not analyzable are reported as warnings and skipped.
+pub fn find_files_from_list(
+    manifest: &str,
+    extensions: &[&str],
+    debug_level: DebugLevel,
+) -> Result<(Vec<String>, Duration), SentinelError> {
+    log(
+        DebugLevel::Info,
+        debug_level,
+        &format!("Reading files to analyze from: \x1b[93m{}\x1b[0m", manifest),
+    );
+
+    let scan_start = Instant::now();
+    let list = profiling::in_span("discover", || read_file_list(manifest, extensions))?;
+    let scan_duration = scan_start.elapsed();
+
+    for path in &list.missing {
+        log(
+            DebugLevel::Warn,
+            debug_level,
+            &format!("Listed file {} does not exist and is skipped", path),
+        );
+    }
+    for path in &list.unsupported {
+        log(
+            DebugLevel::Warn,
+            debug_level,
+            &format!(
+                "Listed file {} does not have an analyzable extension ({}) and is skipped",
+                path,
+                extensions.join(", ")
+            ),
+        );
+    }
+    log(
+        DebugLevel::Info,
+        debug_level,
+        &format!("Found {} source files", list.files.len()),
+    );
+
+    Ok((list.files, scan_duration))
+}
+
+/// Rewrite a file path relative to the scan root, using forward slashes
+///
+/// If the scan root points at a file, its parent directory is used as the root.
+/// Paths outside the root are returned unchanged apart from slash normalization.
+pub fn to_relative_path(file_path: &str, scan_root: &str) -> String {
+    let normalized_file = file_path.replace('\\', "/");
+    let normalized_root = scan_root.replace('\\', "/");
+
+    let root = Path::new(&normalized_root);
+    let root = if root.is_file() {
+        root.parent().unwrap_or(root)
+    } else {
+        root
+    };
+
+    match Path::new(&normalized_file).strip_prefix(root) {
+        Ok(relative) if !relative.as_os_str().is_empty() => {
+            relative.to_string_lossy().replace('\\', "/")
+        }
+        _ => normalized_file,
+    }
+}
+
+/// A 1-based slice of the discovered files, written as `<index>/<total>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shard {
+    pub index: usize,
+    pub total: usize,
+}
+
+impl FromStr for Shard {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (index, total) = s
+            .split_once('/')
+            .ok_or_else(|| format!("Invalid shard '{}', expected <index>/<total>", s))?;
+        let index: usize = index
+            .trim()
+            .parse()
+            .map_err(|_| format!("Invalid shard index in '{}'", s))?;
+        let total: usize = total
+            .trim()
+            .parse()
+            .map_err(|_| format!("Invalid shard total in '{}'", s))?;
+
+        if total == 0 || index == 0 || index > total {
+            return Err(format!(
+                "Invalid shard '{}', index must be between 1 and the total",
+                s
+            ));
+        }
+
+        Ok(Shard { index, total })
+    }
+}
+
+impl fmt::Display for Shard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.index, self.total)
+    }
+}
+
+impl Shard {
+    /// Check whether a file belongs to this shard
+    ///
+    /// The decision hashes the path relative to the scan root with FNV-1a, so every
+    /// machine assigns a file to the same shard regardless of its checkout location.
+    pub fn contains(&self, file_path: &str, scan_root: &str) -> bool {
+        let relative = to_relative_path(file_path, scan_root);
+        (fnv1a_hash(relative.as_bytes()) % self.total as u64) as usize == self.index - 1
+    }
+}
+
+/// 64-bit FNV-1a hash, stable across platforms and releases unlike `DefaultHasher`
+pub fn fnv1a_hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Keep only the files assigned to the given shard
+pub fn select_shard(files: Vec<String>, shard: &Shard, scan_root: &str) -> Vec<String> {
+    files
+        .into_iter()
+        .filter(|file| shard.contains(file, scan_root))
+        .collect()
+}
@@ -1,5 +1,57 @@
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Set when stdout carries machine-readable output and must not receive log lines
+static STDOUT_RESERVED: AtomicBool = AtomicBool::new(false);
+
+/// Reserve stdout for machine-readable output, routing all log lines to stderr
+pub fn reserve_stdout() {
+    STDOUT_RESERVED.store(true, Ordering::Relaxed);
+}
+
+/// Check whether stdout is reserved for machine-readable output
+pub fn stdout_reserved() -> bool {
+    STDOUT_RESERVED.load(Ordering::Relaxed)
+}
+
+/// Set when error-severity findings in the human-readable report go to stderr
+static ERRORS_TO_STDERR: AtomicBool = AtomicBool::new(false);
+
+/// Route error-severity findings in the human-readable report to stderr
+pub fn route_errors_to_stderr() {
+    ERRORS_TO_STDERR.store(true, Ordering::Relaxed);
+}
+
+/// Check whether error-severity findings in the human-readable report go to stderr
+pub fn errors_to_stderr() -> bool {
+    ERRORS_TO_STDERR.load(Ordering::Relaxed)
+}
+
+/// Print report output to stdout, or to stderr if it is about error-severity
+/// findings and errors are routed there
+#[macro_export]
+macro_rules! report_print {
+    ($errors:expr, $($arg:tt)*) => {
+        if $errors && $crate::utilities::logging::errors_to_stderr() {
+            eprint!($($arg)*);
+        } else {
+            print!($($arg)*);
+        }
+    };
+}
+
+/// Print a status line to stdout, or to stderr while stdout is reserved
+#[macro_export]
+macro_rules! status_println {
+    ($($arg:tt)*) => {
+        if $crate::utilities::logging::stdout_reserved() {
+            eprintln!($($arg)*);
+        } else {
+            println!($($arg)*);
+        }
+    };
+}
 
 /// Debug level enum for controlling output verbosity
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -41,9 +93,9 @@ pub fn log(level: DebugLevel, current_level: DebugLevel, message: &str) {
         match level {
             DebugLevel::Error => eprintln!("\x1b[91mERROR:\x1b[0m {}", message),
             DebugLevel::Warn => eprintln!("\x1b[93mWARN:\x1b[0m {}", message),
-            DebugLevel::Info => println!("\x1b[94mINFO:\x1b[0m {}", message),
-            DebugLevel::Debug => println!("\x1b[95mDEBUG:\x1b[0m {}", message),
-            DebugLevel::Trace => println!("\x1b[90mTRACE:\x1b[0m {}", message),
+            DebugLevel::Info => status_println!("\x1b[94mINFO:\x1b[0m {}", message),
+            DebugLevel::Debug => status_println!("\x1b[95mDEBUG:\x1b[0m {}", message),
+            DebugLevel::Trace => status_println!("\x1b[90mTRACE:\x1b[0m {}", message),
             DebugLevel::None => {}
         }
     }
@@ -1,7 +1,10 @@
+pub mod archive;
 pub mod cli;
 pub mod config;
+pub mod directives;
 pub mod file_utils;
 pub mod logging;
+pub mod sfc;
 pub mod threading;
 
 // Re-export the DebugLevel enum directly from the logging module
@@ -0,0 +1,114 @@
+use oxc_span::SourceType;
+use std::path::Path;
+
+/// File extensions of single-file components handled by `--experimental-sfc`
+pub const SFC_EXTENSIONS: [&str; 2] = ["vue", "svelte"];
+
+/// Check whether a path points at a `.vue`/`.svelte` single-file component
+pub fn is_sfc_path(file_path: &str) -> bool {
+    Path::new(file_path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| SFC_EXTENSIONS.contains(&ext))
+}
+
+/// The script block extracted from a single-file component
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptBlock {
+    /// Source text between `<script ...>` and `</script>`
+    pub content: String,
+    /// Byte offset of `content` within the original file
+    pub offset: usize,
+    /// Whether the block declares `lang="ts"` or `lang="tsx"`
+    pub typescript: bool,
+    /// Whether the block declares `lang="tsx"` or `lang="jsx"`
+    pub jsx: bool,
+}
+
+impl ScriptBlock {
+    pub fn source_type(&self) -> SourceType {
+        SourceType::mjs()
+            .with_typescript(self.typescript)
+            .with_jsx(self.jsx)
+    }
+}
+
+/// Check whether the attributes of a `<script>` tag declare one of the given languages
+fn declares_lang(attributes: &str, langs: &[&str]) -> bool {
+    langs.iter().any(|lang| {
+        attributes.contains(&format!("lang=\"{}\"", lang))
+            || attributes.contains(&format!("lang='{}'", lang))
+    })
+}
+
+/// Extract the script block from a `.vue`/`.svelte` file
+///
+/// A `<script lang="ts">` block is preferred; otherwise the first `<script>` block is
+/// used. Files with several script blocks (e.g. Vue `<script setup>` next to a plain
+/// `<script>`) only have the chosen block analyzed. An unterminated trailing block is
+/// skipped; the complete blocks before it are still used.
+pub fn extract_script_block(source: &str) -> Option<ScriptBlock> {
+    let mut blocks = Vec::new();
+    let mut search_from = 0;
+
+    while let Some(start) = source[search_from..].find("<script") {
+        let tag_start = search_from + start;
+        // Nothing after an unterminated tag or block can be a complete block
+        let Some(tag_length) = source[tag_start..].find('>') else {
+            break;
+        };
+        let tag_end = tag_start + tag_length;
+        let attributes = &source[tag_start + "<script".len()..tag_end];
+        let content_start = tag_end + 1;
+        let Some(content_length) = source[content_start..].find("</script>") else {
+            break;
+        };
+        let content_end = content_start + content_length;
+
+        blocks.push(ScriptBlock {
+            content: source[content_start..content_end].to_string(),
+            offset: content_start,
+            typescript: declares_lang(attributes, &["ts", "typescript", "tsx"]),
+            jsx: declares_lang(attributes, &["tsx", "jsx"]),
+        });
+        search_from = content_end;
+    }
+
+    let typescript_block = blocks.iter().position(|block| block.typescript);
+    match typescript_block {
+        Some(index) => Some(blocks.swap_remove(index)),
+        None => blocks.into_iter().next(),
+    }
+}
+
+/// Maps 1-based line/column positions in a script block back to the original file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PositionMapper {
+    /// Number of lines before the block starts
+    line_offset: usize,
+    /// Column of the first block character on its line, minus one
+    column_offset: usize,
+}
+
+impl PositionMapper {
+    pub fn new(source: &str, offset: usize) -> Self {
+        let before = &source[..offset];
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+        Self {
+            line_offset: before.matches('\n').count(),
+            column_offset: before[line_start..].chars().count(),
+        }
+    }
+
+    /// Translate a block position to a file position
+    ///
+    /// Only the first block line is shifted horizontally. A line of 0 marks an unknown
+    /// position and is returned unchanged.
+    pub fn map(&self, line: usize, column: usize) -> (usize, usize) {
+        match line {
+            0 => (line, column),
+            1 => (line + self.line_offset, column + self.column_offset),
+            _ => (line + self.line_offset, column),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+mod common;
+
+use common::run_rule;
+
+const RULE: &str = "angular-no-conflicting-template";
+
+fn component(metadata: &str) -> String {
+    format!(
+        "import {{ Component }} from '@angular/core';\n\n@Component({{\n  selector: 'app-card',\n{}\n}})\nexport class CardComponent {{}}\n",
+        metadata
+    )
+}
+
+#[test]
+fn test_inline_and_external_template_is_flagged() {
+    let code = component("  template: '<div></div>',\n  templateUrl: './card.component.html',");
+
+    let diagnostics = run_rule(RULE, &code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].diagnostic.message,
+        "@Component defines both `template` and `templateUrl`"
+    );
+    assert_eq!(
+        (diagnostics[0].line_number, diagnostics[0].column_number),
+        (3, 1)
+    );
+}
+
+#[test]
+fn test_quoted_keys_are_flagged() {
+    let code =
+        component("  'template': '<div></div>',\n  \"templateUrl\": './card.component.html',");
+
+    let diagnostics = run_rule(RULE, &code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].diagnostic.message,
+        "@Component defines both `template` and `templateUrl`"
+    );
+}
+
+#[test]
+fn test_all_conflicting_keys_are_listed() {
+    let code = component(
+        "  templateUrl: './card.component.html',\n  template: '',\n  styles: ['div {}'],\n  styleUrls: ['./card.component.css'],",
+    );
+
+    let diagnostics = run_rule(RULE, &code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].diagnostic.message,
+        "@Component defines both `template` and `templateUrl`, `styles` and `styleUrls`"
+    );
+}
+
+#[test]
+fn test_clean_component_is_allowed() {
+    for metadata in [
+        "  templateUrl: './card.component.html',\n  styleUrls: ['./card.component.css'],",
+        "  template: '<div></div>',\n  styles: ['div {}'],",
+    ] {
+        assert!(
+            run_rule(RULE, &component(metadata)).is_empty(),
+            "{}",
+            metadata
+        );
+    }
+}
@@ -0,0 +1,44 @@
+mod common;
+
+use common::{run_rule, run_rule_with_config};
+use serde_json::json;
+
+const RULE: &str = "angular-no-inner-html";
+
+#[test]
+fn test_inner_html_binding_in_template_is_flagged() {
+    let code = "@Component({\n  selector: 'app-comment',\n  template: `<p>{{ title }}</p>\n    <div [innerHTML]=\"comment.body\"></div>`,\n})\nexport class CommentComponent {}\n";
+
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line_number, 4);
+    assert_eq!(diagnostics[0].column_number, 10);
+}
+
+#[test]
+fn test_inner_html_assignment_is_flagged() {
+    let code = "function render(el: HTMLElement, html: string) {\n  el.innerHTML = html;\n}\n";
+
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line_number, 2);
+}
+
+#[test]
+fn test_sanitized_values_are_allowed() {
+    let code = "@Component({ template: '<div [innerHTML]=\"sanitizer.sanitize(1, body)\"></div>' })\n\
+                export class CommentComponent {}\n\
+                el.innerHTML = this.sanitizer.sanitize(SecurityContext.HTML, html);\n";
+
+    assert!(run_rule(RULE, code).is_empty());
+}
+
+#[test]
+fn test_allowed_patterns_are_configurable() {
+    let code = "el.innerHTML = purify(html);\nother.innerHTML = html;\n";
+
+    let config = Some(json!({ "allowedPatterns": ["purify("] }));
+    let diagnostics = run_rule_with_config(RULE, config, "render.ts", code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line_number, 2);
+}
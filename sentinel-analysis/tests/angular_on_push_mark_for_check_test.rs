@@ -0,0 +1,59 @@
+mod common;
+
+use common::run_rule;
+
+const RULE: &str = "angular-on-push-mark-for-check";
+
+fn on_push_component(body: &str) -> String {
+    format!(
+        "@Component({{ selector: 'app-users', changeDetection: ChangeDetectionStrategy.OnPush }})\n\
+         export class UsersComponent {{\n{}\n}}\n",
+        body
+    )
+}
+
+#[test]
+fn test_subscribe_assigning_state_is_flagged() {
+    let code = on_push_component(
+        "  ngOnInit() {\n    this.users$.subscribe(users => {\n      this.users = users;\n    });\n  }",
+    );
+
+    let diagnostics = run_rule(RULE, &code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line_number, 4);
+}
+
+#[test]
+fn test_mark_for_check_in_callback_is_clean() {
+    let code = on_push_component(
+        "  ngOnInit() {\n    this.users$.subscribe({ next: users => {\n      this.users = users;\n      this.cdr.markForCheck();\n    } });\n    setTimeout(() => { this.count++; this.cdr.detectChanges(); });\n  }",
+    );
+
+    assert!(run_rule(RULE, &code).is_empty());
+}
+
+#[test]
+fn test_timer_without_mark_for_check_is_flagged() {
+    let code = on_push_component("  start() {\n    setInterval(() => this.ticks++, 1000);\n  }");
+
+    assert_eq!(run_rule(RULE, &code).len(), 1);
+}
+
+#[test]
+fn test_default_change_detection_is_ignored() {
+    let code = "@Component({ selector: 'app-users' })\n\
+                export class UsersComponent {\n\
+                  ngOnInit() { this.users$.subscribe(users => { this.users = users; }); }\n\
+                }\n";
+
+    assert!(run_rule(RULE, code).is_empty());
+}
+
+#[test]
+fn test_ignore_annotation_suppresses_finding() {
+    let code = on_push_component(
+        "  ngOnInit() {\n    // sentinel-ignore: angular-on-push-mark-for-check\n    this.users$.subscribe(users => {\n      this.users = users;\n    });\n  }",
+    );
+
+    assert!(run_rule(RULE, &code).is_empty());
+}
@@ -0,0 +1,57 @@
+mod common;
+
+use common::{run_rule, run_rule_with_config};
+use serde_json::json;
+
+const RULE: &str = "angular-output-count";
+
+fn component(members: &str) -> String {
+    format!(
+        "import {{ Component, EventEmitter, Output, output }} from '@angular/core';\n\n@Component({{ selector: 'app-card', template: '' }})\nexport class CardComponent {{\n{}\n}}\n",
+        members
+    )
+}
+
+#[test]
+fn test_signal_outputs_over_the_maximum_are_flagged() {
+    let members = (1..=7)
+        .map(|index| format!("  event{} = output<void>();", index))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let diagnostics = run_rule(RULE, &component(&members));
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(
+        diagnostics[0].diagnostic.message,
+        "Too many Angular output properties detected"
+    );
+    // The sixth output is the first one over the default maximum of 5
+    assert_eq!(
+        (diagnostics[0].line_number, diagnostics[0].column_number),
+        (10, 12)
+    );
+}
+
+#[test]
+fn test_decorator_outputs_are_counted() {
+    let members = "  @Output() opened = new EventEmitter<void>();\n  @Output('closed') closedChange = new EventEmitter<void>();\n  saved = output<void>();";
+
+    let config = Some(json!({ "maxOutputs": 2 }));
+    let diagnostics = run_rule_with_config(RULE, config, "card.component.ts", &component(members));
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line_number, 7);
+
+    let config = Some(json!({ "maxOutputs": 1 }));
+    let diagnostics = run_rule_with_config(RULE, config, "card.component.ts", &component(members));
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(
+        (diagnostics[0].line_number, diagnostics[0].column_number),
+        (6, 3)
+    );
+}
+
+#[test]
+fn test_components_within_the_maximum_are_allowed() {
+    let members = "  @Output() opened = new EventEmitter<void>();\n  closed = output<void>();\n  label = input<string>();\n  onClick() {\n    this.opened.emit();\n  }";
+    assert!(run_rule(RULE, &component(members)).is_empty());
+}
@@ -0,0 +1,57 @@
+mod common;
+
+use common::run_rule;
+
+const RULE: &str = "angular-prefer-host-metadata";
+
+#[test]
+fn test_host_binding_is_flagged() {
+    let code = r#"
+        import { Component, HostBinding } from '@angular/core';
+
+        @Component({ selector: 'app-button', template: '' })
+        export class ButtonComponent {
+          @HostBinding('class.active') active = false;
+        }
+    "#;
+
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].diagnostic.message.contains("@HostBinding"));
+}
+
+#[test]
+fn test_host_listener_is_flagged() {
+    let code = r#"
+        import { Component, HostListener } from '@angular/core';
+
+        @Component({ selector: 'app-button', template: '' })
+        export class ButtonComponent {
+          @HostListener('click', ['$event'])
+          onClick(event: Event) {}
+        }
+    "#;
+
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].diagnostic.message.contains("@HostListener"));
+}
+
+#[test]
+fn test_host_metadata_component_is_clean() {
+    let code = r#"
+        import { Component } from '@angular/core';
+
+        @Component({
+          selector: 'app-button',
+          template: '',
+          host: { '[class.active]': 'active', '(click)': 'onClick()' },
+        })
+        export class ButtonComponent {
+          active = false;
+          onClick() {}
+        }
+    "#;
+
+    assert!(run_rule(RULE, code).is_empty());
+}
@@ -0,0 +1,75 @@
+mod common;
+
+use common::{run_rule, run_rule_with_config};
+use scoper::rules_registry::create_default_registry;
+use serde_json::json;
+
+const RULE: &str = "angular-sorted-module-arrays";
+
+const UNSORTED_MODULE: &str = r#"
+@NgModule({
+  declarations: [AppComponent, HeaderComponent],
+  imports: [
+    RouterModule.forRoot(routes),
+    // shared
+    CommonModule,
+    HttpClientModule,
+  ],
+  providers: [{ provide: LOGGER, useClass: ConsoleLogger }, { provide: API_URL, useValue: '/api' }],
+})
+export class AppModule {}
+"#;
+
+#[test]
+fn test_first_out_of_order_entry_is_flagged() {
+    let diagnostics = run_rule(RULE, UNSORTED_MODULE);
+
+    assert_eq!(diagnostics.len(), 2);
+    assert!(diagnostics[0].diagnostic.message.contains(
+        "`imports` is not sorted: `CommonModule` should come before `RouterModule.forRoot`"
+    ));
+    assert_eq!(diagnostics[0].line_number, 7);
+    assert_eq!(diagnostics[0].column_number, 5);
+    assert!(diagnostics[1].diagnostic.message.contains("`API_URL`"));
+}
+
+#[test]
+fn test_config_selects_the_arrays() {
+    let config = Some(json!({ "arrays": ["declarations"] }));
+    assert!(run_rule_with_config(RULE, config, "app.module.ts", UNSORTED_MODULE).is_empty());
+}
+
+#[test]
+fn test_sorted_and_unsortable_arrays_are_allowed() {
+    let code = r#"
+        @NgModule({
+          imports: [CommonModule, HttpClientModule, RouterModule.forRoot(routes)],
+          declarations: [...COMPONENTS, AppComponent],
+        })
+        export class AppModule {}
+
+        @Component({ selector: 'app-root', imports: [RouterOutlet, CommonModule] })
+        export class AppComponent {}
+    "#;
+    assert!(run_rule(RULE, code).is_empty());
+}
+
+#[test]
+fn test_fix_sorts_the_array() {
+    let diagnostics = run_rule(RULE, UNSORTED_MODULE);
+    let registry = create_default_registry();
+    let rule = registry.get_rule(RULE).unwrap();
+
+    let fix = rule
+        .fix(&diagnostics[0].diagnostic, UNSORTED_MODULE)
+        .unwrap();
+    let fixed = fix.apply(UNSORTED_MODULE);
+    assert!(fixed.contains(
+        "imports: [\n    CommonModule,\n    // shared\n    HttpClientModule,\n    RouterModule.forRoot(routes),\n  ]"
+    ));
+
+    // The fixed array is no longer reported
+    let remaining = run_rule(RULE, &fixed);
+    assert_eq!(remaining.len(), 1);
+    assert!(remaining[0].diagnostic.message.contains("`providers`"));
+}
@@ -0,0 +1,91 @@
+mod common;
+
+use common::{run_rule, run_rule_with_config};
+use serde_json::json;
+
+const RULE: &str = "angular-unsubscribe";
+
+fn component(body: &str) -> String {
+    format!(
+        "@Component({{ selector: 'app-user' }})\nexport class UserComponent {{\n  ngOnInit() {{\n{}\n  }}\n}}\n",
+        body
+    )
+}
+
+#[test]
+fn test_unmanaged_subscription_is_flagged() {
+    let code = component("    this.user$.subscribe(user => (this.user = user));");
+
+    let diagnostics = run_rule(RULE, &code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line_number, 4);
+    assert_eq!(diagnostics[0].column_number, 16);
+}
+
+#[test]
+fn test_subscription_stored_in_field_is_allowed() {
+    let code =
+        component("    this.subscription = this.user$.subscribe(user => (this.user = user));");
+    assert!(run_rule(RULE, &code).is_empty());
+}
+
+#[test]
+fn test_subscription_used_as_field_initializer_is_allowed() {
+    let code = "@Component({ selector: 'app-user' })\nexport class UserComponent {\n  private subscription = this.user$.subscribe(user => (this.user = user));\n  private other = this.user$;\n}\n";
+    assert!(run_rule(RULE, code).is_empty());
+}
+
+#[test]
+fn test_subscription_added_to_container_is_allowed() {
+    let code =
+        component("    this.subscriptions.add(this.user$.subscribe(user => (this.user = user)));");
+    assert!(run_rule(RULE, &code).is_empty());
+}
+
+#[test]
+fn test_cleanup_operators_are_allowed() {
+    let code = component(
+        "    this.user$.pipe(takeUntil(this.destroy$)).subscribe(user => (this.user = user));\n\
+         this.route.params.pipe(map(p => p.id), takeUntilDestroyed(this.destroyRef)).subscribe();",
+    );
+    assert!(run_rule(RULE, &code).is_empty());
+}
+
+#[test]
+fn test_other_pipes_and_local_variables_are_flagged() {
+    let code = component(
+        "    this.user$.pipe(take(1)).subscribe();\n    const sub = this.user$.subscribe();",
+    );
+    assert_eq!(run_rule(RULE, &code).len(), 2);
+}
+
+#[test]
+fn test_subscriptions_outside_components_are_ignored() {
+    let code = "@Injectable()\nexport class UserService {\n  init() {\n    this.user$.subscribe();\n  }\n}\n\
+                source$.subscribe();\n";
+    assert!(run_rule(RULE, code).is_empty());
+}
+
+#[test]
+fn test_cleanup_patterns_are_configurable() {
+    let config = Some(json!({ "cleanupOperators": ["take"], "cleanupMethods": ["push"] }));
+    let code = component(
+        "    this.user$.pipe(take(1)).subscribe();\n\
+         this.subscriptions.push(this.user$.subscribe());\n\
+         this.user$.pipe(takeUntil(this.destroy$)).subscribe();",
+    );
+
+    let diagnostics = run_rule_with_config(RULE, config, "user.component.ts", &code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line_number, 6);
+}
+
+#[test]
+fn test_inline_suppression() {
+    let code = component(
+        "    // sentinel-disable angular-unsubscribe\n    this.http.get('/api').subscribe();\n    // sentinel-enable angular-unsubscribe\n    this.user$.subscribe();",
+    );
+    let diagnostics = run_rule(RULE, &code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line_number, 7);
+}
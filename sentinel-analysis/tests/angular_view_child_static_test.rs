@@ -0,0 +1,70 @@
+mod common;
+
+use common::run_rule;
+
+const RULE: &str = "angular-view-child-static";
+
+fn component(query: &str, ng_on_init: &str) -> String {
+    format!(
+        "export class SearchComponent {{\n  {} query: ElementRef;\n\n  ngOnInit() {{\n{}\n  }}\n}}\n",
+        query, ng_on_init
+    )
+}
+
+#[test]
+fn test_dynamic_query_used_in_ng_on_init_is_flagged() {
+    let code = component(
+        "@ViewChild('query')",
+        "    this.query.nativeElement.focus();",
+    );
+
+    let diagnostics = run_rule(RULE, &code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line_number, 2);
+    assert_eq!(diagnostics[0].column_number, 3);
+}
+
+#[test]
+fn test_options_without_static_are_flagged() {
+    let code = component(
+        "@ViewChild(QueryDirective, { read: ElementRef })",
+        "    this.query.nativeElement.focus();",
+    );
+    assert_eq!(run_rule(RULE, &code).len(), 1);
+}
+
+#[test]
+fn test_use_in_arrow_callback_is_flagged() {
+    let code = component(
+        "@ViewChild('query')",
+        "    this.ready$.subscribe(() => this.query.nativeElement.focus());",
+    );
+    assert_eq!(run_rule(RULE, &code).len(), 1);
+}
+
+#[test]
+fn test_static_option_is_allowed() {
+    for query in [
+        "@ViewChild('query', { static: true })",
+        "@ViewChild('query', { static: false })",
+        "@ViewChild('query', queryOptions)",
+    ] {
+        let code = component(query, "    this.query.nativeElement.focus();");
+        assert!(run_rule(RULE, &code).is_empty(), "{}", query);
+    }
+}
+
+#[test]
+fn test_query_not_used_in_ng_on_init_is_allowed() {
+    let code = "export class SearchComponent {\n  @ViewChild('query') query: ElementRef;\n\n  ngOnInit() {\n    this.load();\n  }\n\n  ngAfterViewInit() {\n    this.query.nativeElement.focus();\n  }\n}\n";
+    assert!(run_rule(RULE, code).is_empty());
+}
+
+#[test]
+fn test_functions_with_their_own_this_are_ignored() {
+    let code = component(
+        "@ViewChild('query')",
+        "    setTimeout(function () { this.query.focus(); });",
+    );
+    assert!(run_rule(RULE, &code).is_empty());
+}
@@ -0,0 +1,116 @@
+use std::fs::File;
+use std::process::Command;
+
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use scoper::exporter::FindingsExport;
+use scoper::utilities::archive::{extract_archive, is_archive_path};
+
+/// Build `build.tar.gz` in `dir` holding `src/app/debug.ts` and a non-source file
+fn write_archive(dir: &std::path::Path) -> String {
+    let archive_path = dir.join("build.tar.gz");
+    let encoder = GzEncoder::new(File::create(&archive_path).unwrap(), Compression::default());
+    let mut builder = tar::Builder::new(encoder);
+    for (name, content) in [
+        ("src/app/debug.ts", "debugger;\n"),
+        ("README.md", "# artifact\n"),
+    ] {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(content.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder
+            .append_data(&mut header, name, content.as_bytes())
+            .unwrap();
+    }
+    builder.into_inner().unwrap().finish().unwrap();
+    archive_path.to_string_lossy().to_string()
+}
+
+#[test]
+fn test_archive_paths_are_detected() {
+    assert!(is_archive_path("artifacts/sources.tar.gz"));
+    assert!(is_archive_path("sources.TGZ"));
+    assert!(is_archive_path("sources.tar"));
+    assert!(!is_archive_path("src/app"));
+    assert!(!is_archive_path("src/app/main.ts"));
+}
+
+#[test]
+fn test_extracted_archive_is_removed_on_drop() {
+    let dir = tempfile::tempdir().unwrap();
+    let archive_path = write_archive(dir.path());
+
+    let extracted = extract_archive(&archive_path).unwrap();
+    let root = extracted.path().to_path_buf();
+    assert!(root.join("src/app/debug.ts").is_file());
+
+    drop(extracted);
+    assert!(!root.exists());
+}
+
+#[test]
+fn test_missing_archive_is_an_error() {
+    assert!(extract_archive("does-not-exist.tar.gz").is_err());
+}
+
+#[test]
+fn test_findings_are_relative_to_archive_root() {
+    let dir = tempfile::tempdir().unwrap();
+    let archive_path = write_archive(dir.path());
+    std::fs::write(
+        dir.path().join("rules.json"),
+        r#"{ "rules": { "no-debugger": "error" } }"#,
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_scoper"))
+        .arg(&archive_path)
+        .args(["--output-dir", "-", "--rules-config", "rules.json"])
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let export: FindingsExport = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(export.summary.files_processed, 1);
+    assert_eq!(export.findings.len(), 1);
+    assert_eq!(export.findings[0].file, "src/app/debug.ts");
+}
+
+#[test]
+fn test_extracted_archive_is_removed_when_the_run_fails() {
+    let dir = tempfile::tempdir().unwrap();
+    let archive_path = write_archive(dir.path());
+    std::fs::write(
+        dir.path().join("rules.json"),
+        r#"{ "rules": { "no-debugger": "error" }, "budgets": { "no-debugger": 0 } }"#,
+    )
+    .unwrap();
+    let temp = tempfile::tempdir().unwrap();
+
+    let failing_runs: [&[&str]; 2] = [
+        // Over the budget of no-debugger
+        &["--output-dir", "-"],
+        // A file list that cannot be read
+        &["--output-dir", "-", "--files-from", "missing.txt"],
+    ];
+    for args in failing_runs {
+        let output = Command::new(env!("CARGO_BIN_EXE_scoper"))
+            .arg(&archive_path)
+            .args(["--rules-config", "rules.json"])
+            .args(args)
+            .env("TMPDIR", temp.path())
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+        assert_eq!(output.status.code(), Some(1), "{:?}", args);
+
+        let leftovers: Vec<_> = std::fs::read_dir(temp.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .filter(|name| name.to_string_lossy().starts_with("sentinel-archive-"))
+            .collect();
+        assert!(leftovers.is_empty(), "{:?}: {:?}", args, leftovers);
+    }
+}
@@ -0,0 +1,85 @@
+use std::fs;
+use std::process::Command;
+
+use oxc_ast::AstType;
+use scoper::ast_stats::{AstStats, count_node_kinds};
+
+#[test]
+fn test_node_kinds_are_counted() {
+    let code = "const a = b as string;\nconst c = d as number;\n";
+
+    let counts = count_node_kinds("file.ts", code).unwrap();
+    assert_eq!(counts[&AstType::TSAsExpression], 2);
+    assert_eq!(counts[&AstType::VariableDeclaration], 2);
+    assert_eq!(counts[&AstType::Program], 1);
+    // Files without a parser source type are not counted
+    assert!(count_node_kinds("styles.css", "a {}").is_none());
+}
+
+#[test]
+fn test_ast_stats_are_printed_as_json_without_running_rules() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(
+        dir.path().join("a.ts"),
+        "debugger;\nconst a = b as string;\n",
+    )
+    .unwrap();
+    fs::write(dir.path().join("b.ts"), "debugger;\n").unwrap();
+    fs::write(
+        dir.path().join("rules.json"),
+        r#"{ "rules": { "no-debugger": "error" } }"#,
+    )
+    .unwrap();
+    // An unreachable API keeps the run from submitting anything
+    fs::write(
+        dir.path().join("sentinel.json"),
+        r#"{ "api_url": "http://127.0.0.1:9" }"#,
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_scoper"))
+        .arg(dir.path())
+        .args(["--config", "sentinel.json", "--rules-config", "rules.json"])
+        .args(["--output-dir", "out", "--ast-stats=json"])
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stats: AstStats = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(stats.files, 2);
+    assert_eq!(stats.skipped_files, 0);
+    assert_eq!(stats.kinds["DebuggerStatement"].count, 2);
+    assert_eq!(stats.kinds["DebuggerStatement"].files, 2);
+    assert_eq!(stats.kinds["TSAsExpression"].files, 1);
+    assert_eq!(
+        stats.nodes,
+        stats.kinds.values().map(|kind| kind.count).sum::<usize>()
+    );
+    // Rules did not run, so nothing was exported
+    assert!(!dir.path().join("out").exists());
+}
+
+#[test]
+fn test_ast_stats_table_is_the_default() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("a.ts"), "const a = b as string;\n").unwrap();
+    fs::write(
+        dir.path().join("sentinel.json"),
+        r#"{ "api_url": "http://127.0.0.1:9" }"#,
+    )
+    .unwrap();
+
+    // The path after the flag is not taken as its format
+    let output = Command::new(env!("CARGO_BIN_EXE_scoper"))
+        .args(["--config", "sentinel.json", "--ast-stats"])
+        .arg(dir.path())
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("| TSAsExpression"));
+    assert!(stdout.contains("in 1 files"));
+}
@@ -0,0 +1,38 @@
+use std::fs;
+use std::process::Command;
+
+use scoper::exporter::FindingsExport;
+use serde_json::Value;
+
+#[test]
+fn test_combined_report_holds_findings_summary_and_metrics() {
+    let scan_root = tempfile::tempdir().unwrap();
+    fs::write(scan_root.path().join("debug.ts"), "debugger;\n").unwrap();
+    fs::write(
+        scan_root.path().join("rules.json"),
+        r#"{ "rules": { "no-debugger": "error" } }"#,
+    )
+    .unwrap();
+
+    let status = Command::new(env!("CARGO_BIN_EXE_scoper"))
+        .arg(scan_root.path())
+        // Printing findings to stdout also keeps the run from submitting to the API
+        .args(["--rules-config", "rules.json", "--output-dir", "-"])
+        .args(["--combined-report", "reports/run.json"])
+        .current_dir(scan_root.path())
+        .output()
+        .unwrap()
+        .status;
+    assert!(status.success());
+
+    let content = fs::read_to_string(scan_root.path().join("reports/run.json")).unwrap();
+    let report: Value = serde_json::from_str(&content).unwrap();
+    assert_eq!(report["metrics"]["files_processed"], 1);
+    assert!(report["metrics"]["rule_execution_metrics"].is_array());
+
+    // The findings part reads like a regular findings.json
+    let findings: FindingsExport = serde_json::from_value(report).unwrap();
+    assert_eq!(findings.summary.total_findings, 1);
+    assert_eq!(findings.findings[0].rule, "no-debugger");
+    assert_eq!(findings.findings[0].file, "debug.ts");
+}
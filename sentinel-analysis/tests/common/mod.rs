@@ -0,0 +1,27 @@
+#![allow(dead_code)]
+
+use scoper::RuleDiagnostic;
+use scoper::analyzer::analyze_source;
+use scoper::rules_registry::{configure_registry, create_default_registry};
+use serde_json::Value;
+
+/// Run a single rule with an optional configuration against an in-memory snippet
+pub fn run_rule_with_config(
+    rule_name: &str,
+    config: Option<Value>,
+    file_path: &str,
+    code: &str,
+) -> Vec<RuleDiagnostic> {
+    let mut registry = create_default_registry();
+    configure_registry(
+        &mut registry,
+        &[(rule_name.to_string(), config, "error".to_string())],
+    );
+
+    analyze_source(file_path, code, &registry).diagnostics
+}
+
+/// Run a single rule with its default configuration against a TypeScript snippet
+pub fn run_rule(rule_name: &str, code: &str) -> Vec<RuleDiagnostic> {
+    run_rule_with_config(rule_name, None, "test-file.ts", code)
+}
@@ -0,0 +1,69 @@
+use std::fs;
+use std::process::Command;
+
+use scoper::exporter::FindingsExport;
+use scoper::utilities::config::Config;
+
+#[test]
+fn test_load_config_from_non_standard_path() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("ci/sentinel.ci.json");
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, r#"{ "output_dir": "reports", "threads": 2 }"#).unwrap();
+
+    let config = Config::load_from_file(&path.to_string_lossy()).unwrap();
+    assert_eq!(config.output_dir.as_deref(), Some("reports"));
+    assert_eq!(config.threads, Some(2));
+}
+
+#[test]
+fn test_missing_or_invalid_config_is_an_error() {
+    let dir = tempfile::tempdir().unwrap();
+    let missing = dir.path().join("missing.json");
+    assert!(Config::load_from_file(&missing.to_string_lossy()).is_err());
+
+    let invalid = dir.path().join("invalid.json");
+    fs::write(&invalid, "{ not json").unwrap();
+    assert!(Config::load_from_file(&invalid.to_string_lossy()).is_err());
+}
+
+#[test]
+fn test_config_flag_is_used_by_the_cli() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("debug.ts"), "debugger;\n").unwrap();
+    fs::write(
+        dir.path().join("rules.ci.json"),
+        r#"{ "rules": { "no-debugger": "error" } }"#,
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("sentinel.ci.json"),
+        r#"{ "output_dir": "-", "rules_config": "rules.ci.json" }"#,
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_scoper"))
+        .arg(dir.path())
+        .args(["--config", "sentinel.ci.json"])
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let export: FindingsExport = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(export.summary.total_findings, 1);
+}
+
+#[test]
+fn test_missing_config_flag_file_fails_the_run() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_scoper"))
+        .arg(dir.path())
+        .args(["--config", "missing.json", "--output-dir", "-"])
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("missing.json"));
+}
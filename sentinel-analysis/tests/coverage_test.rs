@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+use std::fs;
+use std::process::Command;
+use std::time::Duration;
+
+use scoper::analyzer::analyze_source;
+use scoper::commands::merge::merge_findings;
+use scoper::exporter::{
+    CoverageSummary, DEFAULT_TOP_FILES, FindingsExport, coverage_summary, render_coverage_summary,
+};
+use scoper::rules_registry::{configure_registry, create_default_registry};
+use scoper::{FileAnalysisResult, SkipReason};
+
+fn skipped(file_path: &str, reason: SkipReason) -> FileAnalysisResult {
+    FileAnalysisResult {
+        file_path: file_path.to_string(),
+        parse_duration: Duration::ZERO,
+        semantic_duration: Duration::ZERO,
+        rule_durations: HashMap::new(),
+        total_duration: Duration::ZERO,
+        diagnostics: Vec::new(),
+        imports: Vec::new(),
+        skip_reason: Some(reason),
+    }
+}
+
+#[test]
+fn test_coverage_counts_scanned_skipped_and_errored_files() {
+    let mut registry = create_default_registry();
+    configure_registry(
+        &mut registry,
+        &[("no-debugger".to_string(), None, "error".to_string())],
+    );
+
+    let results = vec![
+        analyze_source("clean.ts", "const a = 1;\n", &registry),
+        analyze_source("debugger.ts", "debugger;\n", &registry),
+        analyze_source("broken.ts", "const x = ;\n", &registry),
+        skipped("binary.ts", SkipReason::Encoding),
+        skipped("gone.ts", SkipReason::ReadError),
+        skipped("other.ts", SkipReason::Encoding),
+    ];
+
+    assert_eq!(
+        coverage_summary(&results),
+        CoverageSummary {
+            files_scanned: 3,
+            files_with_findings: 2,
+            files_skipped: HashMap::from([
+                ("encoding".to_string(), 2),
+                ("read_error".to_string(), 1),
+            ]),
+            files_parse_errored: 1,
+        }
+    );
+}
+
+#[test]
+fn test_render_coverage_summary_lists_skip_reasons() {
+    let coverage = CoverageSummary {
+        files_scanned: 10,
+        files_with_findings: 4,
+        files_skipped: HashMap::from([("read_error".to_string(), 1), ("encoding".to_string(), 2)]),
+        files_parse_errored: 1,
+    };
+
+    assert_eq!(
+        render_coverage_summary(&coverage),
+        "Coverage: 10 files scanned, 4 with findings, 3 skipped, 1 with parse errors\n  \
+         skipped (encoding): 2\n  skipped (read_error): 1\n"
+    );
+}
+
+#[test]
+fn test_merge_sums_coverage() {
+    let export = |scanned: usize, encoding: usize| -> FindingsExport {
+        serde_json::from_value(serde_json::json!({
+            "findings": [],
+            "summary": {
+                "total_findings": 0,
+                "findings_by_rule": {},
+                "findings_by_severity": {},
+                "timestamp": "",
+                "total_duration_ms": 0,
+                "files_processed": scanned + encoding,
+                "files_per_second_wall_time": 0.0,
+                "parallel_cores_used": 1,
+                "parallel_efficiency_percent": 0.0,
+                "scan_duration_ms": 0,
+                "analysis_duration_ms": 0,
+                "coverage": {
+                    "files_scanned": scanned,
+                    "files_with_findings": 1,
+                    "files_skipped": { "encoding": encoding },
+                    "files_parse_errored": 0
+                }
+            }
+        }))
+        .unwrap()
+    };
+
+    let coverage = merge_findings(vec![export(3, 1), export(5, 2)], DEFAULT_TOP_FILES)
+        .summary
+        .coverage;
+    assert_eq!(coverage.files_scanned, 8);
+    assert_eq!(coverage.files_with_findings, 2);
+    assert_eq!(
+        coverage.files_skipped,
+        HashMap::from([("encoding".to_string(), 3)])
+    );
+}
+
+#[test]
+fn test_run_reports_coverage_in_summary() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("clean.ts"), "export const a = 1;\n").unwrap();
+    fs::write(dir.path().join("broken.ts"), "const x = ;\n").unwrap();
+    fs::write(dir.path().join("latin1.ts"), b"const s = '\xe9';\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_scoper"))
+        .arg(dir.path())
+        .args(["--output-dir", "-"])
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let export: FindingsExport = serde_json::from_slice(&output.stdout).unwrap();
+    let coverage = export.summary.coverage;
+    assert_eq!(coverage.files_scanned, 2);
+    assert_eq!(coverage.files_parse_errored, 1);
+    assert_eq!(
+        coverage.files_skipped,
+        HashMap::from([("encoding".to_string(), 1)])
+    );
+}
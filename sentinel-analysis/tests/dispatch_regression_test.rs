@@ -0,0 +1,157 @@
+//! Guards the per-node rule dispatch of `run_rules_with_metrics` against regressions
+//!
+//! The rules used here report nothing, so the measured time is the cost of the
+//! dispatch loop itself. Timings are compared with each other rather than with fixed
+//! numbers where possible, so the checks hold on slow CI machines as well.
+
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use scoper::{Rule, RulesRegistry};
+
+const FIXTURE: &str = include_str!("../test_files/large.ts");
+
+const NOOP_RULES: [&str; 4] = ["noop-1", "noop-2", "noop-3", "noop-4"];
+
+/// Doubling the rules or the nodes must not more than triple the dispatch time
+const MAX_SCALING_FACTOR: f64 = 3.0;
+
+/// Upper bound for dispatching one node to one rule, about ten times the usual cost
+///
+/// Only checked by the ignored budget test, as it depends on the machine.
+const MAX_NANOS_PER_VISIT: f64 = if cfg!(debug_assertions) {
+    5_000.0
+} else {
+    1_500.0
+};
+
+const SAMPLES: usize = 25;
+
+/// Rule without any checks
+struct NoopRule(&'static str);
+
+impl Rule for NoopRule {
+    fn name(&self) -> &'static str {
+        self.0
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports nothing"
+    }
+}
+
+fn noop_registry(rule_count: usize) -> RulesRegistry {
+    let mut registry = RulesRegistry::new();
+    for name in &NOOP_RULES[..rule_count] {
+        registry.register_rule(Box::new(NoopRule(name)));
+        registry.enable_rule(name);
+    }
+    registry
+}
+
+/// Parsed source with its semantic model, ready for dispatching
+struct Fixture {
+    source: String,
+    allocator: Allocator,
+}
+
+impl Fixture {
+    fn new(source: String) -> Self {
+        Self {
+            source,
+            allocator: Allocator::default(),
+        }
+    }
+
+    /// Fastest of several runs of each registry on the fixture, plus the number of nodes
+    ///
+    /// Runs of the registries are interleaved and the minimum is taken, so background
+    /// load affects all registries alike and does not inflate the result.
+    fn dispatch_times(&self, registries: &[&RulesRegistry]) -> (Vec<Duration>, usize) {
+        let parsed = Parser::new(&self.allocator, &self.source, SourceType::ts()).parse();
+        let semantic = SemanticBuilder::new().build(&parsed.program);
+
+        let mut fastest = vec![Duration::MAX; registries.len()];
+        for _ in 0..SAMPLES {
+            for (registry, fastest) in registries.iter().zip(&mut fastest) {
+                let start = Instant::now();
+                std::hint::black_box(registry.run_rules_with_metrics(
+                    &semantic,
+                    "large.ts",
+                    &self.source,
+                ));
+                *fastest = (*fastest).min(start.elapsed());
+            }
+        }
+        (fastest, semantic.semantic.nodes().len())
+    }
+}
+
+/// Timing tests must not run alongside each other
+fn serialize() -> MutexGuard<'static, ()> {
+    static LOCK: Mutex<()> = Mutex::new(());
+    LOCK.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn ratio(larger: Duration, smaller: Duration) -> f64 {
+    larger.as_secs_f64() / smaller.as_secs_f64()
+}
+
+#[test]
+fn test_dispatch_scales_linearly_with_rules() {
+    let _guard = serialize();
+    let fixture = Fixture::new(FIXTURE.to_string());
+    let (times, _) = fixture.dispatch_times(&[&noop_registry(2), &noop_registry(4)]);
+    let (two_rules, four_rules) = (times[0], times[1]);
+
+    let factor = ratio(four_rules, two_rules);
+    assert!(
+        factor < MAX_SCALING_FACTOR,
+        "4 rules took {:.1}x as long as 2 rules ({:?} vs {:?})",
+        factor,
+        four_rules,
+        two_rules
+    );
+}
+
+#[test]
+fn test_dispatch_scales_linearly_with_nodes() {
+    let _guard = serialize();
+    let registry = noop_registry(2);
+    let (single, single_nodes) = Fixture::new(FIXTURE.to_string()).dispatch_times(&[&registry]);
+    let (double, double_nodes) =
+        Fixture::new(format!("{}\n{}", FIXTURE, FIXTURE)).dispatch_times(&[&registry]);
+    let (single, double) = (single[0], double[0]);
+    assert!(double_nodes >= 2 * single_nodes - 1);
+
+    let factor = ratio(double, single);
+    assert!(
+        factor < MAX_SCALING_FACTOR,
+        "twice the nodes took {:.1}x as long ({:?} vs {:?})",
+        factor,
+        double,
+        single
+    );
+}
+
+/// Absolute timings depend on the machine, so this check only runs on request:
+/// `cargo test --release --test dispatch_regression_test -- --ignored`
+#[test]
+#[ignore = "absolute timing budget, flaky on shared runners"]
+fn test_dispatch_cost_per_node_stays_within_budget() {
+    let _guard = serialize();
+    let (times, nodes) = Fixture::new(FIXTURE.to_string()).dispatch_times(&[&noop_registry(4)]);
+    let duration = times[0];
+
+    let nanos_per_visit = duration.as_nanos() as f64 / (nodes * 4) as f64;
+    assert!(
+        nanos_per_visit < MAX_NANOS_PER_VISIT,
+        "dispatching a node to a rule took {:.0}ns, budget is {:.0}ns",
+        nanos_per_visit,
+        MAX_NANOS_PER_VISIT
+    );
+}
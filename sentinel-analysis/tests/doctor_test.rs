@@ -0,0 +1,80 @@
+use std::fs;
+use std::process::Command;
+
+/// Write a sentinel.json pointing at an API that refuses connections
+fn write_config(dir: &std::path::Path, rules_config: &str) {
+    fs::write(
+        dir.join("sentinel.json"),
+        format!(
+            r#"{{ "output_dir": "reports", "rules_config": "{}", "api_url": "http://127.0.0.1:9/" }}"#,
+            rules_config
+        ),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_doctor_passes_for_a_valid_setup() {
+    let dir = tempfile::tempdir().unwrap();
+    write_config(dir.path(), "rules.json");
+    fs::write(
+        dir.path().join("rules.json"),
+        r#"{ "rules": { "no-debugger": "error" } }"#,
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_scoper"))
+        .args(["doctor", "--config", "sentinel.json"])
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success(), "{}", stdout);
+    assert!(stdout.contains("[PASS] Config file: sentinel.json"));
+    assert!(stdout.contains("[PASS] Rules config: rules.json (1 rules)"));
+    assert!(stdout.contains("[PASS] Output directory: reports is writable"));
+    assert!(stdout.contains("[WARN] API endpoint"));
+    assert!(stdout.contains("[PASS] Rules registry"));
+    // Checking the output directory must not create it
+    assert!(!dir.path().join("reports").exists());
+}
+
+#[test]
+fn test_doctor_fails_for_an_invalid_rules_config() {
+    let dir = tempfile::tempdir().unwrap();
+    write_config(dir.path(), "rules.json");
+    fs::write(dir.path().join("rules.json"), "{ not json").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_scoper"))
+        .args(["doctor", "--config", "sentinel.json"])
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(!output.status.success());
+    assert!(stdout.contains("[FAIL] Rules config"));
+    assert!(stdout.contains("1 critical check(s) failed"));
+}
+
+#[test]
+fn test_doctor_reports_unknown_rules_as_warning() {
+    let dir = tempfile::tempdir().unwrap();
+    write_config(dir.path(), "rules.json");
+    fs::write(
+        dir.path().join("rules.json"),
+        r#"{ "rules": { "no-such-rule": "error" } }"#,
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_scoper"))
+        .args(["doctor", "--config", "sentinel.json"])
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success(), "{}", stdout);
+    assert!(stdout.contains("[WARN] Rules config: rules.json: unknown rules no-such-rule"));
+}
@@ -0,0 +1,65 @@
+mod common;
+
+use common::{run_rule, run_rule_with_config};
+use scoper::rules_registry::create_default_registry;
+use serde_json::json;
+
+const RULE: &str = "eqeqeq";
+
+#[test]
+fn test_loose_equality_is_flagged() {
+    let diagnostics = run_rule(RULE, "if (count == '0') {}\n");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].diagnostic.message,
+        "Expected `===` instead of `==`"
+    );
+    assert_eq!(diagnostics[0].column_number, 11);
+
+    let diagnostics = run_rule(RULE, "const changed = (a) != b;\n");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].diagnostic.message,
+        "Expected `!==` instead of `!=`"
+    );
+    assert_eq!(diagnostics[0].column_number, 21);
+}
+
+#[test]
+fn test_strict_equality_is_allowed() {
+    assert!(run_rule(RULE, "if (a === b || c !== d || e >= f) {}\n").is_empty());
+}
+
+#[test]
+fn test_null_comparison_is_configurable() {
+    let code = "if (user == null || null != other) {}\n";
+    assert_eq!(run_rule(RULE, code).len(), 2);
+
+    let config = Some(json!({ "allowNull": true }));
+    assert!(run_rule_with_config(RULE, config.clone(), "test-file.ts", code).is_empty());
+    assert_eq!(
+        run_rule_with_config(RULE, config, "test-file.ts", "if (user == undefined) {}\n").len(),
+        1
+    );
+}
+
+#[test]
+fn test_fix_uses_the_strict_operator() {
+    let registry = create_default_registry();
+    let rule = registry.get_rule(RULE).unwrap();
+
+    let code = "if (a == b && c != d) {}\n";
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 2);
+
+    // Fix from the end of the file so the offsets of earlier findings stay valid
+    let mut fixed = code.to_string();
+    for diagnostic in diagnostics.iter().rev() {
+        fixed = rule
+            .fix(&diagnostic.diagnostic, &fixed)
+            .unwrap()
+            .apply(&fixed);
+    }
+    assert_eq!(fixed, "if (a === b && c !== d) {}\n");
+    assert!(run_rule(RULE, &fixed).is_empty());
+}
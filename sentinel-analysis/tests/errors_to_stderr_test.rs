@@ -0,0 +1,68 @@
+use std::fs;
+use std::process::{Command, Output};
+
+use scoper::exporter::FindingsExport;
+
+/// Run on a file with a `no-debugger` error and a `no-enum` warning
+fn run(extra_args: &[&str]) -> (Output, FindingsExport) {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("app.ts"), "debugger;\nenum Color { Red }\n").unwrap();
+    fs::write(
+        dir.path().join("rules.json"),
+        r#"{ "rules": { "no-debugger": "error", "no-enum": "warn" } }"#,
+    )
+    .unwrap();
+    // An unreachable API keeps the run from submitting anything
+    fs::write(
+        dir.path().join("sentinel.json"),
+        r#"{ "api_url": "http://127.0.0.1:9" }"#,
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_scoper"))
+        .arg(dir.path())
+        .args(["--config", "sentinel.json", "--rules-config", "rules.json"])
+        .args(["--output-dir", "out", "--format", "github"])
+        .args(extra_args)
+        .env_remove("GITHUB_WORKSPACE")
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let findings = fs::read_to_string(dir.path().join("out/findings.json")).unwrap();
+    (output, serde_json::from_str(&findings).unwrap())
+}
+
+#[test]
+fn test_errors_are_printed_to_stderr() {
+    let (output, export) = run(&["--errors-to-stderr"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(stderr.contains("::error file=app.ts,line=1,col=1,title=no-debugger::"));
+    assert!(stderr.contains("no-debugger"));
+    assert!(stderr.contains("Total: 1 errors found"));
+    assert!(!stderr.contains("no-enum"));
+
+    assert!(stdout.contains("::warning file=app.ts,line=2,col=6,title=no-enum::"));
+    assert!(stdout.contains("Total: 1 issues found"));
+    assert!(!stdout.contains("title=no-debugger"));
+    assert!(!stdout.contains("| no-debugger"));
+
+    // The findings file is unchanged
+    assert_eq!(export.findings.len(), 2);
+}
+
+#[test]
+fn test_all_findings_go_to_stdout_by_default() {
+    let (output, export) = run(&[]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(stdout.contains("title=no-debugger::"));
+    assert!(stdout.contains("title=no-enum::"));
+    assert!(stdout.contains("Total: 2 issues found"));
+    assert!(!stderr.contains("no-debugger"));
+    assert_eq!(export.findings.len(), 2);
+}
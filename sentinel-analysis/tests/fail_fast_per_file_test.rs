@@ -0,0 +1,78 @@
+use std::fs;
+use std::process::Command;
+
+use scoper::exporter::FindingsExport;
+
+/// Run on a file with a `no-enum` warning followed by `no-as-any` and `no-debugger` errors,
+/// and on a file with only a `no-debugger` error; returns the sorted (file, rule) pairs
+fn run(extra_args: &[&str]) -> Vec<(String, String)> {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(
+        dir.path().join("app.ts"),
+        "enum Color { Red }\nconst count = value as any;\ndebugger;\n",
+    )
+    .unwrap();
+    fs::write(dir.path().join("other.ts"), "debugger;\n").unwrap();
+    fs::write(
+        dir.path().join("rules.json"),
+        r#"{ "rules": { "no-enum": "warn", "no-as-any": "error", "no-debugger": "error" } }"#,
+    )
+    .unwrap();
+    // An unreachable API keeps the run from submitting anything
+    fs::write(
+        dir.path().join("sentinel.json"),
+        r#"{ "api_url": "http://127.0.0.1:9" }"#,
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_scoper"))
+        .arg(dir.path())
+        .args(["--config", "sentinel.json", "--rules-config", "rules.json"])
+        .args(["--output-dir", "out"])
+        .args(extra_args)
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let findings = fs::read_to_string(dir.path().join("out/findings.json")).unwrap();
+    let export: FindingsExport = serde_json::from_str(&findings).unwrap();
+    let mut findings: Vec<(String, String)> = export
+        .findings
+        .into_iter()
+        .map(|finding| (finding.file, finding.rule))
+        .collect();
+    findings.sort();
+    findings
+}
+
+fn pair(file: &str, rule: &str) -> (String, String) {
+    (file.to_string(), rule.to_string())
+}
+
+#[test]
+fn test_all_rules_run_without_fail_fast() {
+    assert_eq!(
+        run(&[]),
+        [
+            pair("app.ts", "no-as-any"),
+            pair("app.ts", "no-debugger"),
+            pair("app.ts", "no-enum"),
+            pair("other.ts", "no-debugger"),
+        ]
+    );
+}
+
+#[test]
+fn test_fail_fast_per_file_stops_a_file_after_its_first_error() {
+    // The warning does not stop the file; the `as any` error does, so the later
+    // `debugger` is not reported. The other file is still analyzed.
+    assert_eq!(
+        run(&["--fail-fast-per-file"]),
+        [
+            pair("app.ts", "no-as-any"),
+            pair("app.ts", "no-enum"),
+            pair("other.ts", "no-debugger"),
+        ]
+    );
+}
@@ -0,0 +1,42 @@
+use std::fs;
+use std::process::Command;
+
+use scoper::exporter::FindingsExport;
+
+/// Run the analyzer on a scan root with debugger statements in several files
+fn analyze(extra_args: &[&str]) -> FindingsExport {
+    let scan_root = tempfile::tempdir().unwrap();
+    fs::create_dir(scan_root.path().join("nested")).unwrap();
+    for name in ["zeta.ts", "alpha.ts", "nested/beta.ts", "gamma.ts"] {
+        fs::write(scan_root.path().join(name), "debugger;\n").unwrap();
+    }
+    fs::write(
+        scan_root.path().join("rules.json"),
+        r#"{ "rules": { "no-debugger": "error" } }"#,
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_scoper"))
+        .arg(scan_root.path())
+        .args(["--rules-config", "rules.json", "--output-dir", "-"])
+        .args(extra_args)
+        .current_dir(scan_root.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    serde_json::from_slice(&output.stdout).unwrap()
+}
+
+#[test]
+fn test_files_are_analyzed_in_path_order_by_default() {
+    let export = analyze(&[]);
+    let files: Vec<&str> = export.findings.iter().map(|f| f.file.as_str()).collect();
+    assert_eq!(files, ["alpha.ts", "gamma.ts", "nested/beta.ts", "zeta.ts"]);
+}
+
+#[test]
+fn test_no_sort_files_still_analyzes_every_file() {
+    let export = analyze(&["--no-sort-files"]);
+    assert_eq!(export.summary.files_processed, 4);
+    assert_eq!(export.findings.len(), 4);
+}
@@ -0,0 +1,75 @@
+use std::fs;
+use std::process::Command;
+
+use scoper::exporter::FindingsExport;
+use scoper::utilities::file_utils::read_file_list;
+
+/// Scan root with a debugger statement in `src/a.ts`, `src/b.ts` and `src/unlisted.ts`
+fn scan_root() -> tempfile::TempDir {
+    let root = tempfile::tempdir().unwrap();
+    fs::create_dir(root.path().join("src")).unwrap();
+    for name in ["a.ts", "b.ts", "unlisted.ts"] {
+        fs::write(root.path().join("src").join(name), "debugger;\n").unwrap();
+    }
+    fs::write(root.path().join("README.md"), "# Project\n").unwrap();
+    fs::write(
+        root.path().join("rules.json"),
+        r#"{ "rules": { "no-debugger": "error" } }"#,
+    )
+    .unwrap();
+    root
+}
+
+#[test]
+fn test_file_list_reports_missing_and_unsupported_files() {
+    let root = scan_root();
+    let path = |name: &str| root.path().join(name).to_string_lossy().to_string();
+    let manifest = path("files.txt");
+    fs::write(
+        &manifest,
+        [
+            path("src/a.ts"),
+            String::new(),
+            path("src/b.ts"),
+            path("src/gone.ts"),
+            path("README.md"),
+            path("src/a.ts"),
+        ]
+        .join("\n"),
+    )
+    .unwrap();
+
+    let list = read_file_list(&manifest, &["ts"]).unwrap();
+
+    assert_eq!(list.files, [path("src/a.ts"), path("src/b.ts")]);
+    assert_eq!(list.missing, [path("src/gone.ts")]);
+    assert_eq!(list.unsupported, [path("README.md")]);
+
+    assert!(read_file_list(&path("none.txt"), &["ts"]).is_err());
+}
+
+#[test]
+fn test_files_from_analyzes_only_the_listed_files() {
+    let root = scan_root();
+    fs::write(
+        root.path().join("files.txt"),
+        "src/a.ts\nsrc/b.ts\nsrc/gone.ts\n",
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_scoper"))
+        .arg(root.path())
+        .args(["--files-from", "files.txt", "--rules-config", "rules.json"])
+        .args(["--output-dir", "-"])
+        .current_dir(root.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let export: FindingsExport = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(export.summary.files_processed, 2);
+    let mut files: Vec<&str> = export.findings.iter().map(|f| f.file.as_str()).collect();
+    files.sort();
+    assert_eq!(files, ["src/a.ts", "src/b.ts"]);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("src/gone.ts does not exist"));
+}
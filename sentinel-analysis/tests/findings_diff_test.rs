@@ -0,0 +1,107 @@
+use scoper::commands::diff::{RuleDelta, diff_findings};
+use scoper::exporter::{FindingEntry, FindingsExport, FindingsSummary};
+use std::collections::HashMap;
+
+fn finding(rule: &str, file: &str, line: usize) -> FindingEntry {
+    FindingEntry {
+        rule: rule.to_string(),
+        message: format!("{} finding", rule),
+        file: file.to_string(),
+        line,
+        column: 1,
+        severity: "error".to_string(),
+        help: None,
+        fingerprint: format!("{}:{}", rule, file),
+        snippet: None,
+    }
+}
+
+fn export(findings: Vec<FindingEntry>) -> FindingsExport {
+    FindingsExport {
+        summary: FindingsSummary {
+            total_findings: findings.len(),
+            findings_by_rule: HashMap::new(),
+            findings_by_severity: HashMap::new(),
+            timestamp: String::new(),
+            total_duration_ms: 0,
+            files_processed: 1,
+            files_per_second_wall_time: 0.0,
+            parallel_cores_used: 1,
+            parallel_efficiency_percent: 100.0,
+            scan_duration_ms: 0,
+            analysis_duration_ms: 0,
+            coverage: Default::default(),
+            findings_by_file_top: Vec::new(),
+        },
+        findings,
+    }
+}
+
+/// (rule, file, line) of each finding, for comparisons
+fn locations(findings: &[FindingEntry]) -> Vec<(&str, &str, usize)> {
+    findings
+        .iter()
+        .map(|finding| (finding.rule.as_str(), finding.file.as_str(), finding.line))
+        .collect()
+}
+
+#[test]
+fn test_new_finding_is_added() {
+    let old = export(vec![finding("no-var", "a.ts", 1)]);
+    let new = export(vec![
+        finding("no-var", "a.ts", 1),
+        finding("no-debugger", "b.ts", 3),
+    ]);
+
+    let diff = diff_findings(&old, &new);
+    assert_eq!(locations(&diff.added), [("no-debugger", "b.ts", 3)]);
+    assert!(diff.removed.is_empty());
+}
+
+#[test]
+fn test_fixed_finding_is_removed() {
+    let old = export(vec![
+        finding("no-var", "a.ts", 1),
+        finding("no-debugger", "b.ts", 3),
+    ]);
+    let new = export(vec![finding("no-var", "a.ts", 1)]);
+
+    let diff = diff_findings(&old, &new);
+    assert!(diff.added.is_empty());
+    assert_eq!(locations(&diff.removed), [("no-debugger", "b.ts", 3)]);
+}
+
+#[test]
+fn test_finding_on_a_moved_line_is_unchanged() {
+    // Lines inserted above the finding move it, but keep its fingerprint
+    let old = export(vec![finding("no-debugger", "b.ts", 3)]);
+    let new = export(vec![finding("no-debugger", "b.ts", 10)]);
+    assert_eq!(old.findings[0].fingerprint, new.findings[0].fingerprint);
+
+    let diff = diff_findings(&old, &new);
+    assert!(diff.added.is_empty());
+    assert!(diff.removed.is_empty());
+    assert_eq!(
+        diff.rule_deltas,
+        [RuleDelta {
+            rule: "no-debugger".to_string(),
+            old_count: 1,
+            new_count: 1,
+            delta: 0,
+        }]
+    );
+}
+
+#[test]
+fn test_duplicate_findings_are_matched_one_to_one() {
+    let old = export(vec![finding("no-var", "a.ts", 1)]);
+    let new = export(vec![
+        finding("no-var", "a.ts", 1),
+        finding("no-var", "a.ts", 2),
+    ]);
+
+    let diff = diff_findings(&old, &new);
+    assert_eq!(diff.added.len(), 1);
+    assert!(diff.removed.is_empty());
+    assert_eq!(diff.rule_deltas[0].delta, 1);
+}
@@ -0,0 +1,50 @@
+use scoper::analyzer::analyze_source;
+use scoper::exporter::{code_context, finding_fingerprint};
+use scoper::rules_registry::{configure_registry, create_default_registry};
+
+/// Fingerprints of the findings in `code`, as written to the findings export
+fn fingerprints(file: &str, code: &str) -> Vec<String> {
+    let mut registry = create_default_registry();
+    configure_registry(
+        &mut registry,
+        &[("no-debugger".to_string(), None, "error".to_string())],
+    );
+
+    analyze_source(file, code, &registry)
+        .diagnostics
+        .iter()
+        .map(|diagnostic| finding_fingerprint(&diagnostic.rule_id, file, &code_context(diagnostic)))
+        .collect()
+}
+
+#[test]
+fn test_fingerprint_survives_line_shifts() {
+    let original = fingerprints("src/app.ts", "function run() {\n  debugger;\n}\n");
+    let shifted = fingerprints(
+        "src/app.ts",
+        "import { x } from './x';\n\n// setup\nfunction run() {\n    debugger;\n}\n",
+    );
+
+    assert_eq!(original.len(), 1);
+    assert_eq!(original, shifted);
+    assert_eq!(original[0].len(), 16);
+}
+
+#[test]
+fn test_fingerprint_changes_with_code_or_file() {
+    let original = fingerprints("src/app.ts", "function run() {\n  debugger;\n}\n");
+
+    let edited = fingerprints("src/app.ts", "function run() {\n  debugger; reset();\n}\n");
+    assert_ne!(original, edited);
+
+    let moved = fingerprints("src/main.ts", "function run() {\n  debugger;\n}\n");
+    assert_ne!(original, moved);
+}
+
+#[test]
+fn test_fingerprint_normalizes_path_separators() {
+    assert_eq!(
+        finding_fingerprint("no-debugger", "src\\app.ts", "debugger;"),
+        finding_fingerprint("no-debugger", "src/app.ts", "debugger;")
+    );
+}
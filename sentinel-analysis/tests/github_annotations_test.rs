@@ -0,0 +1,207 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use scoper::exporter::{
+    FindingEntry, OutputFormat, SeverityLevel, github_annotation, render_github_annotations,
+    workspace_relative_path,
+};
+
+fn finding(rule: &str, severity: &str, message: &str) -> FindingEntry {
+    FindingEntry {
+        rule: rule.to_string(),
+        message: message.to_string(),
+        file: "src/app.ts".to_string(),
+        line: 3,
+        column: 5,
+        severity: severity.to_string(),
+        help: None,
+        fingerprint: String::new(),
+        snippet: None,
+    }
+}
+
+#[test]
+fn test_format_and_severity_parse() {
+    assert_eq!("github".parse::<OutputFormat>(), Ok(OutputFormat::Github));
+    assert_eq!("text".parse::<OutputFormat>(), Ok(OutputFormat::Text));
+    assert!("sarif".parse::<OutputFormat>().is_err());
+    assert_eq!("warn".parse::<SeverityLevel>(), Ok(SeverityLevel::Warning));
+    assert_eq!("info".parse::<SeverityLevel>(), Ok(SeverityLevel::Info));
+    assert!("fatal".parse::<SeverityLevel>().is_err());
+}
+
+#[test]
+fn test_severity_maps_to_workflow_command() {
+    assert_eq!(
+        github_annotation(&finding("no-debugger", "error", "Unexpected debugger")),
+        "::error file=src/app.ts,line=3,col=5,title=no-debugger::Unexpected debugger"
+    );
+    assert!(github_annotation(&finding("no-enum", "warning", "m")).starts_with("::warning "));
+    assert!(
+        github_annotation(&finding("prefer-const-assertion", "info", "m")).starts_with("::notice ")
+    );
+}
+
+#[test]
+fn test_annotation_values_are_escaped() {
+    let mut entry = finding("no-shadow", "error", "100% wrong\nsecond line");
+    entry.file = "src/a,b:c.ts".to_string();
+
+    assert_eq!(
+        github_annotation(&entry),
+        "::error file=src/a%2Cb%3Ac.ts,line=3,col=5,title=no-shadow::100%25 wrong%0Asecond line"
+    );
+}
+
+#[test]
+fn test_annotations_respect_minimum_severity() {
+    let findings = vec![
+        finding("no-debugger", "error", "error"),
+        finding("no-enum", "warning", "warning"),
+        finding("prefer-const-assertion", "info", "info"),
+    ];
+
+    let render = |min_severity| {
+        render_github_annotations(&findings, min_severity, None, Path::new("/workspace"))
+    };
+
+    assert_eq!(render(SeverityLevel::Info).lines().count(), 3);
+    let warnings = render(SeverityLevel::Warning);
+    assert_eq!(warnings.lines().count(), 2);
+    assert!(!warnings.contains("::notice"));
+    let errors = render(SeverityLevel::Error);
+    assert_eq!(
+        errors.lines().collect::<Vec<_>>(),
+        [github_annotation(&findings[0])]
+    );
+}
+
+#[test]
+fn test_annotation_paths_are_relative_to_the_workspace() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path().join("frontend");
+    fs::create_dir_all(root.join("src")).unwrap();
+    fs::write(root.join("src/app.ts"), "").unwrap();
+    let root = root.to_string_lossy();
+
+    assert_eq!(
+        workspace_relative_path("src/app.ts", Some(&root), dir.path()),
+        "frontend/src/app.ts"
+    );
+    let absolute = format!("{}/src/app.ts", root);
+    assert_eq!(
+        workspace_relative_path(&absolute, None, dir.path()),
+        "frontend/src/app.ts"
+    );
+    // Files outside the workspace keep their exported path
+    let elsewhere = tempfile::tempdir().unwrap();
+    assert_eq!(
+        workspace_relative_path("src/app.ts", Some(&root), elsewhere.path()),
+        "src/app.ts"
+    );
+}
+
+#[test]
+fn test_github_format_prints_annotations_and_writes_findings_json() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("app.ts"), "debugger;\nenum Color { Red }\n").unwrap();
+    fs::write(
+        dir.path().join("rules.json"),
+        r#"{ "rules": { "no-debugger": "error", "no-enum": "warn" } }"#,
+    )
+    .unwrap();
+    // An unreachable API keeps the run from submitting anything
+    fs::write(
+        dir.path().join("sentinel.json"),
+        r#"{ "api_url": "http://127.0.0.1:9" }"#,
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_scoper"))
+        .arg(dir.path())
+        .args(["--config", "sentinel.json", "--rules-config", "rules.json"])
+        .args([
+            "--output-dir",
+            "out",
+            "--format",
+            "github",
+            "--severity",
+            "error",
+        ])
+        .env_remove("GITHUB_WORKSPACE")
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let annotations: Vec<&str> = stdout
+        .lines()
+        .filter(|line| line.starts_with("::"))
+        .collect();
+    assert_eq!(annotations.len(), 1);
+    assert!(annotations[0].starts_with("::error file=app.ts,line=1,col=1,title=no-debugger::"));
+
+    // The findings file is complete, regardless of the annotation severity
+    let findings = fs::read_to_string(dir.path().join("out/findings.json")).unwrap();
+    assert!(findings.contains("no-debugger"));
+    assert!(findings.contains("no-enum"));
+}
+
+#[test]
+fn test_github_format_annotates_files_of_a_nested_scan_root() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::create_dir_all(dir.path().join("frontend/src")).unwrap();
+    fs::write(dir.path().join("frontend/src/app.ts"), "debugger;\n").unwrap();
+    fs::write(
+        dir.path().join("rules.json"),
+        r#"{ "rules": { "no-debugger": "error" } }"#,
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("sentinel.json"),
+        r#"{ "api_url": "http://127.0.0.1:9" }"#,
+    )
+    .unwrap();
+
+    let annotations = |working_dir: &Path, workspace: Option<&Path>| {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_scoper"));
+        command
+            .arg(dir.path().join("frontend"))
+            .arg("--config")
+            .arg(dir.path().join("sentinel.json"))
+            .arg("--rules-config")
+            .arg(dir.path().join("rules.json"))
+            .arg("--output-dir")
+            .arg(dir.path().join("out"))
+            .args(["--format", "github"])
+            .env_remove("GITHUB_WORKSPACE")
+            .current_dir(working_dir);
+        if let Some(workspace) = workspace {
+            command.env("GITHUB_WORKSPACE", workspace);
+        }
+        let output = command.output().unwrap();
+        assert!(output.status.success());
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter(|line| line.starts_with("::"))
+            .map(str::to_string)
+            .collect::<Vec<_>>()
+    };
+
+    let expected = "::error file=frontend/src/app.ts,line=1,col=1,title=no-debugger::";
+    // Relative to the working directory by default, and to GITHUB_WORKSPACE when it is set
+    let from_working_dir = annotations(dir.path(), None);
+    assert!(
+        from_working_dir[0].starts_with(expected),
+        "{:?}",
+        from_working_dir
+    );
+    let from_workspace = annotations(&dir.path().join("frontend"), Some(dir.path()));
+    assert!(
+        from_workspace[0].starts_with(expected),
+        "{:?}",
+        from_workspace
+    );
+}
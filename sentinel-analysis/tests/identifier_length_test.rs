@@ -0,0 +1,46 @@
+mod common;
+
+use common::{run_rule, run_rule_with_config};
+use serde_json::json;
+
+const RULE: &str = "identifier-length";
+
+#[test]
+fn test_short_identifier_is_flagged() {
+    let code = "const a = load();\nfunction run(b: number) {}\n";
+
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 2);
+    assert!(diagnostics[0].diagnostic.message.contains("`a` is shorter"));
+    assert_eq!(diagnostics[0].line_number, 1);
+    assert_eq!(diagnostics[0].column_number, 7);
+    assert!(diagnostics[1].diagnostic.message.contains("`b` is shorter"));
+}
+
+#[test]
+fn test_loop_variables_imports_and_type_parameters_are_allowed() {
+    let code = "import { v } from './v';\n\
+                function first<T>(items: T[]): T { return items[0]; }\n\
+                for (let i = 0; i < 3; i++) {}\n\
+                const points = [{ x: 1, y: 2 }].map(({ x, y }) => x + y);\n";
+    assert!(run_rule(RULE, code).is_empty());
+}
+
+#[test]
+fn test_long_identifier_is_flagged() {
+    let code = "let totalNumberOfUnprocessedNotificationsForTheCurrentUser = 0;\n";
+
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].diagnostic.message.contains("longer than 40"));
+}
+
+#[test]
+fn test_config_overrides_limits_and_exceptions() {
+    let config = Some(json!({ "min": 3, "max": 10, "exceptions": ["id"] }));
+    let code = "const id = 1;\nconst ab = 2;\nconst descriptiveName = 3;\nconst i = 4;\n";
+
+    let diagnostics = run_rule_with_config(RULE, config, "test-file.ts", code);
+    let lines: Vec<usize> = diagnostics.iter().map(|d| d.line_number).collect();
+    assert_eq!(lines, [2, 3, 4]);
+}
@@ -0,0 +1,24 @@
+mod common;
+
+use common::run_rule;
+
+const RULE: &str = "imports-first";
+
+#[test]
+fn test_imports_at_the_top_are_allowed() {
+    let code = "'use strict';\nimport { a } from './a';\nimport b from './b';\nconst value = a(b);\nexport { value };\n";
+    assert!(run_rule(RULE, code).is_empty());
+    assert!(run_rule(RULE, "const value = 1;\n").is_empty());
+}
+
+#[test]
+fn test_import_after_const_is_flagged() {
+    let code = "import { a } from './a';\nconst value = a();\nimport { b } from './b';\nimport './polyfills';\n";
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(
+        (diagnostics[0].line_number, diagnostics[0].column_number),
+        (3, 1)
+    );
+    assert_eq!(diagnostics[1].line_number, 4);
+}
@@ -0,0 +1,72 @@
+use std::fs;
+use std::process::Command;
+
+use scoper::exporter::{FindingEntry, FindingsExport};
+
+#[test]
+fn test_findings_are_appended_as_ndjson() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("a.ts"), "debugger;\n").unwrap();
+    fs::write(dir.path().join("b.ts"), "debugger;\ndebugger;\n").unwrap();
+    fs::write(dir.path().join("clean.ts"), "export const a = 1;\n").unwrap();
+    fs::write(
+        dir.path().join("rules.json"),
+        r#"{ "rules": { "no-debugger": "error" } }"#,
+    )
+    .unwrap();
+    // An unreachable API keeps the run from submitting anything
+    fs::write(
+        dir.path().join("sentinel.json"),
+        r#"{ "api_url": "http://127.0.0.1:9" }"#,
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_scoper"))
+        .arg(dir.path())
+        .args(["--config", "sentinel.json", "--rules-config", "rules.json"])
+        .args(["--output-dir", "out", "--incremental-findings"])
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let ndjson = fs::read_to_string(dir.path().join("out/findings.ndjson")).unwrap();
+    let mut findings: Vec<FindingEntry> = ndjson
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    findings.sort_by(|a, b| (&a.file, a.line).cmp(&(&b.file, b.line)));
+
+    // The consolidated export holds the same findings
+    let content = fs::read_to_string(dir.path().join("out/findings.json")).unwrap();
+    let export: FindingsExport = serde_json::from_str(&content).unwrap();
+    let fingerprints = |findings: &[FindingEntry]| -> Vec<String> {
+        findings.iter().map(|f| f.fingerprint.clone()).collect()
+    };
+    assert_eq!(findings.len(), 3);
+    assert_eq!(fingerprints(&findings), fingerprints(&export.findings));
+}
+
+#[test]
+fn test_previous_ndjson_is_replaced() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::create_dir(dir.path().join("out")).unwrap();
+    fs::write(dir.path().join("out/findings.ndjson"), "stale\n").unwrap();
+    fs::write(dir.path().join("a.ts"), "export const a = 1;\n").unwrap();
+    fs::write(
+        dir.path().join("sentinel.json"),
+        r#"{ "api_url": "http://127.0.0.1:9", "incremental_findings": true }"#,
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_scoper"))
+        .arg(dir.path())
+        .args(["--config", "sentinel.json", "--output-dir", "out"])
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let ndjson = fs::read_to_string(dir.path().join("out/findings.ndjson")).unwrap();
+    assert!(ndjson.is_empty());
+}
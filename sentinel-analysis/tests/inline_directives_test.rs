@@ -0,0 +1,54 @@
+mod common;
+
+use common::run_rule;
+
+/// Lines of the `no-debugger` findings in `code`
+fn debugger_lines(code: &str) -> Vec<usize> {
+    run_rule("no-debugger", code)
+        .iter()
+        .map(|diagnostic| diagnostic.line_number)
+        .collect()
+}
+
+#[test]
+fn test_file_wide_disable() {
+    let code = "// sentinel-disable no-debugger\nimport { a } from './a';\ndebugger;\nfunction f() {\n  debugger;\n}\n";
+    assert!(debugger_lines(code).is_empty());
+}
+
+#[test]
+fn test_file_wide_disable_covers_file_level_findings() {
+    let code = "// sentinel-disable no-empty-module\nimport { a } from './a';\n";
+    assert!(run_rule("no-empty-module", code).is_empty());
+}
+
+#[test]
+fn test_disable_enable_block() {
+    let code = "debugger;\n/* sentinel-disable no-debugger */\ndebugger;\ndebugger;\n// sentinel-enable no-debugger\ndebugger;\n";
+    assert_eq!(debugger_lines(code), vec![1, 6]);
+}
+
+#[test]
+fn test_directive_without_rules_applies_to_all_rules() {
+    let code =
+        "debugger;\n// sentinel-disable\ndebugger;\n// sentinel-enable no-debugger\ndebugger;\n";
+    assert_eq!(debugger_lines(code), vec![1, 5]);
+}
+
+#[test]
+fn test_directives_for_other_rules_are_ignored() {
+    let code = "// sentinel-disable no-as-any, member-ordering\ndebugger;\n// sentinel-disable-next-line no-debugger\ndebugger;\n";
+    assert_eq!(debugger_lines(code), vec![2, 4]);
+}
+
+#[test]
+fn test_ignore_directive_covers_its_line_and_the_next() {
+    let code = "debugger;\n// sentinel-ignore: no-debugger\ndebugger;\ndebugger;\ndebugger; // sentinel-ignore no-debugger\n";
+    assert_eq!(debugger_lines(code), vec![1, 4]);
+}
+
+#[test]
+fn test_ignore_directive_for_other_rules_is_ignored() {
+    let code = "// sentinel-ignore: no-as-any\ndebugger;\n";
+    assert_eq!(debugger_lines(code), vec![2]);
+}
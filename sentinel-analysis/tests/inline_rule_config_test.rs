@@ -0,0 +1,104 @@
+use std::fs;
+use std::process::Command;
+
+use scoper::DebugLevel;
+use scoper::analyzer::analyze_source;
+use scoper::exporter::FindingsExport;
+use scoper::rules_registry::{InlineRuleConfig, setup_rules_registry};
+use scoper::utilities::config::Config;
+use serde_json::json;
+
+/// Component with six signal inputs, one more than the default maximum of five
+const COMPONENT: &str = "import { Component, input } from '@angular/core';\n\
+    @Component({ selector: 'app-card' })\n\
+    export class CardComponent {\n\
+      a = input<string>();\n  b = input<string>();\n  c = input<string>();\n\
+      d = input<string>();\n  e = input<string>();\n  f = input<string>();\n\
+    }\n";
+
+fn input_count_findings(rule_configs: &[&str]) -> usize {
+    let config = Config {
+        rule_configs: rule_configs.iter().map(|s| s.parse().unwrap()).collect(),
+        ..Config::default()
+    };
+    let registry = setup_rules_registry(&config, &[], DebugLevel::None);
+    analyze_source("card.component.ts", COMPONENT, &registry)
+        .diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.rule_id == "angular-input-count")
+        .count()
+}
+
+#[test]
+fn test_inline_config_parses_rule_and_json() {
+    let inline: InlineRuleConfig = r#"angular-input-count={"maxInputs":8}"#.parse().unwrap();
+    assert_eq!(inline.rule, "angular-input-count");
+    assert_eq!(inline.config, json!({ "maxInputs": 8 }));
+
+    assert!("angular-input-count".parse::<InlineRuleConfig>().is_err());
+    assert!(r#"angular-input-count={"maxInputs":}"#.parse::<InlineRuleConfig>().is_err());
+    assert!("angular-input-count=8".parse::<InlineRuleConfig>().is_err());
+    assert!(r#"={"maxInputs":8}"#.parse::<InlineRuleConfig>().is_err());
+}
+
+#[test]
+fn test_inline_config_enables_and_configures_the_rule() {
+    assert_eq!(
+        input_count_findings(&[r#"angular-input-count={"maxInputs":8}"#]),
+        0
+    );
+    // Every input beyond the maximum is reported
+    assert_eq!(
+        input_count_findings(&[r#"angular-input-count={"maxInputs":3}"#]),
+        3
+    );
+}
+
+#[test]
+fn test_inline_config_overrides_rules_config_options() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("card.component.ts"), COMPONENT).unwrap();
+    fs::write(
+        dir.path().join("rules.json"),
+        r#"{ "rules": { "angular-input-count": ["error", { "maxInputs": 5 }] } }"#,
+    )
+    .unwrap();
+
+    let run = |extra_args: &[&str]| {
+        let output = Command::new(env!("CARGO_BIN_EXE_scoper"))
+            .arg(dir.path())
+            .args(["--rules-config", "rules.json", "--output-dir", "-"])
+            .args(extra_args)
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        let export: FindingsExport = serde_json::from_slice(&output.stdout).unwrap();
+        export.summary.total_findings
+    };
+
+    assert_eq!(run(&[]), 1);
+    assert_eq!(
+        run(&["--rule-config", r#"angular-input-count={"maxInputs":8}"#]),
+        0
+    );
+}
+
+#[test]
+fn test_invalid_inline_config_fails_the_run() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_scoper"))
+        .arg(dir.path())
+        .args([
+            "--output-dir",
+            "-",
+            "--rule-config",
+            "angular-input-count={maxInputs:8}",
+        ])
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Invalid JSON"));
+}
@@ -0,0 +1,62 @@
+use std::fs;
+use std::sync::Arc;
+use std::time::Duration;
+
+use scoper::SkipReason;
+use scoper::analyzer::{IO_ERROR_ID, process_files};
+use scoper::exporter::{ExportOptions, build_findings_export, coverage_summary};
+use scoper::metrics::aggregate_metrics;
+use scoper::rules_registry::{configure_registry, create_default_registry};
+use scoper::utilities::DebugLevel;
+
+#[test]
+fn test_unreadable_files_are_reported_as_findings() {
+    let dir = tempfile::tempdir().unwrap();
+    let clean = dir.path().join("clean.ts");
+    fs::write(&clean, "export const a = 1;\n").unwrap();
+    // Disappeared between scanning and reading
+    let gone = dir.path().join("gone.ts");
+
+    let mut registry = create_default_registry();
+    configure_registry(
+        &mut registry,
+        &[("no-debugger".to_string(), None, "error".to_string())],
+    );
+    let files = vec![
+        clean.to_string_lossy().to_string(),
+        gone.to_string_lossy().to_string(),
+    ];
+    let (results, _) = process_files(&files, &Arc::new(registry), DebugLevel::None);
+
+    let gone_result = results
+        .iter()
+        .find(|result| result.file_path.ends_with("gone.ts"))
+        .unwrap();
+    assert_eq!(gone_result.skip_reason, Some(SkipReason::ReadError));
+    assert_eq!(gone_result.diagnostics.len(), 1);
+    let diagnostic = &gone_result.diagnostics[0];
+    assert_eq!(diagnostic.rule_id, IO_ERROR_ID);
+    // The message of the operating system is kept
+    assert!(
+        diagnostic
+            .diagnostic
+            .message
+            .starts_with("Could not read file: No such file or directory")
+    );
+
+    // The file counts as skipped, and its finding as an error
+    let coverage = coverage_summary(&results);
+    assert_eq!(coverage.files_scanned, 1);
+    assert_eq!(coverage.files_skipped["read_error"], 1);
+
+    let metrics = aggregate_metrics(&results, Duration::ZERO, Duration::ZERO);
+    let export = build_findings_export(
+        &results,
+        &metrics,
+        DebugLevel::None,
+        &ExportOptions::default(),
+    );
+    assert_eq!(export.summary.findings_by_rule[IO_ERROR_ID], 1);
+    assert_eq!(export.summary.findings_by_severity["error"], 1);
+    assert!(export.findings[0].file.ends_with("gone.ts"));
+}
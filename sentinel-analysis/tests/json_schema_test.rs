@@ -0,0 +1,116 @@
+use scoper::analyzer::analyze_source;
+use scoper::exporter::{ExportOptions, export_findings_json};
+use scoper::metrics::aggregate_metrics;
+use scoper::rules_registry::{configure_registry, create_default_registry};
+use scoper::utilities::DebugLevel;
+use serde_json::Value;
+use std::fs;
+use std::time::Duration;
+
+/// Load a committed schema from the `schemas/` directory
+fn load_schema(name: &str) -> Value {
+    let path = format!("{}/schemas/{}", env!("CARGO_MANIFEST_DIR"), name);
+    serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap()
+}
+
+/// Validate a JSON document, failing with every schema violation
+fn assert_valid(schema_name: &str, instance: &Value) {
+    let validator = jsonschema::validator_for(&load_schema(schema_name)).unwrap();
+    let errors: Vec<String> = validator
+        .iter_errors(instance)
+        .map(|error| format!("{} at {}", error, error.instance_path))
+        .collect();
+    assert!(
+        errors.is_empty(),
+        "{} violations: {:#?}",
+        schema_name,
+        errors
+    );
+}
+
+/// Analyze a snippet that yields findings and rule timings
+fn sample_run() -> (Vec<scoper::FileAnalysisResult>, scoper::Metrics) {
+    let mut registry = create_default_registry();
+    configure_registry(
+        &mut registry,
+        &[
+            ("no-debugger".to_string(), None, "error".to_string()),
+            ("no-empty-pattern".to_string(), None, "error".to_string()),
+        ],
+    );
+
+    let result = analyze_source("src/app.ts", "debugger;\nconst {} = {};\n", &registry);
+    let results = vec![result];
+    let metrics = aggregate_metrics(&results, Duration::from_millis(3), Duration::from_millis(7));
+    (results, metrics)
+}
+
+#[test]
+fn test_findings_export_matches_schema() {
+    let output_dir = tempfile::tempdir().unwrap();
+    let (results, metrics) = sample_run();
+
+    let options = ExportOptions {
+        output_dir: output_dir.path().to_string_lossy().to_string(),
+        ..ExportOptions::default()
+    };
+    export_findings_json(&results, &metrics, DebugLevel::None, &options);
+
+    let content = fs::read_to_string(output_dir.path().join("findings.json")).unwrap();
+    let findings: Value = serde_json::from_str(&content).unwrap();
+    assert!(!findings["findings"].as_array().unwrap().is_empty());
+    assert_valid("findings.schema.json", &findings);
+}
+
+#[test]
+fn test_metrics_export_matches_schema() {
+    let output_dir = tempfile::tempdir().unwrap();
+    let (_, metrics) = sample_run();
+
+    let path = output_dir.path().join("metrics.json");
+    metrics.export_to_json(&path.to_string_lossy()).unwrap();
+
+    let content = fs::read_to_string(&path).unwrap();
+    let history: Value = serde_json::from_str(&content).unwrap();
+    assert!(
+        !history[0]["rule_execution_metrics"]
+            .as_array()
+            .unwrap()
+            .is_empty()
+    );
+    assert_valid("metrics.schema.json", &history);
+}
+
+#[test]
+fn test_metrics_environment_is_exported_when_recorded() {
+    let output_dir = tempfile::tempdir().unwrap();
+    let (_, mut metrics) = sample_run();
+
+    let path = output_dir.path().join("metrics.json");
+    metrics.export_to_json(&path.to_string_lossy()).unwrap();
+    metrics.record_environment();
+    metrics.export_to_json(&path.to_string_lossy()).unwrap();
+
+    let content = fs::read_to_string(&path).unwrap();
+    let history: Value = serde_json::from_str(&content).unwrap();
+    assert!(history[0].get("environment").is_none());
+    assert!(history[1]["environment"]["logical_cores"].as_u64().unwrap() > 0);
+    assert!(
+        history[1]["environment"]["total_memory_bytes"]
+            .as_u64()
+            .unwrap()
+            > 0
+    );
+    assert_valid("metrics.schema.json", &history);
+}
+
+#[test]
+fn test_schema_rejects_renamed_fields() {
+    let renamed = serde_json::json!({
+        "findings": [],
+        "summary": { "totalFindings": 0 }
+    });
+
+    let validator = jsonschema::validator_for(&load_schema("findings.schema.json")).unwrap();
+    assert!(!validator.is_valid(&renamed));
+}
@@ -0,0 +1,63 @@
+use scoper::RuleDiagnostic;
+use scoper::analyzer::analyze_source;
+use scoper::exporter::{code_context, source_snippet};
+use scoper::rules_registry::{configure_registry, create_default_registry};
+
+/// File with a `debugger` statement on line 4 and a parse error on line 6
+const CODE: &str = "const a = 1;\n\nfunction run() {\n  debugger;\n}\nconst x = ;\n";
+
+fn diagnostics(code: &str) -> Vec<RuleDiagnostic> {
+    let mut registry = create_default_registry();
+    registry.set_continue_on_parse_error(true);
+    configure_registry(
+        &mut registry,
+        &[("no-debugger".to_string(), None, "error".to_string())],
+    );
+    analyze_source("app.ts", code, &registry).diagnostics
+}
+
+fn crlf(code: &str) -> String {
+    code.replace('\n', "\r\n")
+}
+
+#[test]
+fn test_crlf_positions_match_lf() {
+    let positions = |code: &str| -> Vec<(String, usize, usize)> {
+        diagnostics(code)
+            .into_iter()
+            .map(|diagnostic| {
+                (
+                    diagnostic.rule_id,
+                    diagnostic.line_number,
+                    diagnostic.column_number,
+                )
+            })
+            .collect()
+    };
+
+    let lf = positions(CODE);
+    assert_eq!(lf.len(), 1);
+    assert_eq!(lf[0], ("parser".to_string(), 6, 11));
+    assert_eq!(positions(&crlf(CODE)), lf);
+
+    let code = CODE.replace("const x = ;\n", "");
+    let lf = positions(&code);
+    assert_eq!(lf, [("no-debugger".to_string(), 4, 3)]);
+    assert_eq!(positions(&crlf(&code)), lf);
+}
+
+#[test]
+fn test_crlf_snippets_and_fingerprint_context_match_lf() {
+    let code = CODE.replace("const x = ;\n", "");
+    let lf = diagnostics(&code).remove(0);
+    let crlf = diagnostics(&crlf(&code)).remove(0);
+
+    let lf_snippet = source_snippet(&lf).unwrap();
+    let crlf_snippet = source_snippet(&crlf).unwrap();
+    assert_eq!(crlf_snippet.start_line, lf_snippet.start_line);
+    assert_eq!(crlf_snippet.lines, lf_snippet.lines);
+    assert!(crlf_snippet.lines.iter().all(|line| !line.contains('\r')));
+
+    // Converting a file's line endings keeps the fingerprints of its findings
+    assert_eq!(code_context(&crlf), code_context(&lf));
+}
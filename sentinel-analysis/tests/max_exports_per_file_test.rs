@@ -0,0 +1,49 @@
+mod common;
+
+use common::{run_rule, run_rule_with_config};
+use serde_json::json;
+
+const RULE: &str = "max-exports-per-file";
+
+#[test]
+fn test_single_export_is_allowed() {
+    assert!(run_rule(RULE, "export class UserService {}\nconst helper = 1;\n").is_empty());
+}
+
+#[test]
+fn test_too_many_exports_are_reported_at_line_1_with_the_count() {
+    let code = "import { a } from './a';\n\
+                export class UserService {}\n\
+                export const b = 1, c = 2;\n\
+                export { a };\n\
+                export default UserService;\n";
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line_number, 1);
+    let label = diagnostics[0].diagnostic.labels.as_ref().unwrap()[0]
+        .label()
+        .unwrap()
+        .to_string();
+    assert_eq!(label, "File has 5 exports, which exceeds the maximum of 1");
+}
+
+#[test]
+fn test_re_exports_are_not_counted() {
+    let code = "export { a } from './a';\nexport * from './b';\nexport class C {}\n";
+    assert!(run_rule(RULE, code).is_empty());
+}
+
+#[test]
+fn test_max_is_configurable() {
+    let code = "export const a = 1;\nexport const b = 2;\nexport const c = 3;\n";
+    let run = |max: u64| run_rule_with_config(RULE, Some(json!({ "max": max })), "test.ts", code);
+    assert!(run(3).is_empty());
+    assert_eq!(run(2).len(), 1);
+}
+
+#[test]
+fn test_rule_is_tagged_maintainability() {
+    let registry = scoper::rules_registry::create_default_registry();
+    let rule = registry.get_rule(RULE).unwrap();
+    assert_eq!(rule.tags(), ["maintainability"]);
+}
@@ -0,0 +1,52 @@
+mod common;
+
+use common::{run_rule, run_rule_with_config};
+use serde_json::json;
+
+const RULE: &str = "member-ordering";
+
+#[test]
+fn test_field_after_constructor_is_flagged() {
+    let code = "class UserService {\n  static instances = 0;\n  constructor() {}\n  users = [];\n  load() {}\n  private reset() {}\n}\n";
+
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line_number, 4);
+    assert_eq!(diagnostics[0].column_number, 3);
+    assert!(
+        diagnostics[0]
+            .diagnostic
+            .message
+            .contains("`users` (instance-field)")
+    );
+}
+
+#[test]
+fn test_only_first_out_of_order_member_is_reported() {
+    let code =
+        "class Widget {\n  #render() {}\n  update() {}\n  protected hide() {}\n  size = 1;\n}\n";
+
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line_number, 3);
+}
+
+#[test]
+fn test_default_order_is_accepted() {
+    let code = "class Widget {\n  static count = 0;\n  size = 1;\n  #secret = 2;\n  constructor() {}\n  static create() {}\n  get area() { return 1; }\n  render() {}\n  protected hide() {}\n  private reset() {}\n  #cleanup() {}\n}\n";
+
+    assert!(run_rule(RULE, code).is_empty());
+}
+
+#[test]
+fn test_custom_order_is_configurable() {
+    let code = "class Widget {\n  constructor() {}\n  size = 1;\n  render() {}\n}\n";
+
+    let config = Some(json!({ "order": ["constructor", "instance-field"] }));
+    assert!(run_rule_with_config(RULE, config, "test-file.ts", code).is_empty());
+
+    let config = Some(json!({ "order": ["public-method", "constructor"] }));
+    let diagnostics = run_rule_with_config(RULE, config, "test-file.ts", code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line_number, 4);
+}
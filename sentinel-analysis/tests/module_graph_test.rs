@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use scoper::analyzer::{analyze_source, process_files_with_resolver};
+use scoper::module_graph::{ModuleEdge, build_module_graph};
+use scoper::rules_registry::{RegistryResolver, create_default_registry};
+use scoper::utilities::DebugLevel;
+
+fn edge(from: &str, to: &str) -> ModuleEdge {
+    ModuleEdge {
+        from: from.to_string(),
+        to: to.to_string(),
+    }
+}
+
+#[test]
+fn test_relative_imports_become_edges() {
+    let registry = create_default_registry();
+    let files = [
+        (
+            "project/src/app.ts",
+            "import { a } from './lib/a';\nimport { b } from './lib/b.js';\nimport { Component } from '@angular/core';\nexport * from './lib';\n",
+        ),
+        (
+            "project/src/lib/a.ts",
+            "import { c } from '../../shared/c';\nexport const a = 1;\n",
+        ),
+        ("project/src/lib/b.tsx", "export { a as b } from './a';\n"),
+        ("project/src/lib/index.ts", "export const lib = true;\n"),
+        (
+            "project/shared/c.ts",
+            "import './missing';\nexport const c = 1;\n",
+        ),
+    ];
+    let results: Vec<_> = files
+        .iter()
+        .map(|(path, code)| analyze_source(path, code, &registry))
+        .collect();
+
+    let graph = build_module_graph(&results, Some("project"));
+    assert_eq!(
+        graph.nodes,
+        [
+            "shared/c.ts",
+            "src/app.ts",
+            "src/lib/a.ts",
+            "src/lib/b.tsx",
+            "src/lib/index.ts"
+        ]
+    );
+    assert_eq!(
+        graph.edges,
+        [
+            edge("src/app.ts", "src/lib/a.ts"),
+            edge("src/app.ts", "src/lib/b.tsx"),
+            edge("src/app.ts", "src/lib/index.ts"),
+            edge("src/lib/a.ts", "shared/c.ts"),
+            edge("src/lib/b.tsx", "src/lib/a.ts"),
+        ]
+    );
+}
+
+#[test]
+fn test_graph_renders_as_dot() {
+    let registry = create_default_registry();
+    let results = [
+        analyze_source("src/a.ts", "import { b } from './b';\n", &registry),
+        analyze_source("src/b.ts", "export const b = 1;\n", &registry),
+    ];
+
+    assert_eq!(
+        build_module_graph(&results, None).to_dot(),
+        "digraph modules {\n  \"src/a.ts\";\n  \"src/b.ts\";\n  \"src/a.ts\" -> \"src/b.ts\";\n}\n"
+    );
+}
+
+#[test]
+fn test_imports_are_only_collected_for_the_graph() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("app.ts");
+    std::fs::write(&file, "import { b } from './b';\n").unwrap();
+    let files = vec![file.to_string_lossy().to_string()];
+    let resolver = Arc::new(RegistryResolver::single(
+        Arc::new(create_default_registry()),
+    ));
+
+    let imports = |collect_imports| {
+        let (results, _) =
+            process_files_with_resolver(&files, &resolver, None, collect_imports, DebugLevel::None);
+        results[0].imports.clone()
+    };
+    assert_eq!(imports(true), ["./b"]);
+    assert!(imports(false).is_empty());
+}
+
+#[test]
+fn test_cli_exports_the_graph_of_the_analyzed_files() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("a.ts"), "import { b } from './b';\n").unwrap();
+    std::fs::write(dir.path().join("b.ts"), "export const b = 1;\n").unwrap();
+    std::fs::write(
+        dir.path().join("rules.json"),
+        r#"{ "rules": { "no-debugger": "error" } }"#,
+    )
+    .unwrap();
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_scoper"))
+        .arg(dir.path())
+        .args(["--rules-config", "rules.json", "--output-dir", "-"])
+        .args(["--export-graph", "graph.dot"])
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let dot = std::fs::read_to_string(dir.path().join("graph.dot")).unwrap();
+    assert!(dot.contains("\"a.ts\" -> \"b.ts\";"), "{}", dot);
+}
@@ -0,0 +1,175 @@
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use scoper::analyzer::process_files_with_resolver;
+use scoper::rules_registry::{RegistryResolver, create_default_registry};
+use scoper::utilities::DebugLevel;
+use scoper::utilities::file_utils::find_files;
+
+fn write(path: &Path, content: &str) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+fn path_str(path: &Path) -> String {
+    path.to_string_lossy().to_string()
+}
+
+#[test]
+fn test_nearest_config_applies_to_each_package() {
+    let root = tempfile::tempdir().unwrap();
+    let code = "debugger;\nconst {} = value;\n";
+    write(&root.path().join("packages/legacy/src/a.ts"), code);
+    write(&root.path().join("packages/new/src/b.ts"), code);
+    write(&root.path().join("tools/c.ts"), code);
+    write(
+        &root.path().join("packages/legacy/rules.json"),
+        r#"{ "rules": { "no-debugger": "warn" } }"#,
+    );
+    write(
+        &root.path().join("packages/new/sentinel.json"),
+        r#"{ "rules": { "no-debugger": "error", "no-empty-pattern": "error" } }"#,
+    );
+
+    let root_str = path_str(root.path());
+    let resolver = Arc::new(RegistryResolver::nested(
+        Arc::new(create_default_registry()),
+        &root_str,
+        DebugLevel::None,
+    ));
+    let (files, _) = find_files(&root_str, DebugLevel::None);
+    let (results, _) =
+        process_files_with_resolver(&files, &resolver, None, false, DebugLevel::None);
+
+    let count = |suffix: &str| {
+        results
+            .iter()
+            .find(|result| result.file_path.ends_with(suffix))
+            .map(|result| result.diagnostics.len())
+            .unwrap()
+    };
+    assert_eq!(count("a.ts"), 1);
+    assert_eq!(count("b.ts"), 2);
+    // No nested config: the root registry has no rules enabled
+    assert_eq!(count("c.ts"), 0);
+}
+
+#[test]
+fn test_directories_share_a_cached_registry() {
+    let root = tempfile::tempdir().unwrap();
+    write(
+        &root.path().join("pkg/rules.json"),
+        r#"{ "rules": { "no-debugger": "error" } }"#,
+    );
+
+    let root_registry = Arc::new(create_default_registry());
+    let resolver = RegistryResolver::nested(
+        Arc::clone(&root_registry),
+        &path_str(root.path()),
+        DebugLevel::None,
+    );
+
+    let first = resolver.registry_for(&path_str(&root.path().join("pkg/src/a.ts")));
+    let second = resolver.registry_for(&path_str(&root.path().join("pkg/lib/b.ts")));
+    assert!(Arc::ptr_eq(&first, &second));
+    assert!(first.is_rule_enabled("no-debugger"));
+
+    let outside = resolver.registry_for(&path_str(&root.path().join("other/c.ts")));
+    assert!(Arc::ptr_eq(&outside, &root_registry));
+}
+
+#[test]
+fn test_single_resolver_ignores_nested_configs() {
+    let root = tempfile::tempdir().unwrap();
+    write(
+        &root.path().join("pkg/rules.json"),
+        r#"{ "rules": { "no-debugger": "error" } }"#,
+    );
+
+    let root_registry = Arc::new(create_default_registry());
+    let resolver = RegistryResolver::single(Arc::clone(&root_registry));
+
+    let registry = resolver.registry_for(&path_str(&root.path().join("pkg/a.ts")));
+    assert!(Arc::ptr_eq(&registry, &root_registry));
+}
+
+#[test]
+fn test_configs_without_rules_and_the_scan_root_are_passed_over() {
+    let root = tempfile::tempdir().unwrap();
+    // Tool settings only: the rules.json next to it applies
+    write(
+        &root.path().join("pkg/sentinel.json"),
+        r#"{ "api_url": "http://127.0.0.1:9" }"#,
+    );
+    write(
+        &root.path().join("pkg/rules.json"),
+        r#"{ "rules": { "no-debugger": "error" } }"#,
+    );
+    // The scan root is covered by the root registry
+    write(
+        &root.path().join("rules.json"),
+        r#"{ "rules": { "no-empty-pattern": "error" } }"#,
+    );
+
+    let root_registry = Arc::new(create_default_registry());
+    let resolver = RegistryResolver::nested(
+        Arc::clone(&root_registry),
+        &path_str(root.path()),
+        DebugLevel::None,
+    );
+
+    let registry = resolver.registry_for(&path_str(&root.path().join("pkg/src/a.ts")));
+    assert!(registry.is_rule_enabled("no-debugger"));
+    assert!(!registry.is_rule_enabled("no-empty-pattern"));
+
+    let top_level = resolver.registry_for(&path_str(&root.path().join("b.ts")));
+    assert!(Arc::ptr_eq(&top_level, &root_registry));
+}
+
+#[test]
+fn test_rule_config_option_applies_to_nested_configs() {
+    let root = tempfile::tempdir().unwrap();
+    write(
+        &root.path().join("pkg/a.ts"),
+        "export const a = 1;\nexport const b = 2;\n",
+    );
+    write(
+        &root.path().join("pkg/rules.json"),
+        r#"{ "rules": { "max-exports-per-file": "error" } }"#,
+    );
+    write(
+        &root.path().join("root-rules.json"),
+        r#"{ "rules": { "no-debugger": "error" } }"#,
+    );
+    // An unreachable API keeps the run from submitting anything
+    write(
+        &root.path().join("sentinel.json"),
+        r#"{ "api_url": "http://127.0.0.1:9" }"#,
+    );
+
+    let run = |extra_args: &[&str]| {
+        let output = std::process::Command::new(env!("CARGO_BIN_EXE_scoper"))
+            .arg(".")
+            .args([
+                "--config",
+                "sentinel.json",
+                "--rules-config",
+                "root-rules.json",
+            ])
+            .args(["--nested-configs", "--output-dir", "-"])
+            .args(extra_args)
+            .current_dir(root.path())
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        let export: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        export["findings"].as_array().unwrap().len()
+    };
+
+    assert_eq!(run(&[]), 1);
+    assert_eq!(
+        run(&["--rule-config", r#"max-exports-per-file={"max":2}"#]),
+        0
+    );
+}
@@ -0,0 +1,58 @@
+mod common;
+
+use common::{run_rule, run_rule_with_config};
+
+#[test]
+fn test_as_any_is_flagged() {
+    let diagnostics = run_rule("no-as-any", "const value = foo as any;\n");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].column_number, 15);
+}
+
+#[test]
+fn test_double_assertion_reports_the_any_cast_once() {
+    let diagnostics = run_rule("no-as-any", "const value = foo as any as Bar;\n");
+    assert_eq!(diagnostics.len(), 1);
+}
+
+#[test]
+fn test_other_assertions_are_allowed() {
+    let code = "const a = foo as Bar;\nconst b = foo as unknown;\nconst c = foo as Company;\n";
+    assert!(run_rule("no-as-any", code).is_empty());
+}
+
+#[test]
+fn test_type_assertion_rule_leaves_any_casts_to_no_as_any() {
+    let messages = |config: Option<serde_json::Value>, code: &str| {
+        let mut messages: Vec<String> =
+            run_rule_with_config("typescript-type-assertion", config, "test.ts", code)
+                .iter()
+                .map(|diagnostic| diagnostic.diagnostic.message.to_string())
+                .collect();
+        messages.sort();
+        messages
+    };
+
+    assert!(messages(None, "const value = foo as any;\n").is_empty());
+    assert_eq!(
+        messages(None, "const value = foo as any as Bar;\n"),
+        ["Double type assertion detected"]
+    );
+    assert_eq!(
+        messages(None, "const value = foo as Company;\n"),
+        ["Unsafe TypeScript type assertion detected"]
+    );
+
+    let report_any = Some(serde_json::json!({ "reportAnyCasts": true }));
+    assert_eq!(
+        messages(report_any.clone(), "const value = foo as any;\n"),
+        ["Type assertion through 'any' detected"]
+    );
+    assert_eq!(
+        messages(report_any, "const value = foo as any as Bar;\n"),
+        [
+            "Double type assertion detected",
+            "Type assertion through 'any' detected"
+        ]
+    );
+}
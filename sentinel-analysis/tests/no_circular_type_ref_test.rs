@@ -0,0 +1,58 @@
+mod common;
+
+use common::run_rule;
+
+const RULE: &str = "no-circular-type-ref";
+
+#[test]
+fn test_direct_self_reference_is_flagged() {
+    let code = "type Value = string | Value;\n";
+
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line_number, 1);
+    assert_eq!(diagnostics[0].column_number, 1);
+    assert_eq!(
+        diagnostics[0].diagnostic.message,
+        "Type alias `Value` circularly references itself"
+    );
+}
+
+#[test]
+fn test_cycle_through_other_declarations_is_flagged() {
+    let code = "export type Node = Leaf | (Branch & { id: string });\n\
+                type Branch = keyof Node;\n\
+                type Leaf = string;\n\
+                interface Base extends Derived {}\n\
+                interface Derived extends Base {}\n";
+
+    let diagnostics = run_rule(RULE, code);
+    let messages: Vec<&str> = diagnostics
+        .iter()
+        .map(|d| d.diagnostic.message.as_ref())
+        .collect();
+    assert_eq!(
+        messages,
+        [
+            "Type alias `Node` circularly references itself through `Node` -> `Branch` -> `Node`",
+            "Type alias `Branch` circularly references itself through `Branch` -> `Node` -> `Branch`",
+            "Interface `Base` circularly references itself through `Base` -> `Derived` -> `Base`",
+            "Interface `Derived` circularly references itself through `Derived` -> `Base` -> `Derived`",
+        ]
+    );
+}
+
+#[test]
+fn test_references_through_indirection_are_allowed() {
+    let code = "type Json = string | number | Json[] | { [key: string]: Json };\n\
+                type Tree = { value: number; children: Tree[] };\n\
+                type List = [number, List?];\n\
+                type Handler = (next: Handler) => void;\n\
+                type Boxed = Promise<Boxed>;\n\
+                interface Node { parent?: Node; children: Node[] }\n\
+                type Wrapped = Node | string;\n\
+                type Nullable<T> = T | null;\n\
+                type T = Nullable<string>;\n";
+
+    assert!(run_rule(RULE, code).is_empty());
+}
@@ -0,0 +1,43 @@
+mod common;
+
+use common::{run_rule, run_rule_with_config};
+use serde_json::json;
+
+const RULE: &str = "no-commented-code";
+
+#[test]
+fn test_commented_out_line_block_is_flagged() {
+    let code =
+        "const a = 1;\n// const b = a + 1;\n// if (b > 1) {\n//   log(b);\n// }\nexport { a };\n";
+
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line_number, 2);
+}
+
+#[test]
+fn test_commented_out_block_comment_is_flagged() {
+    let code = "/*\nfunction legacy() {\n  return 1;\n}\n*/\nexport const a = 1;\n";
+    assert_eq!(run_rule(RULE, code).len(), 1);
+}
+
+#[test]
+fn test_prose_and_jsdoc_are_allowed() {
+    let code = "// Load the user before rendering, because the\n\
+                // template needs the permissions of the user.\n\
+                export const a = 1;\n\
+                /**\n * Example:\n * const b = a + 1;\n * return b;\n */\n\
+                export const b = 2; // const c = 3;\n";
+    assert!(run_rule(RULE, code).is_empty());
+}
+
+#[test]
+fn test_single_line_is_flagged_only_below_min_lines() {
+    let code = "// legacy();\nexport const a = 1;\n";
+
+    assert!(run_rule(RULE, code).is_empty());
+    assert_eq!(
+        run_rule_with_config(RULE, Some(json!({ "minLines": 1 })), "test-file.ts", code).len(),
+        1
+    );
+}
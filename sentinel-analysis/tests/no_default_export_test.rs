@@ -0,0 +1,50 @@
+mod common;
+
+use common::{run_rule, run_rule_with_config};
+use serde_json::json;
+
+const RULE: &str = "no-default-export";
+
+#[test]
+fn test_default_exports_are_flagged() {
+    let diagnostics = run_rule(RULE, "const a = 1;\nexport default class UserService {}\n");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line_number, 2);
+    assert_eq!(diagnostics[0].column_number, 1);
+
+    assert_eq!(run_rule(RULE, "export default function () {}\n").len(), 1);
+    assert_eq!(
+        run_rule(RULE, "const value = 1;\nexport default value;\n").len(),
+        1
+    );
+}
+
+#[test]
+fn test_default_export_specifier_is_flagged() {
+    let diagnostics = run_rule(
+        RULE,
+        "class UserService {}\nexport { UserService as default };\n",
+    );
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].column_number, 10);
+}
+
+#[test]
+fn test_named_exports_are_allowed() {
+    let code = "export class UserService {}\nexport const a = 1;\nexport { a as b };\nexport * from './c';\n";
+    assert!(run_rule(RULE, code).is_empty());
+}
+
+#[test]
+fn test_allowed_files_may_default_export() {
+    let config = Some(json!({ "allowedFiles": ["**/pages/**/*.tsx"] }));
+    let code = "export default function Home() {}\n";
+
+    assert!(
+        run_rule_with_config(RULE, config.clone(), "src/pages/blog/index.tsx", code).is_empty()
+    );
+    assert_eq!(
+        run_rule_with_config(RULE, config, "src/components/home.tsx", code).len(),
+        1
+    );
+}
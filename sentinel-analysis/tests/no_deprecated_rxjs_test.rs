@@ -0,0 +1,43 @@
+mod common;
+
+use common::{run_rule, run_rule_with_config};
+use serde_json::json;
+
+const RULE: &str = "no-deprecated-rxjs";
+
+#[test]
+fn test_deprecated_operator_import_is_flagged() {
+    let code = "import { map, mapTo } from 'rxjs/operators';\n";
+
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].column_number, 15);
+    assert!(
+        diagnostics[0]
+            .diagnostic
+            .message
+            .contains("map(() => value)")
+    );
+}
+
+#[test]
+fn test_clean_imports_are_allowed() {
+    let code = "import { combineLatest, merge } from 'rxjs';\n\
+                import { map, switchMap } from 'rxjs/operators';\n";
+    assert!(run_rule(RULE, code).is_empty());
+}
+
+#[test]
+fn test_config_extends_the_deprecation_list() {
+    let config = Some(json!({ "deprecated": { "shareReplay": "share" } }));
+    let code = "import { shareReplay, pluck } from 'rxjs/operators';\n";
+
+    let diagnostics = run_rule_with_config(RULE, config, "test-file.ts", code);
+    assert_eq!(diagnostics.len(), 2);
+    assert!(
+        diagnostics[0]
+            .diagnostic
+            .message
+            .contains("use share instead")
+    );
+}
@@ -0,0 +1,49 @@
+mod common;
+
+use common::{run_rule, run_rule_with_config};
+use serde_json::json;
+
+const RULE: &str = "no-direct-date";
+
+#[test]
+fn test_date_now_and_new_date_are_flagged() {
+    let code = "const expiresAt = Date.now() + 1000;\nconst createdAt = new Date();\n";
+
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 2);
+    assert!(diagnostics[0].diagnostic.message.contains("Date.now()"));
+    assert!(diagnostics[1].diagnostic.message.contains("new Date()"));
+    assert_eq!(diagnostics[1].line_number, 2);
+}
+
+#[test]
+fn test_dates_from_values_are_allowed() {
+    let code = "const parsed = new Date(timestamp);\n\
+                const year = new Date(2024, 0, 1).getFullYear();\n\
+                const now = this.clock.now();\n";
+
+    assert!(run_rule(RULE, code).is_empty());
+}
+
+#[test]
+fn test_clock_file_is_allowed() {
+    let code = "export const now = () => Date.now();\nexport const today = () => new Date();\n";
+
+    assert!(run_rule_with_config(RULE, None, "src/shared/clock.ts", code).is_empty());
+    assert_eq!(
+        run_rule_with_config(RULE, None, "src/shared/session.ts", code).len(),
+        2
+    );
+}
+
+#[test]
+fn test_allowed_files_are_configurable() {
+    let config = Some(json!({ "allowedFiles": ["src/time/*.ts"] }));
+    let code = "const now = Date.now();\n";
+
+    assert!(run_rule_with_config(RULE, config.clone(), "src/time/system-time.ts", code).is_empty());
+    assert_eq!(
+        run_rule_with_config(RULE, config, "src/shared/clock.ts", code).len(),
+        1
+    );
+}
@@ -0,0 +1,53 @@
+mod common;
+
+use common::run_rule;
+
+const RULE: &str = "no-duplicate-keys";
+
+#[test]
+fn test_literal_duplicate_is_flagged_with_original() {
+    let code = "const user = {\n  name: 'Ada',\n  role: 'admin',\n  name: 'Grace',\n};\n";
+
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].diagnostic.message.contains("`name`"));
+    assert_eq!(diagnostics[0].line_number, 4);
+
+    let labels = diagnostics[0].diagnostic.labels.as_ref().unwrap();
+    assert_eq!(labels.len(), 2);
+    assert_eq!(labels[1].offset(), code.find("name").unwrap());
+}
+
+#[test]
+fn test_shorthand_and_longhand_duplicates_are_flagged() {
+    let code = "const options = { timeout, retries: 3, 'timeout': 500 };\n\
+                const limits = { 1: 'one', '1': 'uno' };\n";
+
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 2);
+    assert!(diagnostics[0].diagnostic.message.contains("`timeout`"));
+    assert!(diagnostics[1].diagnostic.message.contains("`1`"));
+}
+
+#[test]
+fn test_computed_keys_and_accessor_pairs_are_allowed() {
+    let code = "const lookup = { [key]: 1, [key]: 2, ['static']: 3 };\n\
+                const counter = { get value() { return 1; }, set value(v) {} };\n\
+                const merged = { ...defaults, debug: true };\n";
+
+    assert!(run_rule(RULE, code).is_empty());
+}
+
+#[test]
+fn test_computed_literal_key_duplicates_static_key() {
+    let code = "const flags = { enabled: true, ['enabled']: false };\n";
+
+    assert_eq!(run_rule(RULE, code).len(), 1);
+}
+
+#[test]
+fn test_getter_after_value_is_flagged() {
+    let code = "const config = { value: 1, get value() { return 2; } };\n";
+
+    assert_eq!(run_rule(RULE, code).len(), 1);
+}
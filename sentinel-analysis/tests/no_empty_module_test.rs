@@ -0,0 +1,63 @@
+mod common;
+
+use common::{run_rule, run_rule_with_config};
+use serde_json::json;
+
+const RULE: &str = "no-empty-module";
+
+#[test]
+fn test_empty_file_is_flagged() {
+    let diagnostics = run_rule(RULE, "");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line_number, 1);
+}
+
+#[test]
+fn test_import_only_file_is_flagged() {
+    let code = "import { Injectable } from '@angular/core';\nimport type { User } from './user';\n";
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line_number, 1);
+}
+
+#[test]
+fn test_files_with_code_are_allowed() {
+    assert!(run_rule(RULE, "import { a } from './a';\nexport const b = a + 1;\n").is_empty());
+    assert!(run_rule(RULE, "export interface User {\n  id: string;\n}\n").is_empty());
+    // Side-effect imports run code when the module is loaded
+    assert!(run_rule(RULE, "import 'zone.js';\n").is_empty());
+}
+
+#[test]
+fn test_re_export_only_files_are_allowed_by_default() {
+    let code = "export * from './user';\nexport { Order } from './order';\n";
+
+    assert!(run_rule_with_config(RULE, None, "src/models/index.ts", code).is_empty());
+    assert!(run_rule_with_config(RULE, None, "src/models/public-api.ts", code).is_empty());
+    // An empty export list re-exports nothing
+    assert_eq!(run_rule(RULE, "export {};\n").len(), 1);
+}
+
+#[test]
+fn test_check_re_exports_flags_re_export_only_files_except_barrels() {
+    let code = "export * from './user';\nexport { Order } from './order';\n";
+    let config = json!({ "checkReExports": true });
+
+    assert!(
+        run_rule_with_config(RULE, Some(config.clone()), "src/models/index.ts", code).is_empty()
+    );
+    assert_eq!(
+        run_rule_with_config(RULE, Some(config), "src/models/models.ts", code).len(),
+        1
+    );
+    assert_eq!(
+        run_rule_with_config(
+            RULE,
+            Some(json!({ "checkReExports": true, "ignoreBarrelFiles": false })),
+            "src/models/index.ts",
+            code
+        )
+        .len(),
+        1
+    );
+}
@@ -0,0 +1,36 @@
+mod common;
+
+use common::{run_rule, run_rule_with_config};
+use serde_json::json;
+
+const RULE: &str = "no-enum";
+
+#[test]
+fn test_regular_enum_is_flagged() {
+    let code = "export enum Direction {\n  Up,\n  Down,\n}\n";
+
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line_number, 1);
+    assert_eq!(diagnostics[0].column_number, 13);
+    assert!(diagnostics[0].diagnostic.message.contains("`Direction`"));
+}
+
+#[test]
+fn test_const_enum_respects_allow_const() {
+    let code = "const enum Flags {\n  A = 1,\n  B = 2,\n}\n";
+
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].diagnostic.message.contains("const enum"));
+
+    let config = Some(json!({ "allowConst": true }));
+    assert!(run_rule_with_config(RULE, config, "test-file.ts", code).is_empty());
+}
+
+#[test]
+fn test_union_types_are_allowed() {
+    let code = "type Direction = 'up' | 'down';\n\
+                const Flags = { A: 1, B: 2 } as const;\n";
+    assert!(run_rule(RULE, code).is_empty());
+}
@@ -0,0 +1,58 @@
+mod common;
+
+use common::run_rule;
+
+const RULE: &str = "no-misused-promise-all";
+
+#[test]
+fn test_for_each_with_async_callback_is_flagged() {
+    let code = "async function saveAll(users: User[]) {\n  users.forEach(async (user) => {\n    await api.save(user);\n  });\n}\n";
+
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].diagnostic.message,
+        "`forEach` does not wait for the promises of its async callback"
+    );
+    assert_eq!(
+        (diagnostics[0].line_number, diagnostics[0].column_number),
+        (2, 3)
+    );
+}
+
+#[test]
+fn test_async_function_callback_and_discarded_map_are_flagged() {
+    let code = r#"
+        const results = ids.forEach(async function (id) { await load(id); });
+        ids.map(async (id) => load(id));
+    "#;
+
+    let messages: Vec<String> = run_rule(RULE, code)
+        .into_iter()
+        .map(|d| d.diagnostic.message.to_string())
+        .collect();
+    assert_eq!(
+        messages,
+        [
+            "`forEach` does not wait for the promises of its async callback",
+            "Promises returned by the async `map` callback are never awaited",
+        ]
+    );
+}
+
+#[test]
+fn test_awaited_promise_all_is_allowed() {
+    let code = r#"
+        async function saveAll(users: User[]) {
+          await Promise.all(users.map(async (user) => {
+            await api.save(user);
+          }));
+          const pending = users.map(async (user) => api.save(user));
+          users.forEach((user) => console.log(user));
+          for (const user of users) {
+            await api.save(user);
+          }
+        }
+    "#;
+    assert!(run_rule(RULE, code).is_empty());
+}
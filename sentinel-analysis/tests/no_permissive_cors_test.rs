@@ -0,0 +1,82 @@
+mod common;
+
+use common::{run_rule, run_rule_with_config};
+use serde_json::json;
+
+const RULE: &str = "no-permissive-cors";
+
+const WILDCARD_WITH_CREDENTIALS: &str =
+    "app.use(cors({\n  origin: '*',\n  credentials: true,\n}));\n";
+
+#[test]
+fn test_wildcard_origin_with_credentials_is_flagged() {
+    let diagnostics = run_rule(RULE, WILDCARD_WITH_CREDENTIALS);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].diagnostic.message,
+        "CORS allows credentialed requests from any origin"
+    );
+    assert_eq!(diagnostics[0].line_number, 2);
+    assert_eq!(diagnostics[0].diagnostic.labels.as_ref().unwrap().len(), 2);
+}
+
+#[test]
+fn test_wildcard_and_reflected_origins_are_flagged() {
+    let code = "app.enableCors({ origin: '*' });\n\
+                app.enableCors({ origin: true, credentials: true });\n\
+                app.enableCors({ origin: `*`, credentials: false });\n";
+
+    let messages: Vec<String> = run_rule(RULE, code)
+        .iter()
+        .map(|diagnostic| diagnostic.diagnostic.message.to_string())
+        .collect();
+    assert_eq!(
+        messages,
+        [
+            "CORS allows requests from any origin",
+            "CORS allows credentialed requests from any origin",
+            "CORS allows requests from any origin",
+        ]
+    );
+}
+
+#[test]
+fn test_quoted_option_keys_are_flagged() {
+    let code = "app.use(cors({ 'origin': '*', \"credentials\": true }));\n";
+
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].diagnostic.message,
+        "CORS allows credentialed requests from any origin"
+    );
+}
+
+#[test]
+fn test_explicit_origins_are_allowed() {
+    let code = "app.enableCors({ origin: ['https://app.example.com'], credentials: true });\n\
+                app.enableCors({ origin: process.env.ORIGIN, credentials: true });\n\
+                app.enableCors({ origin: false });\n\
+                const link = { [origin]: '*' };\n";
+
+    assert!(run_rule(RULE, code).is_empty());
+}
+
+#[test]
+fn test_dev_only_files_are_configurable() {
+    let config = Some(json!({ "allowedFiles": ["**/*.dev.ts"] }));
+
+    assert!(
+        run_rule_with_config(
+            RULE,
+            config.clone(),
+            "src/main.dev.ts",
+            WILDCARD_WITH_CREDENTIALS
+        )
+        .is_empty()
+    );
+    assert_eq!(
+        run_rule_with_config(RULE, config, "src/main.ts", WILDCARD_WITH_CREDENTIALS).len(),
+        1
+    );
+}
@@ -0,0 +1,80 @@
+mod common;
+
+use common::{run_rule, run_rule_with_config};
+use serde_json::json;
+
+const RULE: &str = "no-redundant-async";
+
+#[test]
+fn test_async_function_without_await_is_flagged() {
+    let code = "export async function getDefaults() {\n  return { theme: 'dark' };\n}\n";
+
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line_number, 1);
+    // The `async` keyword is labeled
+    assert_eq!(diagnostics[0].column_number, 8);
+}
+
+#[test]
+fn test_methods_and_arrows_without_await_are_flagged() {
+    let code = "class Store {\n\
+                  @Log() public async load() {\n\
+                    return this.items;\n\
+                  }\n\
+                }\n\
+                const save = async (item) => item;\n";
+
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(diagnostics[0].line_number, 2);
+    assert_eq!(diagnostics[0].column_number, 15);
+    assert_eq!(diagnostics[1].line_number, 6);
+    assert_eq!(diagnostics[1].column_number, 14);
+}
+
+#[test]
+fn test_awaiting_functions_are_allowed() {
+    let code = "async function load(api) {\n\
+                  return await api.load();\n\
+                }\n\
+                async function readAll(stream) {\n\
+                  for await (const chunk of stream) {\n\
+                    console.log(chunk);\n\
+                  }\n\
+                }\n\
+                async function* items() {\n\
+                  yield 1;\n\
+                }\n";
+
+    assert!(run_rule(RULE, code).is_empty());
+}
+
+#[test]
+fn test_await_in_nested_function_does_not_count() {
+    let code = "async function outer(items) {\n\
+                  items.forEach(async (item) => {\n\
+                    await item.save();\n\
+                  });\n\
+                }\n";
+
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line_number, 1);
+}
+
+#[test]
+fn test_ignored_names_and_implementations() {
+    let code = "class AuthGuard implements CanActivate {\n\
+                  async canActivate() {\n\
+                    return true;\n\
+                  }\n\
+                }\n\
+                const handler = async () => true;\n\
+                async function other() {}\n";
+
+    let config = Some(json!({ "ignoreNames": ["handler"], "ignoreImplementations": true }));
+    let diagnostics = run_rule_with_config(RULE, config, "test-file.ts", code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line_number, 7);
+}
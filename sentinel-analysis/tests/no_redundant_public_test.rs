@@ -0,0 +1,68 @@
+mod common;
+
+use common::{run_rule, run_rule_with_config};
+use scoper::rules_registry::create_default_registry;
+use serde_json::json;
+
+const RULE: &str = "no-redundant-public";
+
+const SERVICE: &str = "class UserService {\n  public users: User[] = [];\n  @Input() public static name = 'users';\n  constructor(public http: HttpClient, public readonly store: Store) {}\n  public async load() {}\n  private cache = new Map();\n  publicKey = '';\n}\n";
+
+#[test]
+fn test_public_members_are_flagged() {
+    let diagnostics = run_rule(RULE, SERVICE);
+    let positions: Vec<_> = diagnostics
+        .iter()
+        .map(|diagnostic| (diagnostic.line_number, diagnostic.column_number))
+        .collect();
+    assert_eq!(positions, [(2, 3), (3, 12), (5, 3)]);
+}
+
+#[test]
+fn test_parameter_properties_are_flagged_when_not_allowed() {
+    let config = Some(json!({ "allowParameterProperties": false }));
+    let diagnostics = run_rule_with_config(RULE, config, "test-file.ts", SERVICE);
+    let parameters: Vec<_> = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.line_number == 4)
+        .map(|diagnostic| diagnostic.column_number)
+        .collect();
+    assert_eq!(parameters, [15, 40]);
+}
+
+#[test]
+fn test_fix_removes_the_keyword() {
+    let registry = create_default_registry();
+    let rule = registry.get_rule(RULE).unwrap();
+
+    let code = "class A {\n  public load() {}\n}\n";
+    let diagnostics = run_rule(RULE, code);
+    let fixed = rule
+        .fix(&diagnostics[0].diagnostic, code)
+        .unwrap()
+        .apply(code);
+    assert_eq!(fixed, "class A {\n  load() {}\n}\n");
+    assert!(run_rule(RULE, &fixed).is_empty());
+}
+
+#[test]
+fn test_fix_keeps_parameter_properties() {
+    let registry = create_default_registry();
+    let rule = registry.get_rule(RULE).unwrap();
+
+    let code = "class A {\n  constructor(public http: Http, public readonly store: Store) {}\n}\n";
+    let config = Some(json!({ "allowParameterProperties": false }));
+    let diagnostics = run_rule_with_config(RULE, config, "test-file.ts", code);
+    assert_eq!(diagnostics.len(), 2);
+
+    // Removing the only modifier would turn `http` into a plain parameter
+    assert!(rule.fix(&diagnostics[0].diagnostic, code).is_none());
+    let fixed = rule
+        .fix(&diagnostics[1].diagnostic, code)
+        .unwrap()
+        .apply(code);
+    assert_eq!(
+        fixed,
+        "class A {\n  constructor(public http: Http, readonly store: Store) {}\n}\n"
+    );
+}
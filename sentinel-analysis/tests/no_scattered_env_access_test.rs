@@ -0,0 +1,36 @@
+mod common;
+
+use common::run_rule_with_config;
+use serde_json::json;
+
+const RULE: &str = "no-scattered-env-access";
+
+#[test]
+fn test_env_access_in_config_file_is_allowed() {
+    let code = "export const apiUrl = process.env.API_URL;\nexport const { PORT } = process.env;\n";
+
+    assert!(run_rule_with_config(RULE, None, "/repo/src/config/env.ts", code).is_empty());
+}
+
+#[test]
+fn test_env_access_in_component_is_flagged() {
+    let code = "export class UserComponent {\n  apiUrl = process.env['API_URL'];\n  debug = process.env.DEBUG;\n}\n";
+
+    let diagnostics = run_rule_with_config(RULE, None, "/repo/src/app/user.component.ts", code);
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(diagnostics[0].line_number, 2);
+}
+
+#[test]
+fn test_allowed_files_are_configurable() {
+    let code = "export const apiUrl = process.env.API_URL;\n";
+    let config = Some(json!({ "allowedFiles": ["**/environment.ts"] }));
+
+    assert!(
+        run_rule_with_config(RULE, config.clone(), "/repo/src/environment.ts", code).is_empty()
+    );
+    assert_eq!(
+        run_rule_with_config(RULE, config, "/repo/src/config/env.ts", code).len(),
+        1
+    );
+}
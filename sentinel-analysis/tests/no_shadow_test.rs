@@ -0,0 +1,54 @@
+mod common;
+
+use common::{run_rule, run_rule_with_config};
+use serde_json::json;
+
+const RULE: &str = "no-shadow";
+
+#[test]
+fn test_block_scope_shadowing_is_flagged() {
+    let code = "const value = 1;\nif (value) {\n  const value = 2;\n  console.log(value);\n}\n";
+
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line_number, 3);
+
+    // The outer declaration is referenced by a second label
+    let labels = diagnostics[0].diagnostic.labels.as_ref().unwrap();
+    assert_eq!(labels.len(), 2);
+    assert_eq!(labels[1].offset(), code.find("value").unwrap());
+}
+
+#[test]
+fn test_parameter_shadowing_is_flagged() {
+    let code = "const id = 1;\nfunction load(id: number) {\n  return id;\n}\n";
+
+    assert_eq!(run_rule(RULE, code).len(), 1);
+}
+
+#[test]
+fn test_parameter_shadowing_can_be_ignored() {
+    let code = "const id = 1;\nconst load = (id: number) => id;\n";
+
+    let config = Some(json!({ "ignoreParameters": true }));
+    assert!(run_rule_with_config(RULE, config, "load.ts", code).is_empty());
+}
+
+#[test]
+fn test_builtin_globals_can_be_ignored() {
+    let code = "function parse() {\n  const Map = {};\n  return Map;\n}\n";
+
+    assert_eq!(run_rule(RULE, code).len(), 1);
+    let config = Some(json!({ "ignoreBuiltinGlobals": true }));
+    assert!(run_rule_with_config(RULE, config, "parse.ts", code).is_empty());
+}
+
+#[test]
+fn test_sibling_scopes_and_expression_names_are_clean() {
+    let code = "function a() { const x = 1; return x; }\n\
+                function b() { const x = 2; return x; }\n\
+                const handler = function handler() {};\n\
+                class Store {}\n";
+
+    assert!(run_rule(RULE, code).is_empty());
+}
@@ -0,0 +1,82 @@
+mod common;
+
+use common::{run_rule, run_rule_with_config};
+use serde_json::json;
+
+const RULE: &str = "no-ts-ignore";
+
+const CODE: &str = "// @ts-nocheck\n\
+                    // @ts-ignore\n\
+                    const a: number = '1';\n\
+                    /* @ts-expect-error: the typings of lib are wrong */\n\
+                    lib.call(a);\n\
+                    // @ts-expect-error\n\
+                    lib.call(a);\n\
+                    // see @ts-ignore in the docs\n\
+                    // @ts-ignored is not a directive\n";
+
+fn messages(config: Option<serde_json::Value>) -> Vec<(usize, String)> {
+    run_rule_with_config(RULE, config, "test-file.ts", CODE)
+        .into_iter()
+        .map(|diagnostic| {
+            (
+                diagnostic.line_number,
+                diagnostic.diagnostic.message.to_string(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_directives_are_flagged() {
+    assert_eq!(
+        messages(None),
+        [
+            (1, "Unexpected `@ts-nocheck` comment".to_string()),
+            (2, "Unexpected `@ts-ignore` comment".to_string()),
+            (4, "Unexpected `@ts-expect-error` comment".to_string()),
+            (6, "Unexpected `@ts-expect-error` comment".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_only_configured_directives_are_flagged() {
+    let lines: Vec<usize> = messages(Some(json!({ "directives": ["ts-ignore", "@ts-nocheck"] })))
+        .into_iter()
+        .map(|(line, _)| line)
+        .collect();
+    assert_eq!(lines, [1, 2]);
+}
+
+#[test]
+fn test_described_directives_are_allowed_when_descriptions_are_required() {
+    let lines: Vec<usize> = messages(Some(json!({ "requireDescription": true })))
+        .into_iter()
+        .map(|(line, _)| line)
+        .collect();
+    assert_eq!(lines, [1, 2, 6]);
+}
+
+#[test]
+fn test_descriptions_do_not_allow_ts_ignore_or_ts_nocheck() {
+    let code = "// @ts-nocheck: legacy file\n\
+                // @ts-ignore: the typings of lib are wrong\n\
+                lib.call(1);\n";
+    let diagnostics = run_rule_with_config(
+        RULE,
+        Some(json!({ "requireDescription": true })),
+        "test-file.ts",
+        code,
+    );
+    let lines: Vec<usize> = diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.line_number)
+        .collect();
+    assert_eq!(lines, [1, 2]);
+}
+
+#[test]
+fn test_plain_comments_are_allowed() {
+    assert!(run_rule(RULE, "// TODO: fix types\nconst a = 1;\n").is_empty());
+}
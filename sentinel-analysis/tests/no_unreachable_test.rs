@@ -0,0 +1,53 @@
+mod common;
+
+use common::run_rule;
+
+const RULE: &str = "no-unreachable";
+
+/// Lines of the `no-unreachable` findings in `code`
+fn unreachable_lines(code: &str) -> Vec<usize> {
+    run_rule(RULE, code)
+        .iter()
+        .map(|diagnostic| diagnostic.line_number)
+        .collect()
+}
+
+#[test]
+fn test_statement_after_terminator_is_flagged() {
+    let code = "function f() {\n  return 1;\n  log();\n}\n\
+                function g() {\n  throw new Error();\n  log();\n}\n\
+                for (const x of xs) {\n  continue;\n  log(x);\n}\n";
+    assert_eq!(unreachable_lines(code), vec![3, 7, 11]);
+}
+
+#[test]
+fn test_only_first_unreachable_statement_is_reported() {
+    let code = "function f() {\n  return;\n  a();\n  b();\n}\n";
+    assert_eq!(unreachable_lines(code), vec![3]);
+}
+
+#[test]
+fn test_hoisted_declarations_after_terminator_are_allowed() {
+    let code = "function f() {\n  return helper();\n  function helper() {\n    return 1;\n  }\n  type T = string;\n  interface I {}\n}\n";
+    assert!(unreachable_lines(code).is_empty());
+}
+
+#[test]
+fn test_if_else_terminates_only_when_both_branches_do() {
+    let code = "function f(a) {\n  if (a) {\n    return 1;\n  } else {\n    throw new Error();\n  }\n  log();\n}\n\
+                function g(a) {\n  if (a) {\n    return 1;\n  }\n  log();\n}\n";
+    assert_eq!(unreachable_lines(code), vec![7]);
+}
+
+#[test]
+fn test_switch_case_and_nested_block() {
+    let code = "switch (a) {\n  case 1:\n    break;\n    log();\n}\n\
+                function f() {\n  {\n    return;\n  }\n  log();\n}\n";
+    assert_eq!(unreachable_lines(code), vec![4, 10]);
+}
+
+#[test]
+fn test_loops_and_try_are_not_terminators() {
+    let code = "function f() {\n  while (true) {\n    return;\n  }\n  log();\n  try {\n    return;\n  } finally {\n    cleanup();\n  }\n  log();\n}\n";
+    assert!(unreachable_lines(code).is_empty());
+}
@@ -0,0 +1,98 @@
+mod common;
+
+use common::run_rule;
+
+const RULE: &str = "no-unused-private-members";
+
+#[test]
+fn test_unused_private_method_is_flagged() {
+    let code = "class Cart {\n  private items: string[] = [];\n\n  private legacyTotal() {\n    return 0;\n  }\n\n  add(item: string) {\n    this.items.push(item);\n  }\n}\n";
+
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].diagnostic.message,
+        "Private method `legacyTotal` is never used"
+    );
+    assert_eq!(
+        (diagnostics[0].line_number, diagnostics[0].column_number),
+        (4, 3)
+    );
+}
+
+#[test]
+fn test_used_private_members_are_allowed() {
+    let code = r#"
+        class Cart {
+          private items: string[] = [];
+          #count = 0;
+
+          constructor(private readonly store: Store) {
+            store.load();
+          }
+
+          private total() {
+            return this.items.length + this.#count;
+          }
+
+          summary() {
+            const { items } = this;
+            return `${items.length}: ${this['total']()}`;
+          }
+        }
+    "#;
+    assert!(run_rule(RULE, code).is_empty());
+}
+
+#[test]
+fn test_unused_fields_and_parameter_properties_are_flagged() {
+    let code = r#"
+        class Cart {
+          #cache = new Map();
+          private written = 0;
+
+          constructor(private http: HttpClient, public api: Api) {}
+
+          reset() {
+            this.written = 1;
+          }
+        }
+    "#;
+
+    let mut messages: Vec<String> = run_rule(RULE, code)
+        .into_iter()
+        .map(|d| d.diagnostic.message.to_string())
+        .collect();
+    messages.sort();
+    assert_eq!(
+        messages,
+        [
+            "Private field `cache` is never used",
+            "Private field `http` is never used",
+            "Private field `written` is never used",
+        ]
+    );
+}
+
+#[test]
+fn test_members_used_outside_the_class_body_are_allowed() {
+    let code = r#"
+        @Component({
+          selector: 'app-cart',
+          template: '<span>{{ label }}</span>',
+          host: { '(click)': 'onClick()' },
+        })
+        class CartComponent {
+          private label = 'Cart';
+          @Input() private size = 0;
+
+          private onClick() {}
+        }
+
+        @Component({ selector: 'app-list', templateUrl: './list.component.html' })
+        class ListComponent {
+          private rows = [];
+        }
+    "#;
+    assert!(run_rule(RULE, code).is_empty());
+}
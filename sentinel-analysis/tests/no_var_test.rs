@@ -0,0 +1,80 @@
+mod common;
+
+use common::run_rule;
+use scoper::rules_registry::create_default_registry;
+
+const RULE: &str = "no-var";
+
+fn fix(code: &str) -> String {
+    let registry = create_default_registry();
+    let rule = registry.get_rule(RULE).unwrap();
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 1);
+    rule.fix(&diagnostics[0].diagnostic, code)
+        .unwrap()
+        .apply(code)
+}
+
+#[test]
+fn test_var_is_flagged_at_the_keyword() {
+    let diagnostics = run_rule(RULE, "function f() {\n  var x = 1;\n  return x;\n}\n");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        (diagnostics[0].line_number, diagnostics[0].column_number),
+        (2, 3)
+    );
+
+    assert!(run_rule(RULE, "let a = 1;\nconst b = a;\ndeclare var c: number;\n").is_empty());
+}
+
+#[test]
+fn test_binding_that_is_not_reassigned_becomes_const() {
+    let code = "var x = 1;\nconsole.log(x);\n";
+    assert_eq!(
+        run_rule(RULE, code)[0].diagnostic.message,
+        "Unexpected `var`, use `const` instead"
+    );
+    assert_eq!(fix(code), "const x = 1;\nconsole.log(x);\n");
+
+    assert_eq!(
+        fix("for (var item of items) {\n  use(item);\n}\n"),
+        "for (const item of items) {\n  use(item);\n}\n"
+    );
+}
+
+#[test]
+fn test_reassigned_binding_becomes_let() {
+    let code = "var x = 1;\nx = 2;\n";
+    assert_eq!(
+        run_rule(RULE, code)[0].diagnostic.message,
+        "Unexpected `var`, use `let` instead"
+    );
+    assert_eq!(fix(code), "let x = 1;\nx = 2;\n");
+
+    assert_eq!(fix("var total;\ntotal = 3;\n"), "let total;\ntotal = 3;\n");
+    assert_eq!(
+        fix("for (var i = 0; i < 3; i++) {}\n"),
+        "for (let i = 0; i < 3; i++) {}\n"
+    );
+}
+
+#[test]
+fn test_no_fix_when_block_scoping_would_break_the_code() {
+    let registry = create_default_registry();
+    let rule = registry.get_rule(RULE).unwrap();
+
+    for code in [
+        "console.log(x);\nvar x = 1;\n",
+        "if (ok) {\n  var x = 1;\n}\nconsole.log(x);\n",
+        "var x = 1;\nvar x = 2;\n",
+    ] {
+        let diagnostics = run_rule(RULE, code);
+        assert!(!diagnostics.is_empty(), "{}", code);
+        assert_eq!(
+            diagnostics[0].diagnostic.message, "Unexpected `var`",
+            "{}",
+            code
+        );
+        assert!(rule.fix(&diagnostics[0].diagnostic, code).is_none());
+    }
+}
@@ -0,0 +1,54 @@
+mod common;
+
+use common::{run_rule, run_rule_with_config};
+use serde_json::json;
+
+const RULE: &str = "no-zero-delay-timer";
+
+#[test]
+fn test_zero_delay_timeout_is_flagged() {
+    let code = "setTimeout(f, 0);\nwindow.setTimeout(() => focus(), 0);\n";
+
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(
+        diagnostics[0].diagnostic.message,
+        "`setTimeout` with a zero delay"
+    );
+    assert_eq!(
+        (diagnostics[1].line_number, diagnostics[1].column_number),
+        (2, 1)
+    );
+}
+
+#[test]
+fn test_short_interval_is_flagged() {
+    let diagnostics = run_rule(RULE, "setInterval(f, 5);\n");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].diagnostic.message,
+        "`setInterval` with a delay of 5ms, below the minimum of 10ms"
+    );
+}
+
+#[test]
+fn test_longer_and_computed_delays_are_allowed() {
+    let code = "setTimeout(f, 500);\n\
+                setTimeout(f, delay);\n\
+                setInterval(f, 1000);\n\
+                setInterval(f, 0 + interval);\n\
+                scheduler.setTimeout(f, 0);\n";
+
+    assert!(run_rule(RULE, code).is_empty());
+}
+
+#[test]
+fn test_min_interval_is_configurable() {
+    let code = "setInterval(f, 50);\nsetTimeout(f, 1);\n";
+
+    assert!(run_rule(RULE, code).is_empty());
+    let config = Some(json!({ "minInterval": 100 }));
+    let diagnostics = run_rule_with_config(RULE, config, "test-file.ts", code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line_number, 1);
+}
@@ -0,0 +1,53 @@
+mod common;
+
+use common::{run_rule, run_rule_with_config};
+use scoper::rules_registry::create_default_registry;
+use serde_json::json;
+
+const RULE: &str = "numeric-separators";
+
+#[test]
+fn test_large_literal_without_separators_is_flagged() {
+    let diagnostics = run_rule(RULE, "const timeout = 100000;\n");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line_number, 1);
+    assert_eq!(diagnostics[0].column_number, 17);
+    assert!(diagnostics[0].diagnostic.message.contains("`100000`"));
+}
+
+#[test]
+fn test_literals_with_separators_or_below_threshold_are_allowed() {
+    let code = "const timeout = 100_000;\nconst port = 8080;\nconst ratio = 0.000001;\n";
+    assert!(run_rule(RULE, code).is_empty());
+}
+
+#[test]
+fn test_other_notations_are_ignored() {
+    let code = "const mask = 0xFFFFFF;\nconst flags = 0b1111111111111111;\nconst big = 1e10;\nconst id = 1000000n;\n";
+    assert!(run_rule(RULE, code).is_empty());
+}
+
+#[test]
+fn test_threshold_is_configurable() {
+    let config = Some(json!({ "threshold": 1000 }));
+    let diagnostics = run_rule_with_config(RULE, config, "test-file.ts", "const port = 8080;\n");
+    assert_eq!(diagnostics.len(), 1);
+}
+
+#[test]
+fn test_fix_inserts_separators() {
+    let registry = create_default_registry();
+    let rule = registry.get_rule(RULE).unwrap();
+
+    for (code, expected) in [
+        ("const n = 100000;\n", "const n = 100_000;\n"),
+        ("const n = 1234567;\n", "const n = 1_234_567;\n"),
+        ("const n = 1999999.995;\n", "const n = 1_999_999.995;\n"),
+    ] {
+        let diagnostics = run_rule(RULE, code);
+        let fix = rule.fix(&diagnostics[0].diagnostic, code).unwrap();
+        let fixed = fix.apply(code);
+        assert_eq!(fixed, expected);
+        assert!(run_rule(RULE, &fixed).is_empty());
+    }
+}
@@ -0,0 +1,89 @@
+use std::fs;
+use std::path::Path;
+
+use scoper::utilities::DebugLevel;
+use scoper::utilities::file_utils::{dir_inside_scan_root, find_files_with_extensions};
+
+fn write(path: &Path, content: &str) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+#[test]
+fn test_output_dir_inside_scan_root_is_not_analyzed() {
+    let root = tempfile::tempdir().unwrap();
+    write(&root.path().join("src/app.ts"), "export const a = 1;\n");
+    write(&root.path().join("findings/generated.ts"), "debugger;\n");
+    write(&root.path().join("findings/findings.json"), "{}");
+
+    let root_str = root.path().to_string_lossy().to_string();
+    let output_dir = root.path().join("findings").to_string_lossy().to_string();
+    let (files, _) =
+        find_files_with_extensions(&root_str, &["ts"], false, &[&output_dir], DebugLevel::None);
+
+    assert_eq!(files.len(), 1);
+    assert!(files[0].ends_with("app.ts"));
+}
+
+#[test]
+fn test_output_dir_outside_scan_root_is_ignored() {
+    let root = tempfile::tempdir().unwrap();
+    let elsewhere = tempfile::tempdir().unwrap();
+    write(&root.path().join("src/app.ts"), "export const a = 1;\n");
+
+    let root_str = root.path().to_string_lossy();
+    assert!(dir_inside_scan_root(&root_str, &elsewhere.path().to_string_lossy()).is_none());
+    assert!(dir_inside_scan_root(&root_str, &root.path().join("src").to_string_lossy()).is_some());
+    // A directory that does not exist yet cannot contain files to skip
+    assert!(
+        dir_inside_scan_root(&root_str, &root.path().join("missing").to_string_lossy()).is_none()
+    );
+}
+
+#[test]
+fn test_output_dir_equal_to_scan_root_is_still_analyzed() {
+    let root = tempfile::tempdir().unwrap();
+    write(&root.path().join("src/app.ts"), "export const a = 1;\n");
+    write(
+        &root.path().join("index.ts"),
+        "export * from './src/app';\n",
+    );
+
+    let root_str = root.path().to_string_lossy().to_string();
+    let (files, _) =
+        find_files_with_extensions(&root_str, &["ts"], false, &[&root_str], DebugLevel::None);
+
+    assert_eq!(files.len(), 2);
+}
+
+#[test]
+fn test_output_dir_equal_to_scan_root_is_reported_accurately() {
+    let root = tempfile::tempdir().unwrap();
+    write(&root.path().join("app.ts"), "export const a = 1;\n");
+    write(
+        &root.path().join("rules.json"),
+        r#"{ "rules": { "no-debugger": "error" } }"#,
+    );
+    // An unreachable API keeps the run from submitting anything
+    write(
+        &root.path().join("sentinel.json"),
+        r#"{ "api_url": "http://127.0.0.1:9" }"#,
+    );
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_scoper"))
+        .arg(".")
+        .args(["--config", "sentinel.json", "--rules-config", "rules.json"])
+        .args(["--output-dir", ".", "--debug-level", "warn"])
+        .current_dir(root.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let log = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(log.contains("is the scan path and cannot be excluded from analysis"));
+    assert!(!log.contains("is excluded from analysis"));
+}
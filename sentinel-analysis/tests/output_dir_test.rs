@@ -0,0 +1,63 @@
+use std::fs;
+use std::process::Command;
+
+use scoper::exporter::ExportOptions;
+use scoper::utilities::config::{Config, DEFAULT_OUTPUT_DIR};
+
+#[test]
+fn test_findings_path_follows_the_output_dir() {
+    let default = ExportOptions::from_config(&Config::default());
+    assert_eq!(
+        default.findings_path(),
+        format!("{}/findings.json", DEFAULT_OUTPUT_DIR)
+    );
+
+    let config = Config {
+        output_dir: Some("reports".to_string()),
+        ..Config::default()
+    };
+    assert_eq!(
+        ExportOptions::from_config(&config).findings_path(),
+        "reports/findings.json"
+    );
+}
+
+#[test]
+fn test_configured_output_dir_is_used_for_export_and_submission() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("debug.ts"), "debugger;\n").unwrap();
+    fs::write(
+        dir.path().join("rules.ci.json"),
+        r#"{ "rules": { "no-debugger": "error" } }"#,
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("sentinel.ci.json"),
+        // Nothing listens on port 9, so the submission fails right away
+        r#"{ "output_dir": "reports", "rules_config": "rules.ci.json", "api_url": "http://127.0.0.1:9/" }"#,
+    )
+    .unwrap();
+
+    let run = |extra_args: &[&str]| {
+        let output = Command::new(env!("CARGO_BIN_EXE_scoper"))
+            .arg(dir.path())
+            .args(["--config", "sentinel.ci.json", "--debug-level", "info"])
+            .args(extra_args)
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        String::from_utf8_lossy(&output.stdout).to_string()
+    };
+
+    let stdout = run(&[]);
+    assert!(dir.path().join("reports/findings.json").exists());
+    assert!(stdout.contains("Attempting to read findings from: reports/findings.json"));
+
+    // The command line takes precedence over the config file
+    let stdout = run(&["--output-dir", "out"]);
+    assert!(dir.path().join("out/findings.json").exists());
+    assert!(stdout.contains("Attempting to read findings from: out/findings.json"));
+
+    assert!(!dir.path().join(DEFAULT_OUTPUT_DIR).exists());
+}
@@ -0,0 +1,58 @@
+use scoper::analyzer::analyze_source;
+use scoper::rules_registry::{RulesRegistry, configure_registry, create_default_registry};
+
+/// A top-level `return` is a recoverable syntax error; the rest of the file still parses
+const CODE: &str = "debugger;\nconst a = 1;\nreturn a;\n";
+
+fn registry(continue_on_parse_error: bool) -> RulesRegistry {
+    let mut registry = create_default_registry();
+    registry.set_continue_on_parse_error(continue_on_parse_error);
+    configure_registry(
+        &mut registry,
+        &[("no-debugger".to_string(), None, "error".to_string())],
+    );
+    registry
+}
+
+/// Rule IDs and positions of the diagnostics reported for `CODE`
+fn findings(registry: &RulesRegistry) -> Vec<(String, usize, usize)> {
+    analyze_source("broken.ts", CODE, registry)
+        .diagnostics
+        .into_iter()
+        .map(|diagnostic| {
+            (
+                diagnostic.rule_id,
+                diagnostic.line_number,
+                diagnostic.column_number,
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_parse_errors_are_reported_with_positions() {
+    assert_eq!(
+        findings(&registry(false)),
+        vec![("parser".to_string(), 3, 1)]
+    );
+}
+
+#[test]
+fn test_rules_run_on_recovered_ast_when_enabled() {
+    assert_eq!(
+        findings(&registry(true)),
+        vec![
+            ("parser".to_string(), 3, 1),
+            ("no-debugger".to_string(), 1, 1)
+        ]
+    );
+}
+
+#[test]
+fn test_unrecoverable_errors_skip_rules() {
+    let diagnostics =
+        analyze_source("broken.ts", "debugger;\nconst x = ;\n", &registry(true)).diagnostics;
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].rule_id, "parser");
+    assert_eq!(diagnostics[0].line_number, 2);
+}
@@ -0,0 +1,107 @@
+use std::fs;
+use std::sync::Arc;
+use std::time::Duration;
+
+use scoper::analyzer::{PartialResults, process_files_into};
+use scoper::exporter::{FindingsExport, partial_findings_file_name};
+use scoper::metrics::export_partial_results;
+use scoper::rules_registry::{RegistryResolver, configure_registry, create_default_registry};
+use scoper::utilities::DebugLevel;
+use scoper::utilities::config::Config;
+use scoper::utilities::file_utils::find_files;
+
+#[test]
+fn test_partial_results_keep_the_file_order() {
+    let root = tempfile::tempdir().unwrap();
+    for index in 0..100 {
+        fs::write(
+            root.path().join(format!("file{:03}.ts", index)),
+            "debugger;\n",
+        )
+        .unwrap();
+    }
+
+    let mut registry = create_default_registry();
+    configure_registry(
+        &mut registry,
+        &[("no-debugger".to_string(), None, "error".to_string())],
+    );
+    let resolver = Arc::new(RegistryResolver::single(Arc::new(registry)));
+    let (files, _) = find_files(&root.path().to_string_lossy(), DebugLevel::None);
+
+    let partial_results = PartialResults::default();
+    process_files_into(
+        &files,
+        &resolver,
+        None,
+        false,
+        &partial_results,
+        DebugLevel::None,
+    );
+
+    let results = partial_results.take();
+    let analyzed: Vec<&String> = results.iter().map(|result| &result.file_path).collect();
+    assert_eq!(analyzed, files.iter().collect::<Vec<_>>());
+    // Taking the results empties the accumulator
+    assert!(partial_results.take().is_empty());
+}
+
+#[test]
+fn test_partial_findings_are_written_next_to_findings_json() {
+    let root = tempfile::tempdir().unwrap();
+    fs::write(root.path().join("debug.ts"), "debugger;\n").unwrap();
+    let output_dir = root.path().join("out");
+
+    let registry = {
+        let mut registry = create_default_registry();
+        configure_registry(
+            &mut registry,
+            &[("no-debugger".to_string(), None, "error".to_string())],
+        );
+        registry
+    };
+    let resolver = Arc::new(RegistryResolver::single(Arc::new(registry)));
+    let (files, _) = find_files(&root.path().to_string_lossy(), DebugLevel::None);
+    let partial_results = PartialResults::default();
+    process_files_into(
+        &files,
+        &resolver,
+        None,
+        false,
+        &partial_results,
+        DebugLevel::None,
+    );
+
+    let config = Config {
+        output_dir: Some(output_dir.to_string_lossy().to_string()),
+        ..Config::default()
+    };
+    let path = export_partial_results(
+        &config,
+        &partial_results.take(),
+        Duration::ZERO,
+        Duration::ZERO,
+        DebugLevel::None,
+    );
+
+    assert!(path.ends_with("findings.partial.json"));
+    assert!(!output_dir.join("findings.json").exists());
+    let export: FindingsExport = serde_json::from_str(
+        &fs::read_to_string(output_dir.join("findings.partial.json")).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(export.findings.len(), 1);
+    assert_eq!(export.summary.files_processed, 1);
+}
+
+#[test]
+fn test_partial_file_name_keeps_the_shard_suffix() {
+    assert_eq!(
+        partial_findings_file_name("findings.json"),
+        "findings.partial.json"
+    );
+    assert_eq!(
+        partial_findings_file_name("findings-2.json"),
+        "findings-2.partial.json"
+    );
+}
@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+use std::fs;
+use std::process::Command;
+
+use scoper::exporter::{FindingsExport, PathSeverities, SeverityLevel};
+
+#[test]
+fn test_path_severities_override_rule_severity() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::create_dir(dir.path().join("src")).unwrap();
+    fs::create_dir(dir.path().join("scripts")).unwrap();
+    fs::write(dir.path().join("src/app.ts"), "debugger;\n").unwrap();
+    fs::write(dir.path().join("scripts/build.ts"), "debugger;\n").unwrap();
+    fs::write(dir.path().join("other.ts"), "debugger;\n").unwrap();
+    fs::write(
+        dir.path().join("rules.json"),
+        r#"{ "rules": { "no-debugger": "error" } }"#,
+    )
+    .unwrap();
+    // An unreachable API keeps the run from submitting anything
+    fs::write(
+        dir.path().join("sentinel.json"),
+        r#"{
+            "api_url": "http://127.0.0.1:9",
+            "path_severities": { "src/**": "error", "scripts/**": "warning" }
+        }"#,
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_scoper"))
+        .arg(dir.path())
+        .args(["--config", "sentinel.json", "--rules-config", "rules.json"])
+        .args(["--output-dir", "out"])
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let content = fs::read_to_string(dir.path().join("out/findings.json")).unwrap();
+    let export: FindingsExport = serde_json::from_str(&content).unwrap();
+    let severity_of = |file: &str| {
+        export
+            .findings
+            .iter()
+            .find(|f| f.file == file)
+            .map(|f| f.severity.clone())
+            .unwrap()
+    };
+    assert_eq!(severity_of("src/app.ts"), "error");
+    assert_eq!(severity_of("scripts/build.ts"), "warning");
+    // Files outside the globs keep the rule severity
+    assert_eq!(severity_of("other.ts"), "error");
+    assert_eq!(export.summary.findings_by_severity["warning"], 1);
+}
+
+#[test]
+fn test_longest_glob_wins() {
+    let globs = HashMap::from([
+        ("src/**".to_string(), SeverityLevel::Error),
+        ("src/legacy/**".to_string(), SeverityLevel::Warning),
+        ("*.ts".to_string(), SeverityLevel::Info),
+    ]);
+    let path_severities = PathSeverities::new(&globs);
+
+    assert_eq!(
+        path_severities.severity_for("src/app/app.component.ts"),
+        Some(SeverityLevel::Error)
+    );
+    assert_eq!(
+        path_severities.severity_for("src/legacy/old.ts"),
+        Some(SeverityLevel::Warning)
+    );
+    // `*` does not cross directory boundaries
+    assert_eq!(
+        path_severities.severity_for("main.ts"),
+        Some(SeverityLevel::Info)
+    );
+    assert_eq!(path_severities.severity_for("scripts/build.ts"), None);
+}
@@ -0,0 +1,98 @@
+use std::fs;
+use std::process::Command;
+
+use scoper::exporter::{FindingEntry, FindingsExport, FindingsSummary};
+use scoper::porcelain::{PORCELAIN_VERSION, RecordKind, porcelain_record};
+use serde_json::Value;
+
+#[test]
+fn test_record_is_a_single_tab_separated_line() {
+    let payload = serde_json::json!({ "message": "line one\nline\ttwo" });
+    let record = porcelain_record(RecordKind::Finding, &payload).unwrap();
+
+    assert_eq!(record.lines().count(), 1);
+    let fields: Vec<&str> = record.splitn(3, '\t').collect();
+    assert_eq!(fields[..2], ["SENTINEL", "finding"]);
+    assert_eq!(serde_json::from_str::<Value>(fields[2]).unwrap(), payload);
+}
+
+#[test]
+fn test_porcelain_prints_only_records_to_stdout() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("a.ts"), "debugger;\n").unwrap();
+    fs::write(dir.path().join("b.ts"), "debugger;\ndebugger;\n").unwrap();
+    fs::write(
+        dir.path().join("rules.json"),
+        r#"{ "rules": { "no-debugger": "error" } }"#,
+    )
+    .unwrap();
+    // An unreachable API keeps the run from submitting anything
+    fs::write(
+        dir.path().join("sentinel.json"),
+        r#"{ "api_url": "http://127.0.0.1:9" }"#,
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_scoper"))
+        .arg(dir.path())
+        .args(["--config", "sentinel.json", "--rules-config", "rules.json"])
+        .args([
+            "--output-dir",
+            "out",
+            "--porcelain",
+            "--debug-level",
+            "debug",
+        ])
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let records: Vec<(&str, &str)> = stdout
+        .lines()
+        .map(|line| {
+            let fields: Vec<&str> = line.splitn(3, '\t').collect();
+            assert_eq!(fields.len(), 3, "{}", line);
+            assert_eq!(fields[0], "SENTINEL");
+            (fields[1], fields[2])
+        })
+        .collect();
+
+    assert_eq!(records.len(), 5);
+    assert_eq!(records[0].0, "version");
+    let version: Value = serde_json::from_str(records[0].1).unwrap();
+    assert_eq!(version["version"], PORCELAIN_VERSION);
+
+    let findings: Vec<FindingEntry> = records[1..4]
+        .iter()
+        .map(|(kind, json)| {
+            assert_eq!(*kind, "finding");
+            serde_json::from_str(json).unwrap()
+        })
+        .collect();
+    assert_eq!(findings.iter().filter(|f| f.file == "b.ts").count(), 2);
+
+    assert_eq!(records[4].0, "summary");
+    let summary: FindingsSummary = serde_json::from_str(records[4].1).unwrap();
+    assert_eq!(summary.total_findings, 3);
+
+    // The findings file is written as usual
+    let content = fs::read_to_string(dir.path().join("out/findings.json")).unwrap();
+    let export: FindingsExport = serde_json::from_str(&content).unwrap();
+    assert_eq!(export.findings.len(), 3);
+}
+
+#[test]
+fn test_porcelain_conflicts_with_stdout_output() {
+    let dir = tempfile::tempdir().unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_scoper"))
+        .arg(dir.path())
+        .args(["--output-dir", "-", "--porcelain"])
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+}
@@ -0,0 +1,45 @@
+mod common;
+
+use common::run_rule;
+use oxc_diagnostics::Severity;
+
+const RULE: &str = "prefer-const-assertion";
+
+#[test]
+fn test_exported_literal_is_flagged() {
+    let code = "export const ROLES = ['admin', 'user'];\n\
+                export const DEFAULTS = { retries: 3, nested: { verbose: false, ratio: -1 } };\n";
+
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(diagnostics[0].line_number, 1);
+    assert_eq!(diagnostics[0].column_number, 22);
+    assert!(diagnostics[0].diagnostic.message.contains("`ROLES`"));
+    assert_eq!(diagnostics[1].line_number, 2);
+    assert_eq!(diagnostics[0].diagnostic.severity, Severity::Advice);
+}
+
+#[test]
+fn test_mutated_constants_are_allowed() {
+    let code = "export const ROLES = ['admin'];\n\
+                export const LIMITS = { max: 1 };\n\
+                export const FLAGS = { debug: false };\n\
+                export const ORDER = [3, 1, 2];\n\
+                ROLES.push('user');\n\
+                LIMITS.max = 2;\n\
+                Object.assign(FLAGS, { debug: true });\n\
+                function sortOrder() { ORDER.sort(); }\n";
+    assert!(run_rule(RULE, code).is_empty());
+}
+
+#[test]
+fn test_non_candidates_are_allowed() {
+    let code = "const LOCAL = ['a'];\n\
+                export let MUTABLE = ['a'];\n\
+                export const TYPED: string[] = ['a'];\n\
+                export const ASSERTED = ['a'] as const;\n\
+                export const EMPTY = [];\n\
+                export const COMPUTED = [LOCAL.length];\n\
+                export const SPREAD = { ...{ a: 1 } };\n";
+    assert!(run_rule(RULE, code).is_empty());
+}
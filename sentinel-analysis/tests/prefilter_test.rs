@@ -0,0 +1,55 @@
+use scoper::SkipReason;
+use scoper::analyzer::analyze_source;
+use scoper::exporter::coverage_summary;
+use scoper::rules_registry::{RulesRegistry, configure_registry, create_default_registry};
+
+const COMPONENT: &str = "import { Component } from '@angular/core';\n\n\
+                         @Component({ selector: 'app-user' })\n\
+                         export class User {}\n";
+
+const SERVICE: &str = "export class UserService {\n  load() {}\n}\n";
+
+fn registry(rules: &[&str], prefilter: bool) -> RulesRegistry {
+    let mut registry = create_default_registry();
+    registry.set_prefilter(prefilter);
+    let rules: Vec<_> = rules
+        .iter()
+        .map(|rule| (rule.to_string(), None, "error".to_string()))
+        .collect();
+    configure_registry(&mut registry, &rules);
+    registry
+}
+
+#[test]
+fn test_files_no_rule_applies_to_are_skipped() {
+    let registry = registry(&["angular-component-class-suffix"], true);
+
+    let service = analyze_source("user.service.ts", SERVICE, &registry);
+    assert_eq!(service.skip_reason, Some(SkipReason::NoApplicableRules));
+    assert!(service.rule_durations.is_empty());
+
+    let component = analyze_source("user.component.ts", COMPONENT, &registry);
+    assert_eq!(component.skip_reason, None);
+    assert_eq!(component.diagnostics.len(), 1);
+
+    let coverage = coverage_summary(&[service, component]);
+    assert_eq!(coverage.files_skipped["no_applicable_rules"], 1);
+}
+
+#[test]
+fn test_rules_without_a_check_apply_to_every_file() {
+    let registry = registry(&["angular-component-class-suffix", "no-shadow"], true);
+
+    let service = analyze_source("user.service.ts", SERVICE, &registry);
+    assert_eq!(service.skip_reason, None);
+}
+
+#[test]
+fn test_files_are_analyzed_without_prefilter() {
+    let registry = registry(&["angular-component-class-suffix"], false);
+
+    let service = analyze_source("user.service.ts", SERVICE, &registry);
+    assert_eq!(service.skip_reason, None);
+    assert!(registry.any_rule_applies_to(COMPONENT));
+    assert!(!registry.any_rule_applies_to(SERVICE));
+}
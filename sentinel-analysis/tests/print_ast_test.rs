@@ -0,0 +1,18 @@
+use scoper::commands::print_ast::render_ast;
+
+#[test]
+fn test_render_ast_nests_node_kinds_with_spans() {
+    let output = render_ast("snippet.ts", "const a = 1;\n");
+    let lines: Vec<&str> = output.lines().collect();
+
+    assert_eq!(lines[0], "Program 0..13 (1:1)");
+    assert_eq!(lines[1], "  VariableDeclaration 0..12 (1:1)");
+    assert!(lines.contains(&"      NumericLiteral(1) 10..11 (1:11)"));
+}
+
+#[test]
+fn test_render_ast_lists_parse_errors() {
+    let output = render_ast("broken.ts", "const = ;\n");
+
+    assert!(output.starts_with("Parse errors:\n  1:"));
+}
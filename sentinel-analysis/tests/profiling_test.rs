@@ -0,0 +1,47 @@
+#![cfg(feature = "profiling")]
+
+use std::fs;
+use std::process::Command;
+
+#[test]
+fn test_profile_is_written_as_folded_stacks() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(
+        dir.path().join("a.ts"),
+        "async function f() {}\ndebugger;\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("rules.json"),
+        r#"{ "rules": { "no-debugger": "error", "no-redundant-async": "warn" } }"#,
+    )
+    .unwrap();
+    // An unreachable API keeps the run from submitting anything
+    fs::write(
+        dir.path().join("sentinel.json"),
+        r#"{ "api_url": "http://127.0.0.1:9" }"#,
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_scoper"))
+        .arg(dir.path())
+        .args(["--config", "sentinel.json", "--rules-config", "rules.json"])
+        .args(["--output-dir", "out"])
+        .env("SENTINEL_PROFILE", dir.path().join("run.folded"))
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let folded = fs::read_to_string(dir.path().join("run.folded")).unwrap();
+    let stacks: Vec<&str> = folded
+        .lines()
+        .map(|line| line.rsplit_once(' ').unwrap().0)
+        .collect();
+    let has_frame = |frame: &str| stacks.iter().any(|stack| stack.ends_with(frame));
+    assert!(has_frame("; discover"));
+    assert!(has_frame("; batch; file; parse"));
+    assert!(has_frame("; batch; file; semantic"));
+    assert!(has_frame("; batch; file; rules; no-redundant-async"));
+    assert!(has_frame("; batch; file; rules; node_rules"));
+}
@@ -0,0 +1,35 @@
+use std::fs;
+use std::sync::Arc;
+
+use scoper::analyzer::process_files_with_resolver;
+use scoper::rules_registry::{RegistryResolver, configure_registry, create_default_registry};
+use scoper::utilities::DebugLevel;
+use scoper::utilities::file_utils::find_files;
+
+#[test]
+fn test_throttled_reads_produce_the_same_results() {
+    let root = tempfile::tempdir().unwrap();
+    for index in 0..20 {
+        fs::write(root.path().join(format!("file{}.ts", index)), "debugger;\n").unwrap();
+    }
+
+    let mut registry = create_default_registry();
+    configure_registry(
+        &mut registry,
+        &[("no-debugger".to_string(), None, "error".to_string())],
+    );
+    let resolver = Arc::new(RegistryResolver::single(Arc::new(registry)));
+    let (files, _) = find_files(&root.path().to_string_lossy(), DebugLevel::None);
+
+    for read_concurrency in [None, Some(1), Some(3)] {
+        let (results, _) = process_files_with_resolver(
+            &files,
+            &resolver,
+            read_concurrency,
+            false,
+            DebugLevel::None,
+        );
+        assert_eq!(results.len(), 20);
+        assert!(results.iter().all(|result| result.diagnostics.len() == 1));
+    }
+}
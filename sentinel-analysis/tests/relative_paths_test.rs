@@ -0,0 +1,62 @@
+use std::fs;
+use std::sync::Arc;
+
+use scoper::analyzer::process_files;
+use scoper::exporter::{ExportOptions, FindingsExport, export_findings_json};
+use scoper::metrics::aggregate_metrics;
+use scoper::rules_registry::{configure_registry, create_default_registry};
+use scoper::utilities::DebugLevel;
+use scoper::utilities::file_utils::{find_files, to_relative_path};
+
+#[test]
+fn test_to_relative_path_strips_scan_root() {
+    assert_eq!(
+        to_relative_path("/home/ci/project/src/app/app.ts", "/home/ci/project"),
+        "src/app/app.ts"
+    );
+    assert_eq!(to_relative_path("./src/app.ts", "."), "src/app.ts");
+}
+
+#[test]
+fn test_to_relative_path_normalizes_backslashes() {
+    assert_eq!(
+        to_relative_path("C:\\ci\\project\\src\\app.ts", "C:\\ci\\project"),
+        "src/app.ts"
+    );
+    assert_eq!(
+        to_relative_path("C:\\elsewhere\\app.ts", "C:\\ci\\project"),
+        "C:/elsewhere/app.ts"
+    );
+}
+
+#[test]
+fn test_findings_use_relative_paths_for_absolute_scan_root() {
+    let scan_root = tempfile::tempdir().unwrap();
+    let output_dir = tempfile::tempdir().unwrap();
+    let nested = scan_root.path().join("src");
+    fs::create_dir_all(&nested).unwrap();
+    fs::write(nested.join("debug.ts"), "debugger;\n").unwrap();
+
+    let mut registry = create_default_registry();
+    configure_registry(
+        &mut registry,
+        &[("no-debugger".to_string(), None, "error".to_string())],
+    );
+
+    let root = scan_root.path().to_string_lossy().to_string();
+    let (files, scan_duration) = find_files(&root, DebugLevel::None);
+    let (results, analysis_duration) = process_files(&files, &Arc::new(registry), DebugLevel::None);
+    let metrics = aggregate_metrics(&results, scan_duration, analysis_duration);
+
+    let options = ExportOptions {
+        output_dir: output_dir.path().to_string_lossy().to_string(),
+        path_root: Some(root),
+        ..ExportOptions::default()
+    };
+    export_findings_json(&results, &metrics, DebugLevel::None, &options);
+
+    let content = fs::read_to_string(output_dir.path().join("findings.json")).unwrap();
+    let export: FindingsExport = serde_json::from_str(&content).unwrap();
+    assert_eq!(export.findings.len(), 1);
+    assert_eq!(export.findings[0].file, "src/debug.ts");
+}
@@ -0,0 +1,71 @@
+mod common;
+
+use common::{run_rule, run_rule_with_config};
+use serde_json::json;
+
+const RULE: &str = "require-async-error-handling";
+
+#[test]
+fn test_await_without_try_catch_is_flagged() {
+    let code = "async function loadUser(id: string): Promise<User> {\n  const response = await fetch(id);\n  return response.json();\n}\n";
+
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line_number, 1);
+    assert_eq!(diagnostics[0].column_number, 1);
+}
+
+#[test]
+fn test_methods_and_arrow_functions_are_checked() {
+    let code = "class UserService {\n  async load() {\n    await this.http.get();\n  }\n}\n\
+                const save = async (user) => {\n  await api.save(user);\n};\n\
+                const handlers = { async remove() { await api.remove(); } };\n";
+
+    let diagnostics = run_rule(RULE, code);
+    let positions: Vec<_> = diagnostics
+        .iter()
+        .map(|diagnostic| (diagnostic.line_number, diagnostic.column_number))
+        .collect();
+    assert_eq!(positions, [(2, 9), (6, 14), (9, 26)]);
+}
+
+#[test]
+fn test_handled_awaits_are_allowed() {
+    let code = "async function load() {\n\
+                  try {\n    await fetch('/a');\n    for await (const chunk of stream) {}\n  } catch (error) {\n    log(error);\n  }\n\
+                  await fetch('/b').catch(log);\n\
+                }\n\
+                async function noAwait() {\n  return fetch('/c');\n}\n";
+    assert!(run_rule(RULE, code).is_empty());
+}
+
+#[test]
+fn test_try_without_catch_and_callbacks_are_not_protected() {
+    let code = "async function load() {\n  try {\n    await fetch('/a');\n  } finally {\n    done();\n  }\n}\n\
+                async function poll() {\n  try {\n    setTimeout(async () => {\n      await fetch('/b');\n    });\n  } catch {}\n}\n";
+
+    let diagnostics = run_rule(RULE, code);
+    let lines: Vec<_> = diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.line_number)
+        .collect();
+    assert_eq!(lines, [1, 10]);
+}
+
+#[test]
+fn test_only_exported_functions_are_checked_when_configured() {
+    let code = "async function helper() {\n  await fetch('/a');\n}\n\
+                export async function load() {\n  await helper();\n}\n\
+                export const save = async () => {\n  await fetch('/b');\n};\n\
+                export default async function () {\n  await fetch('/c');\n}\n";
+
+    assert_eq!(run_rule(RULE, code).len(), 4);
+
+    let config = Some(json!({ "onlyExported": true }));
+    let diagnostics = run_rule_with_config(RULE, config, "test-file.ts", code);
+    let lines: Vec<_> = diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.line_number)
+        .collect();
+    assert_eq!(lines, [4, 7, 10]);
+}
@@ -0,0 +1,40 @@
+mod common;
+
+use common::{run_rule, run_rule_with_config};
+use serde_json::json;
+
+const RULE: &str = "require-await-on-async";
+
+#[test]
+fn test_floating_calls_are_flagged() {
+    let code = "async function save(http, user$) {\n  firstValueFrom(http.post('/api', 1));\n  user$.toPromise();\n}\n";
+
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(diagnostics[0].line_number, 2);
+    assert_eq!(diagnostics[0].column_number, 3);
+    assert_eq!(diagnostics[1].line_number, 3);
+}
+
+#[test]
+fn test_awaited_and_handled_calls_are_allowed() {
+    let code = "async function load(events$) {\n\
+                  await firstValueFrom(events$);\n\
+                  lastValueFrom(events$).then(log);\n\
+                  const pending = firstValueFrom(events$);\n\
+                  void lastValueFrom(events$);\n\
+                  return firstValueFrom(events$);\n\
+                }\n";
+
+    assert!(run_rule(RULE, code).is_empty());
+}
+
+#[test]
+fn test_function_names_are_configurable() {
+    let code = "fetchUser(1);\nfirstValueFrom(user$);\n";
+
+    let config = Some(json!({ "functions": ["fetchUser"] }));
+    let diagnostics = run_rule_with_config(RULE, config, "test-file.ts", code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line_number, 1);
+}
@@ -0,0 +1,173 @@
+mod common;
+
+use common::{run_rule, run_rule_with_config};
+use scoper::Rule;
+use scoper::rules::RequireFileHeaderRule;
+use serde_json::{Value, json};
+
+const RULE: &str = "require-file-header";
+
+const HEADER: &str = "// Copyright (c) Acme Corp.\n// SPDX-License-Identifier: MIT";
+
+fn header_config() -> Option<Value> {
+    Some(json!({ "header": HEADER }))
+}
+
+#[test]
+fn test_present_header_is_allowed() {
+    let code = "// Copyright (c) Acme Corp.\r\n// SPDX-License-Identifier: MIT  \r\nexport const a = 1;\r\n";
+    assert!(run_rule_with_config(RULE, header_config(), "src/a.ts", code).is_empty());
+
+    let script = format!("#!/usr/bin/env node\n{}\nmain();\n", HEADER);
+    assert!(run_rule_with_config(RULE, header_config(), "bin/cli.ts", &script).is_empty());
+}
+
+#[test]
+fn test_absent_header_is_flagged_at_line_one() {
+    let diagnostics =
+        run_rule_with_config(RULE, header_config(), "src/a.ts", "export const a = 1;\n");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].diagnostic.message,
+        "File is missing the required header"
+    );
+    assert_eq!(
+        (diagnostics[0].line_number, diagnostics[0].column_number),
+        (1, 1)
+    );
+}
+
+#[test]
+fn test_mismatched_header_is_flagged() {
+    let code = "// Copyright (c) Acme Inc.\n// SPDX-License-Identifier: MIT\nexport const a = 1;\n";
+
+    let diagnostics = run_rule_with_config(RULE, header_config(), "src/a.ts", code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].diagnostic.message,
+        "File header does not match the required header"
+    );
+    assert_eq!(diagnostics[0].line_number, 1);
+}
+
+#[test]
+fn test_pattern_and_files_are_configurable() {
+    let config = Some(json!({
+        "pattern": r"// Copyright \(c\) \d{4} Acme Corp\.",
+        "files": ["src/**"]
+    }));
+
+    let dated = "// Copyright (c) 2024 Acme Corp.\nexport const a = 1;\n";
+    assert!(run_rule_with_config(RULE, config.clone(), "src/a.ts", dated).is_empty());
+    let undated = "// Copyright (c) Acme Corp.\nexport const a = 1;\n";
+    assert_eq!(
+        run_rule_with_config(RULE, config.clone(), "src/a.ts", undated).len(),
+        1
+    );
+    assert!(run_rule_with_config(RULE, config, "scripts/a.ts", undated).is_empty());
+}
+
+#[test]
+fn test_nothing_is_reported_without_a_header() {
+    assert!(run_rule(RULE, "export const a = 1;\n").is_empty());
+}
+
+#[test]
+fn test_fix_inserts_the_header() {
+    let mut rule = RequireFileHeaderRule::new();
+    rule.set_config(header_config().unwrap());
+
+    let code = "#!/usr/bin/env node\nmain();\n";
+    let diagnostics = run_rule_with_config(RULE, header_config(), "bin/cli.ts", code);
+    let fixed = rule
+        .fix(&diagnostics[0].diagnostic, code)
+        .unwrap()
+        .apply(code);
+    assert_eq!(
+        fixed,
+        format!("#!/usr/bin/env node\n{}\n\nmain();\n", HEADER)
+    );
+    assert!(run_rule_with_config(RULE, header_config(), "bin/cli.ts", &fixed).is_empty());
+
+    // A different header is left for a person to update
+    let code = "// Copyright (c) Acme Inc.\nexport const a = 1;\n";
+    let diagnostics = run_rule_with_config(RULE, header_config(), "src/a.ts", code);
+    assert!(rule.fix(&diagnostics[0].diagnostic, code).is_none());
+}
+
+#[test]
+fn test_files_globs_are_relative_to_the_scan_root() {
+    let dir = tempfile::tempdir().unwrap();
+    let project = dir.path().join("project");
+    std::fs::create_dir_all(project.join("src")).unwrap();
+    std::fs::create_dir_all(project.join("scripts")).unwrap();
+    std::fs::write(project.join("src/a.ts"), "export const a = 1;\n").unwrap();
+    std::fs::write(project.join("scripts/b.ts"), "export const b = 1;\n").unwrap();
+    std::fs::write(
+        dir.path().join("rules.json"),
+        r#"{ "rules": { "require-file-header": ["error", {
+            "header": "// Copyright (c) Acme Corp.",
+            "files": ["src/**"]
+        }] } }"#,
+    )
+    .unwrap();
+    // An unreachable API keeps the run from submitting anything
+    std::fs::write(
+        dir.path().join("sentinel.json"),
+        r#"{ "api_url": "http://127.0.0.1:9" }"#,
+    )
+    .unwrap();
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_scoper"))
+        .arg("./project")
+        .args(["--config", "sentinel.json", "--rules-config", "rules.json"])
+        .args(["--output-dir", "-"])
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let export: Value = serde_json::from_slice(&output.stdout).unwrap();
+    let files: Vec<&str> = export["findings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|finding| finding["file"].as_str().unwrap())
+        .collect();
+    assert_eq!(files, ["src/a.ts"]);
+}
+
+#[test]
+fn test_invalid_pattern_is_ignored_with_a_warning() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("a.ts"), "export const a = 1;\n").unwrap();
+    std::fs::write(
+        dir.path().join("rules.json"),
+        r#"{ "rules": { "require-file-header": ["error", {
+            "header": "// Copyright (c) Acme Corp.",
+            "pattern": "(unclosed"
+        }] } }"#,
+    )
+    .unwrap();
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_scoper"))
+        .arg(dir.path())
+        .args(["--rules-config", "rules.json", "--output-dir", "-"])
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("require-file-header: ignoring invalid pattern '(unclosed'"),
+        "{}",
+        stderr
+    );
+    // The header is checked instead
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("File is missing the required header"),
+        "{}",
+        stdout
+    );
+}
@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+use std::fs;
+use std::process::{Command, Output};
+
+use scoper::exporter::{BudgetOverrun, exceeded_budgets};
+use scoper::rules_registry::load_rule_budgets;
+
+/// Scan a file with one `debugger` statement under the given rules config
+fn run_with_rules_config(rules_config: &str) -> Output {
+    let scan_root = tempfile::tempdir().unwrap();
+    fs::write(scan_root.path().join("debug.ts"), "debugger;\n").unwrap();
+    fs::write(scan_root.path().join("rules.json"), rules_config).unwrap();
+
+    Command::new(env!("CARGO_BIN_EXE_scoper"))
+        .arg(scan_root.path())
+        .args(["--rules-config", "rules.json", "--output-dir", "-"])
+        .current_dir(scan_root.path())
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn test_exceeded_budget_fails_the_run() {
+    let output = run_with_rules_config(
+        r#"{ "rules": { "no-debugger": "error" }, "budgets": { "no-debugger": 0 } }"#,
+    );
+
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("1 rules exceeded their finding budget"));
+    assert!(stderr.contains("  no-debugger: 1 findings (budget: 0)"));
+}
+
+#[test]
+fn test_run_within_budget_succeeds() {
+    let output = run_with_rules_config(
+        r#"{ "rules": { "no-debugger": "error" }, "budgets": { "no-debugger": 1 } }"#,
+    );
+    assert!(output.status.success());
+
+    let output = run_with_rules_config(r#"{ "rules": { "no-debugger": "error" } }"#);
+    assert!(output.status.success());
+}
+
+#[test]
+fn test_invalid_budget_fails_the_run() {
+    let output = run_with_rules_config(
+        r#"{ "rules": { "no-debugger": "error" }, "budgets": { "no-debugger": "none" } }"#,
+    );
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("invalid budget for 'no-debugger'"));
+}
+
+#[test]
+fn test_exceeded_budgets_are_sorted_by_rule() {
+    let findings_by_rule = HashMap::from([
+        ("no-enum".to_string(), 3),
+        ("no-debugger".to_string(), 2),
+        ("no-as-any".to_string(), 1),
+    ]);
+    let budgets = HashMap::from([
+        ("no-enum".to_string(), 0),
+        ("no-debugger".to_string(), 1),
+        ("no-as-any".to_string(), 1),
+        ("no-shadow".to_string(), 0),
+    ]);
+
+    assert_eq!(
+        exceeded_budgets(&findings_by_rule, &budgets),
+        [
+            BudgetOverrun {
+                rule: "no-debugger".to_string(),
+                findings: 2,
+                budget: 1,
+            },
+            BudgetOverrun {
+                rule: "no-enum".to_string(),
+                findings: 3,
+                budget: 0,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_rules_config_without_budgets_has_none() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("rules.json");
+    fs::write(&path, r#"{ "rules": { "no-debugger": "error" } }"#).unwrap();
+
+    assert!(
+        load_rule_budgets(&path.to_string_lossy())
+            .unwrap()
+            .is_empty()
+    );
+}
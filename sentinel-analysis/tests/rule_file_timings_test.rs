@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+use std::fs;
+use std::process::Command;
+use std::time::Duration;
+
+use oxc_allocator::Allocator;
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::{SourceType, Span};
+use scoper::metrics::{RuleFileTiming, slowest_rule_file_timings};
+use scoper::{FileAnalysisResult, Rule, RulesRegistry};
+
+/// Time a node-based rule spends on each `debugger` statement
+const NODE_DELAY: Duration = Duration::from_millis(5);
+
+/// Node-based rule that takes [`NODE_DELAY`] per `debugger` statement
+struct SlowDebuggerRule {
+    name: &'static str,
+    report: bool,
+}
+
+impl Rule for SlowDebuggerRule {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn description(&self) -> &'static str {
+        "Sleeps on debugger statements"
+    }
+
+    fn run_on_node(&self, node: &AstKind, span: Span, _file_path: &str) -> Vec<OxcDiagnostic> {
+        if !matches!(node, AstKind::DebuggerStatement(_)) {
+            return Vec::new();
+        }
+        std::thread::sleep(NODE_DELAY);
+        if self.report {
+            vec![OxcDiagnostic::warn("debugger").with_label(span)]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+fn result(file_path: &str, rule_durations: &[(&str, u64)]) -> FileAnalysisResult {
+    FileAnalysisResult {
+        file_path: file_path.to_string(),
+        parse_duration: Duration::ZERO,
+        semantic_duration: Duration::ZERO,
+        rule_durations: rule_durations
+            .iter()
+            .map(|(rule, micros)| (rule.to_string(), Duration::from_micros(*micros)))
+            .collect::<HashMap<_, _>>(),
+        total_duration: Duration::ZERO,
+        diagnostics: Vec::new(),
+        imports: Vec::new(),
+        skip_reason: None,
+    }
+}
+
+#[test]
+fn test_slowest_combinations_come_first_and_are_capped() {
+    let results = vec![
+        result("/repo/src/a.ts", &[("no-shadow", 300), ("no-debugger", 10)]),
+        result(
+            "/repo/src/b.ts",
+            &[("no-shadow", 50), ("member-ordering", 4500)],
+        ),
+    ];
+
+    let timings = slowest_rule_file_timings(&results, 2, Some("/repo"));
+    assert_eq!(
+        timings,
+        [
+            RuleFileTiming {
+                rule: "member-ordering".to_string(),
+                file: "src/b.ts".to_string(),
+                duration_ms: 4.5,
+            },
+            RuleFileTiming {
+                rule: "no-shadow".to_string(),
+                file: "src/a.ts".to_string(),
+                duration_ms: 0.3,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_equal_durations_are_ordered_by_rule_and_file() {
+    let results = vec![
+        result("/repo/b.ts", &[("no-shadow", 100)]),
+        result("/repo/a.ts", &[("no-shadow", 100), ("no-as-any", 100)]),
+    ];
+
+    let timings = slowest_rule_file_timings(&results, 10, None);
+    let order: Vec<(&str, &str)> = timings
+        .iter()
+        .map(|timing| (timing.rule.as_str(), timing.file.as_str()))
+        .collect();
+    assert_eq!(
+        order,
+        [
+            ("no-as-any", "/repo/a.ts"),
+            ("no-shadow", "/repo/a.ts"),
+            ("no-shadow", "/repo/b.ts"),
+        ]
+    );
+}
+
+#[test]
+fn test_node_rule_durations_add_up_over_all_nodes() {
+    let mut registry = RulesRegistry::new();
+    for (name, report) in [("slow-reporting", true), ("slow-silent", false)] {
+        registry.register_rule(Box::new(SlowDebuggerRule { name, report }));
+        registry.enable_rule(name);
+    }
+
+    let source = "debugger;\ndebugger;\ndebugger;\n";
+    let allocator = Allocator::default();
+    let parsed = Parser::new(&allocator, source, SourceType::ts()).parse();
+    let semantic = SemanticBuilder::new().build(&parsed.program);
+    let (diagnostics, rule_durations) = registry.run_rules_with_metrics(&semantic, "a.ts", source);
+
+    assert_eq!(diagnostics.len(), 3);
+    // Each rule is timed on every node, whether or not it reported something there
+    for rule in ["slow-reporting", "slow-silent"] {
+        assert!(rule_durations[rule] >= NODE_DELAY * 3, "{}", rule);
+    }
+}
+
+#[test]
+fn test_cli_writes_rule_file_timings_to_output_dir() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("a.ts"), "debugger;\n").unwrap();
+    fs::write(dir.path().join("b.ts"), "const value = 1;\n").unwrap();
+    fs::write(
+        dir.path().join("rules.json"),
+        r#"{ "rules": { "no-debugger": "error", "no-shadow": "warn" } }"#,
+    )
+    .unwrap();
+    // An unreachable API keeps the run from submitting anything
+    fs::write(
+        dir.path().join("sentinel.json"),
+        r#"{ "api_url": "http://127.0.0.1:9" }"#,
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_scoper"))
+        .arg(dir.path())
+        .args(["--config", "sentinel.json", "--rules-config", "rules.json"])
+        .args(["--output-dir", "out", "--rule-file-timings", "3"])
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let json = fs::read_to_string(dir.path().join("out/rule_file_timings.json")).unwrap();
+    let timings: Vec<RuleFileTiming> = serde_json::from_str(&json).unwrap();
+    assert!(!timings.is_empty() && timings.len() <= 3);
+    assert!(
+        timings
+            .windows(2)
+            .all(|pair| pair[0].duration_ms >= pair[1].duration_ms)
+    );
+    assert!(
+        timings
+            .iter()
+            .all(|timing| timing.file == "a.ts" || timing.file == "b.ts")
+    );
+}
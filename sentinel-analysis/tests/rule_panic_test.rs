@@ -0,0 +1,89 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::SemanticBuilderReturn;
+use oxc_span::Span;
+use scoper::analyzer::analyze_source;
+use scoper::rules_registry::{RULE_ERROR_ID, Rule, configure_registry, create_default_registry};
+
+/// Rule that panics on every `debugger` statement
+struct PanicOnNodeRule;
+
+impl Rule for PanicOnNodeRule {
+    fn name(&self) -> &'static str {
+        "panic-on-node"
+    }
+
+    fn description(&self) -> &'static str {
+        "Panics on debugger statements"
+    }
+
+    fn run_on_node(&self, node: &AstKind, _span: Span, _file_path: &str) -> Vec<OxcDiagnostic> {
+        if matches!(node, AstKind::DebuggerStatement(_)) {
+            panic!("unexpected debugger statement");
+        }
+        Vec::new()
+    }
+}
+
+/// Rule that panics before looking at any node
+struct PanicOnSemanticRule;
+
+impl Rule for PanicOnSemanticRule {
+    fn name(&self) -> &'static str {
+        "panic-on-semantic"
+    }
+
+    fn description(&self) -> &'static str {
+        "Panics on every file"
+    }
+
+    fn run_on_semantic(
+        &self,
+        _semantic_result: &SemanticBuilderReturn,
+        _file_path: &str,
+    ) -> Vec<OxcDiagnostic> {
+        panic!("{} is broken", self.name());
+    }
+
+    fn run_on_node(&self, _node: &AstKind, _span: Span, _file_path: &str) -> Vec<OxcDiagnostic> {
+        unreachable!("rules that panicked are not run on the nodes of the file");
+    }
+}
+
+#[test]
+fn test_panicking_rules_report_a_rule_error_and_the_others_still_run() {
+    let mut registry = create_default_registry();
+    registry.register_rule(Box::new(PanicOnNodeRule));
+    registry.register_rule(Box::new(PanicOnSemanticRule));
+    configure_registry(
+        &mut registry,
+        &[
+            ("panic-on-node".to_string(), None, "error".to_string()),
+            ("panic-on-semantic".to_string(), None, "error".to_string()),
+            ("no-debugger".to_string(), None, "error".to_string()),
+        ],
+    );
+
+    let code = "debugger;\nfunction run() {\n  debugger;\n}\n";
+    let diagnostics = analyze_source("app.ts", code, &registry).diagnostics;
+
+    let rule_errors: Vec<String> = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.rule_id == RULE_ERROR_ID)
+        .map(|diagnostic| diagnostic.diagnostic.message.to_string())
+        .collect();
+    assert_eq!(
+        rule_errors,
+        [
+            "Rule `panic-on-semantic` panicked: panic-on-semantic is broken",
+            "Rule `panic-on-node` panicked: unexpected debugger statement",
+        ]
+    );
+
+    let debugger_lines: Vec<usize> = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.rule_id == "no-debugger")
+        .map(|diagnostic| diagnostic.line_number)
+        .collect();
+    assert_eq!(debugger_lines, [1, 3]);
+}
@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+use std::fs;
+use std::process::Command;
+use std::time::Duration;
+
+use scoper::{FileAnalysisResult, Metrics};
+use serde_json::Value;
+
+/// Result of a file on which `no-debugger` ran for `millis` milliseconds
+fn file_result(index: u64, millis: u64) -> FileAnalysisResult {
+    FileAnalysisResult {
+        file_path: format!("src/file-{}.ts", index),
+        parse_duration: Duration::ZERO,
+        semantic_duration: Duration::ZERO,
+        rule_durations: HashMap::from([("no-debugger".to_string(), Duration::from_millis(millis))]),
+        total_duration: Duration::from_millis(millis),
+        diagnostics: Vec::new(),
+        imports: Vec::new(),
+        skip_reason: None,
+    }
+}
+
+#[test]
+fn test_rule_metrics_hold_time_percentiles() {
+    let mut metrics = Metrics::new();
+    // Fast on most files, slow on one
+    for index in 1..=19 {
+        metrics.aggregate_file_result(file_result(index, index));
+    }
+    metrics.aggregate_file_result(file_result(20, 500));
+    metrics.stop();
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("metrics.json");
+    metrics.export_to_json(&path.to_string_lossy()).unwrap();
+
+    let history: Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+    let rule = &history[0]["rule_execution_metrics"][0];
+    assert_eq!(rule["rule_name"], "no-debugger");
+    assert_eq!(rule["execution_count"], 20);
+    assert_eq!(rule["p50_time_us"], 10_000.0);
+    assert_eq!(rule["p95_time_us"], 19_000.0);
+    assert_eq!(rule["max_time_us"], 500_000.0);
+    assert!(rule["avg_time_per_execution_us"].as_f64().unwrap() > 19_000.0);
+}
+
+#[test]
+fn test_metrics_of_older_versions_are_kept() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("metrics.json");
+
+    let mut metrics = Metrics::new();
+    metrics.aggregate_file_result(file_result(1, 1));
+    metrics.stop();
+    metrics.export_to_json(&path.to_string_lossy()).unwrap();
+
+    // Drop the percentiles as an older version would not have written them
+    let mut history: Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+    for key in ["p50_time_us", "p95_time_us", "max_time_us"] {
+        history[0]["rule_execution_metrics"][0]
+            .as_object_mut()
+            .unwrap()
+            .remove(key);
+    }
+    fs::write(&path, history.to_string()).unwrap();
+
+    metrics.export_to_json(&path.to_string_lossy()).unwrap();
+    let history: Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(history.as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn test_trace_summary_prints_rule_percentiles() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("a.ts"), "debugger;\n").unwrap();
+    fs::write(
+        dir.path().join("rules.json"),
+        r#"{ "rules": { "no-debugger": "error" } }"#,
+    )
+    .unwrap();
+    // An unreachable API keeps the run from submitting anything
+    fs::write(
+        dir.path().join("sentinel.json"),
+        r#"{ "api_url": "http://127.0.0.1:9" }"#,
+    )
+    .unwrap();
+
+    let run = |debug_level: &str| {
+        let output = Command::new(env!("CARGO_BIN_EXE_scoper"))
+            .arg(dir.path())
+            .args(["--config", "sentinel.json", "--rules-config", "rules.json"])
+            .args(["--debug-level", debug_level])
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    let trace = run("trace");
+    assert!(
+        trace.contains("--- Rule Execution Metrics ---"),
+        "{}",
+        trace
+    );
+    assert!(trace.contains("P95 (μs)"));
+    assert!(!run("info").contains("--- Rule Execution Metrics ---"));
+}
@@ -0,0 +1,54 @@
+use scoper::commands::rules_catalog::build_catalog;
+use scoper::rules_registry::create_default_registry;
+use serde_json::json;
+
+#[test]
+fn test_catalog_lists_every_registered_rule_sorted() {
+    let registry = create_default_registry();
+    let catalog = build_catalog(&registry);
+
+    assert_eq!(catalog.len(), registry.get_registered_rules().len());
+    assert!(catalog.windows(2).all(|pair| pair[0].id < pair[1].id));
+}
+
+#[test]
+fn test_catalog_reports_severity_tags_and_schema() {
+    let catalog = build_catalog(&create_default_registry());
+    let entry = |id: &str| catalog.iter().find(|entry| entry.id == id).unwrap();
+
+    let no_debugger = entry("no-debugger");
+    assert_eq!(no_debugger.default_severity, "error");
+    assert!(no_debugger.config_schema.is_none());
+
+    let no_shadow = entry("no-shadow");
+    assert_eq!(no_shadow.default_severity, "warning");
+    assert_eq!(
+        no_shadow.config_schema.as_ref().unwrap()["properties"]["ignoreParameters"]["default"],
+        json!(false)
+    );
+
+    assert_eq!(entry("angular-no-inner-html").tags, ["angular", "security"]);
+}
+
+#[test]
+fn test_declared_schemas_accept_their_defaults() {
+    for entry in build_catalog(&create_default_registry()) {
+        let Some(schema) = entry.config_schema else {
+            continue;
+        };
+        let validator = jsonschema::validator_for(&schema)
+            .unwrap_or_else(|err| panic!("invalid schema for {}: {}", entry.id, err));
+
+        let defaults: serde_json::Map<_, _> = schema["properties"]
+            .as_object()
+            .unwrap()
+            .iter()
+            .map(|(key, property)| (key.clone(), property["default"].clone()))
+            .collect();
+        assert!(
+            validator.is_valid(&json!(defaults)),
+            "defaults of {} do not match its schema",
+            entry.id
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::Span;
+use scoper::SentinelError;
+use scoper::rules_registry::{
+    NoDebuggerRule, NoShadowRule, Rule, configure_registry, create_default_registry,
+    load_rule_config,
+};
+
+fn rule(
+    name: &str,
+    config: Option<serde_json::Value>,
+) -> (String, Option<serde_json::Value>, String) {
+    (name.to_string(), config, "error".to_string())
+}
+
+#[test]
+fn test_enabled_rules_are_sorted_regardless_of_insertion_order() {
+    let mut forward = create_default_registry();
+    forward.enable_rules(&["no-debugger", "max-exports-per-file", "no-unreachable"]);
+
+    let mut reverse = create_default_registry();
+    reverse.enable_rules(&["no-unreachable", "max-exports-per-file", "no-debugger"]);
+
+    let expected = vec![
+        "max-exports-per-file".to_string(),
+        "no-debugger".to_string(),
+        "no-unreachable".to_string(),
+    ];
+    assert_eq!(forward.get_enabled_rules(), expected);
+    assert_eq!(reverse.get_enabled_rules(), expected);
+    assert_eq!(forward.get_execution_order(), expected.as_slice());
+    assert_eq!(reverse.get_execution_order(), expected.as_slice());
+}
+
+#[test]
+fn test_execution_order_respects_priority() {
+    let mut registry = create_default_registry();
+    configure_registry(
+        &mut registry,
+        &[
+            rule("max-exports-per-file", None),
+            rule(
+                "no-unreachable",
+                Some(serde_json::json!({ "priority": 10 })),
+            ),
+            rule("no-debugger", Some(serde_json::json!({ "priority": -1 }))),
+        ],
+    );
+
+    assert_eq!(
+        registry.get_execution_order(),
+        ["no-unreachable", "max-exports-per-file", "no-debugger"]
+    );
+}
+
+#[test]
+fn test_load_rule_config_reports_structured_errors() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let missing = dir.path().join("missing.json");
+    let err = load_rule_config(&missing.to_string_lossy()).unwrap_err();
+    assert!(matches!(err, SentinelError::Io { .. }));
+
+    let invalid = dir.path().join("invalid.json");
+    std::fs::write(&invalid, r#"{ "rules": { "no-debugger": 1 } }"#).unwrap();
+    let err = load_rule_config(&invalid.to_string_lossy()).unwrap_err();
+    assert!(matches!(err, SentinelError::ConfigValidation(_)));
+    assert!(err.to_string().contains("no-debugger"));
+}
+
+/// Coerce a rule's node hook to the shared `run_on_node` signature
+fn assert_run_on_node_signature<R: Rule + ?Sized>() {
+    let _: for<'a> fn(&R, &AstKind<'a>, Span, &str) -> Vec<OxcDiagnostic> = R::run_on_node;
+}
+
+#[test]
+fn test_rules_share_the_vec_run_on_node_signature() {
+    // Fails to compile if the node hook changes arity or stops returning a Vec
+    assert_run_on_node_signature::<dyn Rule>();
+    assert_run_on_node_signature::<NoDebuggerRule>();
+    assert_run_on_node_signature::<NoShadowRule>();
+}
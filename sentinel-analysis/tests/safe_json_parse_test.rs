@@ -0,0 +1,55 @@
+mod common;
+
+use common::{run_rule, run_rule_with_config};
+use serde_json::json;
+
+const RULE: &str = "safe-json-parse";
+
+#[test]
+fn test_unwrapped_call_is_flagged() {
+    let code = "const settings = JSON.parse(localStorage.getItem('settings'));\n";
+
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line_number, 1);
+    assert_eq!(diagnostics[0].column_number, 18);
+}
+
+#[test]
+fn test_calls_inside_try_catch_are_allowed() {
+    let code = "function load(raw: string) {\n\
+                  try {\n\
+                    if (raw) { return JSON.parse(raw); }\n\
+                  } catch {\n\
+                    return null;\n\
+                  }\n\
+                }\n";
+    assert!(run_rule(RULE, code).is_empty());
+}
+
+#[test]
+fn test_try_without_catch_and_callbacks_are_flagged() {
+    let code = "try {\n\
+                  JSON.parse(a);\n\
+                } finally {\n\
+                  done();\n\
+                }\n\
+                try {\n\
+                  items.map((item) => JSON.parse(item));\n\
+                } catch (e) {\n\
+                  JSON.parse(fallback);\n\
+                }\n";
+
+    let diagnostics = run_rule(RULE, code);
+    let lines: Vec<usize> = diagnostics.iter().map(|d| d.line_number).collect();
+    assert_eq!(lines, [2, 7, 9]);
+}
+
+#[test]
+fn test_string_literal_arguments_can_be_ignored() {
+    let code = "const defaults = JSON.parse('{\"retries\": 3}');\n";
+    assert_eq!(run_rule(RULE, code).len(), 1);
+
+    let config = Some(json!({ "ignoreStringLiterals": true }));
+    assert!(run_rule_with_config(RULE, config, "test-file.ts", code).is_empty());
+}
@@ -0,0 +1,71 @@
+use std::fs;
+use std::path::Path;
+
+use scoper::utilities::file_utils::find_source_files;
+
+fn write(path: &Path, contents: &str) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, contents).unwrap();
+}
+
+/// Analyzed files below `root`, relative to it and sorted
+fn found_files(root: &Path) -> Vec<String> {
+    let mut files: Vec<String> = find_source_files(&root.to_string_lossy(), &["ts"])
+        .iter()
+        .map(|file| {
+            Path::new(file)
+                .strip_prefix(root)
+                .unwrap()
+                .to_string_lossy()
+                .replace('\\', "/")
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+#[test]
+fn test_sentinelignore_in_scan_root_excludes_paths() {
+    let dir = tempfile::tempdir().unwrap();
+    write(
+        &dir.path().join(".sentinelignore"),
+        "generated/\n*.spec.ts\n",
+    );
+    write(&dir.path().join("src/app.ts"), "");
+    write(&dir.path().join("src/app.spec.ts"), "");
+    write(&dir.path().join("generated/api.ts"), "");
+
+    assert_eq!(found_files(dir.path()), ["src/app.ts"]);
+}
+
+#[test]
+fn test_nested_sentinelignore_overrides_its_parents() {
+    let dir = tempfile::tempdir().unwrap();
+    write(&dir.path().join(".sentinelignore"), "*.spec.ts\n");
+    write(&dir.path().join("src/legacy/.sentinelignore"), "*.ts\n");
+    write(
+        &dir.path().join("src/keep/.sentinelignore"),
+        "!app.spec.ts\n",
+    );
+    write(&dir.path().join("src/legacy/old.ts"), "");
+    write(&dir.path().join("src/keep/app.spec.ts"), "");
+    write(&dir.path().join("src/other.spec.ts"), "");
+    write(&dir.path().join("src/main.ts"), "");
+
+    assert_eq!(
+        found_files(dir.path()),
+        ["src/keep/app.spec.ts", "src/main.ts"]
+    );
+}
+
+#[test]
+fn test_sentinelignore_in_parent_of_scan_root_is_not_read() {
+    // Files outside the scan root do not change what a scan of the tree sees
+    let dir = tempfile::tempdir().unwrap();
+    write(&dir.path().join(".sentinelignore"), "*.spec.ts\n");
+    let root = dir.path().join("project");
+    write(&root.join("src/app.ts"), "");
+    write(&root.join("src/app.spec.ts"), "");
+
+    assert_eq!(found_files(&root), ["src/app.spec.ts", "src/app.ts"]);
+}
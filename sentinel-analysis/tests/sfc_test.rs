@@ -0,0 +1,70 @@
+use oxc_span::SourceType;
+use scoper::analyzer::process_files;
+use scoper::rules_registry::{configure_registry, create_default_registry};
+use scoper::utilities::DebugLevel;
+use scoper::utilities::sfc::{PositionMapper, extract_script_block};
+use std::fs;
+use std::sync::Arc;
+
+#[test]
+fn test_extract_prefers_typescript_block() {
+    let source = "<script>\nexport default {}\n</script>\n<script setup lang=\"ts\">\nconst a = 1;\n</script>\n";
+
+    let block = extract_script_block(source).unwrap();
+    assert!(block.typescript);
+    assert_eq!(block.content, "\nconst a = 1;\n");
+    assert_eq!(&source[block.offset..block.offset + 1], "\n");
+}
+
+#[test]
+fn test_extract_skips_unterminated_trailing_block() {
+    let source = "<script lang=\"ts\">\nconst a = 1;\n</script>\n<script";
+
+    let block = extract_script_block(source).unwrap();
+    assert_eq!(block.content, "\nconst a = 1;\n");
+
+    let source = "<script lang=\"ts\">\nconst a = 1;\n</script>\n<script>\nconst b = 2;\n";
+    let block = extract_script_block(source).unwrap();
+    assert_eq!(block.content, "\nconst a = 1;\n");
+}
+
+#[test]
+fn test_extract_tsx_block_parses_as_tsx() {
+    let source = "<script lang=\"tsx\">\nconst a = <div />;\n</script>\n";
+
+    let block = extract_script_block(source).unwrap();
+    assert_eq!(block.source_type(), SourceType::tsx());
+}
+
+#[test]
+fn test_position_mapper_shifts_lines_and_first_line_columns() {
+    let source = "<template></template>\n<script lang=\"ts\">const a = 1;\n</script>";
+    let block = extract_script_block(source).unwrap();
+    let mapper = PositionMapper::new(source, block.offset);
+
+    assert_eq!(mapper.map(1, 1), (2, 19));
+    assert_eq!(mapper.map(2, 4), (3, 4));
+    assert_eq!(mapper.map(0, 0), (0, 0));
+}
+
+#[test]
+fn test_sfc_findings_point_into_original_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("App.vue");
+    fs::write(
+        &file,
+        "<template>\n  <div />\n</template>\n<script lang=\"ts\">\nconst a = 1;\n  debugger;\n</script>\n",
+    )
+    .unwrap();
+
+    let mut registry = create_default_registry();
+    configure_registry(
+        &mut registry,
+        &[("no-debugger".to_string(), None, "error".to_string())],
+    );
+
+    let files = vec![file.to_string_lossy().to_string()];
+    let (results, _) = process_files(&files, &Arc::new(registry), DebugLevel::None);
+    let diagnostic = &results[0].diagnostics[0];
+    assert_eq!((diagnostic.line_number, diagnostic.column_number), (6, 3));
+}
@@ -0,0 +1,104 @@
+use scoper::commands::merge::merge_findings;
+use scoper::exporter::{DEFAULT_TOP_FILES, FindingEntry, FindingsExport, FindingsSummary};
+use scoper::utilities::file_utils::{Shard, select_shard};
+use std::collections::HashMap;
+
+fn finding(rule: &str, file: &str, severity: &str) -> FindingEntry {
+    FindingEntry {
+        rule: rule.to_string(),
+        message: format!("{} finding", rule),
+        file: file.to_string(),
+        line: 1,
+        column: 1,
+        severity: severity.to_string(),
+        help: None,
+        fingerprint: String::new(),
+        snippet: None,
+    }
+}
+
+fn export(findings: Vec<FindingEntry>, files_processed: usize, analysis_ms: u64) -> FindingsExport {
+    FindingsExport {
+        summary: FindingsSummary {
+            total_findings: findings.len(),
+            findings_by_rule: HashMap::new(),
+            findings_by_severity: HashMap::new(),
+            timestamp: String::new(),
+            total_duration_ms: analysis_ms,
+            files_processed,
+            files_per_second_wall_time: 0.0,
+            parallel_cores_used: 4,
+            parallel_efficiency_percent: 50.0,
+            scan_duration_ms: 0,
+            analysis_duration_ms: analysis_ms,
+            coverage: Default::default(),
+            findings_by_file_top: Vec::new(),
+        },
+        findings,
+    }
+}
+
+#[test]
+fn test_parse_shard() {
+    assert_eq!("2/4".parse::<Shard>(), Ok(Shard { index: 2, total: 4 }));
+    assert!("0/4".parse::<Shard>().is_err());
+    assert!("5/4".parse::<Shard>().is_err());
+    assert!("1/0".parse::<Shard>().is_err());
+    assert!("3".parse::<Shard>().is_err());
+}
+
+#[test]
+fn test_shards_partition_files() {
+    let files: Vec<String> = (0..50)
+        .map(|i| format!("/checkout/src/file{}.ts", i))
+        .collect();
+
+    let mut seen = Vec::new();
+    for index in 1..=3 {
+        let shard = Shard { index, total: 3 };
+        seen.extend(select_shard(files.clone(), &shard, "/checkout"));
+    }
+    seen.sort();
+
+    let mut expected = files.clone();
+    expected.sort();
+    assert_eq!(seen, expected);
+
+    // Assignment depends only on the path relative to the scan root
+    let shard = Shard { index: 1, total: 3 };
+    let moved: Vec<String> = files
+        .iter()
+        .map(|f| f.replace("/checkout", "/elsewhere"))
+        .collect();
+    assert_eq!(
+        select_shard(files.clone(), &shard, "/checkout").len(),
+        select_shard(moved, &shard, "/elsewhere").len()
+    );
+}
+
+#[test]
+fn test_merge_recomputes_summary() {
+    let merged = merge_findings(
+        vec![
+            export(
+                vec![
+                    finding("no-debugger", "a.ts", "error"),
+                    finding("no-var", "a.ts", "warning"),
+                ],
+                10,
+                1000,
+            ),
+            export(vec![finding("no-debugger", "b.ts", "error")], 30, 2000),
+        ],
+        DEFAULT_TOP_FILES,
+    );
+
+    assert_eq!(merged.findings.len(), 3);
+    assert_eq!(merged.summary.total_findings, 3);
+    assert_eq!(merged.summary.findings_by_rule["no-debugger"], 2);
+    assert_eq!(merged.summary.findings_by_severity["warning"], 1);
+    assert_eq!(merged.summary.files_processed, 40);
+    assert_eq!(merged.summary.analysis_duration_ms, 2000);
+    assert_eq!(merged.summary.parallel_cores_used, 8);
+    assert_eq!(merged.summary.files_per_second_wall_time, 20.0);
+}
@@ -0,0 +1,77 @@
+use std::fs;
+use std::process::Command;
+
+use scoper::analyzer::analyze_source;
+use scoper::exporter::{SourceSnippet, source_snippet};
+use scoper::rules_registry::{configure_registry, create_default_registry};
+
+/// Snippets of the no-debugger findings in `code`
+fn snippets(code: &str) -> Vec<Option<SourceSnippet>> {
+    let mut registry = create_default_registry();
+    configure_registry(
+        &mut registry,
+        &[("no-debugger".to_string(), None, "error".to_string())],
+    );
+
+    analyze_source("src/app.ts", code, &registry)
+        .diagnostics
+        .iter()
+        .map(source_snippet)
+        .collect()
+}
+
+#[test]
+fn test_snippet_includes_context_lines() {
+    let code =
+        "const a = 1;\nconst b = 2;\nfunction run() {\n  debugger;\n}\nrun();\nconst c = 3;\n";
+
+    let snippet = snippets(code).remove(0).unwrap();
+    assert_eq!(snippet.start_line, 2);
+    assert_eq!(
+        snippet.lines,
+        [
+            "const b = 2;",
+            "function run() {",
+            "  debugger;",
+            "}",
+            "run();"
+        ]
+    );
+}
+
+#[test]
+fn test_snippet_is_capped() {
+    let long_line = format!("debugger; // {}", "x".repeat(500));
+
+    let snippet = snippets(&long_line).remove(0).unwrap();
+    assert_eq!(snippet.start_line, 1);
+    assert_eq!(snippet.lines.len(), 1);
+    assert_eq!(snippet.lines[0].chars().count(), 203);
+    assert!(snippet.lines[0].ends_with("..."));
+}
+
+#[test]
+fn test_snippets_are_only_exported_when_requested() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("debug.ts"), "let a = 1;\ndebugger;\n").unwrap();
+
+    let run = |extra: &[&str]| {
+        let output = Command::new(env!("CARGO_BIN_EXE_scoper"))
+            .arg(dir.path())
+            .args(["--rules", "no-debugger", "--output-dir", "-"])
+            .args(extra)
+            .output()
+            .unwrap();
+        serde_json::from_slice::<serde_json::Value>(&output.stdout).unwrap()
+    };
+
+    let without = run(&[]);
+    assert!(without["findings"][0].get("snippet").is_none());
+
+    let with = run(&["--include-snippets"]);
+    assert_eq!(with["findings"][0]["snippet"]["start_line"], 1);
+    assert_eq!(
+        with["findings"][0]["snippet"]["lines"],
+        serde_json::json!(["let a = 1;", "debugger;"])
+    );
+}
@@ -0,0 +1,26 @@
+use std::fs;
+use std::process::Command;
+
+use scoper::exporter::FindingsExport;
+
+#[test]
+fn test_output_dir_dash_prints_only_findings_json() {
+    let scan_root = tempfile::tempdir().unwrap();
+    fs::write(scan_root.path().join("debug.ts"), "debugger;\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_scoper"))
+        .arg(scan_root.path())
+        .args(["--output-dir", "-", "--debug-level", "debug"])
+        .current_dir(scan_root.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    // stdout must parse as a whole, so no log line or summary table may leak into it
+    let export: FindingsExport = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(export.summary.files_processed, 1);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("skipping API submission"));
+
+    // Nothing is written to a directory named `-`
+    assert!(!scan_root.path().join("-").exists());
+}
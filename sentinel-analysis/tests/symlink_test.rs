@@ -0,0 +1,52 @@
+#![cfg(unix)]
+
+use std::fs;
+use std::os::unix::fs::symlink;
+
+use scoper::utilities::file_utils::walk_source_files;
+
+/// Scan root with `src/a.ts`, a link to `src`, a link to `a.ts` and a cycle back to the root
+fn scan_root_with_links() -> tempfile::TempDir {
+    let root = tempfile::tempdir().unwrap();
+    let src = root.path().join("src");
+    fs::create_dir(&src).unwrap();
+    fs::write(src.join("a.ts"), "const a = 1;\n").unwrap();
+
+    symlink(&src, root.path().join("linked")).unwrap();
+    symlink(src.join("a.ts"), root.path().join("alias.ts")).unwrap();
+    symlink(root.path(), src.join("loop")).unwrap();
+    root
+}
+
+#[test]
+fn test_symlinks_are_not_followed_by_default() {
+    let root = scan_root_with_links();
+    let files = walk_source_files(&root.path().to_string_lossy(), &["ts"], false);
+
+    assert_eq!(files.len(), 1);
+    assert!(files[0].ends_with("src/a.ts"));
+}
+
+#[test]
+fn test_followed_symlinks_skip_cycles_and_duplicates() {
+    let root = scan_root_with_links();
+    let files = walk_source_files(&root.path().to_string_lossy(), &["ts"], true);
+
+    // Every path resolves to the same file, which is reported once
+    assert_eq!(files.len(), 1);
+}
+
+#[test]
+fn test_followed_symlinks_reach_linked_directories() {
+    let root = tempfile::tempdir().unwrap();
+    let shared = tempfile::tempdir().unwrap();
+    fs::write(shared.path().join("shared.ts"), "export {};\n").unwrap();
+    symlink(shared.path(), root.path().join("shared")).unwrap();
+
+    let root_path = root.path().to_string_lossy().to_string();
+    assert!(walk_source_files(&root_path, &["ts"], false).is_empty());
+
+    let files = walk_source_files(&root_path, &["ts"], true);
+    assert_eq!(files.len(), 1);
+    assert!(files[0].ends_with("shared/shared.ts"));
+}
@@ -0,0 +1,57 @@
+use scoper::exporter::{FindingsSummary, TableStyle, render_rule_summary};
+use std::collections::HashMap;
+
+fn summary() -> FindingsSummary {
+    FindingsSummary {
+        total_findings: 3,
+        findings_by_rule: HashMap::from([
+            ("no-debugger".to_string(), 2),
+            ("no-as-any".to_string(), 1),
+        ]),
+        findings_by_severity: HashMap::new(),
+        timestamp: String::new(),
+        total_duration_ms: 0,
+        files_processed: 1,
+        files_per_second_wall_time: 0.0,
+        parallel_cores_used: 1,
+        parallel_efficiency_percent: 0.0,
+        scan_duration_ms: 0,
+        analysis_duration_ms: 0,
+        coverage: Default::default(),
+        findings_by_file_top: Vec::new(),
+    }
+}
+
+#[test]
+fn test_parse_table_style() {
+    assert_eq!("ascii".parse::<TableStyle>(), Ok(TableStyle::Ascii));
+    assert_eq!("markdown".parse::<TableStyle>(), Ok(TableStyle::Markdown));
+    assert_eq!("minimal".parse::<TableStyle>(), Ok(TableStyle::Minimal));
+    assert!("html".parse::<TableStyle>().is_err());
+    assert_eq!(TableStyle::default(), TableStyle::Ascii);
+}
+
+#[test]
+fn test_markdown_summary_is_a_github_table() {
+    let rendered = render_rule_summary(&summary(), TableStyle::Markdown);
+    let lines: Vec<&str> = rendered.lines().filter(|line| !line.is_empty()).collect();
+
+    assert!(lines[0].starts_with("| Rule"));
+    assert!(lines[1].starts_with("|-"));
+    assert!(lines[2].starts_with("| no-as-any"));
+    assert!(lines[3].starts_with("| no-debugger"));
+    assert_eq!(lines[4], "Total: 3 issues found");
+    assert!(!rendered.contains("----------------\n"));
+}
+
+#[test]
+fn test_ascii_and_minimal_summaries() {
+    let ascii = render_rule_summary(&summary(), TableStyle::Ascii);
+    assert!(ascii.contains("Rule hit summary:"));
+    assert!(ascii.contains(".--"));
+
+    let minimal = render_rule_summary(&summary(), TableStyle::Minimal);
+    assert!(!minimal.contains('|'));
+    assert!(minimal.contains("no-debugger"));
+    assert!(minimal.contains("Total: 3 issues found"));
+}
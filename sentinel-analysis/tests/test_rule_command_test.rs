@@ -0,0 +1,63 @@
+use std::fs;
+use std::process::Command;
+
+use scoper::commands::test_rule::render_rule_findings;
+use serde_json::json;
+
+#[test]
+fn test_render_lists_findings_with_positions() {
+    let output =
+        render_rule_findings("no-enum", None, "snippet.ts", "\nenum Color { Red }\n").unwrap();
+    let lines: Vec<&str> = output.lines().collect();
+
+    assert_eq!(
+        lines[0],
+        "snippet.ts:2:6 warning no-enum: Unexpected enum `Color`"
+    );
+    assert!(lines[1].starts_with("  help: "));
+    assert_eq!(lines[2], "1 findings");
+}
+
+#[test]
+fn test_only_the_given_rule_runs_with_its_options() {
+    let code = "debugger;\nconst enum Color { Red }\n";
+
+    let output =
+        render_rule_findings("no-enum", Some(json!({ "allowConst": true })), "a.ts", code).unwrap();
+    assert_eq!(output, "0 findings\n");
+}
+
+#[test]
+fn test_unknown_rule_is_an_error() {
+    let error = render_rule_findings("no-such-rule", None, "a.ts", "").unwrap_err();
+    assert!(error.to_string().contains("Unknown rule 'no-such-rule'"));
+}
+
+#[test]
+fn test_command_reads_snippet_from_code_or_file() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("app.ts"), "const a = 1;\ndebugger;\n").unwrap();
+
+    let run = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_scoper"))
+            .args(["test-rule", "--rule", "no-debugger"])
+            .args(args)
+            .current_dir(dir.path())
+            .output()
+            .unwrap()
+    };
+
+    let output = run(&["--code", "debugger;"]);
+    assert!(output.status.success());
+    assert!(
+        String::from_utf8_lossy(&output.stdout).starts_with("snippet.ts:1:1 error no-debugger:")
+    );
+
+    let output = run(&["--file", "app.ts"]);
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).starts_with("app.ts:2:1 error no-debugger:"));
+
+    let output = run(&["--code", "debugger;", "--options", "{"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Invalid rule options JSON"));
+}
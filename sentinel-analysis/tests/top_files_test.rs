@@ -0,0 +1,105 @@
+use scoper::commands::merge::merge_findings;
+use scoper::exporter::{
+    DEFAULT_TOP_FILES, FileHits, FindingEntry, FindingsExport, TableStyle, render_top_files,
+    top_files_by_findings,
+};
+
+fn finding(file: &str) -> FindingEntry {
+    FindingEntry {
+        rule: "no-debugger".to_string(),
+        message: "Unexpected debugger statement".to_string(),
+        file: file.to_string(),
+        line: 1,
+        column: 1,
+        severity: "error".to_string(),
+        help: None,
+        fingerprint: String::new(),
+        snippet: None,
+    }
+}
+
+fn hits(file: &str, findings: usize) -> FileHits {
+    FileHits {
+        file: file.to_string(),
+        findings,
+    }
+}
+
+#[test]
+fn test_files_are_ranked_by_findings() {
+    let findings: Vec<FindingEntry> = ["b.ts", "a.ts", "c.ts", "c.ts", "b.ts", "c.ts", "d.ts"]
+        .iter()
+        .map(|file| finding(file))
+        .collect();
+
+    assert_eq!(
+        top_files_by_findings(&findings, 3),
+        [hits("c.ts", 3), hits("b.ts", 2), hits("a.ts", 1)]
+    );
+    assert!(top_files_by_findings(&findings, 0).is_empty());
+}
+
+#[test]
+fn test_top_files_table_follows_the_table_style() {
+    let top_files = [hits("src/app.ts", 12), hits("src/util.ts", 3)];
+
+    let ascii = render_top_files(&top_files, TableStyle::Ascii);
+    assert!(ascii.contains("Most violated files:"));
+    assert!(ascii.contains("src/app.ts"));
+
+    let markdown = render_top_files(&top_files, TableStyle::Markdown);
+    let lines: Vec<&str> = markdown.lines().filter(|line| !line.is_empty()).collect();
+    assert!(lines[0].starts_with("| File"));
+    assert!(lines[2].starts_with("| src/app.ts"));
+    assert!(lines[2].ends_with("12 |"));
+
+    assert!(render_top_files(&[], TableStyle::Ascii).is_empty());
+}
+
+#[test]
+fn test_merge_ranks_files_across_shards() {
+    let shard = |files: &[&str], top: Vec<FileHits>| {
+        let mut export: FindingsExport = serde_json::from_value(serde_json::json!({
+            "findings": [],
+            "summary": {
+                "total_findings": 0, "findings_by_rule": {}, "findings_by_severity": {},
+                "timestamp": "", "total_duration_ms": 0, "files_processed": 0,
+                "files_per_second_wall_time": 0.0, "parallel_cores_used": 1,
+                "parallel_efficiency_percent": 0.0, "scan_duration_ms": 0,
+                "analysis_duration_ms": 0
+            }
+        }))
+        .unwrap();
+        export.findings = files.iter().map(|file| finding(file)).collect();
+        export.summary.findings_by_file_top = top;
+        export
+    };
+
+    let merged = merge_findings(
+        vec![
+            shard(&["a.ts", "a.ts"], vec![hits("a.ts", 2)]),
+            shard(
+                &["b.ts", "b.ts", "b.ts", "c.ts"],
+                vec![hits("b.ts", 3), hits("c.ts", 1)],
+            ),
+        ],
+        2,
+    );
+    assert_eq!(
+        merged.summary.findings_by_file_top,
+        [hits("b.ts", 3), hits("a.ts", 2)]
+    );
+
+    // The merged list may be longer than the list of any shard
+    let merged = merge_findings(
+        vec![
+            shard(&["a.ts"], vec![hits("a.ts", 1)]),
+            shard(&["b.ts", "b.ts"], vec![hits("b.ts", 2)]),
+        ],
+        DEFAULT_TOP_FILES,
+    );
+    assert_eq!(
+        merged.summary.findings_by_file_top,
+        [hits("b.ts", 2), hits("a.ts", 1)]
+    );
+}
@@ -0,0 +1,36 @@
+mod common;
+
+use common::run_rule_with_config;
+use serde_json::json;
+
+const RULE: &str = "typescript-max-union-members";
+
+#[test]
+fn test_union_over_limit_is_flagged() {
+    let code = "type Size = 'xs' | 'sm' | 'md' | 'lg';";
+
+    let diagnostics = run_rule_with_config(RULE, Some(json!({ "max": 3 })), "size.ts", code);
+    assert_eq!(diagnostics.len(), 1);
+}
+
+#[test]
+fn test_union_within_limit_is_clean() {
+    let code = "type Size = 'sm' | 'md' | 'lg';";
+
+    assert!(run_rule_with_config(RULE, Some(json!({ "max": 3 })), "size.ts", code).is_empty());
+}
+
+#[test]
+fn test_nested_unions_are_flattened_and_reported_once() {
+    let code = "type Mixed = ('a' | 'b') | ('c' | 'd');";
+
+    let diagnostics = run_rule_with_config(RULE, Some(json!({ "max": 3 })), "mixed.ts", code);
+    assert_eq!(diagnostics.len(), 1);
+    assert!(
+        diagnostics[0]
+            .diagnostic
+            .labels
+            .as_ref()
+            .is_some_and(|labels| labels[0].label().unwrap_or_default().contains("4 members"))
+    );
+}
@@ -0,0 +1,31 @@
+mod common;
+
+use common::run_rule;
+
+const RULE: &str = "typescript-prefer-abstract-class";
+
+#[test]
+fn test_constructor_that_throws_is_flagged() {
+    let code = "export class Shape {\n  constructor() {\n    throw new Error('Shape is abstract');\n  }\n}\n";
+
+    let diagnostics = run_rule(RULE, code);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line_number, 1);
+    assert_eq!(diagnostics[0].column_number, 8);
+}
+
+#[test]
+fn test_new_target_guard_is_flagged() {
+    let code = "class Repository {\n  constructor() {\n    if (new.target === Repository) {\n      throw new Error('Not implemented');\n    }\n  }\n}\n";
+
+    assert_eq!(run_rule(RULE, code).len(), 1);
+}
+
+#[test]
+fn test_abstract_and_regular_classes_are_allowed() {
+    let code = "abstract class Shape {\n  constructor() {\n    throw new Error('abstract');\n  }\n}\n\
+                class Circle {\n  constructor(private radius: number) {\n    if (radius < 0) throw new Error('negative');\n  }\n}\n\
+                class Square {\n  constructor() {\n    this.init();\n    throw new Error('late');\n  }\n}\n";
+
+    assert!(run_rule(RULE, code).is_empty());
+}